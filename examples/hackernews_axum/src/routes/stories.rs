@@ -85,7 +85,7 @@ pub fn Stories(cx: Scope) -> impl IntoView {
                 <div>
                     <Transition
                         fallback=move || view! { cx,  <p>"Loading..."</p> }
-                        set_pending=set_pending.into()
+                        set_pending=set_pending
                     >
                         {move || match stories.read(cx) {
                             None => None,
@@ -17,11 +17,15 @@ use futures::{Stream, StreamExt};
 use http::StatusCode;
 use leptos::{
     leptos_server::{server_fn_by_path, Payload},
-    server_fn::Encoding,
+    server_fn::{csrf, is_form_submission, Encoding},
     ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+use leptos_integration_utils::{
+    body_prefix, build_async_response, html_parts_for_scope, with_head_patches,
+    IMAGE_CACHE_CONTROL,
+};
+pub use leptos_integration_utils::{ImageError, ImageOptimizer};
 use leptos_meta::*;
 use leptos_router::*;
 use parking_lot::RwLock;
@@ -218,7 +222,23 @@ pub fn handle_server_fns_with_context(
                         Encoding::Url | Encoding::Cbor => body_ref,
                         Encoding::GetJSON | Encoding::GetCBOR => query,
                     };
-                    let res = match (server_fn.trait_obj)(cx, data).await {
+                    // mutating requests must echo back the CSRF cookie
+                    let csrf_ok = match &server_fn.encoding {
+                        Encoding::Url | Encoding::Cbor => csrf::check_csrf(
+                            header_str(&req, header::COOKIE.as_str()),
+                            header_str(&req, csrf::CSRF_HEADER),
+                            body_ref,
+                        ),
+                        Encoding::GetJSON | Encoding::GetCBOR => true,
+                    };
+                    let result = if csrf_ok {
+                        (server_fn.trait_obj)(cx, data).await
+                    } else {
+                        Err(ServerFnError::Forbidden(
+                            "invalid CSRF token".to_string(),
+                        ))
+                    };
+                    let res = match result {
                         Ok(serialized) => {
                             let res_options =
                                 use_context::<ResponseOptions>(cx).unwrap();
@@ -276,10 +296,38 @@ pub fn handle_server_fns_with_context(
                                 }
                             }
                         }
-                        Err(e) => HttpResponse::InternalServerError().body(
-                            serde_json::to_string(&e)
-                                .unwrap_or_else(|_| e.to_string()),
-                        ),
+                        Err(e) => {
+                            let content_type =
+                                header_str(&req, header::CONTENT_TYPE.as_str());
+                            if !is_form_submission(content_type, accept_header)
+                            {
+                                HttpResponse::build(
+                                    StatusCode::from_u16(e.status_code())
+                                        .unwrap_or(
+                                            StatusCode::INTERNAL_SERVER_ERROR,
+                                        ),
+                                )
+                                .body(
+                                    serde_json::to_string(&e)
+                                        .unwrap_or_else(|_| e.to_string()),
+                                )
+                            }
+                            // a <form> submit can't show the error itself, so
+                            // send it back to the referrer in the query
+                            else {
+                                let referer = req
+                                    .headers()
+                                    .get("Referer")
+                                    .and_then(|value| value.to_str().ok())
+                                    .unwrap_or("/");
+                                HttpResponse::SeeOther()
+                                    .insert_header((
+                                        "Location",
+                                        e.redirect_location(referer),
+                                    ))
+                                    .finish()
+                            }
+                        }
                     };
                     // clean up the scope
                     disposer.dispose();
@@ -299,6 +347,49 @@ pub fn handle_server_fns_with_context(
     )
 }
 
+/// An Actix [Route](actix_web::Route) that serves the resized images requested by an
+/// [`ImageLoader::endpoint`](leptos_meta::ImageLoader::endpoint), using the given
+/// [ImageOptimizer].
+///
+/// ```ignore
+/// use actix_web::App;
+/// use leptos_actix::{handle_image_optimization, ImageOptimizer};
+///
+/// let images = ImageOptimizer::new(
+///     "target/site",
+///     "target/image-cache",
+///     "image/webp",
+///     |original, width| resize_to_webp(original, width),
+/// );
+/// let app = App::new()
+///     .route("/_images", handle_image_optimization(images))
+///     // ...
+/// ```
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn handle_image_optimization(optimizer: ImageOptimizer) -> Route {
+    web::get().to(move |req: HttpRequest| {
+        let optimizer = optimizer.clone();
+        async move {
+            let Some(request) = ImageRequest::from_query(req.query_string())
+            else {
+                return HttpResponse::BadRequest()
+                    .body("expected `src` and `w`");
+            };
+            match optimizer.load(&request).await {
+                Ok(bytes) => HttpResponse::Ok()
+                    .content_type(optimizer.content_type())
+                    .insert_header((header::CACHE_CONTROL, IMAGE_CACHE_CONTROL))
+                    .body(bytes),
+                Err(error) => HttpResponse::build(
+                    StatusCode::from_u16(error.status_code())
+                        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+                )
+                .body(error.to_string()),
+            }
+        }
+    })
+}
+
 /// Returns an Actix [Route](actix_web::Route) that listens for a `GET` request and tries
 /// to route it using [leptos_router], serving an HTML stream of your application. The stream
 /// will include fallback content for any `<Suspense/>` nodes, and be immediately interactive,
@@ -718,12 +809,41 @@ fn provide_contexts(
 ) {
     let path = leptos_corrected_path(req);
 
+    // only issued if CSRF protection has been enabled
+    if let Some((csrf_token, set_cookie)) =
+        csrf::issue_csrf_token(header_str(req, header::COOKIE.as_str()))
+    {
+        if let Some(cookie) = set_cookie
+            .and_then(|cookie| header::HeaderValue::from_str(&cookie).ok())
+        {
+            res_options.append_header(header::SET_COOKIE, cookie);
+        }
+        provide_context(cx, csrf_token);
+    }
+    if let Some(accept_language) =
+        header_str(req, header::ACCEPT_LANGUAGE.as_str())
+    {
+        provide_context(cx, AcceptLanguage(accept_language.to_string()));
+    }
+
     let integration = ServerIntegration { path };
     provide_context(cx, RouterIntegrationContext::new(integration));
     provide_context(cx, MetaContext::new());
+    let status_options = res_options.clone();
     provide_context(cx, res_options);
     provide_context(cx, req.clone());
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let Ok(status) = StatusCode::from_u16(status) {
+            status_options.set_status(status);
+        }
+    });
+}
+
+fn header_str<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
 }
 
 fn leptos_corrected_path(req: &HttpRequest) -> String {
@@ -746,7 +866,7 @@ async fn stream_app(
     let (stream, runtime, scope) =
         render_to_stream_with_prefix_undisposed_with_context_and_block_replacement(
             app,
-            move |cx| generate_head_metadata_separated(cx).1.into(),
+            move |cx| body_prefix(cx).into(),
             additional_context,
             replace_blocks
         );
@@ -767,7 +887,7 @@ async fn stream_app_in_order(
         leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
             app,
             move |cx| {
-                generate_head_metadata_separated(cx).1.into()
+                body_prefix(cx).into()
             },
             additional_context,
         );
@@ -783,13 +903,12 @@ async fn build_stream_response(
     scope: ScopeId,
 ) -> HttpResponse {
     let cx = leptos::Scope { runtime, id: scope };
-    let mut stream = Box::pin(stream);
+    let mut stream = Box::pin(with_head_patches(cx, stream));
 
     // wait for any blocking resources to load before pulling metadata
     let first_app_chunk = stream.next().await.unwrap_or_default();
 
-    let (head, tail) =
-        html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
+    let (head, tail) = html_parts_for_scope(cx, options);
 
     let mut stream = Box::pin(
         futures::stream::once(async move { head.clone() })
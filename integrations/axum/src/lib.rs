@@ -7,7 +7,7 @@
 
 use axum::{
     body::{Body, Bytes, Full, StreamBody},
-    extract::{FromRef, FromRequestParts, Path, RawQuery},
+    extract::{FromRef, FromRequestParts, Path, RawQuery, State},
     http::{
         header::{HeaderName, HeaderValue},
         HeaderMap, Request, StatusCode,
@@ -26,12 +26,16 @@ use http::{
 use hyper::body;
 use leptos::{
     leptos_server::{server_fn_by_path, Payload},
-    server_fn::Encoding,
+    server_fn::{csrf, is_form_submission, Encoding},
     ssr::*,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
-use leptos_meta::{generate_head_metadata_separated, MetaContext};
+use leptos_integration_utils::{
+    body_prefix, build_async_response, html_parts_for_scope, with_head_patches,
+    IMAGE_CACHE_CONTROL,
+};
+pub use leptos_integration_utils::{ImageError, ImageOptimizer};
+use leptos_meta::{ImageRequest, MetaContext};
 use leptos_router::*;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
@@ -257,7 +261,23 @@ async fn handle_server_fns_inner(
                     Encoding::Url | Encoding::Cbor => &req_parts.body,
                     Encoding::GetJSON | Encoding::GetCBOR => query,
                 };
-                let res = match (server_fn.trait_obj)(cx, data).await {
+                // mutating requests must echo back the CSRF cookie
+                let csrf_ok = match &server_fn.encoding {
+                    Encoding::Url | Encoding::Cbor => csrf::check_csrf(
+                        header_str(&headers, header::COOKIE.as_str()),
+                        header_str(&headers, csrf::CSRF_HEADER),
+                        &req_parts.body,
+                    ),
+                    Encoding::GetJSON | Encoding::GetCBOR => true,
+                };
+                let result = if csrf_ok {
+                    (server_fn.trait_obj)(cx, data).await
+                } else {
+                    Err(ServerFnError::Forbidden(
+                        "invalid CSRF token".to_string(),
+                    ))
+                };
+                let res = match result {
                     Ok(serialized) => {
                         // If ResponseOptions are set, add the headers and status to the request
                         let res_options = use_context::<ResponseOptions>(cx);
@@ -320,12 +340,40 @@ async fn handle_server_fns_inner(
                                 .body(Full::from(data)),
                         }
                     }
-                    Err(e) => Response::builder()
-                        .status(StatusCode::INTERNAL_SERVER_ERROR)
-                        .body(Full::from(
-                            serde_json::to_string(&e)
-                                .unwrap_or_else(|_| e.to_string()),
-                        )),
+                    Err(e) => {
+                        let content_type =
+                            header_str(&headers, header::CONTENT_TYPE.as_str());
+                        let accept =
+                            header_str(&headers, header::ACCEPT.as_str());
+                        if !is_form_submission(content_type, accept) {
+                            Response::builder()
+                                .status(
+                                    StatusCode::from_u16(e.status_code())
+                                        .unwrap_or(
+                                            StatusCode::INTERNAL_SERVER_ERROR,
+                                        ),
+                                )
+                                .body(Full::from(
+                                    serde_json::to_string(&e)
+                                        .unwrap_or_else(|_| e.to_string()),
+                                ))
+                        }
+                        // a <form> submit can't show the error itself, so
+                        // send it back to the referrer in the query
+                        else {
+                            let referer = headers
+                                .get("Referer")
+                                .and_then(|value| value.to_str().ok())
+                                .unwrap_or("/");
+                            Response::builder()
+                                .status(StatusCode::SEE_OTHER)
+                                .header(
+                                    "Location",
+                                    e.redirect_location(referer),
+                                )
+                                .body(Full::from(""))
+                        }
+                    }
                 };
                 // clean up the scope
                 disposer.dispose();
@@ -620,7 +668,7 @@ where
                 let (bundle, runtime, scope) =
                     leptos::leptos_dom::ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement(
                         app,
-                        |cx| generate_head_metadata_separated(cx).1.into(),
+                        |cx| body_prefix(cx).into(),
                         add_context,
                         replace_blocks
                     );
@@ -685,11 +733,10 @@ async fn forward_stream(
     mut tx: Sender<String>,
 ) {
     let cx = Scope { runtime, id: scope };
-    let mut shell = Box::pin(bundle);
+    let mut shell = Box::pin(with_head_patches(cx, bundle));
     let first_app_chunk = shell.next().await.unwrap_or_default();
 
-    let (head, tail) =
-        html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
+    let (head, tail) = html_parts_for_scope(cx, options);
 
     _ = tx.send(head).await;
     _ = tx.send(first_app_chunk).await;
@@ -790,7 +837,7 @@ where
                     let (bundle, runtime, scope) =
                         leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
                             app,
-                            |cx| generate_head_metadata_separated(cx).1.into(),
+                            |cx| body_prefix(cx).into(),
                             add_context,
                         );
 
@@ -816,13 +863,40 @@ fn provide_contexts(
     extractor: ExtractorHelper,
     default_res_options: ResponseOptions,
 ) {
+    // only issued if CSRF protection has been enabled
+    if let Some((csrf_token, set_cookie)) = csrf::issue_csrf_token(header_str(
+        &req_parts.headers,
+        header::COOKIE.as_str(),
+    )) {
+        if let Some(cookie) = set_cookie
+            .and_then(|cookie| header::HeaderValue::from_str(&cookie).ok())
+        {
+            default_res_options.append_header(header::SET_COOKIE, cookie);
+        }
+        provide_context(cx, csrf_token);
+    }
+    if let Some(accept_language) =
+        header_str(&req_parts.headers, header::ACCEPT_LANGUAGE.as_str())
+    {
+        provide_context(cx, AcceptLanguage(accept_language.to_string()));
+    }
     let integration = ServerIntegration { path };
     provide_context(cx, RouterIntegrationContext::new(integration));
     provide_context(cx, MetaContext::new());
     provide_context(cx, req_parts);
     provide_context(cx, extractor);
+    let status_options = default_res_options.clone();
     provide_context(cx, default_res_options);
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let Ok(status) = StatusCode::from_u16(status) {
+            status_options.set_status(status);
+        }
+    });
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
 }
 
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
@@ -1082,6 +1156,60 @@ where
     }
 }
 
+/// An Axum handler that serves the resized images requested by an
+/// [`ImageLoader::endpoint`](leptos_meta::ImageLoader::endpoint), using the
+/// [ImageOptimizer] from the router's state.
+///
+/// ```ignore
+/// use axum::{extract::FromRef, routing::get, Router};
+/// use leptos::*;
+/// use leptos_axum::{handle_image_optimization, ImageOptimizer};
+///
+/// #[derive(Clone, FromRef)]
+/// struct AppState {
+///     leptos_options: LeptosOptions,
+///     images: ImageOptimizer,
+/// }
+///
+/// let images = ImageOptimizer::new(
+///     "target/site",
+///     "target/image-cache",
+///     "image/webp",
+///     |original, width| resize_to_webp(original, width),
+/// );
+/// let app = Router::new()
+///     .route("/_images", get(handle_image_optimization))
+///     // ...
+///     .with_state(AppState { leptos_options, images });
+/// ```
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn handle_image_optimization(
+    State(optimizer): State<ImageOptimizer>,
+    RawQuery(query): RawQuery,
+) -> impl IntoResponse {
+    let Some(request) = query.as_deref().and_then(ImageRequest::from_query)
+    else {
+        return (StatusCode::BAD_REQUEST, "expected `src` and `w`")
+            .into_response();
+    };
+    match optimizer.load(&request).await {
+        Ok(bytes) => (
+            [
+                (header::CONTENT_TYPE, optimizer.content_type()),
+                (header::CACHE_CONTROL, IMAGE_CACHE_CONTROL),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Err(error) => (
+            StatusCode::from_u16(error.status_code())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            error.to_string(),
+        )
+            .into_response(),
+    }
+}
+
 /// This trait allows one to pass a list of routes and a render function to Axum's router, letting us avoid
 /// having to use wildcards or manually define all routes in multiple places.
 pub trait LeptosRoutes<S>
@@ -0,0 +1,257 @@
+use leptos_meta::ImageRequest;
+use std::{
+    collections::HashMap,
+    fmt, io,
+    path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::UNIX_EPOCH,
+};
+
+/// The `Cache-Control` header that resized images are served with.
+pub const IMAGE_CACHE_CONTROL: &str = "public, max-age=604800";
+
+type Optimize = dyn Fn(&[u8], u32) -> Result<Vec<u8>, String> + Send + Sync;
+
+/// Resizes images for [`ImageLoader::endpoint`](leptos_meta::ImageLoader::endpoint), and
+/// caches the results on disk. Each integration has a `handle_image_optimization` handler
+/// that serves it.
+///
+/// Leptos doesn't include an image encoder: `optimize` is given the bytes of the original
+/// image and the requested width, and returns the bytes of the resized image, for example
+/// by using the `image` crate. It runs on a blocking thread the first time each image is
+/// requested at each width, and its result is then written to the `cache_dir` and served
+/// from there, until the original image is modified. Identical requests that arrive while
+/// an image is being resized wait for it, rather than resizing it again.
+///
+/// ```ignore
+/// use leptos_integration_utils::ImageOptimizer;
+///
+/// let images = ImageOptimizer::new(
+///     "target/site",
+///     "target/image-cache",
+///     "image/webp",
+///     |original, width| resize_to_webp(original, width),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ImageOptimizer {
+    source_dir: PathBuf,
+    cache_dir: PathBuf,
+    content_type: &'static str,
+    max_width: u32,
+    optimize: Arc<Optimize>,
+    in_flight: Arc<Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl ImageOptimizer {
+    /// Creates an optimizer for the images in `source_dir`, which caches them in
+    /// `cache_dir`. Every resized image is served with the given `content_type`.
+    pub fn new<F, E>(
+        source_dir: impl Into<PathBuf>,
+        cache_dir: impl Into<PathBuf>,
+        content_type: &'static str,
+        optimize: F,
+    ) -> Self
+    where
+        F: Fn(&[u8], u32) -> Result<Vec<u8>, E> + Send + Sync + 'static,
+        E: fmt::Display,
+    {
+        Self {
+            source_dir: source_dir.into(),
+            cache_dir: cache_dir.into(),
+            content_type,
+            max_width: 3840,
+            optimize: Arc::new(move |original, width| {
+                optimize(original, width).map_err(|e| e.to_string())
+            }),
+            in_flight: Default::default(),
+        }
+    }
+
+    /// Sets the widest image that can be requested, in pixels, so that requests can't
+    /// fill the cache with arbitrarily large images. Defaults to `3840`.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// The `Content-Type` that resized images are served with.
+    pub fn content_type(&self) -> &'static str {
+        self.content_type
+    }
+
+    /// Returns the bytes of the image requested by `request`, from the cache if it has
+    /// already been resized.
+    pub async fn load(
+        &self,
+        request: &ImageRequest,
+    ) -> Result<Vec<u8>, ImageError> {
+        if request.width == 0 || request.width > self.max_width {
+            return Err(ImageError::BadRequest(format!(
+                "width must be between 1 and {}",
+                self.max_width
+            )));
+        }
+        let relative = source_path(&request.src).ok_or_else(|| {
+            ImageError::BadRequest("invalid image path".into())
+        })?;
+        let source = self.source_dir.join(relative);
+        let not_found =
+            || ImageError::NotFound(format!("{} not found", request.src));
+        let metadata = tokio::fs::metadata(&source)
+            .await
+            .ok()
+            .filter(|metadata| metadata.is_file())
+            .ok_or_else(not_found)?;
+
+        let cached = self.cache_dir.join(cache_key(
+            &request.src,
+            &metadata,
+            request.width,
+        ));
+        if let Ok(bytes) = tokio::fs::read(&cached).await {
+            return Ok(bytes);
+        }
+
+        let lock = Arc::clone(
+            self.in_flight
+                .lock()
+                .unwrap()
+                .entry(cached.clone())
+                .or_default(),
+        );
+        let _resizing = lock.lock().await;
+        let result = self.resize(&source, &cached, request.width).await;
+        self.in_flight.lock().unwrap().remove(&cached);
+        result
+    }
+
+    async fn resize(
+        &self,
+        source: &Path,
+        cached: &Path,
+        width: u32,
+    ) -> Result<Vec<u8>, ImageError> {
+        // another request may have resized it while this one was waiting
+        if let Ok(bytes) = tokio::fs::read(cached).await {
+            return Ok(bytes);
+        }
+
+        let original = tokio::fs::read(source)
+            .await
+            .map_err(|e| ImageError::NotFound(e.to_string()))?;
+        let optimize = Arc::clone(&self.optimize);
+        let bytes =
+            tokio::task::spawn_blocking(move || optimize(&original, width))
+                .await
+                .map_err(|e| ImageError::Failed(e.to_string()))?
+                .map_err(ImageError::Failed)?;
+
+        // if the cache can't be written, the image is resized again next time
+        _ = write_atomically(cached, &bytes).await;
+        Ok(bytes)
+    }
+}
+
+impl fmt::Debug for ImageOptimizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageOptimizer")
+            .field("source_dir", &self.source_dir)
+            .field("cache_dir", &self.cache_dir)
+            .field("content_type", &self.content_type)
+            .field("max_width", &self.max_width)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Why an [`ImageOptimizer`] couldn't load an image.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageError {
+    /// The requested width or path isn't allowed.
+    BadRequest(String),
+    /// The image doesn't exist.
+    NotFound(String),
+    /// The image couldn't be resized.
+    Failed(String),
+}
+
+impl ImageError {
+    /// The HTTP status code to respond with.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ImageError::BadRequest(_) => 400,
+            ImageError::NotFound(_) => 404,
+            ImageError::Failed(_) => 500,
+        }
+    }
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::BadRequest(message)
+            | ImageError::NotFound(message)
+            | ImageError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// Returns the path of `src` relative to the source directory, unless it
+/// could point outside of it.
+fn source_path(src: &str) -> Option<&Path> {
+    let relative = Path::new(src.trim_start_matches('/'));
+    let inside = relative
+        .components()
+        .all(|part| matches!(part, Component::Normal(_)));
+    (inside && relative.components().next().is_some()).then_some(relative)
+}
+
+/// The file name of the version of the image at `src` that is `width` pixels
+/// wide, which changes when the image is modified.
+///
+/// This uses FNV-1a, because `DefaultHasher` may hash differently in another
+/// Rust release, which would orphan the whole cache.
+fn cache_key(src: &str, metadata: &std::fs::Metadata, width: u32) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |since| since.as_nanos());
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in src
+        .bytes()
+        .chain(modified.to_le_bytes())
+        .chain(metadata.len().to_le_bytes())
+    {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}-{width}")
+}
+
+/// Writes `bytes` to a temporary file next to `path`, and then renames it to
+/// `path`, so that other requests never read a partly written file.
+async fn write_atomically(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    tokio::fs::create_dir_all(dir).await?;
+    let temp = dir.join(format!(
+        ".{}-{}.tmp",
+        std::process::id(),
+        NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = match tokio::fs::write(&temp, bytes).await {
+        Ok(()) => tokio::fs::rename(&temp, path).await,
+        Err(e) => Err(e),
+    };
+    if result.is_err() {
+        _ = tokio::fs::remove_file(&temp).await;
+    }
+    result
+}
@@ -1,7 +1,11 @@
 use futures::{Stream, StreamExt};
-use leptos::{use_context, RuntimeId, ScopeId};
+use leptos::{ssr::HtmlStream, use_context, RuntimeId, ScopeId, View};
 use leptos_config::LeptosOptions;
 use leptos_meta::MetaContext;
+use std::{fmt, sync::Arc};
+
+mod image_optimizer;
+pub use image_optimizer::*;
 
 extern crate tracing;
 
@@ -47,18 +51,15 @@ pub fn html_parts(
     options: &LeptosOptions,
     meta: Option<&MetaContext>,
 ) -> (String, &'static str) {
-    let pkg_path = &options.site_pkg_dir;
-    let output_name = &options.output_name;
-
     // Because wasm-pack adds _bg to the end of the WASM filename, and we want to mantain compatibility with it's default options
     // we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME at compile time
     // Otherwise we need to add _bg because wasm_pack always does.
-    let mut wasm_output_name = output_name.clone();
+    let mut wasm_output_name = options.output_name.clone();
     if std::option_env!("LEPTOS_OUTPUT_NAME").is_none() {
         wasm_output_name.push_str("_bg");
     }
 
-    let leptos_autoreload = autoreload(options);
+    let scripts = scripts(options, &wasm_output_name);
 
     let html_metadata =
         meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
@@ -68,10 +69,7 @@ pub fn html_parts(
                 <head>
                     <meta charset="utf-8"/>
                     <meta name="viewport" content="width=device-width, initial-scale=1"/>
-                    <link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
-                    <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
-                    <script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>
-                    {leptos_autoreload}
+                    {scripts}
                     "#
     );
     let tail = "</body></html>";
@@ -83,18 +81,7 @@ pub fn html_parts_separated(
     options: &LeptosOptions,
     meta: Option<&MetaContext>,
 ) -> (String, &'static str) {
-    let pkg_path = &options.site_pkg_dir;
-    let output_name = &options.output_name;
-
-    // Because wasm-pack adds _bg to the end of the WASM filename, and we want to mantain compatibility with it's default options
-    // we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME
-    // Otherwise we need to add _bg because wasm_pack always does. This is not the same as options.output_name, which is set regardless
-    let mut wasm_output_name = output_name.clone();
-    if std::env::var("LEPTOS_OUTPUT_NAME").is_err() {
-        wasm_output_name.push_str("_bg");
-    }
-
-    let leptos_autoreload = autoreload(options);
+    let scripts = hydration_scripts(options);
 
     let html_metadata =
         meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
@@ -109,16 +96,62 @@ pub fn html_parts_separated(
                     <meta charset="utf-8"/>
                     <meta name="viewport" content="width=device-width, initial-scale=1"/>
                     {head}
-                    <link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
-                    <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
-                    <script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>
-                    {leptos_autoreload}
+                    {scripts}
                     "#
     );
     let tail = "</body></html>";
     (head, tail)
 }
 
+/// Returns the HTML that should be sent before and after the app’s body,
+/// using the [HtmlShell] provided as context if there is one, and
+/// [html_parts_separated] otherwise.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn html_parts_for_scope(
+    cx: leptos::Scope,
+    options: &LeptosOptions,
+) -> (String, String) {
+    let meta = use_context::<MetaContext>(cx);
+    match use_context::<HtmlShell>(cx) {
+        Some(shell) => shell.html_parts(options, meta.as_ref()),
+        None => {
+            let (head, tail) = html_parts_separated(options, meta.as_ref());
+            (head, tail.to_string())
+        }
+    }
+}
+
+/// Returns the HTML that opens the `<body>` tag, which is sent before the app’s
+/// body in a stream. When an [HtmlShell] has been provided, the shell contains
+/// its own `<body>` tag, so this is empty.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn body_prefix(cx: leptos::Scope) -> String {
+    if use_context::<HtmlShell>(cx).is_some() {
+        String::new()
+    } else {
+        leptos_meta::generate_head_metadata_separated(cx).1
+    }
+}
+
+/// Adds a `<script>` to each chunk of an HTML stream that brings the
+/// document head up to date with any metadata from [leptos_meta] that has
+/// changed since the head was sent, for example a `<Title/>` under a
+/// `<Suspense/>` that resolved after the shell was streamed. See
+/// [MetaContext::dehydrate_late].
+///
+/// Chunks that are sent before the head is rendered are left unchanged.
+pub fn with_head_patches(
+    cx: leptos::Scope,
+    stream: impl Stream<Item = String>,
+) -> impl Stream<Item = String> {
+    stream.map(move |mut chunk| {
+        if let Some(meta) = use_context::<MetaContext>(cx) {
+            chunk.push_str(&meta.dehydrate_late());
+        }
+        chunk
+    })
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub async fn build_async_response(
     stream: impl Stream<Item = String> + 'static,
@@ -133,6 +166,13 @@ pub async fn build_async_response(
     }
 
     let cx = leptos::Scope { runtime, id: scope };
+    if let Some(shell) = use_context::<HtmlShell>(cx) {
+        let (head, tail) =
+            shell.html_parts(options, use_context::<MetaContext>(cx).as_ref());
+        runtime.dispose();
+        return format!("{head}{buf}{tail}");
+    }
+
     let (head, tail) =
         html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
 
@@ -151,3 +191,321 @@ pub async fn build_async_response(
 
     format!("{head}{head_meta}</head><body{body_meta}>{buf}{tail}")
 }
+
+const HEAD_PLACEHOLDER: &str = "<!--leptos-head-->";
+const BODY_PLACEHOLDER: &str = "<!--leptos-body-->";
+const STATE_PLACEHOLDER: &str = "<!--leptos-state-->";
+
+/// A user-provided HTML document (for example, an existing `index.html`) that
+/// server-rendered pages are rendered into, in place of the default document.
+///
+/// The template marks where Leptos should insert its output with HTML comments:
+/// - `<!--leptos-head-->` (required): the `<head>` contents from
+///   [leptos_meta], like `<Title/>`, `<Meta/>` and `<Stylesheet/>`
+/// - `<!--leptos-body-->` (required): the server-rendered app, followed by
+///   any streamed `<Suspense/>` fragments and resource data
+/// - `<!--leptos-state-->` (optional): the scripts that load the WASM bundle
+///   and hydrate the app, along with the live-reload script in development.
+///   If the template doesn’t contain this placeholder, they are inserted
+///   at the `<!--leptos-head-->` placeholder.
+///
+/// Everything else in the template is sent unchanged, so it can keep the
+/// markup generated by an existing asset pipeline. Attributes set with
+/// [leptos_meta]’s `<Html/>` and `<Body/>` components are not applied, as the
+/// template owns those elements.
+///
+/// To use a shell, provide it as context in the `additional_context` closure
+/// passed to one of the integrations’ `_with_context` handlers.
+/// ```
+/// # use leptos_integration_utils::HtmlShell;
+/// let shell = HtmlShell::new(
+///     r#"<!DOCTYPE html>
+/// <html lang="en">
+///   <head><!--leptos-head--><link rel="icon" href="/favicon.ico"></head>
+///   <body><!--leptos-body--><!--leptos-state--></body>
+/// </html>"#,
+/// )
+/// .unwrap();
+/// // then, in `additional_context`:
+/// // provide_context(cx, shell.clone());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct HtmlShell {
+    // the template, split at the body placeholder
+    before_body: Arc<str>,
+    after_body: Arc<str>,
+}
+
+impl HtmlShell {
+    /// Parses a template, checking that it contains exactly one
+    /// `<!--leptos-head-->` placeholder before exactly one
+    /// `<!--leptos-body-->` placeholder.
+    pub fn new(template: impl AsRef<str>) -> Result<Self, HtmlShellError> {
+        let template = template.as_ref();
+        for placeholder in [HEAD_PLACEHOLDER, BODY_PLACEHOLDER] {
+            match template.matches(placeholder).count() {
+                0 => {
+                    return Err(HtmlShellError::MissingPlaceholder(placeholder))
+                }
+                1 => {}
+                _ => {
+                    return Err(HtmlShellError::DuplicatePlaceholder(
+                        placeholder,
+                    ))
+                }
+            }
+        }
+        if template.matches(STATE_PLACEHOLDER).count() > 1 {
+            return Err(HtmlShellError::DuplicatePlaceholder(
+                STATE_PLACEHOLDER,
+            ));
+        }
+        let (before_body, after_body) = template
+            .split_once(BODY_PLACEHOLDER)
+            .expect("body placeholder to be present");
+        if !before_body.contains(HEAD_PLACEHOLDER) {
+            return Err(HtmlShellError::HeadAfterBody);
+        }
+        Ok(Self {
+            before_body: before_body.into(),
+            after_body: after_body.into(),
+        })
+    }
+
+    /// Reads and parses a template from a file, like an `index.html`.
+    pub fn from_file(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, HtmlShellError> {
+        let template = std::fs::read_to_string(path)
+            .map_err(|e| HtmlShellError::Io(Arc::new(e)))?;
+        Self::new(template)
+    }
+
+    /// Fills in the template, returning the HTML that should be sent before
+    /// and after the app’s body.
+    pub fn html_parts(
+        &self,
+        options: &LeptosOptions,
+        meta: Option<&MetaContext>,
+    ) -> (String, String) {
+        let head = meta.map(|meta| meta.dehydrate()).unwrap_or_default();
+        let state = hydration_scripts(options);
+
+        let has_state = self.before_body.contains(STATE_PLACEHOLDER)
+            || self.after_body.contains(STATE_PLACEHOLDER);
+        let (head, state) = if has_state {
+            (head, state)
+        } else {
+            (format!("{head}{state}"), String::new())
+        };
+
+        let before_body = self
+            .before_body
+            .replacen(HEAD_PLACEHOLDER, &head, 1)
+            .replacen(STATE_PLACEHOLDER, &state, 1);
+        let after_body = self.after_body.replacen(STATE_PLACEHOLDER, &state, 1);
+        (before_body, after_body)
+    }
+}
+
+impl fmt::Debug for HtmlShell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HtmlShell")
+            .field(
+                "template",
+                &format!(
+                    "{}{BODY_PLACEHOLDER}{}",
+                    self.before_body, self.after_body
+                ),
+            )
+            .finish()
+    }
+}
+
+/// An error that occurs while loading an [HtmlShell].
+#[derive(Debug, Clone)]
+pub enum HtmlShellError {
+    /// The template file could not be read.
+    Io(Arc<std::io::Error>),
+    /// The template does not contain a required placeholder.
+    MissingPlaceholder(&'static str),
+    /// The template contains a placeholder more than once.
+    DuplicatePlaceholder(&'static str),
+    /// The head placeholder appears after the body placeholder.
+    HeadAfterBody,
+}
+
+impl fmt::Display for HtmlShellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HtmlShellError::Io(e) => {
+                write!(f, "could not read HTML shell: {e}")
+            }
+            HtmlShellError::MissingPlaceholder(placeholder) => {
+                write!(f, "HTML shell is missing {placeholder}")
+            }
+            HtmlShellError::DuplicatePlaceholder(placeholder) => {
+                write!(f, "HTML shell contains {placeholder} more than once")
+            }
+            HtmlShellError::HeadAfterBody => write!(
+                f,
+                "HTML shell contains {HEAD_PLACEHOLDER} after \
+                 {BODY_PLACEHOLDER}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HtmlShellError {}
+
+/// Returns the `<link>` and `<script>` tags that load the WASM bundle and
+/// hydrate the app, along with the live-reload script in development.
+pub fn hydration_scripts(options: &LeptosOptions) -> String {
+    // Because wasm-pack adds _bg to the end of the WASM filename, and we want to mantain compatibility with it's default options
+    // we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME
+    // Otherwise we need to add _bg because wasm_pack always does. This is not the same as options.output_name, which is set regardless
+    let mut wasm_output_name = options.output_name.clone();
+    if std::env::var("LEPTOS_OUTPUT_NAME").is_err() {
+        wasm_output_name.push_str("_bg");
+    }
+
+    scripts(options, &wasm_output_name)
+}
+
+fn scripts(options: &LeptosOptions, wasm_output_name: &str) -> String {
+    let pkg_path = &options.site_pkg_dir;
+    let output_name = &options.output_name;
+    let leptos_autoreload = autoreload(options);
+
+    format!(
+        r#"<link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
+<link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
+<script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>
+{leptos_autoreload}"#
+    )
+}
+
+/// The parts of a server-rendered page that belong outside the app’s body, as
+/// contributed by [leptos_meta] and the hydration scripts. Returned by
+/// [SplitRender::head].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadParts {
+    /// Attributes for the `<html>` tag, set with `<Html/>`, with a leading
+    /// space if not empty.
+    pub html_attributes: String,
+    /// Tags for the `<head>`, set with `<Title/>`, `<Meta/>`,
+    /// `<Stylesheet/>` and the like.
+    pub head: String,
+    /// Attributes for the `<body>` tag, set with `<Body/>`, with a leading
+    /// space if not empty.
+    pub body_attributes: String,
+    /// The tags that load the WASM bundle and hydrate the app; see
+    /// [hydration_scripts].
+    pub scripts: String,
+}
+
+/// Renders an app with the head contributions and the body stream available
+/// separately, so that integrations can compose them into any envelope document,
+/// rather than the one produced by [html_parts_separated] or an [HtmlShell].
+///
+/// The head is only known once the app’s shell has rendered (including any
+/// `<Suspense/>` blocks that are set to block the stream), so
+/// [head](SplitRender::head) waits for that before returning. The body stream
+/// then picks up where the shell left off.
+/// ```ignore
+/// let mut render = SplitRender::new(
+///     |cx| view! { cx, <App/> }.into_view(cx),
+///     |cx| provide_context(cx, tenant.clone()),
+/// );
+/// let head = render.head(&options).await;
+/// let envelope_start = format!(
+///     "<!DOCTYPE html><html{}><head>{}{}</head><body{}>",
+///     head.html_attributes, head.head, head.scripts, head.body_attributes
+/// );
+/// let body = futures::stream::once(async move { envelope_start })
+///     .chain(render.into_body())
+///     .chain(futures::stream::once(async { "</body></html>".to_string() }));
+/// ```
+pub struct SplitRender {
+    first_chunk: Option<String>,
+    // owns the runtime, so it is disposed even if the body is never read
+    body: HtmlStream,
+    runtime: RuntimeId,
+    scope: ScopeId,
+}
+
+impl SplitRender {
+    /// Starts rendering the view, calling `additional_context` in its root scope
+    /// first. Rendering happens in an out-of-order stream, as with
+    /// [render_to_stream](leptos::ssr::render_to_stream).
+    pub fn new(
+        view: impl FnOnce(leptos::Scope) -> View + 'static,
+        additional_context: impl FnOnce(leptos::Scope) + 'static,
+    ) -> Self {
+        let (body, runtime, scope) =
+            leptos::ssr::render_to_stream_with_prefix_undisposed_with_context(
+                view,
+                |_| "".into(),
+                additional_context,
+            );
+        Self {
+            first_chunk: None,
+            body: HtmlStream::with_runtime(body, runtime),
+            runtime,
+            scope,
+        }
+    }
+
+    /// The root scope of the app, which can be used to read any context
+    /// it has provided, like response headers.
+    pub fn scope(&self) -> leptos::Scope {
+        leptos::Scope {
+            runtime: self.runtime,
+            id: self.scope,
+        }
+    }
+
+    /// Waits for the app’s shell to render, and returns its head contributions.
+    pub async fn head(&mut self, options: &LeptosOptions) -> HeadParts {
+        if self.first_chunk.is_none() {
+            self.first_chunk = Some(self.body.next().await.unwrap_or_default());
+        }
+
+        let meta = use_context::<MetaContext>(self.scope());
+        let meta = meta.as_ref();
+        HeadParts {
+            html_attributes: meta
+                .and_then(|meta| meta.html.as_string())
+                .unwrap_or_default(),
+            head: meta.map(|meta| meta.dehydrate()).unwrap_or_default(),
+            body_attributes: meta
+                .and_then(|meta| meta.body.as_string())
+                .unwrap_or_default(),
+            scripts: hydration_scripts(options),
+        }
+    }
+
+    /// Returns the body stream, including any HTML already rendered while
+    /// waiting for the [head](SplitRender::head). The app’s runtime is
+    /// disposed once the stream ends or is dropped, or when the
+    /// `SplitRender` is dropped without calling this.
+    pub fn into_body(self) -> impl Stream<Item = String> {
+        let SplitRender {
+            first_chunk,
+            body,
+            runtime,
+            scope,
+        } = self;
+        let cx = leptos::Scope { runtime, id: scope };
+        futures::stream::iter(first_chunk).chain(with_head_patches(cx, body))
+    }
+}
+
+impl fmt::Debug for SplitRender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitRender")
+            .field("runtime", &self.runtime)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
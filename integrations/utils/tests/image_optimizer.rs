@@ -0,0 +1,111 @@
+use leptos_integration_utils::{ImageError, ImageOptimizer};
+use leptos_meta::ImageRequest;
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Creates an empty directory with `photo.jpg` in it, and an optimizer for
+/// it that counts how often it resizes an image.
+fn optimizer(name: &str) -> (PathBuf, ImageOptimizer, Arc<AtomicUsize>) {
+    let dir = std::env::temp_dir()
+        .join(format!("leptos-images-{}-{name}", std::process::id()));
+    _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("site")).unwrap();
+    std::fs::write(dir.join("site/photo.jpg"), "original").unwrap();
+    std::fs::write(dir.join("secret.txt"), "secret").unwrap();
+
+    let resized = Arc::new(AtomicUsize::new(0));
+    let optimizer = ImageOptimizer::new(
+        dir.join("site"),
+        dir.join("cache"),
+        "image/webp",
+        {
+            let resized = Arc::clone(&resized);
+            move |original: &[u8], width| {
+                resized.fetch_add(1, Ordering::SeqCst);
+                let original = String::from_utf8_lossy(original);
+                Ok::<_, String>(format!("{original}@{width}").into_bytes())
+            }
+        },
+    )
+    .max_width(1000);
+    (dir, optimizer, resized)
+}
+
+fn request(src: &str, width: u32) -> ImageRequest {
+    ImageRequest {
+        src: src.to_string(),
+        width,
+    }
+}
+
+#[tokio::test]
+async fn paths_outside_the_source_dir_are_rejected() {
+    let (_dir, optimizer, resized) = optimizer("traversal");
+    for src in [
+        "../secret.txt",
+        "/../secret.txt",
+        "photos/../../secret.txt",
+        "./photo.jpg",
+        "",
+        "/",
+    ] {
+        let error = optimizer.load(&request(src, 100)).await.unwrap_err();
+        assert!(
+            matches!(error, ImageError::BadRequest(_)),
+            "{src:?} was {error:?}"
+        );
+        assert_eq!(error.status_code(), 400);
+    }
+    assert_eq!(resized.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn widths_must_be_in_range() {
+    let (_dir, optimizer, _) = optimizer("widths");
+    for width in [0, 1001] {
+        let error = optimizer.load(&request("photo.jpg", width)).await;
+        assert!(matches!(error, Err(ImageError::BadRequest(_))));
+    }
+    assert!(optimizer.load(&request("photo.jpg", 1000)).await.is_ok());
+}
+
+#[tokio::test]
+async fn missing_images_are_not_found() {
+    let (_dir, optimizer, _) = optimizer("missing");
+    let error = optimizer.load(&request("nope.jpg", 100)).await.unwrap_err();
+    assert_eq!(error.status_code(), 404);
+}
+
+#[tokio::test]
+async fn resized_images_are_cached_until_modified() {
+    let (dir, optimizer, resized) = optimizer("cache");
+    let photo = request("/photo.jpg", 100);
+
+    assert_eq!(optimizer.load(&photo).await.unwrap(), b"original@100");
+    assert_eq!(optimizer.load(&photo).await.unwrap(), b"original@100");
+    assert_eq!(resized.load(Ordering::SeqCst), 1);
+    // only the finished image is left in the cache
+    let cached = std::fs::read_dir(dir.join("cache")).unwrap().count();
+    assert_eq!(cached, 1);
+
+    std::fs::write(dir.join("site/photo.jpg"), "edited").unwrap();
+    assert_eq!(optimizer.load(&photo).await.unwrap(), b"edited@100");
+    assert_eq!(resized.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn identical_requests_resize_once() {
+    let (_dir, optimizer, resized) = optimizer("herd");
+    let photo = request("photo.jpg", 200);
+
+    let loads = (0..8).map(|_| optimizer.load(&photo));
+    for bytes in futures::future::join_all(loads).await {
+        assert_eq!(bytes.unwrap(), b"original@200");
+    }
+    assert_eq!(resized.load(Ordering::SeqCst), 1);
+}
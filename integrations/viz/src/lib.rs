@@ -14,12 +14,16 @@ use http::{header, method::Method, uri::Uri, version::Version, StatusCode};
 use hyper::body;
 use leptos::{
     leptos_server::{server_fn_by_path, Payload},
-    server_fn::Encoding,
+    server_fn::{csrf, is_form_submission, Encoding},
     ssr::*,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
-use leptos_meta::{generate_head_metadata_separated, MetaContext};
+use leptos_integration_utils::{
+    body_prefix, build_async_response, html_parts_for_scope, with_head_patches,
+    IMAGE_CACHE_CONTROL,
+};
+pub use leptos_integration_utils::{ImageError, ImageOptimizer};
+use leptos_meta::{ImageRequest, MetaContext};
 use leptos_router::*;
 use parking_lot::RwLock;
 use std::{pin::Pin, sync::Arc};
@@ -215,10 +219,29 @@ async fn handle_server_fns_inner(
                                 }
                                 Encoding::GetJSON | Encoding::GetCBOR => &query,
                             };
+                            // mutating requests must echo back the CSRF cookie
+                            let csrf_ok = match &server_fn.encoding {
+                                Encoding::Url | Encoding::Cbor => {
+                                    csrf::check_csrf(
+                                        header_str(
+                                            &headers,
+                                            header::COOKIE.as_str(),
+                                        ),
+                                        header_str(&headers, csrf::CSRF_HEADER),
+                                        &req_parts.body,
+                                    )
+                                }
+                                Encoding::GetJSON | Encoding::GetCBOR => true,
+                            };
+                            let result = if csrf_ok {
+                                (server_fn.trait_obj)(cx, data).await
+                            } else {
+                                Err(ServerFnError::Forbidden(
+                                    "invalid CSRF token".to_string(),
+                                ))
+                            };
 
-                            let res = match (server_fn.trait_obj)(cx, data)
-                                .await
-                            {
+                            let res = match result {
                                 Ok(serialized) => {
                                     // If ResponseOptions are set, add the headers and status to the request
                                     let res_options =
@@ -297,12 +320,52 @@ async fn handle_server_fns_inner(
                                             .body(Body::from(data)),
                                     }
                                 }
-                                Err(e) => Response::builder()
-                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                                    .body(Body::from(
-                                        serde_json::to_string(&e)
-                                            .unwrap_or_else(|_| e.to_string()),
-                                    )),
+                                Err(e) => {
+                                    let content_type = header_str(
+                                        &headers,
+                                        header::CONTENT_TYPE.as_str(),
+                                    );
+                                    let accept = header_str(
+                                        &headers,
+                                        header::ACCEPT.as_str(),
+                                    );
+                                    if !is_form_submission(content_type, accept)
+                                    {
+                                        Response::builder()
+                                            .status(
+                                                StatusCode::from_u16(
+                                                    e.status_code(),
+                                                )
+                                                .unwrap_or(
+                                            StatusCode::INTERNAL_SERVER_ERROR,
+                                        ),
+                                            )
+                                            .body(Body::from(
+                                                serde_json::to_string(&e)
+                                                    .unwrap_or_else(|_| {
+                                                        e.to_string()
+                                                    }),
+                                            ))
+                                    }
+                                    // a <form> submit can't show the error
+                                    // itself, so send it back to the referrer
+                                    // in the query
+                                    else {
+                                        let referer = headers
+                                            .get("Referer")
+                                            .and_then(|value| {
+                                                value.to_str().ok()
+                                            })
+                                            .unwrap_or("/");
+                                        Response::builder()
+                                            .status(StatusCode::SEE_OTHER)
+                                            .header(
+                                                "Location",
+                                                e.redirect_location(referer),
+                                            )
+                                            .body(Body::empty())
+                                    }
+                                }
                             };
                             // clean up the scope
                             disposer.dispose();
@@ -329,6 +392,58 @@ async fn handle_server_fns_inner(
 
     rx.await.map_err(Error::normal)
 }
+/// Returns a Viz [Handler](viz::Handler) that serves the resized images requested by an
+/// [`ImageLoader::endpoint`](leptos_meta::ImageLoader::endpoint), using the given
+/// [ImageOptimizer].
+///
+/// ```ignore
+/// use leptos_viz::{handle_image_optimization, ImageOptimizer};
+/// use viz::Router;
+///
+/// let images = ImageOptimizer::new(
+///     "target/site",
+///     "target/image-cache",
+///     "image/webp",
+///     |original, width| resize_to_webp(original, width),
+/// );
+/// let app = Router::new()
+///     .get("/_images", handle_image_optimization(images))
+///     // ...
+/// ```
+pub fn handle_image_optimization(
+    optimizer: ImageOptimizer,
+) -> impl Fn(
+    Request,
+) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |req: Request| {
+        let optimizer = optimizer.clone();
+        let request = req.query_string().and_then(ImageRequest::from_query);
+        Box::pin(async move {
+            let res = match request {
+                None => Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .body(Body::from("expected `src` and `w`")),
+                Some(request) => match optimizer.load(&request).await {
+                    Ok(bytes) => Response::builder()
+                        .header(header::CONTENT_TYPE, optimizer.content_type())
+                        .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+                        .body(Body::from(bytes)),
+                    Err(error) => Response::builder()
+                        .status(
+                            StatusCode::from_u16(error.status_code())
+                                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+                        )
+                        .body(Body::from(error.to_string())),
+                },
+            };
+            Ok(res.expect("could not build Response"))
+        })
+    }
+}
+
 /// Returns a Viz [Handler](viz::Handler) that listens for a `GET` request and tries
 /// to route it using [leptos_router], serving an HTML stream of your application.
 ///
@@ -592,7 +707,7 @@ where
                                             let (bundle, runtime, scope) =
                                                 leptos::leptos_dom::ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement(
                                                     app,
-                                                    |cx| generate_head_metadata_separated(cx).1.into(),
+                                                    |cx| body_prefix(cx).into(),
                                                     add_context,
                                                     replace_blocks
                                                 );
@@ -649,11 +764,10 @@ async fn forward_stream(
     mut tx: Sender<String>,
 ) {
     let cx = Scope { runtime, id: scope };
-    let (head, tail) =
-        html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
+    let (head, tail) = html_parts_for_scope(cx, options);
 
     _ = tx.send(head).await;
-    let mut shell = Box::pin(bundle);
+    let mut shell = Box::pin(with_head_patches(cx, bundle));
     while let Some(fragment) = shell.next().await {
         _ = tx.send(fragment).await;
     }
@@ -757,7 +871,7 @@ where
                                             let (bundle, runtime, scope) =
                                                 leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
                                                     app,
-                                                    |cx| generate_head_metadata_separated(cx).1.into(),
+                                                    |cx| body_prefix(cx).into(),
                                                     add_context,
                                                 );
 
@@ -781,12 +895,39 @@ fn provide_contexts(
     req_parts: RequestParts,
     default_res_options: ResponseOptions,
 ) {
+    // only issued if CSRF protection has been enabled
+    if let Some((csrf_token, set_cookie)) = csrf::issue_csrf_token(header_str(
+        &req_parts.headers,
+        header::COOKIE.as_str(),
+    )) {
+        if let Some(cookie) =
+            set_cookie.and_then(|cookie| HeaderValue::from_str(&cookie).ok())
+        {
+            default_res_options.append_header(header::SET_COOKIE, cookie);
+        }
+        provide_context(cx, csrf_token);
+    }
+    if let Some(accept_language) =
+        header_str(&req_parts.headers, header::ACCEPT_LANGUAGE.as_str())
+    {
+        provide_context(cx, AcceptLanguage(accept_language.to_string()));
+    }
     let integration = ServerIntegration { path };
     provide_context(cx, RouterIntegrationContext::new(integration));
     provide_context(cx, MetaContext::new());
     provide_context(cx, req_parts);
+    let status_options = default_res_options.clone();
     provide_context(cx, default_res_options);
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let Ok(status) = StatusCode::from_u16(status) {
+            status_options.set_status(status);
+        }
+    });
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
 }
 
 /// Returns a Viz [Handler](viz::Handler) that listens for a `GET` request and tries
@@ -0,0 +1,113 @@
+use crate::{ChildrenFn, Show};
+use leptos_dom::{
+    helpers::{set_timeout_with_handle, TimeoutHandle},
+    IntoView,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, on_cleanup,
+    signal_prelude::*, Scope,
+};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+/// Shows its children when `when` is `true`, like [Show], but gives them
+/// time to animate in and out.
+///
+/// The children are wrapped in a `<div>` whose class is `show_class` while
+/// `when` is `true`. When it becomes `false`, the class is switched to
+/// `hide_class`, and the children are only removed from the DOM once
+/// `hide_delay` has passed, so a CSS exit animation can finish. If `when`
+/// becomes `true` again before then, the children are kept and the
+/// `show_class` is restored.
+///
+/// ```
+/// # use leptos::*;
+/// # use std::time::Duration;
+/// #[component]
+/// fn Menu(cx: Scope) -> impl IntoView {
+///     let (open, set_open) = create_signal(cx, false);
+///     view! { cx,
+///         <button on:click=move |_| set_open.update(|open| *open = !*open)>
+///             "Menu"
+///         </button>
+///         <AnimatedShow
+///             when=open
+///             show_class="fade-in"
+///             hide_class="fade-out"
+///             hide_delay=Duration::from_millis(200)
+///         >
+///             <nav>"…"</nav>
+///         </AnimatedShow>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn AnimatedShow<W>(
+    cx: Scope,
+    /// The children that are shown while `when` is `true`.
+    children: ChildrenFn,
+    /// Whether the children should be shown.
+    when: W,
+    /// The class of the wrapper while the children are shown, e.g., one
+    /// with an enter animation.
+    #[prop(optional)]
+    show_class: &'static str,
+    /// The class of the wrapper while the children are being hidden, e.g.,
+    /// one with an exit animation.
+    #[prop(optional)]
+    hide_class: &'static str,
+    /// How long to keep the children in the DOM after `when` becomes
+    /// `false`, usually the length of the exit animation.
+    #[prop(optional)]
+    hide_delay: Duration,
+) -> impl IntoView
+where
+    W: Fn() -> bool + 'static,
+{
+    let when = create_memo(cx, move |_| when());
+    let initial = when.get_untracked();
+    let mounted = create_rw_signal(cx, initial);
+    let class =
+        create_rw_signal(cx, if initial { show_class } else { hide_class });
+    let timeout = Rc::new(Cell::new(None::<TimeoutHandle>));
+
+    // only runs in the browser, so the initial state is rendered on the server
+    create_effect(cx, {
+        let timeout = Rc::clone(&timeout);
+        move |prev: Option<bool>| {
+            let shown = when.get();
+            if prev.is_some() && prev != Some(shown) {
+                if let Some(handle) = timeout.take() {
+                    handle.clear();
+                }
+                if shown {
+                    mounted.set(true);
+                    class.set(show_class);
+                } else {
+                    class.set(hide_class);
+                    let handle = set_timeout_with_handle(
+                        move || mounted.set(false),
+                        hide_delay,
+                    );
+                    timeout.set(handle.ok());
+                }
+            }
+            shown
+        }
+    });
+    on_cleanup(cx, move || {
+        if let Some(handle) = timeout.take() {
+            handle.clear();
+        }
+    });
+
+    view! { cx,
+        <Show when=move || mounted.get() fallback=|_| ()>
+            <div class=move || class.get()>{children(cx)}</div>
+        </Show>
+    }
+}
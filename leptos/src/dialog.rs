@@ -0,0 +1,231 @@
+use crate::{Children, TextProp};
+use leptos_dom::{create_node_ref, ev, html, IntoView};
+use leptos_macro::{component, view};
+use leptos_reactive::{create_effect, signal_prelude::*, RwSignal, Scope};
+
+/// A native `<dialog>`, opened and closed with a signal.
+///
+/// While `open` is `true` the dialog is shown, by default as a modal with
+/// [`showModal()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDialogElement/showModal),
+/// which makes the rest of the page inert, moves focus into the dialog, and
+/// closes it when <kbd>Escape</kbd> is pressed. However the dialog is closed
+/// (by <kbd>Escape</kbd>, a `<form method="dialog">`, or the backdrop),
+/// `open` is set back to `false`.
+///
+/// In browsers without `<dialog>` support, the `open` attribute is toggled
+/// instead, so the content is still shown and hidden. Dialogs are only
+/// opened in the browser, so an open dialog is rendered closed on the server.
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let open = create_rw_signal(cx, false);
+/// let choice = create_rw_signal(cx, String::new());
+/// view! { cx,
+///     <button on:click=move |_| open.set(true)>"Delete…"</button>
+///     <Dialog open=open return_value=choice close_on_backdrop=true>
+///         <form method="dialog">
+///             <p>"Delete this file?"</p>
+///             <button value="cancel">"Cancel"</button>
+///             <button value="delete">"Delete"</button>
+///         </form>
+///     </Dialog>
+///     <p>"You chose: " {move || choice.get()}</p>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Dialog(
+    cx: Scope,
+    /// Whether the dialog is open.
+    open: RwSignal<bool>,
+    /// Whether to show the dialog as a modal, with a backdrop. Defaults to
+    /// `true`.
+    #[prop(default = true)]
+    modal: bool,
+    /// Set to the dialog’s [`returnValue`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDialogElement/returnValue)
+    /// when it closes, e.g., the `value` of the button that submitted a
+    /// `<form method="dialog">`, or `""` if it was dismissed.
+    #[prop(optional)]
+    return_value: Option<RwSignal<String>>,
+    /// Closes a modal dialog when its backdrop is clicked.
+    #[prop(optional)]
+    close_on_backdrop: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+    /// The content of the dialog.
+    children: Children,
+) -> impl IntoView {
+    let dialog_ref = create_node_ref::<html::Dialog>(cx);
+
+    create_effect(cx, move |_| {
+        let is_open = open.get();
+        let Some(dialog) = dialog_ref.get() else {
+            return;
+        };
+        // the attribute reflects whether the dialog is open, even where
+        // `<dialog>` isn't supported
+        if is_open == dialog.has_attribute("open") {
+            return;
+        }
+        if is_open {
+            dialog.set_return_value("");
+            let method = if modal { "showModal" } else { "show" };
+            if !call_method(&dialog, method) {
+                _ = dialog.set_attribute("open", "");
+            }
+        } else if !call_method(&dialog, "close") {
+            _ = dialog.remove_attribute("open");
+        }
+    });
+
+    let on_close = move |_| {
+        if let (Some(return_value), Some(dialog)) =
+            (return_value, dialog_ref.get_untracked())
+        {
+            return_value.set(dialog.return_value());
+        }
+        open.set(false);
+    };
+
+    let on_click = move |ev: ev::MouseEvent| {
+        let Some(dialog) = dialog_ref.get_untracked() else {
+            return;
+        };
+        if !close_on_backdrop || !modal {
+            return;
+        }
+        // clicks on the backdrop target the dialog itself, as do clicks on
+        // its padding, which are inside its bounds
+        let on_dialog = ev
+            .target()
+            .map_or(false, |target| js_sys::Object::is(&target, &dialog));
+        let rect = dialog.get_bounding_client_rect();
+        let (x, y) = (ev.client_x() as f64, ev.client_y() as f64);
+        let outside = x < rect.left()
+            || x > rect.right()
+            || y < rect.top()
+            || y > rect.bottom();
+        if on_dialog && outside {
+            call_method(&dialog, "close");
+        }
+    };
+
+    view! { cx,
+        <dialog
+            node_ref=dialog_ref
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            on:close=on_close
+            on:click=on_click
+        >
+            {children(cx)}
+        </dialog>
+    }
+}
+
+/// An element using the native [Popover API](https://developer.mozilla.org/en-US/docs/Web/API/Popover_API),
+/// shown and hidden with a signal.
+///
+/// The popover is shown in the top layer while `open` is `true`. An `auto`
+/// popover (the default) is also closed by <kbd>Escape</kbd> or a click
+/// outside it, and another button can toggle it without any code by setting
+/// `popovertarget` to its `id`; either way, `open` is kept in sync.
+///
+/// In browsers without the Popover API, the element’s `hidden` property is
+/// toggled instead.
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let open = create_rw_signal(cx, false);
+/// view! { cx,
+///     <button popovertarget="help">"Help"</button>
+///     <Popover open=open id="help">
+///         "Press Tab to move to the next field."
+///     </Popover>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Popover(
+    cx: Scope,
+    /// Whether the popover is shown.
+    open: RwSignal<bool>,
+    /// Makes a `manual` popover, which is only closed by setting `open` to
+    /// `false`, rather than by <kbd>Escape</kbd> or a click outside it.
+    #[prop(optional)]
+    manual: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute, which a button’s `popovertarget` can refer
+    /// to.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+    /// The content of the popover.
+    children: Children,
+) -> impl IntoView {
+    let popover_ref = create_node_ref::<html::Div>(cx);
+    let kind = if manual { "manual" } else { "auto" };
+
+    create_effect(cx, move |_| {
+        let is_open = open.get();
+        let Some(popover) = popover_ref.get() else {
+            return;
+        };
+        match popover.matches(":popover-open") {
+            Ok(showing) if showing == is_open => {}
+            Ok(_) => {
+                let method = if is_open {
+                    "showPopover"
+                } else {
+                    "hidePopover"
+                };
+                call_method(&popover, method);
+            }
+            // the selector is invalid where popovers aren't supported
+            Err(_) => popover.set_hidden(!is_open),
+        }
+    });
+
+    let on_toggle = move |_| {
+        if let Some(popover) = popover_ref.get_untracked() {
+            if let Ok(showing) = popover.matches(":popover-open") {
+                if showing != open.get_untracked() {
+                    open.set(showing);
+                }
+            }
+        }
+    };
+
+    view! { cx,
+        <div
+            node_ref=popover_ref
+            popover=kind
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            on:toggle=on_toggle
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// Calls the method `name` of `el`, returning `false` if the browser
+/// doesn’t have it.
+pub(crate) fn call_method(el: &js_sys::Object, name: &str) -> bool {
+    match js_sys::Reflect::get(el, &name.into()) {
+        Ok(method) if method.is_function() => {
+            _ = js_sys::Function::from(method).call0(el);
+            true
+        }
+        _ => false,
+    }
+}
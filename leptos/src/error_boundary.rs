@@ -1,14 +1,19 @@
 use crate::Children;
-use leptos_dom::{Errors, IntoView};
+use leptos_dom::{throw_error, Errors, IntoView};
 use leptos_macro::{component, view};
 use leptos_reactive::{
-    create_rw_signal, provide_context, signal_prelude::*, RwSignal, Scope,
+    create_rw_signal, provide_context, signal_prelude::*, ErrorSink, RwSignal,
+    Scope,
 };
 
 /// When you render a `Result<_, _>` in your view, in the `Err` case it will
 /// render nothing, and search up through the view tree for an `<ErrorBoundary/>`.
 /// This component lets you define a fallback that should be rendered in that
 /// error case, allowing you to handle errors within a section of the interface.
+/// Errors that happen outside of the view, like in an event listener, can be
+/// reported to it with [`throw_error`](crate::throw_error), and a
+/// [Resource](leptos_reactive::Resource) read inside it whose fetcher panics
+/// reports a [`ResourcePanic`](leptos_reactive::ResourcePanic) to it.
 ///
 /// ```
 /// # use leptos_reactive::*;
@@ -28,12 +33,46 @@ use leptos_reactive::{
 /// }
 /// # });
 /// ```
+///
+/// ## Retrying
+/// The children are kept alive while the fallback is shown, so an error is
+/// cleared automatically when the part of the view that returned it renders
+/// `Ok` instead, for example when a [Resource](leptos_reactive::Resource)
+/// is refetched successfully. The fallback can also clear the errors itself
+/// with [Errors::clear], showing the children again; any child that is
+/// still failing will report its error again the next time it re-renders.
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// # async fn load_data() -> Result<String, ServerFnError> { todo!() }
+/// let data = create_resource(cx, || (), |_| load_data());
+///
+/// view! { cx,
+///   <ErrorBoundary
+///     fallback=move |cx, errors| view! { cx,
+///       <p class="error">"Something went wrong."</p>
+///       <button on:click=move |_| {
+///         errors.update(Errors::clear);
+///         data.refetch();
+///       }>
+///         "Retry"
+///       </button>
+///     }
+///   >
+///     <Suspense fallback=|| ()>
+///       {move || data.read(cx)}
+///     </Suspense>
+///   </ErrorBoundary>
+/// }
+/// # });
+/// ```
 #[component(transparent)]
 pub fn ErrorBoundary<F, IV>(
     cx: Scope,
     /// The components inside the tag which will get rendered
     children: Children,
-    /// A fallback that will be shown if an error occurs.
+    /// A fallback that will be shown if an error occurs. It receives the
+    /// collected errors, which it can clear to retry rendering the children.
     fallback: F,
 ) -> impl IntoView
 where
@@ -43,6 +82,12 @@ where
     let errors: RwSignal<Errors> = create_rw_signal(cx, Errors::default());
 
     provide_context(cx, errors);
+    provide_context(
+        cx,
+        ErrorSink::new(move |error| {
+            throw_error(cx, error);
+        }),
+    );
 
     // Run children so that they render and execute resources
     let children = children(cx).into_view(cx);
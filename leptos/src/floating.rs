@@ -0,0 +1,121 @@
+use crate::{Children, TextProp};
+use leptos_dom::{
+    create_node_ref,
+    floating::{use_floating, Alignment, FloatingOptions, Placement, Side},
+    html::{self, ElementDescriptor},
+    IntoView, NodeRef,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{signal_prelude::*, Scope};
+
+/// Positions its children next to an `anchor` element, like a tooltip,
+/// dropdown menu, or combobox list.
+///
+/// The children are rendered in a `<div>` with `position: fixed`, placed on
+/// the `side` of the anchor with the given `alignment` and `offset`. If
+/// there isn’t room on that side, they are flipped to the other side, and
+/// they are shifted along it to stay within the viewport, `padding` pixels
+/// from its edges. The placement that was used is set as the
+/// `data-placement` attribute, e.g., `top-start`, for styling arrows.
+///
+/// The position is updated whenever either element moves or is resized,
+/// e.g., when the page is scrolled. Wrap `<Floating/>` in a
+/// [`<Show/>`](crate::Show) to only render it while it is open.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::leptos_dom::floating::Side;
+///
+/// #[component]
+/// fn Menu(cx: Scope) -> impl IntoView {
+///     let button = create_node_ref::<html::Button>(cx);
+///     let (open, set_open) = create_signal(cx, false);
+///
+///     view! { cx,
+///         <button _ref=button on:click=move |_| set_open.update(|o| *o = !*o)>
+///             "Options"
+///         </button>
+///         <Show when=move || open.get() fallback=|_| ()>
+///             <Floating anchor=button side=Side::Bottom offset=4.0>
+///                 <ul role="menu">
+///                     <li role="menuitem">"Rename"</li>
+///                     <li role="menuitem">"Delete"</li>
+///                 </ul>
+///             </Floating>
+///         </Show>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Floating<El>(
+    cx: Scope,
+    /// The element to position the children next to.
+    anchor: NodeRef<El>,
+    /// The preferred side of the anchor. Defaults to [`Side::Bottom`].
+    #[prop(optional)]
+    side: Side,
+    /// The alignment along that side. Defaults to [`Alignment::Center`].
+    #[prop(optional)]
+    alignment: Alignment,
+    /// The gap between the anchor and the children, in pixels.
+    #[prop(optional)]
+    offset: f64,
+    /// The space to keep from the edges of the viewport, in pixels.
+    #[prop(optional)]
+    padding: f64,
+    /// Whether to move to the opposite side if there isn’t room on the
+    /// preferred one. Defaults to `true`.
+    #[prop(default = true)]
+    flip: bool,
+    /// Whether to move along the side to stay within the viewport. Defaults
+    /// to `true`.
+    #[prop(default = true)]
+    shift: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+    /// The floating content.
+    children: Children,
+) -> impl IntoView
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let floating = create_node_ref::<html::Div>(cx);
+    let position = use_floating(
+        cx,
+        anchor,
+        floating,
+        FloatingOptions {
+            placement: Placement::new(side, alignment),
+            offset,
+            flip,
+            shift,
+            padding,
+        },
+    );
+
+    let style = move || {
+        let position = position.get();
+        format!(
+            "position: fixed; top: 0; left: 0; \
+             transform: translate({}px, {}px)",
+            position.x.round(),
+            position.y.round()
+        )
+    };
+
+    view! { cx,
+        <div
+            node_ref=floating
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            style=style
+            data-placement=move || position.get().placement.to_string()
+        >
+            {children(cx)}
+        </div>
+    }
+}
@@ -1,6 +1,9 @@
 use leptos_dom::IntoView;
 use leptos_macro::component;
-use leptos_reactive::Scope;
+use leptos_reactive::{
+    create_isomorphic_effect, create_rw_signal, create_signal,
+    signal_prelude::*, store_value, ReadSignal, RwSignal, Scope,
+};
 use std::hash::Hash;
 
 /// Iterates over children and displays them, keyed by the `key` function given.
@@ -66,3 +69,144 @@ where
 {
     leptos_dom::Each::new(each, key, view).into_view(cx)
 }
+
+/// Renders each item of a [`ListStore`](leptos_reactive::ListStore),
+/// applying every push, insert, remove, swap or set directly to the DOM
+/// rather than diffing the whole list.
+///
+/// This is useful for very hot lists, like logs and tickers, where
+/// [`For`] would compare the entire list on every change.
+///
+/// ```
+/// # use leptos::*;
+///
+/// #[component]
+/// fn Log(cx: Scope) -> impl IntoView {
+///   let lines = create_list_store(cx, Vec::<String>::new());
+///
+///   view! {
+///     cx,
+///     <button on:click=move |_| lines.push("tick".to_string())>"Tick"</button>
+///     <ul>
+///       <ForStore
+///         store=lines
+///         view=move |cx, line: String| view! { cx, <li>{line}</li> }
+///       />
+///     </ul>
+///   }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component(transparent)]
+pub fn ForStore<T, EF, N>(
+    cx: Scope,
+    /// The list to render.
+    store: leptos_reactive::ListStore<T>,
+    /// The view that will be displayed for each item.
+    view: EF,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    EF: Fn(Scope, T) -> N + 'static,
+    N: IntoView,
+{
+    leptos_dom::EachOps::new(store, view).into_view(cx)
+}
+
+/// Iterates over items and displays them, keyed by their position in the
+/// list rather than by a key function.
+///
+/// Each row receives a signal of its item rather than the item itself. When
+/// the list changes, rows stay where they are and only the signals of the
+/// items that changed are updated; rows are only created or removed at the
+/// end of the list when its length changes. This is cheaper than [`For`]
+/// when identity is positional, like the cells of an editable grid, but
+/// reordering the list updates every row that moved, rather than moving it.
+///
+/// ```
+/// # use leptos::*;
+///
+/// #[component]
+/// fn Grid(cx: Scope) -> impl IntoView {
+///   let (cells, _set_cells) = create_signal(cx, vec![0; 10]);
+///
+///   view! {
+///     cx,
+///     <div>
+///       <IndexedFor
+///         each=cells
+///         view=move |cx, cell: ReadSignal<i32>| {
+///           view! {
+///             cx,
+///             <input type="number" prop:value=move || cell.get().to_string()/>
+///           }
+///         }
+///       />
+///     </div>
+///   }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component(transparent)]
+pub fn IndexedFor<IF, I, T, EF, N>(
+    cx: Scope,
+    /// Items over which the component should iterate.
+    each: IF,
+    /// The view that will be displayed for each row, given a signal of the
+    /// item at that position.
+    view: EF,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(Scope, ReadSignal<T>) -> N + 'static,
+    N: IntoView,
+    T: Clone + PartialEq + 'static,
+{
+    let items = store_value(cx, Vec::<T>::new());
+    let rows = store_value(cx, Vec::<RwSignal<T>>::new());
+    let (len, set_len) = create_signal(cx, 0);
+
+    create_isomorphic_effect(cx, move |_| {
+        let new_items = each().into_iter().collect::<Vec<_>>();
+
+        // rows past the new end are about to be removed, and the rest are
+        // updated in place
+        rows.update_value(|rows| rows.truncate(new_items.len()));
+        for (row, item) in rows.get_value().into_iter().zip(&new_items) {
+            if row.with_untracked(|value| value != item) {
+                row.set(item.clone());
+            }
+        }
+
+        let new_len = new_items.len();
+        items.set_value(new_items);
+        if len.get_untracked() != new_len {
+            set_len.set(new_len);
+        }
+    });
+
+    leptos_dom::Each::new(
+        move || 0..len.get(),
+        |idx| *idx,
+        move |cx, idx| {
+            let item = items.with_value(|items| items[idx].clone());
+            let row = create_rw_signal(cx, item);
+            rows.update_value(|rows| {
+                if idx < rows.len() {
+                    rows[idx] = row;
+                } else {
+                    rows.push(row);
+                }
+            });
+            view(cx, row.read_only())
+        },
+    )
+    .into_view(cx)
+}
@@ -0,0 +1,306 @@
+use super::{use_widget, widget_id};
+use crate::{dialog::call_method, document, Children, TextProp};
+use leptos_dom::{
+    create_node_ref, ev, helpers::event_target_value, html, IntoView, NodeRef,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_rw_signal, provide_context, signal_prelude::*, RwSignal, Scope,
+};
+use std::{fmt::Display, rc::Rc};
+
+#[derive(Clone)]
+struct ComboboxContext {
+    query: RwSignal<String>,
+    open: RwSignal<bool>,
+    /// The `id` of the option that is highlighted with the arrow keys.
+    active: RwSignal<Option<String>>,
+    options: NodeRef<html::Div>,
+    id: Rc<str>,
+}
+
+impl ComboboxContext {
+    fn options_id(&self) -> String {
+        format!("{}-options", self.id)
+    }
+
+    /// The `id`s of the options that can be chosen, in the order they’re
+    /// shown.
+    fn option_ids(&self) -> Vec<String> {
+        let Some(options) = self.options.get_untracked() else {
+            return Vec::new();
+        };
+        let Ok(nodes) =
+            options.query_selector_all("[role=option]:not([aria-disabled])")
+        else {
+            return Vec::new();
+        };
+        (0..nodes.length())
+            .filter_map(|idx| nodes.item(idx))
+            .filter_map(|node| {
+                js_sys::Reflect::get(&node, &"id".into()).ok()?.as_string()
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+struct ComboboxValue<T: 'static>(RwSignal<T>);
+
+/// A text input with a list of suggestions to choose from, like a search
+/// box with autocomplete.
+///
+/// Put a [`ComboboxInput`] and [`ComboboxOptions`] containing
+/// [`ComboboxOption`]s inside it. Typing sets `query`, which you can use to
+/// decide which options to show, and opens the options. <kbd>↑</kbd> and
+/// <kbd>↓</kbd> highlight an option while focus stays in the input, and
+/// <kbd>Enter</kbd> or a click chooses it, which sets `value` and fills in
+/// the input with the option’s [Display] output. <kbd>Escape</kbd> closes the
+/// options, or clears the input if they’re already closed.
+///
+/// The highlighted option has a `data-active` attribute, for styling.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let fruits = ["Apple", "Banana", "Cherry"];
+/// let value = create_rw_signal(cx, "");
+/// let query = create_rw_signal(cx, String::new());
+/// let matches = move || {
+///     let query = query.get().to_lowercase();
+///     fruits
+///         .into_iter()
+///         .filter(|fruit| fruit.to_lowercase().contains(&query))
+///         .collect::<Vec<_>>()
+/// };
+///
+/// view! { cx,
+///     <Combobox value=value query=query>
+///         <ComboboxInput label="Fruit"/>
+///         <ComboboxOptions class="suggestions">
+///             <For
+///                 each=matches
+///                 key=|fruit| *fruit
+///                 view=|cx, fruit| view! { cx,
+///                     <ComboboxOption value=fruit>{fruit}</ComboboxOption>
+///                 }
+///             />
+///         </ComboboxOptions>
+///     </Combobox>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Combobox<T>(
+    cx: Scope,
+    /// The chosen option.
+    value: RwSignal<T>,
+    /// The text in the input.
+    query: RwSignal<String>,
+    /// The input and options.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    provide_context(cx, ComboboxValue(value));
+    provide_context(
+        cx,
+        ComboboxContext {
+            query,
+            open: create_rw_signal(cx, false),
+            active: create_rw_signal(cx, None),
+            options: create_node_ref(cx),
+            id: widget_id("combobox").into(),
+        },
+    );
+
+    children(cx)
+}
+
+/// The text input of a [`Combobox`].
+#[component]
+pub fn ComboboxInput(
+    cx: Scope,
+    /// Describes the purpose of the input to assistive technology.
+    #[prop(optional, into)]
+    label: Option<TextProp>,
+    /// Sets the `placeholder` attribute.
+    #[prop(optional, into)]
+    placeholder: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+) -> impl IntoView {
+    let combobox =
+        use_widget::<ComboboxContext>(cx, "ComboboxInput", "Combobox");
+    let (query, open, active) =
+        (combobox.query, combobox.open, combobox.active);
+
+    let on_input = move |ev: ev::Event| {
+        query.set(event_target_value(&ev));
+        active.set(None);
+        open.set(true);
+    };
+
+    let on_keydown = {
+        let combobox = combobox.clone();
+        move |ev: ev::KeyboardEvent| {
+            let key = ev.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    ev.prevent_default();
+                    open.set(true);
+                    let ids = combobox.option_ids();
+                    let current = active.with_untracked(|active| {
+                        ids.iter().position(|id| Some(id) == active.as_ref())
+                    });
+                    let next = match (current, key.as_str()) {
+                        (_, _) if ids.is_empty() => None,
+                        (None, "ArrowDown") => Some(0),
+                        (None, _) => Some(ids.len() - 1),
+                        (Some(idx), "ArrowDown") => Some((idx + 1) % ids.len()),
+                        (Some(idx), _) => {
+                            Some(idx.checked_sub(1).unwrap_or(ids.len() - 1))
+                        }
+                    };
+                    active.set(next.map(|idx| ids[idx].clone()));
+                }
+                "Enter" => {
+                    let option = active
+                        .get_untracked()
+                        .filter(|_| open.get_untracked())
+                        .and_then(|id| document().get_element_by_id(&id));
+                    if let Some(option) = option {
+                        ev.prevent_default();
+                        call_method(&option, "click");
+                    }
+                }
+                "Escape" => {
+                    ev.prevent_default();
+                    if open.get_untracked() {
+                        open.set(false);
+                        active.set(None);
+                    } else {
+                        query.set(String::new());
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    view! { cx,
+        <input
+            type="text"
+            role="combobox"
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            placeholder=move || placeholder.as_ref().map(TextProp::get)
+            aria-label=move || label.as_ref().map(TextProp::get)
+            aria-autocomplete="list"
+            aria-expanded=move || open.get().to_string()
+            aria-controls=combobox.options_id()
+            aria-activedescendant=move || active.get()
+            autocomplete="off"
+            value=move || query.get()
+            prop:value=move || query.get()
+            on:input=on_input
+            on:keydown=on_keydown
+            on:blur=move |_| open.set(false)
+        />
+    }
+}
+
+/// The list of [`ComboboxOption`]s in a [`Combobox`], which is hidden while
+/// it is closed.
+#[component]
+pub fn ComboboxOptions(
+    cx: Scope,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The options.
+    children: Children,
+) -> impl IntoView {
+    let combobox =
+        use_widget::<ComboboxContext>(cx, "ComboboxOptions", "Combobox");
+    let open = combobox.open;
+
+    view! { cx,
+        <div
+            node_ref=combobox.options
+            role="listbox"
+            id=combobox.options_id()
+            class=move || class.as_ref().map(TextProp::get)
+            hidden=move || !open.get()
+            // keep focus in the input while an option is clicked
+            on:mousedown=|ev| ev.prevent_default()
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// One of the options in [`ComboboxOptions`].
+#[component]
+pub fn ComboboxOption<T>(
+    cx: Scope,
+    /// The value the combobox is set to when this option is chosen.
+    value: T,
+    /// Prevents the option from being chosen.
+    #[prop(optional)]
+    disabled: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the option.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    let combobox =
+        use_widget::<ComboboxContext>(cx, "ComboboxOption", "Combobox");
+    let (query, open, active) =
+        (combobox.query, combobox.open, combobox.active);
+    let ComboboxValue(chosen) =
+        use_widget::<ComboboxValue<T>>(cx, "ComboboxOption", "Combobox");
+    let id = widget_id("combobox-option");
+
+    let aria_selected = {
+        let value = value.clone();
+        move || chosen.with(|chosen| chosen == &value).to_string()
+    };
+    let is_active = {
+        let id = id.clone();
+        move || active.with(|active| active.as_ref() == Some(&id))
+    };
+    let choose = move |_| {
+        if !disabled {
+            query.set(value.to_string());
+            chosen.set(value.clone());
+            active.set(None);
+            open.set(false);
+        }
+    };
+
+    view! { cx,
+        <div
+            role="option"
+            id=id
+            class=move || class.as_ref().map(TextProp::get)
+            aria-selected=aria_selected
+            aria-disabled=disabled.then_some("true")
+            data-active=is_active
+            on:click=choose
+        >
+            {children(cx)}
+        </div>
+    }
+}
@@ -0,0 +1,108 @@
+use super::{use_widget, widget_id};
+use crate::{Children, TextProp};
+use leptos_dom::IntoView;
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_rw_signal, provide_context, signal_prelude::*, RwSignal, Scope,
+};
+
+#[derive(Clone)]
+struct DisclosureContext {
+    open: RwSignal<bool>,
+    panel_id: String,
+}
+
+/// A button that shows and hides a section of content, like an FAQ entry
+/// or an “advanced settings” section.
+///
+/// Put a [`DisclosureButton`] and a [`DisclosurePanel`] inside it. The panel
+/// is hidden while `open` is `false`, and the button toggles it.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let open = create_rw_signal(cx, false);
+/// view! { cx,
+///     <Disclosure open=open>
+///         <DisclosureButton class="faq-question">
+///             "Can I return my order?"
+///         </DisclosureButton>
+///         <DisclosurePanel class="faq-answer">
+///             "Yes, within 30 days."
+///         </DisclosurePanel>
+///     </Disclosure>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Disclosure(
+    cx: Scope,
+    /// Whether the panel is shown. Defaults to a signal that starts out
+    /// `false`.
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// The button and panel.
+    children: Children,
+) -> impl IntoView {
+    provide_context(
+        cx,
+        DisclosureContext {
+            open: open.unwrap_or_else(|| create_rw_signal(cx, false)),
+            panel_id: widget_id("disclosure-panel"),
+        },
+    );
+
+    children(cx)
+}
+
+/// The button of a [`Disclosure`], which shows and hides its panel.
+#[component]
+pub fn DisclosureButton(
+    cx: Scope,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the button.
+    children: Children,
+) -> impl IntoView {
+    let DisclosureContext { open, panel_id } =
+        use_widget(cx, "DisclosureButton", "Disclosure");
+
+    view! { cx,
+        <button
+            type="button"
+            class=move || class.as_ref().map(TextProp::get)
+            aria-expanded=move || open.get().to_string()
+            aria-controls=panel_id
+            on:click=move |_| open.update(|open| *open = !*open)
+        >
+            {children(cx)}
+        </button>
+    }
+}
+
+/// The content of a [`Disclosure`], which is hidden while it is closed.
+#[component]
+pub fn DisclosurePanel(
+    cx: Scope,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the panel.
+    children: Children,
+) -> impl IntoView {
+    let DisclosureContext { open, panel_id } =
+        use_widget(cx, "DisclosurePanel", "Disclosure");
+
+    view! { cx,
+        <div
+            id=panel_id
+            class=move || class.as_ref().map(TextProp::get)
+            hidden=move || !open.get()
+        >
+            {children(cx)}
+        </div>
+    }
+}
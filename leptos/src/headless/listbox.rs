@@ -0,0 +1,141 @@
+use super::use_widget;
+use crate::{Children, TextProp};
+use leptos_dom::{
+    create_node_ref, ev, html,
+    roving_focus::{use_roving_tabindex, Orientation, RovingOptions},
+    IntoView,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{provide_context, signal_prelude::*, RwSignal, Scope};
+
+#[derive(Clone)]
+struct ListboxContext<T: 'static> {
+    value: RwSignal<T>,
+}
+
+/// A list of [`ListboxOption`]s to choose one from, which are always shown.
+///
+/// Unlike a `<select>`, the options can contain any content. <kbd>↑</kbd>,
+/// <kbd>↓</kbd>, <kbd>Home</kbd>, <kbd>End</kbd>, and typing the start of an
+/// option move focus between the options, and <kbd>Enter</kbd> or
+/// <kbd>Space</kbd> choose the focused one, as does clicking it.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let assignee = create_rw_signal(cx, None::<u32>);
+/// view! { cx,
+///     <Listbox value=assignee label="Assignee">
+///         <ListboxOption value=None>"Unassigned"</ListboxOption>
+///         <ListboxOption value=Some(1)>"Ada"</ListboxOption>
+///         <ListboxOption value=Some(2)>"Grace"</ListboxOption>
+///     </Listbox>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Listbox<T>(
+    cx: Scope,
+    /// The chosen option.
+    value: RwSignal<T>,
+    /// Describes the purpose of the list to assistive technology.
+    #[prop(optional, into)]
+    label: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+    /// The options.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    provide_context(cx, ListboxContext { value });
+    let list = create_node_ref::<html::Div>(cx);
+    use_roving_tabindex(
+        cx,
+        list,
+        RovingOptions {
+            orientation: Orientation::Vertical,
+            item_selector: "[role=option]",
+            ..Default::default()
+        },
+    );
+
+    view! { cx,
+        <div
+            node_ref=list
+            role="listbox"
+            aria-label=move || label.as_ref().map(TextProp::get)
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// One of the options in a [`Listbox`].
+#[component]
+pub fn ListboxOption<T>(
+    cx: Scope,
+    /// The value the listbox is set to when this option is chosen.
+    value: T,
+    /// Prevents the option from being chosen.
+    #[prop(optional)]
+    disabled: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the option.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let chosen =
+        use_widget::<ListboxContext<T>>(cx, "ListboxOption", "Listbox").value;
+    let aria_selected = {
+        let value = value.clone();
+        move || chosen.with(|chosen| chosen == &value).to_string()
+    };
+    // after this, the listbox moves the tab stop to the focused option
+    let tabindex = if chosen.with_untracked(|chosen| chosen == &value) {
+        0
+    } else {
+        -1
+    };
+    let choose = move || {
+        if !disabled {
+            chosen.set(value.clone());
+        }
+    };
+    let on_keydown = {
+        let choose = choose.clone();
+        move |ev: ev::KeyboardEvent| {
+            if ev.key() == "Enter" || ev.key() == " " {
+                ev.prevent_default();
+                choose();
+            }
+        }
+    };
+
+    view! { cx,
+        <div
+            role="option"
+            class=move || class.as_ref().map(TextProp::get)
+            aria-selected=aria_selected
+            aria-disabled=disabled.then_some("true")
+            tabindex=tabindex
+            on:click=move |_| choose()
+            on:keydown=on_keydown
+        >
+            {children(cx)}
+        </div>
+    }
+}
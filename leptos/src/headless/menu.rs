@@ -0,0 +1,264 @@
+use super::{use_widget, widget_id};
+use crate::{dialog::call_method, Children, TextProp};
+use leptos_dom::{
+    create_node_ref, ev,
+    helpers::request_animation_frame,
+    html,
+    roving_focus::{use_roving_tabindex, Orientation, RovingOptions},
+    IntoView, NodeRef,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_rw_signal, provide_context, signal_prelude::*,
+    RwSignal, Scope,
+};
+use std::rc::Rc;
+
+#[derive(Clone)]
+struct MenuContext {
+    open: RwSignal<bool>,
+    button: NodeRef<html::Button>,
+    id: Rc<str>,
+}
+
+impl MenuContext {
+    fn button_id(&self) -> String {
+        format!("{}-button", self.id)
+    }
+
+    fn items_id(&self) -> String {
+        format!("{}-items", self.id)
+    }
+
+    /// Closes the menu and moves focus back to its button.
+    fn close(&self) {
+        self.open.set(false);
+        if let Some(button) = self.button.get_untracked() {
+            _ = button.focus();
+        }
+    }
+}
+
+/// A button that opens a list of actions, like a “more options” menu.
+///
+/// Put a [`MenuButton`] and [`MenuItems`] containing [`MenuItem`]s inside
+/// it. Opening the menu moves focus to its first item, and the arrow keys,
+/// <kbd>Home</kbd>, <kbd>End</kbd>, and typing the start of an item move
+/// between the items. Choosing an item with a click, <kbd>Enter</kbd>, or
+/// <kbd>Space</kbd> runs its `on_select` and closes the menu, as do
+/// <kbd>Escape</kbd> and moving focus out of it.
+///
+/// To position the items next to the button, put them in a
+/// [`Floating`](crate::Floating).
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// view! { cx,
+///     <Menu>
+///         <MenuButton class="icon-button">"⋯"</MenuButton>
+///         <MenuItems class="menu">
+///             <MenuItem on_select=|| log!("renaming")>"Rename"</MenuItem>
+///             <MenuItem on_select=|| log!("deleting")>"Delete"</MenuItem>
+///         </MenuItems>
+///     </Menu>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Menu(
+    cx: Scope,
+    /// Whether the menu is open. Defaults to a signal that starts out
+    /// `false`.
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// The button and items.
+    children: Children,
+) -> impl IntoView {
+    provide_context(
+        cx,
+        MenuContext {
+            open: open.unwrap_or_else(|| create_rw_signal(cx, false)),
+            button: create_node_ref(cx),
+            id: widget_id("menu").into(),
+        },
+    );
+
+    children(cx)
+}
+
+/// The button that opens a [`Menu`].
+#[component]
+pub fn MenuButton(
+    cx: Scope,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the button.
+    children: Children,
+) -> impl IntoView {
+    let menu = use_widget::<MenuContext>(cx, "MenuButton", "Menu");
+    let open = menu.open;
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "ArrowDown" || ev.key() == "ArrowUp" {
+            ev.prevent_default();
+            open.set(true);
+        }
+    };
+
+    view! { cx,
+        <button
+            type="button"
+            node_ref=menu.button
+            id=menu.button_id()
+            class=move || class.as_ref().map(TextProp::get)
+            aria-haspopup="menu"
+            aria-expanded=move || open.get().to_string()
+            aria-controls=menu.items_id()
+            on:click=move |_| open.update(|open| *open = !*open)
+            on:keydown=on_keydown
+        >
+            {children(cx)}
+        </button>
+    }
+}
+
+/// The list of [`MenuItem`]s in a [`Menu`], which is hidden while the menu
+/// is closed.
+#[component]
+pub fn MenuItems(
+    cx: Scope,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The items.
+    children: Children,
+) -> impl IntoView {
+    let menu = use_widget::<MenuContext>(cx, "MenuItems", "Menu");
+    let open = menu.open;
+    let items = create_node_ref::<html::Div>(cx);
+    use_roving_tabindex(
+        cx,
+        items,
+        RovingOptions {
+            orientation: Orientation::Vertical,
+            item_selector: "[role=menuitem]",
+            ..Default::default()
+        },
+    );
+
+    // focus the first item once the menu is shown
+    create_effect(cx, move |_| {
+        if let (true, Some(items)) = (open.get(), items.get()) {
+            request_animation_frame(move || {
+                if let Ok(Some(item)) = items.query_selector("[role=menuitem]")
+                {
+                    call_method(&item, "focus");
+                }
+            });
+        }
+    });
+
+    let on_keydown = {
+        let menu = menu.clone();
+        move |ev: ev::KeyboardEvent| match ev.key().as_str() {
+            "Escape" => {
+                ev.prevent_default();
+                menu.close();
+            }
+            "Tab" => open.set(false),
+            _ => {}
+        }
+    };
+    // close the menu when focus moves anywhere outside of it
+    let button = menu.button;
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let (Some(items), Some(related)) =
+            (items.get_untracked(), ev.related_target())
+        else {
+            return;
+        };
+        let contains = js_sys::Reflect::get(&items, &"contains".into())
+            .ok()
+            .filter(|contains| contains.is_function())
+            .and_then(|contains| {
+                js_sys::Function::from(contains)
+                    .call1(&items, &related)
+                    .ok()
+            })
+            .and_then(|contains| contains.as_bool())
+            .unwrap_or(true);
+        let is_button = button
+            .get_untracked()
+            .map_or(false, |button| js_sys::Object::is(&related, &button));
+        if !contains && !is_button {
+            open.set(false);
+        }
+    };
+
+    view! { cx,
+        <div
+            node_ref=items
+            role="menu"
+            id=menu.items_id()
+            class=move || class.as_ref().map(TextProp::get)
+            aria-labelledby=menu.button_id()
+            hidden=move || !open.get()
+            on:keydown=on_keydown
+            on:focusout=on_focusout
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// An action in [`MenuItems`].
+#[component]
+pub fn MenuItem<F>(
+    cx: Scope,
+    /// Runs when the item is chosen.
+    on_select: F,
+    /// Prevents the item from being chosen.
+    #[prop(optional)]
+    disabled: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The label of the item.
+    children: Children,
+) -> impl IntoView
+where
+    F: Fn() + 'static,
+{
+    let menu = use_widget::<MenuContext>(cx, "MenuItem", "Menu");
+    let select = Rc::new(move || {
+        if !disabled {
+            on_select();
+            menu.close();
+        }
+    });
+    let on_keydown = {
+        let select = Rc::clone(&select);
+        move |ev: ev::KeyboardEvent| {
+            if ev.key() == "Enter" || ev.key() == " " {
+                ev.prevent_default();
+                select();
+            }
+        }
+    };
+
+    view! { cx,
+        <div
+            role="menuitem"
+            class=move || class.as_ref().map(TextProp::get)
+            aria-disabled=disabled.then_some("true")
+            tabindex="-1"
+            on:click=move |_| select()
+            on:keydown=on_keydown
+        >
+            {children(cx)}
+        </div>
+    }
+}
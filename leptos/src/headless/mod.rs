@@ -0,0 +1,52 @@
+//! Unstyled, accessible building blocks for interactive widgets.
+//!
+//! Each widget is a set of components that render the elements, ARIA roles,
+//! and attributes that assistive technology expects, and handle focus and
+//! keyboard interaction as described in the
+//! [ARIA Authoring Practices](https://www.w3.org/WAI/ARIA/apg/patterns/).
+//! They don’t apply any styles: pass a `class` to each part, and style the
+//! states with attribute selectors like `[aria-selected=true]` or
+//! `[aria-expanded=true]`. Their state lives in signals that you pass in,
+//! so you can read or change it from anywhere.
+//!
+//! The parts of a widget are nested inside its root component, which
+//! connects them, and they panic if they’re used outside of it:
+//! - [`Disclosure`]: [`DisclosureButton`] and [`DisclosurePanel`]
+//! - [`Menu`]: [`MenuButton`], [`MenuItems`], and [`MenuItem`]
+//! - [`Listbox`]: [`ListboxOption`]
+//! - [`Combobox`]: [`ComboboxInput`], [`ComboboxOptions`], and
+//!   [`ComboboxOption`]
+//! - [`Tabs`]: [`TabList`], [`Tab`], and [`TabPanel`]
+//! - [`RadioGroup`]: [`Radio`]
+
+mod combobox;
+mod disclosure;
+mod listbox;
+mod menu;
+mod radio_group;
+mod tabs;
+
+pub use combobox::*;
+pub use disclosure::*;
+pub use listbox::*;
+pub use menu::*;
+pub use radio_group::*;
+pub use tabs::*;
+
+use leptos_dom::HydrationCtx;
+use leptos_reactive::{use_context, Scope};
+
+/// Creates an `id` for one of a widget’s elements, which is the same on the
+/// server and in the browser, so the elements can refer to each other with
+/// `aria-controls` and `aria-labelledby`.
+fn widget_id(name: &str) -> String {
+    HydrationCtx::generated_name(name)
+}
+
+/// Gets the context provided by the root component of a widget.
+#[track_caller]
+fn use_widget<T: Clone + 'static>(cx: Scope, part: &str, root: &str) -> T {
+    use_context::<T>(cx).unwrap_or_else(|| {
+        panic!("<{part}/> must be used inside of a <{root}/>")
+    })
+}
@@ -0,0 +1,150 @@
+use super::use_widget;
+use crate::{Children, TextProp};
+use leptos_dom::{
+    create_node_ref, ev, html,
+    roving_focus::{use_roving_tabindex, RovingOptions},
+    IntoView,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{provide_context, signal_prelude::*, RwSignal, Scope};
+
+#[derive(Clone)]
+struct RadioGroupContext<T: 'static> {
+    value: RwSignal<T>,
+}
+
+/// A set of [`Radio`] buttons, of which only one can be checked at a time.
+///
+/// Unlike `<input type="radio">`, the radios can contain any content, like
+/// a description or an image. Only the checked radio is in the tab order,
+/// and the arrow keys move to the previous or next radio and check it.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let plan = create_rw_signal(cx, "monthly");
+/// view! { cx,
+///     <RadioGroup value=plan label="Billing plan">
+///         <Radio value="monthly">
+///             <strong>"Monthly"</strong> " $10 per month"
+///         </Radio>
+///         <Radio value="yearly">
+///             <strong>"Yearly"</strong> " $100 per year"
+///         </Radio>
+///     </RadioGroup>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn RadioGroup<T>(
+    cx: Scope,
+    /// The value of the checked radio.
+    value: RwSignal<T>,
+    /// Describes the purpose of the group to assistive technology.
+    #[prop(optional, into)]
+    label: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The radios.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    provide_context(cx, RadioGroupContext { value });
+    let group = create_node_ref::<html::Div>(cx);
+    use_roving_tabindex(
+        cx,
+        group,
+        RovingOptions {
+            typeahead: false,
+            item_selector: "[role=radio]",
+            ..Default::default()
+        },
+    );
+
+    view! { cx,
+        <div
+            node_ref=group
+            role="radiogroup"
+            aria-label=move || label.as_ref().map(TextProp::get)
+            class=move || class.as_ref().map(TextProp::get)
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// One of the options in a [`RadioGroup`].
+#[component]
+pub fn Radio<T>(
+    cx: Scope,
+    /// The value the group is set to when this radio is checked.
+    value: T,
+    /// Prevents the radio from being checked.
+    #[prop(optional)]
+    disabled: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The label of the radio.
+    children: Children,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let group_value =
+        use_widget::<RadioGroupContext<T>>(cx, "Radio", "RadioGroup").value;
+    let is_checked = {
+        let value = value.clone();
+        move || group_value.with(|checked| checked == &value)
+    };
+    // after this, the group moves the tab stop to the focused radio
+    let tabindex = if group_value.with_untracked(|checked| checked == &value) {
+        0
+    } else {
+        -1
+    };
+    let check = move || {
+        if !disabled && !group_value.with_untracked(|checked| checked == &value)
+        {
+            group_value.set(value.clone());
+        }
+    };
+    let aria_checked = {
+        let is_checked = is_checked.clone();
+        move || is_checked().to_string()
+    };
+    let on_keydown = {
+        let check = check.clone();
+        move |ev: ev::KeyboardEvent| {
+            if ev.key() == " " {
+                ev.prevent_default();
+                check();
+            }
+        }
+    };
+    // moving focus to a radio with the keyboard also checks it
+    let on_focus = {
+        let check = check.clone();
+        move |_| check()
+    };
+
+    view! { cx,
+        <div
+            role="radio"
+            class=move || class.as_ref().map(TextProp::get)
+            aria-checked=aria_checked
+            aria-disabled=disabled.then_some("true")
+            tabindex=tabindex
+            on:focus=on_focus
+            on:click=move |_| check()
+            on:keydown=on_keydown
+        >
+            {children(cx)}
+        </div>
+    }
+}
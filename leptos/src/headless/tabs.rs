@@ -0,0 +1,206 @@
+use super::{use_widget, widget_id};
+use crate::{Children, TextProp};
+use leptos_dom::{
+    create_node_ref, html,
+    roving_focus::{use_roving_tabindex, Orientation, RovingOptions},
+    IntoView,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_rw_signal, provide_context, signal_prelude::*, RwSignal, Scope,
+};
+use std::rc::Rc;
+
+#[derive(Clone)]
+struct TabsContext {
+    selected: RwSignal<usize>,
+    vertical: bool,
+    id: Rc<str>,
+}
+
+impl TabsContext {
+    fn tab_id(&self, index: usize) -> String {
+        format!("{}-tab-{index}", self.id)
+    }
+
+    fn panel_id(&self, index: usize) -> String {
+        format!("{}-panel-{index}", self.id)
+    }
+}
+
+/// A set of panels, one of which is shown at a time, chosen with a list of
+/// tabs.
+///
+/// Put a [`TabList`] of [`Tab`]s and a [`TabPanel`] for each of them inside
+/// it. Tabs and panels are matched by their `index`, and the panel whose
+/// index is `selected` is shown. Within the tab list, the arrow keys,
+/// <kbd>Home</kbd>, and <kbd>End</kbd> move to another tab and select it,
+/// and only the selected tab is in the tab order.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::headless::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let selected = create_rw_signal(cx, 0);
+/// view! { cx,
+///     <Tabs selected=selected>
+///         <TabList label="Account">
+///             <Tab index=0>"Profile"</Tab>
+///             <Tab index=1>"Billing"</Tab>
+///         </TabList>
+///         <TabPanel index=0>"Your name and photo"</TabPanel>
+///         <TabPanel index=1>"Your payment methods"</TabPanel>
+///     </Tabs>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Tabs(
+    cx: Scope,
+    /// The index of the selected tab. Defaults to a signal that starts out
+    /// `0`.
+    #[prop(optional)]
+    selected: Option<RwSignal<usize>>,
+    /// Whether the tabs are laid out vertically, so they are navigated with
+    /// <kbd>↑</kbd> and <kbd>↓</kbd> instead of <kbd>←</kbd> and
+    /// <kbd>→</kbd>.
+    #[prop(optional)]
+    vertical: bool,
+    /// The tab list and panels.
+    children: Children,
+) -> impl IntoView {
+    provide_context(
+        cx,
+        TabsContext {
+            selected: selected.unwrap_or_else(|| create_rw_signal(cx, 0)),
+            vertical,
+            id: widget_id("tabs").into(),
+        },
+    );
+
+    children(cx)
+}
+
+/// The list of [`Tab`]s in [`Tabs`].
+#[component]
+pub fn TabList(
+    cx: Scope,
+    /// Describes the purpose of the tabs to assistive technology.
+    #[prop(optional, into)]
+    label: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The tabs.
+    children: Children,
+) -> impl IntoView {
+    let tabs = use_widget::<TabsContext>(cx, "TabList", "Tabs");
+    let (orientation, aria_orientation) = if tabs.vertical {
+        (Orientation::Vertical, "vertical")
+    } else {
+        (Orientation::Horizontal, "horizontal")
+    };
+    let list = create_node_ref::<html::Div>(cx);
+    use_roving_tabindex(
+        cx,
+        list,
+        RovingOptions {
+            orientation,
+            typeahead: false,
+            item_selector: "[role=tab]",
+            ..Default::default()
+        },
+    );
+
+    view! { cx,
+        <div
+            node_ref=list
+            role="tablist"
+            aria-orientation=aria_orientation
+            aria-label=move || label.as_ref().map(TextProp::get)
+            class=move || class.as_ref().map(TextProp::get)
+        >
+            {children(cx)}
+        </div>
+    }
+}
+
+/// A tab in a [`TabList`], which selects the [`TabPanel`] with the same
+/// `index`.
+#[component]
+pub fn Tab(
+    cx: Scope,
+    /// The index of this tab.
+    index: usize,
+    /// Prevents the tab from being selected.
+    #[prop(optional)]
+    disabled: bool,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The label of the tab.
+    children: Children,
+) -> impl IntoView {
+    let tabs = use_widget::<TabsContext>(cx, "Tab", "Tabs");
+    let selected = tabs.selected;
+    let is_selected = move || selected.get() == index;
+    // after this, the tab list moves the tab stop to the focused tab
+    let tabindex = if selected.get_untracked() == index {
+        0
+    } else {
+        -1
+    };
+    let select = move || {
+        if !disabled && !selected.with_untracked(|s| *s == index) {
+            selected.set(index);
+        }
+    };
+
+    view! { cx,
+        <button
+            type="button"
+            role="tab"
+            id=tabs.tab_id(index)
+            class=move || class.as_ref().map(TextProp::get)
+            aria-selected=move || is_selected().to_string()
+            aria-controls=tabs.panel_id(index)
+            aria-disabled=disabled.then_some("true")
+            tabindex=tabindex
+            // moving focus to a tab with the keyboard also selects it
+            on:focus=move |_| select()
+            on:click=move |_| select()
+        >
+            {children(cx)}
+        </button>
+    }
+}
+
+/// The content shown when the [`Tab`] with the same `index` is selected.
+#[component]
+pub fn TabPanel(
+    cx: Scope,
+    /// The index of the tab that shows this panel.
+    index: usize,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// The content of the panel.
+    children: Children,
+) -> impl IntoView {
+    let tabs = use_widget::<TabsContext>(cx, "TabPanel", "Tabs");
+    let selected = tabs.selected;
+
+    view! { cx,
+        <div
+            role="tabpanel"
+            id=tabs.panel_id(index)
+            class=move || class.as_ref().map(TextProp::get)
+            aria-labelledby=tabs.tab_id(index)
+            tabindex=0
+            hidden=move || selected.get() != index
+        >
+            {children(cx)}
+        </div>
+    }
+}
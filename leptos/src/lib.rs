@@ -96,6 +96,12 @@
 //!   from the server to the client.
 //! - `miniserde` In SSR/hydrate mode, uses [miniserde](https://docs.rs/miniserde/latest/miniserde/) to serialize resources and send them
 //!   from the server to the client.
+//! - `text-markers` In release builds, anchors components and control flow in the DOM with empty
+//!   text nodes instead of comments, which are cheaper to create on component-heavy pages.
+//! - `metrics` Measures hydration time, time to interactive, and resource latency, and can report
+//!   the browser’s core web vitals. See [`metrics`](crate::metrics) for how to receive them.
+//! - `a11y` In debug builds, checks the mounted app for common accessibility problems, like
+//!   images without `alt` text, and logs a warning for each one.
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
 //! which mode your app is operating in.
@@ -147,6 +153,8 @@
 
 mod additional_attributes;
 pub use additional_attributes::*;
+mod animated_show;
+pub use animated_show::*;
 mod await_;
 pub use await_::*;
 pub use leptos_config::{self, get_configuration, LeptosOptions};
@@ -167,9 +175,12 @@ pub use leptos_dom::{
         set_interval_with_handle, set_timeout, set_timeout_with_handle,
         window_event_listener, window_event_listener_untyped,
     },
-    html, log, math, mount_to, mount_to_body, svg, warn, window, Attribute,
-    Class, CollectView, Errors, Fragment, HtmlElement, IntoAttribute,
-    IntoClass, IntoProperty, IntoStyle, IntoView, NodeRef, Property, View,
+    html, hydrate, hydrate_body, log, math, mount_to, mount_to_body,
+    mount_to_body_with_handle, mount_to_with_handle, provide_error_reporter,
+    set_error_reporter, svg, throw_error, warn, window, Attribute, Class,
+    CollectView, Directive, ErrorOrigin, ErrorReport, Errors, EventFlow,
+    EventOptions, Fragment, HtmlElement, IntoAttribute, IntoClass,
+    IntoProperty, IntoStyle, IntoView, MountHandle, NodeRef, Property, View,
 };
 #[cfg(not(any(target_arch = "wasm32", feature = "template_macro")))]
 pub use leptos_macro::view as template;
@@ -177,27 +188,41 @@ pub use leptos_macro::{component, server, slot, view, Params};
 pub use leptos_reactive::*;
 pub use leptos_server::{
     self, create_action, create_multi_action, create_server_action,
-    create_server_multi_action, Action, MultiAction, ServerFn, ServerFnError,
+    create_server_action_with_progress, create_server_multi_action, Action,
+    MultiAction, Progress, ServerFn, ServerFnError,
 };
 pub use server_fn::{self, ServerFn as _};
 pub use typed_builder;
 #[cfg(all(target_arch = "wasm32", feature = "template_macro"))]
 pub use {leptos_macro::template, wasm_bindgen, web_sys};
+mod dialog;
+pub use dialog::*;
 mod error_boundary;
 pub use error_boundary::*;
+mod floating;
 mod for_loop;
+pub mod headless;
+mod relative_time;
+mod select;
 mod show;
+pub use floating::*;
 pub use for_loop::*;
+pub use relative_time::*;
+pub use select::*;
 pub use show::*;
 mod suspense_component;
 pub use suspense_component::*;
 mod text_prop;
+mod toast;
 mod transition;
+mod virtual_table;
 pub use text_prop::TextProp;
+pub use toast::*;
 #[cfg(any(debug_assertions, feature = "ssr"))]
 #[doc(hidden)]
 pub use tracing;
 pub use transition::*;
+pub use virtual_table::*;
 extern crate self as leptos;
 
 /// The most common type for the `children` property on components,
@@ -0,0 +1,170 @@
+use crate::TextProp;
+use leptos_dom::{
+    helpers::{set_timeout_with_handle, TimeoutHandle},
+    intl::{
+        format_relative_time, to_iso_string, use_date_time_format,
+        use_intl_hydrated, use_intl_locale, DateTimeFormatOptions,
+        DateTimeStyle,
+    },
+    IntoView,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_rw_signal, on_cleanup, signal_prelude::*,
+    MaybeSignal, Scope,
+};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+/// Shows how long ago, or in how long, a time is, e.g., “5 minutes ago”,
+/// and keeps it up to date.
+///
+/// This renders a `<time>` whose `datetime` attribute is the exact time, and
+/// whose `title` is the time formatted for the locale, so it can be seen by
+/// hovering. The text is formatted for the locale from
+/// [`use_intl_locale`], and is updated more often the closer the time is to
+/// now: every second for the first minute, and then less and less often.
+///
+/// The server’s “now” isn’t the browser’s, so on the server, and until the
+/// app has been hydrated, the text is the formatted time, like the `title`.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Comment(cx: Scope, text: String, posted_at: f64) -> impl IntoView {
+///     view! { cx,
+///         <article>
+///             <p>{text}</p>
+///             <RelativeTime datetime=posted_at/>
+///         </article>
+///     }
+/// }
+/// ```
+#[component]
+pub fn RelativeTime(
+    cx: Scope,
+    /// The time, in milliseconds since the Unix epoch, like `Date.now()`.
+    #[prop(into)]
+    datetime: MaybeSignal<f64>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+) -> impl IntoView {
+    let locale = use_intl_locale(cx);
+    let now = create_rw_signal(cx, current_time());
+    let timeout = Rc::new(Cell::new(None::<TimeoutHandle>));
+
+    // only runs in the browser, so the time is rendered once on the server
+    create_effect(cx, {
+        let timeout = Rc::clone(&timeout);
+        move |_| {
+            let distance = (datetime.get() - now.get()).abs();
+            if let Some(handle) = timeout.take() {
+                handle.clear();
+            }
+            let handle = set_timeout_with_handle(
+                move || now.set(current_time()),
+                refresh_interval(distance),
+            );
+            timeout.set(handle.ok());
+        }
+    });
+    on_cleanup(cx, move || {
+        if let Some(handle) = timeout.take() {
+            handle.clear();
+        }
+    });
+
+    let date_time = use_date_time_format(
+        cx,
+        DateTimeFormatOptions {
+            date_style: Some(DateTimeStyle::Medium),
+            time_style: Some(DateTimeStyle::Short),
+            time_zone: None,
+        },
+    );
+    let title = move || date_time.format(datetime.get());
+    let hydrated = use_intl_hydrated(cx);
+    let text = move || {
+        if cfg!(any(feature = "csr", feature = "hydrate")) && hydrated.get() {
+            let seconds = (datetime.get() - now.get()) / 1000.0;
+            format_relative_time(&locale.get(), seconds)
+        } else {
+            title()
+        }
+    };
+
+    view! { cx,
+        <time
+            datetime=move || to_iso_string(datetime.get())
+            title=title
+            class=move || class.as_ref().map(TextProp::get)
+        >
+            {text}
+        </time>
+    }
+}
+
+/// The current time, in milliseconds since the Unix epoch.
+fn current_time() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |since| since.as_secs_f64() * 1000.0)
+    }
+}
+
+/// How long to wait before updating a time that is `distance` milliseconds
+/// from now, so that it’s updated about as often as its text changes.
+fn refresh_interval(distance: f64) -> Duration {
+    const MINUTE: f64 = 60_000.0;
+    if distance < MINUTE {
+        Duration::from_secs(1)
+    } else if distance < 60.0 * MINUTE {
+        Duration::from_secs(15)
+    } else if distance < 24.0 * 60.0 * MINUTE {
+        Duration::from_secs(5 * 60)
+    } else {
+        Duration::from_secs(60 * 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closer_times_are_refreshed_more_often() {
+        const MINUTE: f64 = 60_000.0;
+        assert_eq!(refresh_interval(0.0), Duration::from_secs(1));
+        assert_eq!(refresh_interval(59_999.0), Duration::from_secs(1));
+        assert_eq!(refresh_interval(MINUTE), Duration::from_secs(15));
+        assert_eq!(refresh_interval(60.0 * MINUTE), Duration::from_secs(300));
+        assert_eq!(
+            refresh_interval(24.0 * 60.0 * MINUTE),
+            Duration::from_secs(3600)
+        );
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    #[test]
+    fn the_server_renders_the_exact_time() {
+        use leptos_reactive::{create_runtime, create_scope};
+
+        _ = create_scope(create_runtime(), |cx| {
+            let html = view! { cx,
+                <RelativeTime datetime=1_685_628_309_000.0/>
+            }
+            .into_view(cx)
+            .render_to_string(cx);
+
+            assert!(html.contains("datetime=\"2023-06-01T14:05:09Z\""));
+            assert!(html.contains(">2023-06-01 14:05<"));
+            assert!(!html.contains(" ago"));
+        });
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{For, TextProp};
+use leptos_dom::{create_node_ref, html, IntoView, NodeRef};
+use leptos_macro::{component, view};
+use leptos_reactive::{signal_prelude::*, MaybeSignal, RwSignal, Scope};
+use std::fmt::Display;
+
+/// A `<select>` bound to a signal holding one of a list of typed options.
+///
+/// Each option’s `value` attribute is the option’s [Display] output, which
+/// is also used to find the option that was chosen, so it should be unique.
+/// The options can be a signal: when the list changes, options that are
+/// still present stay selected. The label defaults to the same text, or can
+/// be set with `label`.
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// enum Size {
+///     Small,
+///     Large,
+/// }
+///
+/// impl std::fmt::Display for Size {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{self:?}")
+///     }
+/// }
+///
+/// let size = create_rw_signal(cx, Size::Small);
+/// view! { cx,
+///     <Select options=vec![Size::Small, Size::Large] value=size/>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn Select<T>(
+    cx: Scope,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<T>>,
+    /// The selected option.
+    value: RwSignal<T>,
+    /// Creates the text shown for an option. Defaults to its [Display] output.
+    #[prop(optional)]
+    label: Option<fn(&T) -> String>,
+    /// Sets the `name` attribute, for use in a `<form>`.
+    #[prop(optional, into)]
+    name: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    let select_ref = create_node_ref::<html::Select>(cx);
+    let on_change = {
+        let options = options.clone();
+        move |_| {
+            let selected = selected_values(select_ref);
+            let chosen = options.with(|options| {
+                options
+                    .iter()
+                    .find(|option| selected.contains(&option.to_string()))
+                    .cloned()
+            });
+            if let Some(chosen) = chosen {
+                value.set(chosen);
+            }
+        }
+    };
+
+    view! { cx,
+        <select
+            node_ref=select_ref
+            name=move || name.as_ref().map(TextProp::get)
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            on:change=on_change
+        >
+            <For
+                each=move || options.get()
+                key=ToString::to_string
+                view=move |cx, option: T| {
+                    let key = option.to_string();
+                    let text = label.map_or_else(|| key.clone(), |label| label(&option));
+                    let is_selected = move || value.with(|value| value == &option);
+                    view! { cx,
+                        <option
+                            value=key
+                            // the attribute is rendered on the server, and
+                            // the property keeps the selection in sync
+                            selected=is_selected.clone()
+                            prop:selected=is_selected
+                        >
+                            {text}
+                        </option>
+                    }
+                }
+            />
+        </select>
+    }
+}
+
+/// A `<select multiple>` bound to a signal holding any number of a list of
+/// typed options. See [Select].
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let (toppings, _) = create_signal(cx, vec!["cheese", "olives", "basil"]);
+/// let chosen = create_rw_signal(cx, vec!["cheese"]);
+/// view! { cx,
+///     <MultiSelect options=toppings value=chosen/>
+/// }
+/// # ;
+/// # });
+/// ```
+#[component]
+pub fn MultiSelect<T>(
+    cx: Scope,
+    /// The options to choose from.
+    #[prop(into)]
+    options: MaybeSignal<Vec<T>>,
+    /// The selected options, in the order they’re listed in `options`.
+    value: RwSignal<Vec<T>>,
+    /// Creates the text shown for an option. Defaults to its [Display] output.
+    #[prop(optional)]
+    label: Option<fn(&T) -> String>,
+    /// Sets the `name` attribute, for use in a `<form>`.
+    #[prop(optional, into)]
+    name: Option<TextProp>,
+    /// Sets the `class` attribute.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    let select_ref = create_node_ref::<html::Select>(cx);
+    let on_change = {
+        let options = options.clone();
+        move |_| {
+            let selected = selected_values(select_ref);
+            let chosen = options.with(|options| {
+                options
+                    .iter()
+                    .filter(|option| selected.contains(&option.to_string()))
+                    .cloned()
+                    .collect()
+            });
+            value.set(chosen);
+        }
+    };
+
+    view! { cx,
+        <select
+            multiple
+            node_ref=select_ref
+            name=move || name.as_ref().map(TextProp::get)
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            on:change=on_change
+        >
+            <For
+                each=move || options.get()
+                key=ToString::to_string
+                view=move |cx, option: T| {
+                    let key = option.to_string();
+                    let text = label.map_or_else(|| key.clone(), |label| label(&option));
+                    let is_selected = move || value.with(|value| value.contains(&option));
+                    view! { cx,
+                        <option
+                            value=key
+                            // the attribute is rendered on the server, and
+                            // the property keeps the selection in sync
+                            selected=is_selected.clone()
+                            prop:selected=is_selected
+                        >
+                            {text}
+                        </option>
+                    }
+                }
+            />
+        </select>
+    }
+}
+
+/// The `value`s of the options that are currently selected.
+fn selected_values(select_ref: NodeRef<html::Select>) -> Vec<String> {
+    let Some(select) = select_ref.get() else {
+        return Vec::new();
+    };
+    let selected = select.selected_options();
+    (0..selected.length())
+        .filter_map(|index| selected.item(index))
+        .filter_map(|option| option.get_attribute("value"))
+        .collect()
+}
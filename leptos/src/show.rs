@@ -7,6 +7,12 @@ use std::{cell::RefCell, rc::Rc};
 /// and show the fallback when it is `false`, without rerendering every time
 /// the condition changes.
 ///
+/// Unlike a `move || if when() { ... } else { ... }` closure, the children
+/// and the fallback are only created again when the value of `when` flips,
+/// not whenever a signal it reads is set. Signals that are read while they
+/// are being created, rather than in a closure inside them, don't cause them
+/// to be created again either.
+///
 /// *Note*: Because of the nature of generic arguments, it’s not really possible
 /// to make the `fallback` optional. If you want an empty fallback state—in other
 /// words, if you want to show the children if `when` is true and noting otherwise—use
@@ -53,14 +59,18 @@ where
     let prev_disposer = Rc::new(RefCell::new(None::<ScopeDisposer>));
 
     move || {
+        let when = memoized_when.get();
         if let Some(disposer) = prev_disposer.take() {
             disposer.dispose();
         }
-        let (view, disposer) =
-            cx.run_child_scope(|cx| match memoized_when.get() {
+        // only `when` is tracked, so nothing read while building the view can
+        // cause it to be built again
+        let (view, disposer) = cx.run_child_scope(|cx| {
+            cx.untrack(|| match when {
                 true => children(cx).into_view(cx),
                 false => fallback(cx).into_view(cx),
-            });
+            })
+        });
         *prev_disposer.borrow_mut() = Some(disposer);
         view
     }
@@ -16,6 +16,17 @@ use std::rc::Rc;
 /// those resources are read under the suspense), so you cannot assume that resources have
 /// `Some` value in `children`.
 ///
+/// Only resources read under the nearest `<Suspense/>` count: a nested `<Suspense/>`
+/// shows its own fallback without affecting its parent. In the browser, the `children`
+/// are rendered again whenever the pending resources change from some to none, and the
+/// `fallback` is rendered when they change from none to some. Use a
+/// [`Transition`](crate::Transition) to keep showing the previous `children` instead.
+///
+/// During server rendering, the `fallback` is rendered into the HTML for now, between
+/// markers that identify this `<Suspense/>`. Once its resources resolve, the streaming
+/// renderers, like [`render_to_stream`](leptos_dom::ssr::render_to_stream), send the
+/// `children` to replace everything between the markers, and the client hydrates them.
+///
 /// ```
 /// # use leptos_reactive::*;
 /// # use leptos_macro::*;
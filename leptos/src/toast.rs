@@ -0,0 +1,403 @@
+use crate::{For, TextProp};
+use leptos_dom::{
+    helpers::{set_timeout_with_handle, TimeoutHandle},
+    IntoView, Portal,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, on_cleanup, provide_context,
+    signal_prelude::*, store_value, use_context, Memo, RwSignal, Scope,
+    StoredValue,
+};
+use std::{borrow::Cow, cell::Cell, rc::Rc, time::Duration};
+
+/// How long a toast is shown by default.
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+
+/// Provides a [`Toasts`] manager to this [`Scope`] and all of its
+/// descendants, which can then be used with [`use_toasts`].
+///
+/// Call this once, high in the tree (e.g., in the root component), and
+/// render a [`ToastViewport`] below it to show the toasts.
+pub fn provide_toasts(cx: Scope) -> Toasts {
+    let toasts = Toasts {
+        list: create_rw_signal(cx, Vec::new()),
+        latest: create_rw_signal(cx, None),
+        next_id: store_value(cx, 0),
+    };
+    provide_context(cx, toasts);
+    toasts
+}
+
+/// Gets the [`Toasts`] manager from the nearest [`provide_toasts`].
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn App(cx: Scope) -> impl IntoView {
+///     provide_toasts(cx);
+///     view! { cx,
+///         <SaveButton/>
+///         <ToastViewport/>
+///     }
+/// }
+///
+/// #[component]
+/// fn SaveButton(cx: Scope) -> impl IntoView {
+///     let toasts = use_toasts(cx);
+///     let on_click = move |_| {
+///         toasts.push(Toast::new("Saved").kind(ToastKind::Success));
+///     };
+///     view! { cx, <button on:click=on_click>"Save"</button> }
+/// }
+/// ```
+///
+/// # Panics
+/// Panics if [`provide_toasts`] wasn't called in a parent scope.
+#[track_caller]
+pub fn use_toasts(cx: Scope) -> Toasts {
+    use_context(cx).expect(
+        "use_toasts() was called without provide_toasts() being called in a \
+         parent scope",
+    )
+}
+
+/// What kind of message a [`Toast`] is.
+///
+/// This is set as the toast’s `data-kind` attribute, for styling.
+/// [`ToastKind::Error`]s interrupt screen readers, and the others are
+/// announced once the user is idle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToastKind {
+    /// A neutral message.
+    #[default]
+    Info,
+    /// An action succeeded.
+    Success,
+    /// Something may need attention.
+    Warning,
+    /// An action failed.
+    Error,
+}
+
+impl ToastKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ToastKind::Info => "info",
+            ToastKind::Success => "success",
+            ToastKind::Warning => "warning",
+            ToastKind::Error => "error",
+        }
+    }
+}
+
+/// A short message shown by a [`ToastViewport`], created with
+/// [`Toasts::push`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Toast {
+    message: Cow<'static, str>,
+    kind: ToastKind,
+    duration: Option<Duration>,
+}
+
+impl Toast {
+    /// Creates an [`Info`](ToastKind::Info) toast, which is dismissed after
+    /// five seconds.
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            message: message.into(),
+            kind: ToastKind::default(),
+            duration: Some(DEFAULT_DURATION),
+        }
+    }
+
+    /// Sets what kind of message this is.
+    pub fn kind(mut self, kind: ToastKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets how long the toast is shown before it is dismissed, not
+    /// counting the time the pointer is over the viewport. If this is
+    /// `None`, the toast is shown until it is dismissed.
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<&'static str> for Toast {
+    fn from(message: &'static str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for Toast {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Identifies a toast, to dismiss it with [`Toasts::dismiss`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// Manages the toasts shown by a [`ToastViewport`], from
+/// [`provide_toasts`] or [`use_toasts`].
+#[derive(Copy, Clone, Debug)]
+pub struct Toasts {
+    list: RwSignal<Vec<(ToastId, Toast)>>,
+    latest: RwSignal<Option<Toast>>,
+    next_id: StoredValue<usize>,
+}
+
+impl Toasts {
+    /// Shows a toast, returning its ID.
+    pub fn push(&self, toast: impl Into<Toast>) -> ToastId {
+        let id = ToastId(self.next_id.get_value());
+        self.next_id.update_value(|next_id| *next_id += 1);
+        let toast = toast.into();
+        self.latest.set(Some(toast.clone()));
+        self.list.update(|list| list.push((id, toast)));
+        id
+    }
+
+    /// Dismisses a toast, if it’s still shown.
+    pub fn dismiss(&self, id: ToastId) {
+        if self
+            .list
+            .with_untracked(|list| list.iter().any(|t| t.0 == id))
+        {
+            self.list.update(|list| list.retain(|t| t.0 != id));
+        }
+    }
+
+    /// Dismisses every toast.
+    pub fn clear(&self) {
+        self.list.update(Vec::clear);
+    }
+
+    /// Returns the number of toasts that are shown.
+    pub fn len(&self) -> usize {
+        self.list.with(Vec::len)
+    }
+
+    /// Returns `true` if no toasts are shown.
+    pub fn is_empty(&self) -> bool {
+        self.list.with(Vec::is_empty)
+    }
+
+    /// The message of the latest toast pushed that is (or, if `assertive`
+    /// is `false`, isn’t) an [`Error`](ToastKind::Error). It only changes
+    /// when a toast is pushed, so that live regions don’t read out older
+    /// messages again when a toast is dismissed.
+    fn announcement(&self, cx: Scope, assertive: bool) -> Memo<Option<String>> {
+        let latest = self.latest;
+        create_memo(cx, move |prev: Option<&Option<String>>| {
+            latest.with(|latest| match latest {
+                Some(toast)
+                    if (toast.kind == ToastKind::Error) == assertive =>
+                {
+                    Some(toast.message.to_string())
+                }
+                _ => prev.cloned().flatten(),
+            })
+        })
+    }
+}
+
+/// Shows the toasts pushed to [`use_toasts`], at the end of the `<body>`.
+///
+/// Each toast is an `<li>` with a `data-kind` attribute and a dismiss
+/// button, and is dismissed automatically after its duration. While the
+/// pointer is over the viewport, or focus is inside it, the timers are
+/// paused, so that toasts don’t disappear while someone is reading or
+/// dismissing them. New messages are also announced by screen readers.
+///
+/// The viewport doesn’t position itself: style its `class` with, e.g.,
+/// `position: fixed; bottom: 1rem; right: 1rem`.
+///
+/// See [`use_toasts`] for an example.
+#[component]
+pub fn ToastViewport(
+    cx: Scope,
+    /// The most toasts to show at once. When there are more, the oldest ones
+    /// wait until newer ones are dismissed. Defaults to `3`.
+    #[prop(default = 3)]
+    max_visible: usize,
+    /// Describes the viewport to assistive technology. Defaults to
+    /// “Notifications”.
+    #[prop(optional, into)]
+    label: Option<TextProp>,
+    /// The accessible label of each toast’s dismiss button. Defaults to
+    /// “Dismiss”.
+    #[prop(optional, into)]
+    dismiss_label: Option<TextProp>,
+    /// Sets the `class` attribute of the `<ol>` of toasts.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+) -> impl IntoView {
+    let toasts = use_toasts(cx);
+    let paused = create_rw_signal(cx, false);
+    let label = label.unwrap_or_else(|| "Notifications".into());
+    let dismiss_label = dismiss_label.unwrap_or_else(|| "Dismiss".into());
+
+    let visible = move || {
+        toasts.list.with(|list| {
+            let start = list.len().saturating_sub(max_visible);
+            list[start..].to_vec()
+        })
+    };
+    let polite = toasts.announcement(cx, false);
+    let assertive = toasts.announcement(cx, true);
+
+    let toast_view = move |cx: Scope, (id, toast): (ToastId, Toast)| {
+        if let Some(duration) = toast.duration {
+            dismiss_after(cx, toasts, id, duration, paused);
+        }
+        let dismiss_label = dismiss_label.clone();
+
+        view! { cx,
+            <li data-kind=toast.kind.as_str()>
+                <span>{toast.message.into_owned()}</span>
+                <button
+                    type="button"
+                    aria-label=move || dismiss_label.get()
+                    on:click=move |_| toasts.dismiss(id)
+                >
+                    "×"
+                </button>
+            </li>
+        }
+    };
+
+    Portal::new(move |cx| {
+        view! { cx,
+            <section aria-label=move || label.get()>
+                <ol
+                    class=move || class.as_ref().map(TextProp::get)
+                    on:mouseenter=move |_| paused.set(true)
+                    on:mouseleave=move |_| paused.set(false)
+                    on:focusin=move |_| paused.set(true)
+                    on:focusout=move |_| paused.set(false)
+                >
+                    <For each=visible key=|(id, _)| *id view=toast_view/>
+                </ol>
+                <div role="status" aria-live="polite" style=VISUALLY_HIDDEN>
+                    {move || polite.get()}
+                </div>
+                <div role="alert" aria-live="assertive" style=VISUALLY_HIDDEN>
+                    {move || assertive.get()}
+                </div>
+            </section>
+        }
+    })
+}
+
+/// Hides an element visually, while keeping it available to screen
+/// readers.
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; \
+                               overflow: hidden; clip: rect(0 0 0 0); \
+                               white-space: nowrap";
+
+/// Dismisses the toast after it has been shown for `duration`, not counting
+/// the time the viewport is `paused`.
+fn dismiss_after(
+    cx: Scope,
+    toasts: Toasts,
+    id: ToastId,
+    duration: Duration,
+    paused: RwSignal<bool>,
+) {
+    let remaining = Rc::new(Cell::new(duration));
+    let started_at = Rc::new(Cell::new(0.0));
+    let timeout = Rc::new(Cell::new(None::<TimeoutHandle>));
+
+    create_effect(cx, {
+        let timeout = Rc::clone(&timeout);
+        move |_| {
+            let now = js_sys::Date::now();
+            if paused.get() {
+                if let Some(handle) = timeout.take() {
+                    handle.clear();
+                    let elapsed = Duration::from_secs_f64(
+                        (now - started_at.get()).max(0.0) / 1000.0,
+                    );
+                    remaining.set(remaining.get().saturating_sub(elapsed));
+                }
+            } else if timeout.get().is_none() {
+                started_at.set(now);
+                let handle = set_timeout_with_handle(
+                    move || toasts.dismiss(id),
+                    remaining.get(),
+                );
+                timeout.set(handle.ok());
+            }
+        }
+    });
+
+    on_cleanup(cx, move || {
+        if let Some(handle) = timeout.take() {
+            handle.clear();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, create_scope};
+
+    #[test]
+    fn toasts_can_be_pushed_and_dismissed() {
+        _ = create_scope(create_runtime(), |cx| {
+            let toasts = provide_toasts(cx);
+            let first = toasts.push("First");
+            let second = toasts.push("Second");
+            assert_ne!(first, second);
+            assert_eq!(toasts.len(), 2);
+
+            toasts.dismiss(first);
+            toasts.dismiss(first);
+            assert_eq!(toasts.len(), 1);
+
+            toasts.clear();
+            assert!(toasts.is_empty());
+        });
+    }
+
+    #[test]
+    fn only_pushed_toasts_are_announced() {
+        _ = create_scope(create_runtime(), |cx| {
+            let toasts = provide_toasts(cx);
+            let polite = toasts.announcement(cx, false);
+            let assertive = toasts.announcement(cx, true);
+            assert_eq!(polite.get(), None);
+
+            let saved = toasts.push("Saved");
+            let failed =
+                toasts.push(Toast::new("Failed").kind(ToastKind::Error));
+            assert_eq!(polite.get().as_deref(), Some("Saved"));
+            assert_eq!(assertive.get().as_deref(), Some("Failed"));
+
+            let copied = toasts.push("Copied");
+            assert_eq!(polite.get().as_deref(), Some("Copied"));
+            assert_eq!(assertive.get().as_deref(), Some("Failed"));
+
+            // dismissing toasts doesn't read out the ones before them again
+            toasts.dismiss(copied);
+            toasts.dismiss(failed);
+            assert_eq!(polite.get().as_deref(), Some("Copied"));
+            assert_eq!(assertive.get().as_deref(), Some("Failed"));
+
+            toasts.dismiss(saved);
+            toasts.clear();
+            assert_eq!(polite.get().as_deref(), Some("Copied"));
+        });
+    }
+}
@@ -10,6 +10,8 @@ use std::{
 /// component, it will show the `fallback` while they are loading. Once all are resolved,
 /// it will render the `children`. Unlike [`Suspense`](crate::Suspense), this will not fall
 /// back to the `fallback` state if there are further changes after the initial load.
+/// Instead, it keeps showing the previous `children` until the new ones are ready, and
+/// calls `set_pending` so the UI can show that something is loading.
 ///
 /// Note that the `children` will be rendered initially (in order to capture the fact that
 /// those resources are read under the suspense), so you cannot assume that resources have
@@ -33,9 +35,10 @@ use std::{
 ///
 /// view! { cx,
 ///   <div>
+///     {move || pending.get().then(|| view! { cx, <p>"Updating..."</p> })}
 ///     <Transition
 ///       fallback=move || view! { cx, <p>"Loading..."</p>}
-///       set_pending=set_pending.into()
+///       set_pending=set_pending
 ///     >
 ///       {move || {
 ///           cats.read(cx).map(|data| match data {
@@ -68,8 +71,10 @@ pub fn Transition<F, E>(
     fallback: F,
     /// A function that will be called when the component transitions into or out of
     /// the `pending` state, with its argument indicating whether it is pending (`true`)
-    /// or not pending (`false`).
-    #[prop(optional)]
+    /// or not pending (`false`). This is usually a [`WriteSignal`](leptos_reactive::WriteSignal),
+    /// which can be used to show a loading indicator while the previous `children` are still
+    /// shown.
+    #[prop(optional, into)]
     set_pending: Option<SignalSetter<bool>>,
     /// Will be displayed once all resources have resolved.
     children: Box<dyn Fn(Scope) -> Fragment>,
@@ -0,0 +1,424 @@
+use crate::{For, TextProp};
+use leptos_dom::{
+    create_node_ref, helpers::request_animation_frame, html, IntoView, View,
+};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, signal_prelude::*,
+    store_value, Scope, Signal,
+};
+use std::{collections::HashMap, hash::Hash, rc::Rc};
+
+/// A column of a [`VirtualTable`].
+pub struct TableColumn<T> {
+    width: f64,
+    header: Rc<dyn Fn(Scope) -> View>,
+    cell: Rc<dyn Fn(Scope, &T) -> View>,
+}
+
+impl<T> TableColumn<T> {
+    /// Creates a column that is `width` pixels wide, with a `header`, and
+    /// renders each row’s cell with `cell`.
+    pub fn new<H, HN, C, CN>(width: f64, header: H, cell: C) -> Self
+    where
+        H: Fn(Scope) -> HN + 'static,
+        HN: IntoView,
+        C: Fn(Scope, &T) -> CN + 'static,
+        CN: IntoView,
+    {
+        Self {
+            width,
+            header: Rc::new(move |cx| header(cx).into_view(cx)),
+            cell: Rc::new(move |cx, row| cell(cx, row).into_view(cx)),
+        }
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width,
+            header: Rc::clone(&self.header),
+            cell: Rc::clone(&self.cell),
+        }
+    }
+}
+
+/// A `<table>` that only renders the rows and columns that are scrolled
+/// into view, so it stays fast with many thousands of rows.
+///
+/// The table scrolls inside a container `height` pixels tall, and its
+/// header row sticks to the top while scrolling; give the header cells a
+/// background so rows don’t show through them. Rows can have different
+/// heights: each row is assumed to be `estimated_row_height` until it has
+/// been rendered, and its measured height is then cached by the row’s `key`,
+/// so that it follows the row when rows are inserted or removed before it,
+/// and measured again whenever `rows` changes while it is visible. Columns have fixed widths,
+/// and only the ones that are scrolled into view horizontally are rendered.
+///
+/// On the server, the first rows that fit in `height` are rendered, with all
+/// of their columns.
+///
+/// ```
+/// # use leptos::*;
+/// #[derive(Clone)]
+/// struct Order {
+///     id: u32,
+///     customer: String,
+/// }
+///
+/// #[component]
+/// fn Orders(cx: Scope, orders: ReadSignal<Vec<Order>>) -> impl IntoView {
+///     let columns = vec![
+///         TableColumn::new(
+///             80.0,
+///             |_| "ID",
+///             |_, order: &Order| order.id.to_string(),
+///         ),
+///         TableColumn::new(
+///             240.0,
+///             |_| "Customer",
+///             |_, order: &Order| order.customer.clone(),
+///         ),
+///     ];
+///
+///     view! { cx,
+///         <VirtualTable
+///             rows=orders
+///             key=|order: &Order| order.id
+///             columns=columns
+///             height=600.0
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn VirtualTable<T, KF, K>(
+    cx: Scope,
+    /// The rows of the table.
+    #[prop(into)]
+    rows: Signal<Vec<T>>,
+    /// A key function for each row, which must be unique among the rows.
+    key: KF,
+    /// The columns of the table.
+    columns: Vec<TableColumn<T>>,
+    /// The height of the scrolling area, in pixels. Defaults to `400`.
+    #[prop(default = 400.0)]
+    height: f64,
+    /// The height a row is assumed to have until it has been measured, in
+    /// pixels. Defaults to `32`.
+    #[prop(default = 32.0)]
+    estimated_row_height: f64,
+    /// How many extra rows and columns to render past each edge of the
+    /// visible area. Defaults to `2`.
+    #[prop(default = 2)]
+    overscan: usize,
+    /// Sets the `class` attribute of the scrolling container.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+    /// Sets the `id` attribute of the scrolling container.
+    #[prop(optional, into)]
+    id: Option<TextProp>,
+) -> impl IntoView
+where
+    T: 'static,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + 'static,
+{
+    let container = create_node_ref::<html::Div>(cx);
+    let scroll_top = create_rw_signal(cx, 0.0);
+    let scroll_left = create_rw_signal(cx, 0.0);
+    // until the container has been measured, every column is rendered
+    let viewport_width = create_rw_signal(cx, f64::INFINITY);
+    let row_heights = create_rw_signal(cx, HashMap::<K, f64>::new());
+    let key = store_value(cx, key);
+
+    let column_count = columns.len();
+    let total_width = columns.iter().map(|column| column.width).sum::<f64>();
+    let columns = store_value(cx, columns);
+
+    let measure_viewport = move || {
+        if let Some(container) = container.get_untracked() {
+            scroll_top.set(container.scroll_top() as f64);
+            scroll_left.set(container.scroll_left() as f64);
+            viewport_width.set(container.client_width() as f64);
+        }
+    };
+    container.on_load(cx, move |_| request_animation_frame(measure_viewport));
+
+    let row_window = create_memo(cx, move |_| {
+        rows.with(|rows| {
+            row_heights.with(|heights| {
+                key.with_value(|key| {
+                    visible_window(
+                        |idx| {
+                            heights
+                                .get(&key(&rows[idx]))
+                                .copied()
+                                .unwrap_or(estimated_row_height)
+                        },
+                        rows.len(),
+                        scroll_top.get(),
+                        height,
+                        overscan,
+                    )
+                })
+            })
+        })
+    });
+    let column_window = create_memo(cx, move |_| {
+        columns.with_value(|columns| {
+            visible_window(
+                |idx| columns[idx].width,
+                columns.len(),
+                scroll_left.get(),
+                viewport_width.get(),
+                overscan,
+            )
+        })
+    });
+    let visible_columns = move || {
+        let window = column_window.get();
+        window.start..window.end
+    };
+    let spacer_style = |width: f64| {
+        format!("padding: 0; border: 0; width: {width}px; min-width: {width}px")
+    };
+    let left_spacer = move || spacer_style(column_window.get().before);
+    let right_spacer = move || spacer_style(column_window.get().after);
+    let cell_style = move |idx: usize| {
+        let width = columns.with_value(|columns| columns[idx].width);
+        format!("width: {width}px; min-width: {width}px; max-width: {width}px")
+    };
+
+    let header_cell = move |cx: Scope, idx: usize| {
+        let style = format!(
+            "position: sticky; top: 0; z-index: 1; {}",
+            cell_style(idx)
+        );
+        view! { cx,
+            <th scope="col" aria-colindex={idx + 1} style=style>
+                {columns.with_value(|columns| (columns[idx].header)(cx))}
+            </th>
+        }
+    };
+
+    let body_row = move |cx: Scope, idx: usize| {
+        let row_ref = create_node_ref::<html::Tr>(cx);
+        // measure the row once it's rendered, and again if its data changes
+        create_effect(cx, move |_| {
+            rows.with(|_| ());
+            if let Some(row) = row_ref.get() {
+                request_animation_frame(move || {
+                    let measured = row.offset_height() as f64;
+                    let Some(row_key) = rows.with_untracked(|rows| {
+                        key.with_value(|key| rows.get(idx).map(key))
+                    }) else {
+                        return;
+                    };
+                    let changed = row_heights.with_untracked(|heights| {
+                        heights.get(&row_key) != Some(&measured)
+                    });
+                    // detached rows have no height
+                    if measured > 0.0 && changed {
+                        row_heights.update(|heights| {
+                            heights.insert(row_key, measured);
+                        });
+                    }
+                });
+            }
+        });
+
+        let body_cell = move |cx: Scope, column: usize| {
+            let cell =
+                move || {
+                    rows.with(|rows| {
+                        let row = rows.get(idx)?;
+                        Some(columns.with_value(|columns| {
+                            (columns[column].cell)(cx, row)
+                        }))
+                    })
+                };
+            view! { cx,
+                <td aria-colindex={column + 1} style=cell_style(column)>
+                    {cell}
+                </td>
+            }
+        };
+
+        view! { cx,
+            <tr node_ref=row_ref aria-rowindex={idx + 2}>
+                <td aria-hidden="true" style=left_spacer></td>
+                <For each=visible_columns key=|column| *column view=body_cell/>
+                <td aria-hidden="true" style=right_spacer></td>
+            </tr>
+        }
+    };
+
+    // rows the spacers are rendered in span every column, since a row
+    // needs at least one cell to have a height
+    let spacer_row = move |cx: Scope, height: Box<dyn Fn() -> f64>| {
+        let style =
+            move || format!("padding: 0; border: 0; height: {}px", height());
+        view! { cx,
+            <tr aria-hidden="true">
+                <td colspan={column_count + 2} style=style></td>
+            </tr>
+        }
+    };
+
+    view! { cx,
+        <div
+            node_ref=container
+            class=move || class.as_ref().map(TextProp::get)
+            id=move || id.as_ref().map(TextProp::get)
+            style=format!("overflow: auto; height: {height}px")
+            on:scroll=move |_| measure_viewport()
+        >
+            <table
+                role="grid"
+                aria-rowcount=move || rows.with(Vec::len) + 1
+                aria-colcount=column_count
+                style=format!(
+                    "table-layout: fixed; border-collapse: separate; \
+                     border-spacing: 0; width: {total_width}px"
+                )
+            >
+                <thead>
+                    <tr aria-rowindex=1>
+                        <th aria-hidden="true" style=left_spacer></th>
+                        <For
+                            each=visible_columns
+                            key=|column| *column
+                            view=header_cell
+                        />
+                        <th aria-hidden="true" style=right_spacer></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {spacer_row(cx, Box::new(move || row_window.get().before))}
+                    <For
+                        each=move || {
+                            let window = row_window.get();
+                            window.start..window.end
+                        }
+                        key=|idx| *idx
+                        view=body_row
+                    />
+                    {spacer_row(cx, Box::new(move || row_window.get().after))}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// The range of rows or columns to render, and the size of the space
+/// before and after them that isn't rendered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Window {
+    start: usize,
+    end: usize,
+    before: f64,
+    after: f64,
+}
+
+fn visible_window(
+    size: impl Fn(usize) -> f64,
+    len: usize,
+    offset: f64,
+    viewport: f64,
+    overscan: usize,
+) -> Window {
+    let mut start = None;
+    let mut end = len;
+    let mut position = 0.0;
+    for idx in 0..len {
+        let item_size = size(idx);
+        if start.is_none() && position + item_size > offset {
+            start = Some(idx);
+        }
+        if start.is_some() && position >= offset + viewport {
+            end = idx;
+            break;
+        }
+        position += item_size;
+    }
+    // if the list has shrunk past the scroll position, show its end
+    let start = start.unwrap_or(len).saturating_sub(overscan);
+    let end = end.saturating_add(overscan).min(len);
+
+    Window {
+        start,
+        end,
+        before: (0..start).map(&size).sum(),
+        after: (end..len).map(&size).sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_covers_the_viewport() {
+        let window = visible_window(|_| 10.0, 100, 0.0, 50.0, 0);
+        assert_eq!(
+            window,
+            Window {
+                start: 0,
+                end: 5,
+                before: 0.0,
+                after: 950.0
+            }
+        );
+
+        // a partly visible item at either edge is included
+        let window = visible_window(|_| 10.0, 100, 95.0, 50.0, 0);
+        assert_eq!(
+            window,
+            Window {
+                start: 9,
+                end: 15,
+                before: 90.0,
+                after: 850.0
+            }
+        );
+    }
+
+    #[test]
+    fn window_includes_overscan() {
+        let window = visible_window(|_| 10.0, 100, 95.0, 50.0, 2);
+        assert_eq!((window.start, window.end), (7, 17));
+        assert_eq!((window.before, window.after), (70.0, 830.0));
+
+        // but not past either end
+        let window = visible_window(|_| 10.0, 10, 0.0, 50.0, 20);
+        assert_eq!((window.start, window.end), (0, 10));
+        assert_eq!((window.before, window.after), (0.0, 0.0));
+    }
+
+    #[test]
+    fn window_follows_item_sizes() {
+        let sizes = [10.0, 50.0, 10.0, 10.0];
+        let window = visible_window(|idx| sizes[idx], 4, 15.0, 20.0, 0);
+        assert_eq!(
+            window,
+            Window {
+                start: 1,
+                end: 2,
+                before: 10.0,
+                after: 20.0
+            }
+        );
+    }
+
+    #[test]
+    fn window_shows_the_end_of_a_shrunk_list() {
+        let window = visible_window(|_| 10.0, 3, 100.0, 50.0, 1);
+        assert_eq!((window.start, window.end), (2, 3));
+        assert_eq!((window.before, window.after), (20.0, 0.0));
+
+        let window = visible_window(|_| 10.0, 0, 100.0, 50.0, 1);
+        assert_eq!((window.start, window.end), (0, 0));
+    }
+}
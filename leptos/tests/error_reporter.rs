@@ -0,0 +1,57 @@
+use leptos::*;
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+#[derive(Debug)]
+struct Oops;
+
+impl std::fmt::Display for Oops {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("oops")
+    }
+}
+
+impl std::error::Error for Oops {}
+
+#[component]
+fn Failing(cx: Scope) -> impl IntoView {
+    view! { cx, <p>{Err::<(), _>(Oops)}</p> }
+}
+
+#[component]
+fn Page(cx: Scope) -> impl IntoView {
+    view! { cx, <Failing/> }
+}
+
+#[test]
+fn error_reporters_receive_errors_with_their_component_path() {
+    _ = create_scope(create_runtime(), |cx| {
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        provide_error_reporter(cx, {
+            let reports = Rc::clone(&reports);
+            move |report: &ErrorReport| {
+                reports.borrow_mut().push((
+                    report.component_path.clone(),
+                    report.origin,
+                    report.caught,
+                    report.error.to_string(),
+                ))
+            }
+        });
+
+        _ = view! { cx, <Page/> }.into_view(cx);
+        throw_error(cx, Oops);
+
+        assert_eq!(
+            *reports.borrow(),
+            vec![
+                (
+                    vec![Cow::from("Page"), Cow::from("Failing")],
+                    ErrorOrigin::View,
+                    false,
+                    "oops".to_string()
+                ),
+                (vec![], ErrorOrigin::Thrown, false, "oops".to_string()),
+            ]
+        );
+    });
+}
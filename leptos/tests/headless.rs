@@ -0,0 +1,207 @@
+use leptos::{headless::*, *};
+
+/// Returns the opening tag that contains `needle`, or of the element whose
+/// text starts with it.
+#[allow(dead_code)]
+fn tag<'a>(html: &'a str, needle: &str) -> &'a str {
+    let pos = html
+        .find(needle)
+        .unwrap_or_else(|| panic!("{needle:?} isn't in {html}"));
+    // skip closing tags and hydration comments
+    let start = html[..pos]
+        .match_indices('<')
+        .map(|(idx, _)| idx)
+        .filter(|idx| html.as_bytes()[idx + 1].is_ascii_alphabetic())
+        .last()
+        .unwrap();
+    let end = start + html[start..].find('>').unwrap();
+    &html[start..=end]
+}
+
+/// Returns the value of the attribute `name` in an opening `tag`.
+#[allow(dead_code)]
+fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {name}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn tabs_show_the_selected_panel() {
+    _ = create_scope(create_runtime(), |cx| {
+        let selected = create_rw_signal(cx, 1);
+        let html = view! { cx,
+            <Tabs selected=selected>
+                <TabList label="Account">
+                    <Tab index=0>"Profile"</Tab>
+                    <Tab index=1>"Billing"</Tab>
+                </TabList>
+                <TabPanel index=0>"Your name"</TabPanel>
+                <TabPanel index=1>"Your payment methods"</TabPanel>
+            </Tabs>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let list = tag(&html, "role=\"tablist\"");
+        assert_eq!(attr(list, "role"), Some("tablist"));
+        assert_eq!(attr(list, "aria-orientation"), Some("horizontal"));
+        assert_eq!(attr(list, "aria-label"), Some("Account"));
+
+        let (profile, billing) = (tag(&html, "Profile"), tag(&html, "Billing"));
+        assert_eq!(attr(profile, "aria-selected"), Some("false"));
+        assert_eq!(attr(billing, "aria-selected"), Some("true"));
+        // only the selected tab starts out in the tab order
+        assert_eq!(attr(profile, "tabindex"), Some("-1"));
+        assert_eq!(attr(billing, "tabindex"), Some("0"));
+
+        let (name, payment) =
+            (tag(&html, "Your name"), tag(&html, "Your payment methods"));
+        assert!(name.contains(" hidden"));
+        assert!(!payment.contains(" hidden"));
+
+        // each tab controls its panel, which is labelled by the tab
+        assert_eq!(attr(billing, "aria-controls"), attr(payment, "id"));
+        assert_eq!(attr(payment, "aria-labelledby"), attr(billing, "id"));
+        assert_ne!(attr(profile, "id"), attr(billing, "id"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn disclosure_hides_its_panel_until_opened() {
+    _ = create_scope(create_runtime(), |cx| {
+        let html = view! { cx,
+            <Disclosure>
+                <DisclosureButton>"Details"</DisclosureButton>
+                <DisclosurePanel>"More information"</DisclosurePanel>
+            </Disclosure>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let button = tag(&html, "Details");
+        let panel = tag(&html, "More information");
+        assert_eq!(attr(button, "aria-expanded"), Some("false"));
+        assert_eq!(attr(button, "aria-controls"), attr(panel, "id"));
+        assert!(panel.contains(" hidden"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn menu_items_are_labelled_by_the_button() {
+    _ = create_scope(create_runtime(), |cx| {
+        let open = create_rw_signal(cx, true);
+        let html = view! { cx,
+            <Menu open=open>
+                <MenuButton>"Options"</MenuButton>
+                <MenuItems>
+                    <MenuItem on_select=|| ()>"Edit"</MenuItem>
+                    <MenuItem on_select=|| () disabled=true>"Delete"</MenuItem>
+                </MenuItems>
+            </Menu>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let button = tag(&html, "Options");
+        let items = tag(&html, "role=\"menu\"");
+        assert_eq!(attr(button, "aria-haspopup"), Some("menu"));
+        assert_eq!(attr(button, "aria-expanded"), Some("true"));
+        assert_eq!(attr(button, "aria-controls"), attr(items, "id"));
+        assert_eq!(attr(items, "aria-labelledby"), attr(button, "id"));
+        assert!(!items.contains(" hidden"));
+
+        let (edit, delete) = (tag(&html, "Edit"), tag(&html, "Delete"));
+        assert_eq!(attr(edit, "tabindex"), Some("-1"));
+        assert_eq!(attr(edit, "aria-disabled"), None);
+        assert_eq!(attr(delete, "aria-disabled"), Some("true"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn listbox_and_radio_group_mark_the_chosen_value() {
+    _ = create_scope(create_runtime(), |cx| {
+        let size = create_rw_signal(cx, "m");
+        let plan = create_rw_signal(cx, "yearly");
+        let html = view! { cx,
+            <Listbox value=size label="Size">
+                <ListboxOption value="s">"Small"</ListboxOption>
+                <ListboxOption value="m">"Medium"</ListboxOption>
+            </Listbox>
+            <RadioGroup value=plan label="Plan">
+                <Radio value="monthly">"Monthly"</Radio>
+                <Radio value="yearly">"Yearly"</Radio>
+                <Radio value="lifetime" disabled=true>"Lifetime"</Radio>
+            </RadioGroup>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let (small, medium) = (tag(&html, "Small"), tag(&html, "Medium"));
+        assert_eq!(attr(small, "aria-selected"), Some("false"));
+        assert_eq!(attr(small, "tabindex"), Some("-1"));
+        assert_eq!(attr(medium, "aria-selected"), Some("true"));
+        assert_eq!(attr(medium, "tabindex"), Some("0"));
+
+        let (monthly, yearly, lifetime) = (
+            tag(&html, "Monthly"),
+            tag(&html, "Yearly"),
+            tag(&html, "Lifetime"),
+        );
+        assert_eq!(attr(monthly, "aria-checked"), Some("false"));
+        assert_eq!(attr(monthly, "tabindex"), Some("-1"));
+        assert_eq!(attr(yearly, "aria-checked"), Some("true"));
+        assert_eq!(attr(yearly, "tabindex"), Some("0"));
+        assert_eq!(attr(lifetime, "aria-disabled"), Some("true"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn combobox_input_controls_its_options() {
+    _ = create_scope(create_runtime(), |cx| {
+        let value = create_rw_signal(cx, String::from("Rust"));
+        let query = create_rw_signal(cx, String::from("Ru"));
+        let html = view! { cx,
+            <Combobox value=value query=query>
+                <ComboboxInput label="Language"/>
+                <ComboboxOptions>
+                    <ComboboxOption value=String::from("Ruby")>
+                        "Ruby"
+                    </ComboboxOption>
+                    <ComboboxOption value=String::from("Rust")>
+                        "Rust"
+                    </ComboboxOption>
+                </ComboboxOptions>
+            </Combobox>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let input = tag(&html, "role=\"combobox\"");
+        let options = tag(&html, "role=\"listbox\"");
+        assert_eq!(attr(input, "role"), Some("combobox"));
+        assert_eq!(attr(input, "value"), Some("Ru"));
+        assert_eq!(attr(input, "aria-expanded"), Some("false"));
+        assert_eq!(attr(input, "aria-controls"), attr(options, "id"));
+        assert!(options.contains(" hidden"));
+
+        let (ruby, rust) = (tag(&html, "Ruby"), tag(&html, "Rust"));
+        assert_eq!(attr(ruby, "aria-selected"), Some("false"));
+        assert_eq!(attr(rust, "aria-selected"), Some("true"));
+        assert_ne!(attr(ruby, "id"), attr(rust, "id"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "<Tab/> must be used inside of a <Tabs/>")]
+fn parts_panic_outside_of_their_widget() {
+    _ = create_scope(create_runtime(), |cx| {
+        _ = view! { cx, <Tab index=0>"Orphan"</Tab> };
+    });
+}
@@ -0,0 +1,68 @@
+use leptos::*;
+
+// the rows are rendered as the view is created, and updated in place by
+// the effect that follows `each`
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn indexed_for_updates_rows_in_place() {
+    use std::{cell::RefCell, rc::Rc};
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (cells, set_cells) = create_signal(cx, vec![1, 2, 3]);
+        let rows = Rc::new(RefCell::new(Vec::new()));
+
+        _ = view! { cx,
+            <IndexedFor
+                each=move || cells.get()
+                view={
+                    let rows = Rc::clone(&rows);
+                    move |cx, cell: ReadSignal<i32>| {
+                        rows.borrow_mut().push(cell);
+                        view! { cx, <span>{move || cell.get()}</span> }
+                    }
+                }
+            />
+        }
+        .into_view(cx);
+
+        let values = || {
+            rows.borrow()
+                .iter()
+                .map(|row| row.get_untracked())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(), [1, 2, 3]);
+
+        set_cells.set(vec![1, 5, 3]);
+        assert_eq!(rows.borrow().len(), 3, "no row is recreated");
+        assert_eq!(values(), [1, 5, 3]);
+
+        // a reordered list moves the items, not the rows
+        set_cells.set(vec![3, 5, 1]);
+        assert_eq!(rows.borrow().len(), 3);
+        assert_eq!(values(), [3, 5, 1]);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn indexed_for_renders_each_item() {
+    _ = create_scope(create_runtime(), |cx| {
+        let html = view! { cx,
+            <ul>
+                <IndexedFor
+                    each=|| vec!["a", "b"]
+                    view=|cx, item: ReadSignal<&'static str>| {
+                        view! { cx, <li>{move || item.get()}</li> }
+                    }
+                />
+            </ul>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let a = html.find(">a<").expect("first item");
+        let b = html.find(">b<").expect("second item");
+        assert!(a < b);
+    });
+}
@@ -0,0 +1,42 @@
+use leptos::*;
+use std::{cell::RefCell, rc::Rc};
+
+async fn load_user() -> String {
+    panic!("database unavailable")
+}
+
+// the fetcher resolves as the resource is created, so the boundary already
+// knows about the panic when it decides what to render
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn fetcher_panics_reach_the_error_boundary_through_suspense() {
+    _ = create_scope(create_runtime(), |cx| {
+        let shown = Rc::new(RefCell::new(Vec::new()));
+        let fallback = {
+            let shown = Rc::clone(&shown);
+            move |_, errors: RwSignal<Errors>| {
+                shown.borrow_mut().extend(
+                    errors
+                        .get_untracked()
+                        .into_iter()
+                        .map(|(_, error)| error.to_string()),
+                );
+            }
+        };
+        let user = create_resource(cx, || (), |_| load_user());
+
+        _ = view! { cx,
+            <ErrorBoundary fallback=fallback>
+                <Suspense fallback=|| "Loading…">
+                    {move || user.read(cx)}
+                </Suspense>
+            </ErrorBoundary>
+        }
+        .into_view(cx);
+
+        assert_eq!(
+            *shown.borrow(),
+            vec!["resource fetcher panicked: database unavailable"]
+        );
+    });
+}
@@ -157,3 +157,352 @@ fn ssr_option() {
             .contains("<option id=\"_0-1\"></option>"));
     });
 }
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_escapes_text_and_attributes() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (text, _) = create_signal(cx, "<script>alert(1)</script>");
+        let rendered = view! {
+            cx,
+            <p title="\"quoted\" & <tagged>">
+                "a < b"
+                {move || text.get()}
+                <br/>
+            </p>
+        };
+
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.contains("&quot;quoted&quot; &amp; "));
+        assert!(html.contains("a &lt; b"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;&#x2F;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("<br"));
+        assert!(!html.contains("</br>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_custom_elements_in_svg() {
+    use leptos::{
+        leptos_dom::html::{Custom, ElementDescriptor, ElementNamespace},
+        *,
+    };
+
+    _ = create_scope(create_runtime(), |cx| {
+        let el = Custom::new_ns("font-face", ElementNamespace::Svg);
+        assert_eq!(el.namespace(), ElementNamespace::Svg);
+        assert_eq!(
+            Custom::new("my-element").namespace(),
+            ElementNamespace::Html
+        );
+
+        let rendered = view! { cx,
+            <svg viewBox="0 0 10 10">
+                <font-face font-family="Serif"/>
+            </svg>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.contains("<font-face"), "{html}");
+        assert!(html.ends_with("</svg>"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_math_ml() {
+    use leptos::*;
+
+    assert_eq!(math::AnnotationXml::METADATA.name, "annotation-xml");
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <math display="block">
+                <msup>
+                    <mi>"x"</mi>
+                    <mn>"2"</mn>
+                </msup>
+            </math>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.starts_with("<math"), "{html}");
+        assert!(html.contains("<mi"), "{html}");
+        assert!(html.contains(">x</mi><mn"), "{html}");
+        assert!(html.ends_with("</msup></math>"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_void_custom_elements() {
+    use leptos::{
+        html::{custom, Custom},
+        *,
+    };
+
+    _ = create_scope(create_runtime(), |cx| {
+        let keygen =
+            custom(cx, Custom::new("keygen").void(true)).attr("name", "key");
+        let html = keygen.into_view(cx).render_to_string(cx);
+        assert!(html.starts_with("<keygen"), "{html}");
+        assert!(!html.contains("</keygen>"), "{html}");
+
+        let widget = custom(cx, Custom::new("my-widget"));
+        let html = widget.into_view(cx).render_to_string(cx);
+        assert!(html.ends_with("></my-widget>"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_inner_html_is_not_escaped() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (text, _) = create_signal(cx, "<b>bold</b>".to_string());
+        let rendered = html::div(cx).inner_html(move || text.get());
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.ends_with("><b>bold</b></div>"), "{html}");
+
+        let rendered = view! { cx, <p inner_html=""></p> };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(!html.contains("inner_html"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_animated_show_renders_the_initial_state() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <AnimatedShow when=|| true show_class="in" hide_class="out">
+                <p>"Shown"</p>
+            </AnimatedShow>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.contains("class=\"in\""), "{html}");
+        assert!(html.contains("Shown"), "{html}");
+
+        let rendered = view! { cx,
+            <AnimatedShow when=|| false show_class="in" hide_class="out">
+                <p>"Hidden"</p>
+            </AnimatedShow>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(!html.contains("Hidden"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_event_modifiers_are_not_rendered() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <form on:submit:prevent=|_| ()>
+                <input on:keydown:enter:stop=|_| ()/>
+            </form>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(!html.contains("submit"), "{html}");
+        assert!(!html.contains("keydown"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_directives_are_not_rendered() {
+    use leptos::*;
+
+    fn autofocus(_cx: Scope, _el: web_sys::Element) {}
+    fn highlight(_cx: Scope, _el: web_sys::Element, _color: &'static str) {}
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <input use:autofocus use:highlight="yellow"/>
+        };
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(!html.contains("autofocus"), "{html}");
+        assert!(!html.contains("highlight"), "{html}");
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_render_to_writer_matches_render_to_string() {
+    use leptos::*;
+
+    fn app(cx: Scope) -> impl IntoView {
+        let (count, _) = create_signal(cx, 3);
+        view! { cx,
+            <ul>
+                <li>"Count: " {move || count.get()}</li>
+                <li>"<escaped>"</li>
+            </ul>
+        }
+    }
+
+    let expected = leptos::ssr::render_to_string(app);
+
+    // writing appends to what's already in the buffer
+    let mut html = String::from("<!DOCTYPE html>");
+    leptos::ssr::render_to_writer(app, &mut html).unwrap();
+    assert_eq!(html, format!("<!DOCTYPE html>{expected}"));
+
+    let mut bytes = Vec::new();
+    leptos::ssr::render_to_io_writer(app, &mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn seeded_ids_cover_generated_names_and_survive_panics() {
+    use leptos::{leptos_dom::HydrationCtx, *};
+
+    let name = leptos::ssr::with_seeded_ids(3, || {
+        HydrationCtx::generated_name("tabs")
+    });
+    assert_eq!(name, "tabs-3-0");
+
+    let result = std::panic::catch_unwind(|| {
+        leptos::ssr::with_seeded_ids(5, || panic!("the render failed"))
+    });
+    assert!(result.is_err());
+
+    // the seed doesn't outlive the panic, so renders start from zero again
+    let html = leptos::ssr::render_to_string(|cx| view! { cx, <p>"Hi"</p> });
+    assert!(html.contains("_0-1"), "{html}");
+}
+
+/// Renders `app` to a stream, with the `timeout` if there is one, and
+/// collects every chunk. Resources are loaded on a `LocalSet`, as they are
+/// by the server integrations.
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+fn render_to_chunks(
+    app: fn(leptos::Scope) -> leptos::View,
+    timeout: Option<leptos::ssr::RenderTimeout>,
+) -> Vec<String> {
+    use futures::{stream::LocalBoxStream, StreamExt};
+    use leptos::ssr::*;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, async move {
+        let (stream, runtime): (LocalBoxStream<String>, _) = match timeout {
+            Some(timeout) => {
+                let (stream, runtime, _) = render_to_stream_with_timeout(
+                    app,
+                    |_| "".into(),
+                    |_| {},
+                    false,
+                    timeout,
+                );
+                (stream.boxed_local(), runtime)
+            }
+            None => {
+                let (stream, runtime, _) =
+                    render_to_stream_with_prefix_undisposed(app, |_| "".into());
+                (stream.boxed_local(), runtime)
+            }
+        };
+        let chunks = stream.collect::<Vec<_>>().await;
+        runtime.dispose();
+        chunks
+    })
+}
+
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+#[test]
+fn stream_timeout_sends_the_shell_while_blocked() {
+    use leptos::{ssr::RenderTimeout, *};
+
+    fn app(cx: Scope) -> View {
+        let user = create_blocking_resource(
+            cx,
+            || (),
+            |_| futures::future::pending::<String>(),
+        );
+        view! { cx,
+            <main>
+                <Suspense fallback=|| "Loading…">{move || user.read(cx)}</Suspense>
+            </main>
+        }
+        .into_view(cx)
+    }
+
+    let timeout = || RenderTimeout::new(futures::future::ready(()));
+
+    let chunks = render_to_chunks(app, Some(timeout()));
+    assert!(chunks[0].contains("<main"), "{chunks:?}");
+    assert!(chunks[0].contains("Loading…"), "{chunks:?}");
+    // the blocked resource is handed over to the client
+    assert_eq!(chunks.len(), 2, "{chunks:?}");
+    assert!(chunks[1].contains("__LEPTOS_RESOURCE_RESOLVERS[key]"));
+
+    let chunks =
+        render_to_chunks(app, Some(timeout().error_page("<h1>Timed out</h1>")));
+    assert_eq!(chunks[0], "<h1>Timed out</h1>");
+}
+
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+#[test]
+fn stream_timeout_hands_unresolved_resources_to_the_client() {
+    use leptos::{ssr::RenderTimeout, *};
+
+    fn app(cx: Scope) -> View {
+        let user = create_resource(
+            cx,
+            || (),
+            |_| futures::future::pending::<String>(),
+        );
+        view! { cx,
+            <main>
+                <Suspense fallback=|| "Loading…">{move || user.read(cx)}</Suspense>
+            </main>
+        }
+        .into_view(cx)
+    }
+
+    let chunks = render_to_chunks(
+        app,
+        Some(RenderTimeout::new(futures::future::ready(()))),
+    );
+    assert_eq!(chunks.len(), 2, "{chunks:?}");
+    assert!(chunks[0].contains("Loading…"), "{chunks:?}");
+
+    // the resources the shell said were pending are the ones handed over
+    let (_, pending) = chunks[0]
+        .split_once("__LEPTOS_PENDING_RESOURCES = ")
+        .unwrap();
+    let (pending, _) = pending.split_once(';').unwrap();
+    assert_ne!(pending, "[]");
+    assert!(chunks[1].contains(&format!("{pending}.map")), "{chunks:?}");
+}
+
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+#[test]
+fn stream_timeout_that_never_fires_changes_nothing() {
+    use leptos::{ssr::RenderTimeout, *};
+
+    fn app(cx: Scope) -> View {
+        let user = create_resource(cx, || (), |_| async { "Ada".to_string() });
+        view! { cx,
+            <main>
+                <Suspense fallback=|| "Loading…">{move || user.read(cx)}</Suspense>
+            </main>
+        }
+        .into_view(cx)
+    }
+
+    let timeout = RenderTimeout::new(futures::future::pending());
+    let chunks = render_to_chunks(app, Some(timeout));
+    assert_eq!(chunks, render_to_chunks(app, None));
+    assert!(chunks.concat().contains("Ada"), "{chunks:?}");
+}
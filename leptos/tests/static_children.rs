@@ -0,0 +1,64 @@
+#[cfg(all(feature = "csr", not(feature = "hydrate")))]
+#[test]
+fn static_children_render_the_same_html() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (count, _) = create_signal(cx, 0);
+        let rendered = view! { cx,
+            <ul class="list">
+                <li class="item" data-label="a \"b\"">"1 < 2 & 3"</li>
+                <li><input type="checkbox" checked/><span>"b"</span></li>
+            </ul>
+            <p>"Count: " {move || count.get()}</p>
+        };
+
+        let html = rendered.into_view(cx).render_to_string(cx);
+        assert!(html.contains(
+            "<li class=\"item\" data-label=\"a &quot;b&quot;\">1 &lt; 2 \
+             &amp; 3</li><li><input type=\"checkbox\" \
+             checked><span>b</span></li></ul>"
+        ));
+        assert!(html.contains("Count: "));
+    });
+}
+
+#[cfg(all(feature = "csr", not(feature = "hydrate")))]
+#[test]
+fn reshaped_nestings_render_like_the_builder() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        // the parser would close the outer element of each of these, so the
+        // macro builds them rather than cloning them from a template
+        let from_macro = view! { cx,
+            <section>
+                <p><span><div>"block in a paragraph"</div></span></p>
+                <a href="/"><span><a href="/inner">"nested link"</a></span></a>
+                <li><div><li>"nested item"</li></div></li>
+            </section>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+
+        let from_builder = html::section(cx)
+            .child(
+                html::p(cx).child(
+                    html::span(cx)
+                        .child(html::div(cx).child("block in a paragraph")),
+                ),
+            )
+            .child(html::a(cx).attr("href", "/").child(html::span(cx).child(
+                html::a(cx).attr("href", "/inner").child("nested link"),
+            )))
+            .child(
+                html::li(cx).child(
+                    html::div(cx).child(html::li(cx).child("nested item")),
+                ),
+            )
+            .into_view(cx)
+            .render_to_string(cx);
+
+        assert_eq!(from_macro, from_builder);
+    });
+}
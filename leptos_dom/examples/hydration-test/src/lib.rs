@@ -65,7 +65,7 @@ pub fn hydrate() {
 
   gloo::console::debug!("starting WASM");
 
-  leptos::mount_to_body(move |cx| {
+  leptos::hydrate_body(move |cx| {
     view! { cx, <App/> }
   });
 }
@@ -0,0 +1,182 @@
+//! Accessibility checks, run in debug builds with the `a11y` feature.
+//!
+//! After an app is mounted, and again after each batch of DOM changes, the
+//! rendered elements are scanned for common accessibility problems, which
+//! are logged as warnings along with the place in a `view!` block where the
+//! element was created. Each problem is only reported once.
+
+use crate::source_location;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+
+thread_local! {
+    static REPORTED: RefCell<HashSet<String>> = Default::default();
+}
+
+/// ARIA attributes whose values must be one of a fixed list.
+const ARIA_VALUES: &[(&str, &[&str])] = &[
+    ("aria-atomic", &["true", "false"]),
+    ("aria-busy", &["true", "false"]),
+    ("aria-checked", &["true", "false", "mixed", "undefined"]),
+    (
+        "aria-current",
+        &["page", "step", "location", "date", "time", "true", "false"],
+    ),
+    ("aria-disabled", &["true", "false"]),
+    ("aria-expanded", &["true", "false", "undefined"]),
+    ("aria-hidden", &["true", "false", "undefined"]),
+    ("aria-invalid", &["grammar", "spelling", "true", "false"]),
+    ("aria-live", &["assertive", "polite", "off"]),
+    ("aria-modal", &["true", "false"]),
+    ("aria-multiline", &["true", "false"]),
+    ("aria-multiselectable", &["true", "false"]),
+    ("aria-orientation", &["horizontal", "vertical", "undefined"]),
+    ("aria-pressed", &["true", "false", "mixed", "undefined"]),
+    ("aria-readonly", &["true", "false"]),
+    ("aria-required", &["true", "false"]),
+    ("aria-selected", &["true", "false", "undefined"]),
+    ("aria-sort", &["ascending", "descending", "none", "other"]),
+];
+
+/// Checks `root` now, and again whenever anything inside it changes.
+pub(crate) fn watch(root: &web_sys::HtmlElement) {
+    check(root);
+
+    // checks are batched until the next frame, so a burst of updates is
+    // only scanned once
+    let scheduled = Rc::new(Cell::new(false));
+    let on_mutation = Closure::<dyn Fn()>::new({
+        let root = root.clone();
+        move || {
+            if !scheduled.replace(true) {
+                let scheduled = Rc::clone(&scheduled);
+                let root = root.clone();
+                crate::helpers::request_animation_frame(move || {
+                    scheduled.set(false);
+                    check(&root);
+                });
+            }
+        }
+    });
+
+    if let Ok(observer) =
+        web_sys::MutationObserver::new(on_mutation.as_ref().unchecked_ref())
+    {
+        let mut init = web_sys::MutationObserverInit::new();
+        init.child_list(true).subtree(true).attributes(true);
+        _ = observer.observe_with_options(root, &init);
+    }
+    on_mutation.forget();
+}
+
+/// Scans `root` and everything inside it, warning about each new problem.
+fn check(root: &web_sys::HtmlElement) {
+    let mut elements = vec![root.clone().unchecked_into::<web_sys::Element>()];
+    if let Ok(descendants) = root.query_selector_all("*") {
+        elements.extend(
+            (0..descendants.length())
+                .filter_map(|i| descendants.get(i))
+                .filter_map(|node| node.dyn_into::<web_sys::Element>().ok()),
+        );
+    }
+
+    let mut ids = HashMap::<String, usize>::new();
+    for el in &elements {
+        let id = el.id();
+        if !id.is_empty() {
+            *ids.entry(id).or_default() += 1;
+        }
+        check_element(el);
+    }
+    for (id, count) in ids {
+        if count > 1 {
+            report(
+                format!("duplicate-id:{id}"),
+                format!(
+                    "{count} elements have the id \"{id}\". IDs must be \
+                     unique, or labels and ARIA references will point to the \
+                     wrong element."
+                ),
+            );
+        }
+    }
+}
+
+fn check_element(el: &web_sys::Element) {
+    let tag = el.tag_name().to_lowercase();
+    let location = describe(el);
+
+    if tag == "img" && !el.has_attribute("alt") {
+        report(
+            format!("img-alt{location}"),
+            format!(
+                "<img> has no `alt` attribute{location}. Describe the image, \
+                 or use alt=\"\" if it is only decorative."
+            ),
+        );
+    }
+
+    if tag == "button" && !has_accessible_name(el) {
+        report(
+            format!("button-name{location}"),
+            format!(
+                "<button> has no accessible name{location}. Give it some \
+                 text, or an `aria-label`."
+            ),
+        );
+    }
+
+    for (name, allowed) in ARIA_VALUES {
+        if let Some(value) = el.get_attribute(name) {
+            if !allowed.contains(&value.trim()) {
+                report(
+                    format!("{name}={value}{location}"),
+                    format!(
+                        "<{tag}> has an invalid value \"{value}\" for \
+                         `{name}`{location}. Expected one of: {}.",
+                        allowed.join(", ")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn has_accessible_name(el: &web_sys::Element) -> bool {
+    let has_text = el
+        .text_content()
+        .map(|text| !text.trim().is_empty())
+        .unwrap_or(false);
+    let has_label =
+        ["aria-label", "aria-labelledby", "title"]
+            .iter()
+            .any(|attr| {
+                el.get_attribute(attr)
+                    .map(|value| !value.trim().is_empty())
+                    .unwrap_or(false)
+            });
+    // an image with alt text also names the button it's in
+    let has_labelled_image = el
+        .query_selector("img[alt]:not([alt=''])")
+        .ok()
+        .flatten()
+        .is_some();
+    has_text || has_label || has_labelled_image
+}
+
+fn describe(el: &web_sys::Element) -> String {
+    source_location(el)
+        .map(|location| format!(" (created at {location})"))
+        .unwrap_or_default()
+}
+
+fn report(key: String, message: String) {
+    let is_new = REPORTED.with(|reported| reported.borrow_mut().insert(key));
+    if is_new {
+        crate::warn!("[a11y] {message}");
+    }
+}
@@ -0,0 +1,238 @@
+//! Drawing to a `<canvas>` on every animation frame.
+//!
+//! [`use_canvas`] calls a draw function once per frame with
+//! [`requestAnimationFrame`](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame),
+//! keeps the canvas’s backing store the size of the element in device
+//! pixels, and stops drawing while the canvas is scrolled out of view.
+
+use crate::{html, NodeRef};
+use leptos_reactive::Scope;
+
+/// Describes the frame being drawn by [`use_canvas`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CanvasFrame {
+    /// The width of the canvas element, in CSS pixels.
+    pub width: f64,
+    /// The height of the canvas element, in CSS pixels.
+    pub height: f64,
+    /// The [device pixel ratio](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio)
+    /// that the canvas’s backing store is sized for. Its `width` and
+    /// `height` are the element’s size multiplied by this, so a 2D context
+    /// should usually be scaled by it before drawing.
+    pub pixel_ratio: f64,
+    /// The time of the frame, in milliseconds since the page started loading.
+    pub time: f64,
+    /// The time since the previous frame was drawn, in milliseconds, or
+    /// `0.0` for the first frame after the canvas became visible.
+    pub delta: f64,
+}
+
+/// Calls `draw` on every animation frame with the `<canvas>` loaded into
+/// `node_ref`.
+///
+/// Before each frame, the canvas’s `width` and `height` are set to its
+/// displayed size multiplied by the device pixel ratio, so drawings stay
+/// sharp on high-density screens. The size is tracked with a
+/// [`ResizeObserver`](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver).
+///
+/// Frames are only requested while part of the canvas is within the
+/// viewport, and stop for good when `cx` is disposed. On the server, `draw`
+/// is never called.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{html::Canvas, leptos_dom::canvas::use_canvas};
+///
+/// #[component]
+/// pub fn Clock(cx: Scope) -> impl IntoView {
+///     let canvas = create_node_ref::<Canvas>(cx);
+///     use_canvas(cx, canvas, |canvas, frame| {
+///         // get a drawing context from `canvas`, and draw the clock
+///         // for `frame.time`
+///         # _ = (canvas, frame);
+///     });
+///
+///     view! { cx, <canvas _ref=canvas style="width: 100%; height: 200px"/> }
+/// }
+/// ```
+pub fn use_canvas(
+    cx: Scope,
+    node_ref: NodeRef<html::Canvas>,
+    draw: impl FnMut(&web_sys::HtmlCanvasElement, CanvasFrame) + 'static,
+) {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    web::attach(cx, node_ref, Box::new(draw));
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = cx;
+        _ = node_ref;
+        _ = draw;
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use super::CanvasFrame;
+    use crate::{helpers::use_element_visibility, html, window, NodeRef};
+    use leptos_reactive::{create_effect, on_cleanup, Scope, SignalGet};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::{Rc, Weak},
+    };
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    type Draw = Box<dyn FnMut(&web_sys::HtmlCanvasElement, CanvasFrame)>;
+
+    struct CanvasLoop {
+        canvas: RefCell<Option<web_sys::HtmlCanvasElement>>,
+        /// The element's size in CSS pixels, updated when it's resized.
+        size: Cell<(f64, f64)>,
+        draw: RefCell<Draw>,
+        frame: RefCell<Option<Closure<dyn FnMut(f64)>>>,
+        request: Cell<Option<i32>>,
+        last_time: Cell<Option<f64>>,
+        running: Cell<bool>,
+    }
+
+    impl CanvasLoop {
+        fn start(&self) {
+            if !self.running.replace(true) {
+                self.request_frame();
+            }
+        }
+
+        fn stop(&self) {
+            self.running.set(false);
+            self.last_time.set(None);
+            if let Some(request) = self.request.take() {
+                _ = window().cancel_animation_frame(request);
+            }
+        }
+
+        fn request_frame(&self) {
+            if let Some(frame) = &*self.frame.borrow() {
+                self.request.set(
+                    window()
+                        .request_animation_frame(frame.as_ref().unchecked_ref())
+                        .ok(),
+                );
+            }
+        }
+
+        fn draw_frame(&self, time: f64) {
+            self.request.set(None);
+            if !self.running.get() {
+                return;
+            }
+
+            if let Some(canvas) = &*self.canvas.borrow() {
+                let (width, height) = self.size.get();
+                let pixel_ratio = window().device_pixel_ratio();
+                // checked every frame, as the ratio changes when zooming or
+                // moving to another screen, which doesn't resize the element
+                let backing_width = (width * pixel_ratio).round() as u32;
+                let backing_height = (height * pixel_ratio).round() as u32;
+                if canvas.width() != backing_width {
+                    canvas.set_width(backing_width);
+                }
+                if canvas.height() != backing_height {
+                    canvas.set_height(backing_height);
+                }
+
+                let delta = self
+                    .last_time
+                    .replace(Some(time))
+                    .map(|last| time - last)
+                    .unwrap_or(0.0);
+                (self.draw.borrow_mut())(
+                    canvas,
+                    CanvasFrame {
+                        width,
+                        height,
+                        pixel_ratio,
+                        time,
+                        delta,
+                    },
+                );
+            }
+
+            self.request_frame();
+        }
+    }
+
+    pub(super) fn attach(
+        cx: Scope,
+        node_ref: NodeRef<html::Canvas>,
+        draw: Draw,
+    ) {
+        let state = Rc::new(CanvasLoop {
+            canvas: Default::default(),
+            size: Default::default(),
+            draw: RefCell::new(draw),
+            frame: Default::default(),
+            request: Default::default(),
+            last_time: Default::default(),
+            running: Default::default(),
+        });
+        // the frame callback only holds a weak reference, so the loop can be
+        // dropped once `cx` is disposed
+        let weak: Weak<CanvasLoop> = Rc::downgrade(&state);
+        *state.frame.borrow_mut() = Some(Closure::new(move |time: f64| {
+            if let Some(state) = weak.upgrade() {
+                state.draw_frame(time);
+            }
+        }));
+
+        let resize_observer = Rc::new(RefCell::new(
+            None::<(web_sys::ResizeObserver, Closure<dyn FnMut()>)>,
+        ));
+        node_ref.on_load(cx, {
+            let state = Rc::clone(&state);
+            let resize_observer = Rc::clone(&resize_observer);
+            move |canvas| {
+                let canvas: web_sys::HtmlCanvasElement = (*canvas).clone();
+                let measure = {
+                    let state = Rc::clone(&state);
+                    let canvas = canvas.clone();
+                    move || {
+                        state.size.set((
+                            canvas.client_width() as f64,
+                            canvas.client_height() as f64,
+                        ))
+                    }
+                };
+                measure();
+                *state.canvas.borrow_mut() = Some(canvas.clone());
+
+                let on_resize = Closure::<dyn FnMut()>::new(measure);
+                if let Ok(observer) = web_sys::ResizeObserver::new(
+                    on_resize.as_ref().unchecked_ref(),
+                ) {
+                    observer.observe(&canvas);
+                    *resize_observer.borrow_mut() = Some((observer, on_resize));
+                }
+            }
+        });
+
+        let (is_visible, _) = use_element_visibility(cx, node_ref);
+        create_effect(cx, {
+            let state = Rc::clone(&state);
+            move |_| {
+                if is_visible.get() {
+                    state.start();
+                } else {
+                    state.stop();
+                }
+            }
+        });
+
+        on_cleanup(cx, move || {
+            state.stop();
+            state.frame.take();
+            if let Some((observer, _)) = resize_observer.take() {
+                observer.disconnect();
+            }
+        });
+    }
+}
@@ -1,19 +1,25 @@
 mod dyn_child;
 mod each;
+mod each_ops;
 mod errors;
 mod fragment;
+mod portal;
 mod unit;
 
+#[cfg(debug_assertions)]
+use crate::SourceLocation;
 use crate::{
     hydration::{HydrationCtx, HydrationKey},
     Comment, IntoView, View,
 };
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
-use crate::{mount_child, prepare_to_move, MountKind, Mountable};
+use crate::{mount_children, prepare_to_move, MountKind, Mountable};
 pub use dyn_child::*;
 pub use each::*;
+pub use each_ops::*;
 pub use errors::*;
 pub use fragment::*;
+pub use portal::*;
 use leptos_reactive::Scope;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::OnceCell;
@@ -65,6 +71,8 @@ pub struct ComponentRepr {
     pub(crate) id: HydrationKey,
     #[cfg(debug_assertions)]
     pub(crate) view_marker: Option<String>,
+    #[cfg(debug_assertions)]
+    location: Option<SourceLocation>,
 }
 
 impl fmt::Debug for ComponentRepr {
@@ -139,14 +147,23 @@ impl Mountable for ComponentRepr {
     fn get_closing_node(&self) -> web_sys::Node {
         self.closing.node.clone()
     }
+
+    fn unmount(&self) {
+        prepare_to_move(
+            &self.document_fragment,
+            &self.get_opening_node(),
+            &self.closing.node,
+        );
+    }
 }
 impl From<ComponentRepr> for View {
     fn from(value: ComponentRepr) -> Self {
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         if !HydrationCtx::is_hydrating() {
-            for child in &value.children {
-                mount_child(MountKind::Before(&value.closing.node), child);
-            }
+            mount_children(
+                MountKind::Before(&value.closing.node),
+                &value.children,
+            );
         }
 
         View::Component(value)
@@ -177,8 +194,30 @@ impl ComponentRepr {
     }
 
     fn new_with_id_concrete(name: Cow<'static, str>, id: HydrationKey) -> Self {
+        // in the browser, the name only appears in the marker comments, which
+        // are left empty in release builds with `strip-debug`
+        #[cfg(all(
+            target_arch = "wasm32",
+            feature = "web",
+            feature = "strip-debug",
+            not(debug_assertions),
+            not(feature = "ssr")
+        ))]
+        let closing = {
+            _ = name;
+            Cow::Borrowed("")
+        };
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            feature = "web",
+            feature = "strip-debug",
+            not(debug_assertions),
+            not(feature = "ssr")
+        )))]
+        let closing = Cow::Owned(format!("</{name}>"));
+
         let markers = (
-            Comment::new(Cow::Owned(format!("</{name}>")), &id, true),
+            Comment::new(closing, &id, true),
             #[cfg(debug_assertions)]
             Comment::new(Cow::Owned(format!("<{name}>")), &id, false),
         );
@@ -219,8 +258,17 @@ impl ComponentRepr {
             id,
             #[cfg(debug_assertions)]
             view_marker: None,
+            #[cfg(debug_assertions)]
+            location: None,
         }
     }
+
+    /// The location in a `view!` block at which this component was used,
+    /// if it was used in the `view!` macro.
+    #[cfg(debug_assertions)]
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        self.location
+    }
 }
 
 /// A user-defined `leptos` component.
@@ -232,6 +280,8 @@ where
     id: HydrationKey,
     name: Cow<'static, str>,
     children_fn: F,
+    #[cfg(debug_assertions)]
+    location: Option<SourceLocation>,
 }
 
 impl<F, V> Component<F, V>
@@ -245,6 +295,8 @@ where
             id: HydrationCtx::id(),
             name: name.into(),
             children_fn: f,
+            #[cfg(debug_assertions)]
+            location: crate::source_location::take_next(),
         }
     }
 }
@@ -254,19 +306,54 @@ where
     F: FnOnce(Scope) -> V,
     V: IntoView,
 {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn into_view(self, cx: Scope) -> View {
         let Self {
             id,
             name,
             children_fn,
+            #[cfg(debug_assertions)]
+            location,
         } = self;
 
+        #[cfg(all(
+            target_arch = "wasm32",
+            feature = "web",
+            feature = "debug-dom"
+        ))]
+        let component_name = name.clone();
+        // the name is also kept for the paths in error reports
+        let path_name = name.clone();
         let mut repr = ComponentRepr::new_with_id(name, id);
+        #[cfg(debug_assertions)]
+        {
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            if let Some(location) = location {
+                crate::source_location::attach(&repr._opening.node, location);
+            }
+            repr.location = location;
+        }
 
         // disposed automatically when the parent scope is disposed
-        let (child, _) = cx
-            .run_child_scope(|cx| cx.untrack(|| children_fn(cx).into_view(cx)));
+        let render = || {
+            cx.run_child_scope(|cx| {
+                errors::enter_component(cx, &path_name);
+                cx.untrack(|| children_fn(cx).into_view(cx))
+            })
+        };
+        #[cfg(all(
+            target_arch = "wasm32",
+            feature = "web",
+            feature = "debug-dom"
+        ))]
+        let (child, _) =
+            crate::debug_dom::in_component(&component_name, render);
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            feature = "web",
+            feature = "debug-dom"
+        )))]
+        let (child, _) = render();
 
         repr.children.push(child);
 
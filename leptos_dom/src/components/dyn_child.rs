@@ -7,8 +7,8 @@ use leptos_reactive::Scope;
 use std::{borrow::Cow, cell::RefCell, fmt, ops::Deref, rc::Rc};
 cfg_if! {
   if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
-    use crate::{mount_child, prepare_to_move, unmount_child, MountKind, Mountable};
-    use leptos_reactive::{create_effect, ScopeDisposer};
+    use crate::{mount_child, prepare_to_move, MountKind, Mountable};
+    use leptos_reactive::{create_render_effect, ScopeDisposer};
     use wasm_bindgen::JsCast;
   }
 }
@@ -79,6 +79,14 @@ impl Mountable for DynChildRepr {
     fn get_closing_node(&self) -> web_sys::Node {
         self.closing.node.clone()
     }
+
+    fn unmount(&self) {
+        prepare_to_move(
+            &self.document_fragment,
+            &self.get_opening_node(),
+            &self.closing.node,
+        );
+    }
 }
 
 impl DynChildRepr {
@@ -138,14 +146,14 @@ where
 {
     /// Creates a new dynamic child which will re-render whenever it's
     /// signal dependencies change.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn new(child_fn: CF) -> Self {
         Self::new_with_id(HydrationCtx::id(), child_fn)
     }
 
     #[doc(hidden)]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub const fn new_with_id(id: HydrationKey, child_fn: CF) -> Self {
         Self { id, child_fn }
@@ -182,8 +190,17 @@ where
             ))]
             let span = tracing::Span::current();
 
+            #[cfg(all(
+                target_arch = "wasm32",
+                feature = "web",
+                feature = "debug-dom"
+            ))]
+            let owner = crate::debug_dom::Owner::current();
+
+            // the child is rendered in place between the markers, so this is
+            // a render effect, like the ones that update attributes
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
-            create_effect(
+            create_render_effect(
                 cx,
                 move |prev_run: Option<(
                     Option<web_sys::Node>,
@@ -192,8 +209,12 @@ where
                     #[cfg(debug_assertions)]
                     let _guard = span.enter();
 
-                    let (new_child, disposer) =
-                        cx.run_child_scope(|cx| child_fn().into_view(cx));
+                    let render =
+                        || cx.run_child_scope(|cx| child_fn().into_view(cx));
+                    #[cfg(feature = "debug-dom")]
+                    let (new_child, disposer) = owner.enter(render);
+                    #[cfg(not(feature = "debug-dom"))]
+                    let (new_child, disposer) = render();
 
                     let mut child_borrow = child.borrow_mut();
 
@@ -223,10 +244,21 @@ where
                         let ret = if let Some(prev_t) = prev_t {
                             // Here, our child is also a text node
                             if let Some(new_t) = new_child.get_text() {
-                                if !was_child_moved && child != new_child {
-                                    prev_t
-                                        .unchecked_ref::<web_sys::Text>()
-                                        .set_data(&new_t.content);
+                                if !was_child_moved {
+                                    // the DOM is only touched if the text
+                                    // actually changed
+                                    let changed = child
+                                        .get_text()
+                                        .map(|t| t.content != new_t.content)
+                                        .unwrap_or(true);
+                                    if changed {
+                                        #[cfg(feature = "debug-dom")]
+                                        owner.log("text", &new_t.content);
+
+                                        prev_t
+                                            .unchecked_ref::<web_sys::Text>()
+                                            .set_data(&new_t.content);
+                                    }
 
                                     **child_borrow = Some(new_child);
 
@@ -275,36 +307,7 @@ where
                             if !HydrationCtx::is_hydrating() {
                                 if !was_child_moved && child != new_child {
                                     // Remove the child
-                                    let start = child.get_opening_node();
-                                    let end = &closing;
-
-                                    match child {
-                                        View::CoreComponent(
-                                            crate::CoreComponent::DynChild(
-                                                child,
-                                            ),
-                                        ) => {
-                                            let start =
-                                                child.get_opening_node();
-                                            let end = child.closing.node;
-                                            prepare_to_move(
-                                                &child.document_fragment,
-                                                &start,
-                                                &end,
-                                            );
-                                        }
-                                        View::Component(child) => {
-                                            let start =
-                                                child.get_opening_node();
-                                            let end = child.closing.node;
-                                            prepare_to_move(
-                                                &child.document_fragment,
-                                                &start,
-                                                &end,
-                                            );
-                                        }
-                                        _ => unmount_child(&start, end),
-                                    }
+                                    child.unmount();
                                 }
 
                                 // Mount the new child
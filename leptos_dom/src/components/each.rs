@@ -159,6 +159,14 @@ impl Mountable for EachRepr {
     fn get_closing_node(&self) -> web_sys::Node {
         self.closing.node.clone()
     }
+
+    fn unmount(&self) {
+        prepare_to_move(
+            &self.document_fragment,
+            &self.get_opening_node(),
+            &self.closing.node,
+        );
+    }
 }
 
 /// The internal representation of an [`Each`] item.
@@ -190,7 +198,7 @@ impl fmt::Debug for EachItem {
 }
 
 impl EachItem {
-    fn new(cx: Scope, child: View) -> Self {
+    pub(crate) fn new(cx: Scope, child: View) -> Self {
         let id = HydrationCtx::id();
         let needs_closing = !matches!(child, View::Element(_));
 
@@ -277,6 +285,11 @@ impl Mountable for EachItem {
             self.child.get_mountable_node().clone()
         }
     }
+
+    #[inline(always)]
+    fn unmount(&self) {
+        self.prepare_for_move();
+    }
 }
 
 impl EachItem {
@@ -428,7 +441,13 @@ where
                   let (each_item, _) = cx.run_child_scope(|cx| EachItem::new(cx, each_fn(cx, item).into_view(cx)));
                 #[cfg(all(target_arch = "wasm32", feature = "web"))]
                 {
-                  _ = fragment.append_child(&each_item.get_mountable_node());
+                  let child = each_item.get_mountable_node();
+
+                  #[cfg(feature = "debug-dom")]
+                  crate::debug_dom::Owner::current()
+                    .log("mount", child.node_name());
+
+                  _ = fragment.append_child(&child);
                 }
 
                   children_borrow.push(Some(each_item));
@@ -482,37 +501,51 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
         };
     }
 
+    // Keys that are unchanged at the start of the list are never removed,
+    // added or moved, so only the rest of the list needs to be diffed. This
+    // keeps appends, and updates that leave every key in place, cheap.
+    let unchanged = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(from_key, to_key)| from_key == to_key)
+        .count();
+
+    if unchanged == from.len() && unchanged == to.len() {
+        return Diff::default();
+    }
+
     // Get removed items
-    let mut removed = from.difference(to);
+    let mut removed = from
+        .iter()
+        .enumerate()
+        .skip(unchanged)
+        .filter(|(_, k)| !to.contains(*k))
+        .map(|(idx, _)| idx);
 
-    let removed_cmds = removed
-        .clone()
-        .map(|k| from.get_full(k).unwrap().0)
-        .map(|idx| DiffOpRemove { at: idx });
+    let removed_cmds = removed.clone().map(|idx| DiffOpRemove { at: idx });
 
     // Get added items
-    let mut added = to.difference(from);
-
-    let added_cmds =
-        added
-            .clone()
-            .map(|k| to.get_full(k).unwrap().0)
-            .map(|idx| DiffOpAdd {
-                at: idx,
-                mode: Default::default(),
-            });
+    let mut added = to
+        .iter()
+        .enumerate()
+        .skip(unchanged)
+        .filter(|(_, k)| !from.contains(*k))
+        .map(|(idx, _)| idx);
+
+    let added_cmds = added.clone().map(|idx| DiffOpAdd {
+        at: idx,
+        mode: Default::default(),
+    });
 
     // Get moved items
-    let mut normalized_idx = 0;
+    let mut normalized_idx = unchanged;
     let mut move_cmds = Vec::new();
-    let mut added_idx = added.next().map(|k| to.get_full(k).unwrap().0);
-    let mut removed_idx = removed.next().map(|k| from.get_full(k).unwrap().0);
+    let mut added_idx = added.next();
+    let mut removed_idx = removed.next();
 
-    for (idx, k) in to.iter().enumerate() {
+    for (idx, k) in to.iter().enumerate().skip(unchanged) {
         if let Some(added_idx) = added_idx.as_mut().filter(|r_i| **r_i == idx) {
-            if let Some(next_added) =
-                added.next().map(|k| to.get_full(k).unwrap().0)
-            {
+            if let Some(next_added) = added.next() {
                 *added_idx = next_added;
 
                 normalized_idx = usize::wrapping_sub(normalized_idx, 1);
@@ -524,9 +557,7 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
         {
             normalized_idx = normalized_idx.wrapping_add(1);
 
-            if let Some(next_removed) =
-                removed.next().map(|k| from.get_full(k).unwrap().0)
-            {
+            if let Some(next_removed) = removed.next() {
                 *removed_idx = next_removed;
             }
         }
@@ -702,7 +733,7 @@ fn apply_cmds<T, EF, N>(
     for DiffOpRemove { at } in cmds.removed {
         let item_to_remove = std::mem::take(&mut children[at]).unwrap();
 
-        item_to_remove.prepare_for_move();
+        item_to_remove.unmount();
     }
 
     for DiffOpMove {
@@ -0,0 +1,169 @@
+use super::each::{EachItem, EachRepr};
+use crate::{CoreComponent, IntoView, View};
+use leptos_reactive::{ListStore, Scope};
+use std::rc::Rc;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use {
+    crate::{mount_child, MountKind, Mountable},
+    leptos_reactive::ListOp,
+    wasm_bindgen::JsCast,
+};
+
+/// A component for rendering a [`ListStore`].
+///
+/// Unlike [`Each`](crate::Each), which diffs a new snapshot of the list
+/// every time it changes, this applies each
+/// [`ListOp`](leptos_reactive::ListOp) directly to the DOM: a `push`
+/// mounts one new item and a `remove` unmounts one, without looking at the
+/// rest of the list.
+pub struct EachOps<T, EF, N>
+where
+    T: Clone + 'static,
+    EF: Fn(Scope, T) -> N + 'static,
+    N: IntoView,
+{
+    store: ListStore<T>,
+    each_fn: EF,
+}
+
+impl<T, EF, N> EachOps<T, EF, N>
+where
+    T: Clone + 'static,
+    EF: Fn(Scope, T) -> N + 'static,
+    N: IntoView,
+{
+    /// Creates a new [`EachOps`] component.
+    #[inline(always)]
+    pub const fn new(store: ListStore<T>, each_fn: EF) -> Self {
+        Self { store, each_fn }
+    }
+}
+
+impl<T, EF, N> IntoView for EachOps<T, EF, N>
+where
+    T: Clone + 'static,
+    EF: Fn(Scope, T) -> N + 'static,
+    N: IntoView,
+{
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "info", name = "<EachOps />", skip_all)
+    )]
+    fn into_view(self, cx: Scope) -> View {
+        let Self { store, each_fn } = self;
+
+        let component = EachRepr::default();
+        let each_fn = Rc::new(each_fn);
+        let render = move |item: T| {
+            cx.untrack(|| {
+                cx.run_child_scope(|cx| {
+                    EachItem::new(cx, each_fn(cx, item).into_view(cx))
+                })
+                .0
+            })
+        };
+
+        let items = store.with_untracked(|items| {
+            items.iter().cloned().map(&render).collect::<Vec<_>>()
+        });
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let closing = component.get_closing_node();
+
+            let fragment = crate::document().create_document_fragment();
+            for item in &items {
+                let child = item.get_mountable_node();
+
+                #[cfg(feature = "debug-dom")]
+                crate::debug_dom::Owner::current()
+                    .log("mount", child.node_name());
+
+                _ = fragment.append_child(&child);
+            }
+            closing
+                .unchecked_ref::<web_sys::Element>()
+                .before_with_node_1(&fragment)
+                .expect("before to not err");
+
+            let children = component.children.clone();
+            store.on_op(cx, move |op| {
+                apply_op(&mut children.borrow_mut(), &closing, op, &render)
+            });
+        }
+
+        *component.children.borrow_mut() =
+            items.into_iter().map(Some).collect();
+
+        View::CoreComponent(CoreComponent::Each(component))
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn apply_op<T: Clone>(
+    children: &mut Vec<Option<EachItem>>,
+    closing: &web_sys::Node,
+    op: &ListOp<T>,
+    render: &impl Fn(T) -> EachItem,
+) {
+    // the node that the item at `at` should be mounted before
+    let next_sibling = |children: &[Option<EachItem>], at: usize| {
+        children
+            .get(at)
+            .and_then(|item| item.as_ref())
+            .map(|item| item.get_opening_node())
+            .unwrap_or_else(|| closing.clone())
+    };
+
+    match op {
+        ListOp::Push(item) => {
+            let item = render(item.clone());
+            mount_child(MountKind::Before(closing), &item);
+            children.push(Some(item));
+        }
+        ListOp::Insert(at, item) => {
+            let item = render(item.clone());
+            let before = next_sibling(&children[..], *at);
+            mount_child(MountKind::Before(&before), &item);
+            children.insert(*at, Some(item));
+        }
+        ListOp::Remove(at) => {
+            if let Some(item) = children.remove(*at) {
+                item.unmount();
+            }
+        }
+        ListOp::Swap(a, b) if a != b => {
+            let (low, high) = if a < b { (*a, *b) } else { (*b, *a) };
+            let after_high = next_sibling(&children[..], high + 1);
+
+            if let (Some(low_item), Some(high_item)) =
+                (&children[low], &children[high])
+            {
+                let before_low = low_item.get_opening_node();
+
+                // moving an item means first collecting its nodes
+                high_item.unmount();
+                mount_child(MountKind::Before(&before_low), high_item);
+
+                low_item.unmount();
+                mount_child(MountKind::Before(&after_high), low_item);
+            }
+
+            children.swap(low, high);
+        }
+        ListOp::Swap(..) => {}
+        ListOp::Set(at, item) => {
+            let item = render(item.clone());
+            let before = next_sibling(&children[..], *at);
+            mount_child(MountKind::Before(&before), &item);
+            if let Some(prev) = children[*at].replace(item) {
+                prev.unmount();
+            }
+        }
+        ListOp::Clear => {
+            for item in children.drain(..).flatten() {
+                item.unmount();
+            }
+        }
+    }
+}
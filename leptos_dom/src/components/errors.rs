@@ -1,7 +1,19 @@
 use crate::{HydrationCtx, IntoView};
 use cfg_if::cfg_if;
-use leptos_reactive::{signal_prelude::*, use_context, RwSignal};
-use std::{borrow::Cow, collections::HashMap, error::Error, sync::Arc};
+use leptos_reactive::{
+    provide_context, signal_prelude::*, use_context, RwSignal, Scope,
+};
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    collections::HashMap,
+    error::Error,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+};
 
 /// A struct to hold all the possible errors that could be provided by child Views
 #[derive(Debug, Clone, Default)]
@@ -92,6 +104,14 @@ where
                 stuff.into_view(cx)
             }
             Err(error) => {
+                let error: Arc<dyn Error + Send + Sync> = Arc::new(error);
+                report_error(
+                    cx,
+                    &id,
+                    &error,
+                    ErrorOrigin::View,
+                    errors.is_some(),
+                );
                 match errors {
                     Some(errors) => {
                         errors.update({
@@ -100,7 +120,9 @@ where
                                 feature = "web"
                             ))]
                             let id = id.clone();
-                            move |errors: &mut Errors| errors.insert(id, error)
+                            move |errors: &mut Errors| {
+                                errors.0.insert(id, error);
+                            }
                         });
 
                         // remove the error from the list if this drops,
@@ -133,6 +155,100 @@ where
         }
     }
 }
+
+/// Reports an error to the nearest `<ErrorBoundary/>`, which then shows its
+/// fallback, just like rendering an `Err`. The error is also passed to any
+/// [error reporters](set_error_reporter).
+///
+/// This is useful for errors that happen outside of the view, e.g., in an
+/// event listener or an effect. The error is removed when `cx` is disposed,
+/// such as when the part of the view that threw it is rendered again, or
+/// when the boundary’s errors are cleared. Returns the error’s key, which can
+/// be used to remove it with [`Errors::remove`].
+///
+/// ```
+/// # use leptos::*;
+/// #[derive(Debug)]
+/// struct TooLarge;
+///
+/// impl std::fmt::Display for TooLarge {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("the file is too large")
+///     }
+/// }
+///
+/// impl std::error::Error for TooLarge {}
+///
+/// #[component]
+/// fn Upload(cx: Scope) -> impl IntoView {
+///     let on_change = move |ev| {
+///         if event_target_value(&ev).len() > 1_000 {
+///             throw_error(cx, TooLarge);
+///         }
+///     };
+///     view! { cx, <input type="text" on:change=on_change/> }
+/// }
+///
+/// #[component]
+/// fn Form(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///         <ErrorBoundary fallback=|cx, _| view! { cx, <p>"Try again."</p> }>
+///             <Upload/>
+///         </ErrorBoundary>
+///     }
+/// }
+/// ```
+pub fn throw_error<E>(cx: Scope, error: E) -> ErrorKey
+where
+    E: Error + Send + Sync + 'static,
+{
+    thread_local! {
+        static NEXT_ID: Cell<usize> = Cell::new(0);
+    }
+    let id = NEXT_ID.with(|next_id| next_id.replace(next_id.get() + 1));
+    let key = ErrorKey(format!("thrown-{id}").into());
+    let error: Arc<dyn Error + Send + Sync> = Arc::new(error);
+    let errors = use_context::<RwSignal<Errors>>(cx);
+    report_error(cx, &key, &error, ErrorOrigin::Thrown, errors.is_some());
+
+    match errors {
+        Some(errors) => {
+            errors.update({
+                let key = key.clone();
+                move |errors: &mut Errors| {
+                    errors.0.insert(key, error);
+                }
+            });
+
+            // Only can run on the client, will panic on the server
+            cfg_if! {
+              if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+                use leptos_reactive::{on_cleanup, queue_microtask};
+                let key = key.clone();
+                on_cleanup(cx, move || {
+                  queue_microtask(move || {
+                    errors.update(|errors: &mut Errors| {
+                      errors.remove(&key);
+                    });
+                  });
+                });
+              }
+            }
+        }
+        None => {
+            #[cfg(debug_assertions)]
+            warn!(
+                "throw_error() was called without an ErrorBoundary above it, \
+                 so the error will not be handled and will silently \
+                 disappear"
+            );
+            _ = error;
+        }
+    }
+
+    key
+}
+
 impl Errors {
     /// Returns `true` if there are no errors.
     #[inline(always)]
@@ -164,9 +280,156 @@ impl Errors {
         self.0.remove(key)
     }
 
+    /// Removes all the errors, for example to let an `<ErrorBoundary/>`
+    /// show its children again after the user clicks “Retry.”
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
     /// An iterator over all the errors, in arbitrary order.
     #[inline(always)]
     pub fn iter(&self) -> Iter<'_> {
         Iter(self.0.iter())
     }
 }
+
+/// An error that was rendered as an `Err` or passed to [throw_error], as
+/// passed to the [error reporters](set_error_reporter).
+#[derive(Debug, Clone)]
+pub struct ErrorReport {
+    /// The key of the error in the [Errors] of its `<ErrorBoundary/>`.
+    pub key: ErrorKey,
+    /// The error itself.
+    pub error: Arc<dyn Error + Send + Sync>,
+    /// How the error was reported.
+    pub origin: ErrorOrigin,
+    /// The names of the components the error happened in, from the
+    /// outermost to the innermost.
+    pub component_path: Vec<Cow<'static, str>>,
+    /// Whether an `<ErrorBoundary/>` caught the error. If not, the error
+    /// isn't shown anywhere in the view.
+    pub caught: bool,
+}
+
+/// How an [ErrorReport] came about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorOrigin {
+    /// A `Result` was rendered as an `Err`, e.g., a failed resource read
+    /// inside a `<Suspense/>`.
+    View,
+    /// The error was passed to [throw_error], e.g., in an event listener.
+    Thrown,
+}
+
+type GlobalReporter = Arc<dyn Fn(&ErrorReport) + Send + Sync>;
+
+static GLOBAL_REPORTER: RwLock<Option<GlobalReporter>> = RwLock::new(None);
+
+// whether any reporter has been registered, so that component paths are
+// only tracked when they will be used
+static IS_REPORTING: AtomicBool = AtomicBool::new(false);
+
+/// Sets a function that receives every error in the app, whether it's
+/// caught by an `<ErrorBoundary/>` or not, e.g., to send it to an error
+/// tracking service. Replaces any reporter set before.
+///
+/// This applies to the whole process, including every request on the
+/// server. For a single part of the view, use [provide_error_reporter].
+///
+/// ```
+/// # use leptos::*;
+/// set_error_reporter(|report| {
+///     log!(
+///         "error in {}: {}",
+///         report.component_path.join(" > "),
+///         report.error
+///     );
+/// });
+/// ```
+pub fn set_error_reporter(
+    reporter: impl Fn(&ErrorReport) + Send + Sync + 'static,
+) {
+    IS_REPORTING.store(true, Ordering::Relaxed);
+    *GLOBAL_REPORTER.write().unwrap_or_else(|e| e.into_inner()) =
+        Some(Arc::new(reporter));
+}
+
+/// Adds a function that receives the errors that happen in `cx` and its
+/// children, before they are passed to the reporters above it and to the
+/// one set with [set_error_reporter].
+pub fn provide_error_reporter(
+    cx: Scope,
+    reporter: impl Fn(&ErrorReport) + 'static,
+) {
+    IS_REPORTING.store(true, Ordering::Relaxed);
+    let parent = use_context::<ErrorReporter>(cx).map(Rc::new);
+    provide_context(
+        cx,
+        ErrorReporter {
+            report: Rc::new(reporter),
+            parent,
+        },
+    );
+}
+
+#[derive(Clone)]
+struct ErrorReporter {
+    report: Rc<dyn Fn(&ErrorReport)>,
+    parent: Option<Rc<ErrorReporter>>,
+}
+
+#[derive(Clone)]
+struct ComponentPath(Rc<(Cow<'static, str>, Option<ComponentPath>)>);
+
+/// Records that `cx` is the scope of the component `name`, so that errors
+/// in it can be reported with their component path.
+pub(crate) fn enter_component(cx: Scope, name: &Cow<'static, str>) {
+    if IS_REPORTING.load(Ordering::Relaxed) {
+        let parent = use_context::<ComponentPath>(cx);
+        provide_context(cx, ComponentPath(Rc::new((name.clone(), parent))));
+    }
+}
+
+fn report_error(
+    cx: Scope,
+    key: &ErrorKey,
+    error: &Arc<dyn Error + Send + Sync>,
+    origin: ErrorOrigin,
+    caught: bool,
+) {
+    if !IS_REPORTING.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut component_path = Vec::new();
+    let mut component = use_context::<ComponentPath>(cx);
+    while let Some(ComponentPath(node)) = component {
+        component_path.push(node.0.clone());
+        component = node.1.clone();
+    }
+    component_path.reverse();
+
+    let report = ErrorReport {
+        key: key.clone(),
+        error: Arc::clone(error),
+        origin,
+        component_path,
+        caught,
+    };
+
+    let mut reporter = use_context::<ErrorReporter>(cx).map(Rc::new);
+    while let Some(current) = reporter {
+        (current.report)(&report);
+        reporter = current.parent.clone();
+    }
+
+    // cloned, so that the reporter can replace itself
+    let global = GLOBAL_REPORTER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Some(global) = global {
+        global(&report);
+    }
+}
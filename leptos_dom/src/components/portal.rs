@@ -0,0 +1,138 @@
+use crate::{IntoView, Unit, View};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+    use crate::{
+      document, hydration::HydrationCtx, mount_child, MountKind, Mountable,
+    };
+    use leptos_reactive::on_cleanup;
+    use wasm_bindgen::{JsCast, JsValue};
+
+    // the property of a portal's container that points back to the portal's
+    // place in its parent
+    const OWNER: &str = "$$leptos_portal_owner";
+
+    /// Returns the node that marks the place of the portal whose content is
+    /// rendered into `node`, if `node` is the container of a portal.
+    pub(crate) fn portal_owner(node: &web_sys::Node) -> Option<web_sys::Node> {
+      js_sys::Reflect::get(node, &JsValue::from_str(OWNER))
+        .ok()?
+        .dyn_into()
+        .ok()
+    }
+  }
+}
+
+/// Renders its children somewhere else in the DOM, like the end of the
+/// `<body>`, while keeping them in the reactive scope where the portal is
+/// created.
+///
+/// This is useful for modals, tooltips, and toasts, which need to escape
+/// their parent’s `overflow: hidden` or stacking context. The children can
+/// still use context from the portal’s parents, and they are removed from
+/// the DOM when the portal’s [`Scope`] is disposed.
+///
+/// The children are rendered in a `<div>` that is appended to the mount
+/// point, which is the `<body>` unless it is set with
+/// [`mount`](Portal::mount). Portals are only rendered in the browser: on
+/// the server, and during hydration, the children are created once the app
+/// is running in the browser.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::leptos_dom::Portal;
+///
+/// #[component]
+/// fn Toast(cx: Scope, message: String) -> impl IntoView {
+///     Portal::new(move |cx| {
+///         view! { cx, <div class="toast" role="status">{message}</div> }
+///     })
+/// }
+/// ```
+pub struct Portal<CF, N>
+where
+    CF: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    mount: Option<web_sys::Element>,
+    children_fn: CF,
+}
+
+impl<CF, N> Portal<CF, N>
+where
+    CF: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    /// Creates a portal that renders `children_fn` into the `<body>`.
+    #[inline(always)]
+    pub const fn new(children_fn: CF) -> Self {
+        Self {
+            mount: None,
+            children_fn,
+        }
+    }
+
+    /// Renders the children into `mount` instead of the `<body>`.
+    #[inline(always)]
+    pub fn mount(mut self, mount: web_sys::Element) -> Self {
+        self.mount = Some(mount);
+        self
+    }
+}
+
+impl<CF, N> IntoView for Portal<CF, N>
+where
+    CF: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "info", name = "<Portal />", skip_all)
+    )]
+    fn into_view(self, cx: Scope) -> View {
+        let Self { mount, children_fn } = self;
+
+        // marks the portal's place in its parent, and keeps hydration keys
+        // in sync with the server
+        let placeholder = Unit.into_view(cx);
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let mount = mount
+                .or_else(|| document().body().map(Into::into))
+                .expect("a mount point for <Portal/>");
+            let container = document()
+                .create_element("div")
+                .expect("to create a <div> for <Portal/>");
+
+            // the children weren't rendered on the server, so they're
+            // rendered fresh, without using up any hydration keys
+            let id = HydrationCtx::peek();
+            let children = HydrationCtx::without_hydrating(|| {
+                children_fn(cx).into_view(cx)
+            });
+            HydrationCtx::continue_from(id);
+
+            mount_child(MountKind::Append(&container), &children);
+            _ = mount.append_child(&container);
+            // lets helpers like `on_click_outside` treat the children as
+            // being where the portal is
+            _ = js_sys::Reflect::set(
+                &container,
+                &JsValue::from_str(OWNER),
+                &placeholder.get_opening_node(),
+            );
+
+            on_cleanup(cx, move || container.remove());
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = mount;
+            _ = children_fn;
+        }
+
+        placeholder
+    }
+}
@@ -0,0 +1,112 @@
+//! Logging of DOM operations, enabled with the `debug-dom` feature.
+//!
+//! Every mount, unmount, attribute update and text update is logged to the
+//! console, along with the name of the component that created the node and
+//! the source location it was created at. To keep the console usable during
+//! large updates, at most [`MAX_MESSAGES_PER_SECOND`] messages are logged each
+//! second; the number of messages skipped is logged once the limit resets.
+//!
+//! Mounts include the root view, the children of elements and dynamic
+//! children, the static HTML cloned from templates, and the initial items of
+//! `<For/>` and list stores. Updates include reactive attributes, classes,
+//! styles and properties, and text nodes that are patched in place.
+//!
+//! Not logged:
+//! - nodes that are adopted from the server-rendered HTML while hydrating,
+//!   since they aren't mounted by the client
+//! - `<For/>` items that are moved rather than added or removed
+//! - the containers that `<Portal/>` creates, and classes set with
+//!   `dyn_classes`
+
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    fmt,
+    panic::Location,
+};
+
+const MAX_MESSAGES_PER_SECOND: usize = 100;
+
+thread_local! {
+    static COMPONENTS: RefCell<Vec<Cow<'static, str>>> = Default::default();
+    // (start of the current one-second window, messages logged, messages skipped)
+    static RATE_LIMIT: Cell<(f64, usize, usize)> = Cell::new((0.0, 0, 0));
+}
+
+/// Runs `f` as part of rendering the component named `name`, so that any
+/// nodes it creates are attributed to that component.
+pub(crate) fn in_component<T>(
+    name: &Cow<'static, str>,
+    f: impl FnOnce() -> T,
+) -> T {
+    COMPONENTS.with(|components| components.borrow_mut().push(name.clone()));
+    let value = f();
+    COMPONENTS.with(|components| components.borrow_mut().pop());
+    value
+}
+
+/// The component and source location that created a node, captured when it
+/// was created so that later updates can be attributed to them.
+#[derive(Clone, Debug)]
+pub(crate) struct Owner {
+    component: Option<Cow<'static, str>>,
+    location: &'static Location<'static>,
+}
+
+impl Owner {
+    /// Captures the component currently being rendered and the caller's
+    /// location.
+    #[track_caller]
+    pub(crate) fn current() -> Self {
+        Self {
+            component: COMPONENTS
+                .with(|components| components.borrow().last().cloned()),
+            location: Location::caller(),
+        }
+    }
+
+    /// Runs `f` as part of rendering this owner's component.
+    pub(crate) fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        match &self.component {
+            Some(component) => in_component(component, f),
+            None => f(),
+        }
+    }
+
+    /// Logs a DOM operation, unless the rate limit has been reached.
+    pub(crate) fn log(&self, operation: &str, detail: impl fmt::Display) {
+        if !take_message() {
+            return;
+        }
+        let component = self.component.as_deref().unwrap_or("unknown");
+        crate::console_log(&format!(
+            "[debug-dom] {operation} in <{component}/> ({}:{}:{}): {detail}",
+            self.location.file(),
+            self.location.line(),
+            self.location.column()
+        ));
+    }
+}
+
+// returns `true` if another message can be logged in the current window
+fn take_message() -> bool {
+    RATE_LIMIT.with(|rate_limit| {
+        let (start, logged, skipped) = rate_limit.get();
+        let now = js_sys::Date::now();
+        if now - start >= 1000.0 {
+            if skipped > 0 {
+                crate::console_log(&format!(
+                    "[debug-dom] {skipped} messages skipped"
+                ));
+            }
+            rate_limit.set((now, 1, 0));
+            true
+        } else if logged < MAX_MESSAGES_PER_SECOND {
+            rate_limit.set((start, logged + 1, skipped));
+            true
+        } else {
+            rate_limit.set((start, logged, skipped + 1));
+            false
+        }
+    })
+}
@@ -0,0 +1,56 @@
+use leptos_reactive::Scope;
+
+/// A reusable behavior that can be attached to any element with
+/// [`HtmlElement::directive`](crate::HtmlElement::directive), or with
+/// `use:` in the `view` macro.
+///
+/// Once the element has been mounted, the directive runs with the
+/// [`Scope`] that created the element, so it can create effects and
+/// register cleanups that live as long as the element, and with the
+/// [`web_sys::Element`] itself. It is implemented for functions that take
+/// the scope and the element, and optionally a parameter:
+///
+/// ```
+/// # use leptos::*;
+/// fn highlight(cx: Scope, el: web_sys::Element, color: &'static str) {
+///     _ = el.set_attribute("style", &format!("background: {color}"));
+///     on_cleanup(cx, move || _ = el.remove_attribute("style"));
+/// }
+///
+/// fn autofocus(_cx: Scope, el: web_sys::Element) {
+///     use wasm_bindgen::JsCast;
+///     _ = el.unchecked_into::<web_sys::HtmlElement>().focus();
+/// }
+///
+/// #[component]
+/// fn Search(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///         <input use:autofocus use:highlight="yellow"/>
+///     }
+/// }
+/// ```
+///
+/// The type parameter `T` only distinguishes the two kinds of function, and
+/// `P` is the type of the parameter, `()` if there is none.
+pub trait Directive<T: ?Sized, P> {
+    /// Runs the directive for the given element.
+    fn run(&self, cx: Scope, el: web_sys::Element, param: P);
+}
+
+impl<F> Directive<(Scope, web_sys::Element), ()> for F
+where
+    F: Fn(Scope, web_sys::Element),
+{
+    fn run(&self, cx: Scope, el: web_sys::Element, _: ()) {
+        self(cx, el)
+    }
+}
+
+impl<F, P> Directive<(Scope, web_sys::Element, P), P> for F
+where
+    F: Fn(Scope, web_sys::Element, P),
+{
+    fn run(&self, cx: Scope, el: web_sys::Element, param: P) {
+        self(cx, el, param)
+    }
+}
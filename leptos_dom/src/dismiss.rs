@@ -0,0 +1,208 @@
+//! Dismissing dropdowns, popovers and menus when the user moves on to the
+//! rest of the page.
+//!
+//! A pointer press or a focus change lands “outside” an element when it
+//! isn’t on the element or anything inside it. The content of a
+//! [`Portal`](crate::Portal) counts as being where the portal is, so a
+//! dropdown whose menu is rendered into the `<body>` isn’t dismissed by a
+//! click on its own menu. Shadow DOM is looked through, and focus moving
+//! into an `<iframe>` outside the element counts as well, even though the
+//! iframe’s events don’t reach the page.
+
+use crate::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::Scope;
+
+/// Calls `handler` whenever a pointer is pressed, or focus moves, outside
+/// the element loaded into `target`, e.g., to close a dropdown.
+///
+/// The listeners are removed when `cx` is disposed. On the server, this
+/// does nothing.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{html::Div, leptos_dom::dismiss::on_click_outside};
+///
+/// #[component]
+/// pub fn Dropdown(cx: Scope) -> impl IntoView {
+///     let (open, set_open) = create_signal(cx, false);
+///     let dropdown = create_node_ref::<Div>(cx);
+///     on_click_outside(cx, dropdown, move |_| set_open.set(false));
+///
+///     view! { cx,
+///         <div node_ref=dropdown class="dropdown">
+///             <button on:click=move |_| set_open.update(|o| *o = !*o)>
+///                 "Options"
+///             </button>
+///             <Show when=move || open.get() fallback=|_| ()>
+///                 <div role="menu">"…"</div>
+///             </Show>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn on_click_outside<T, F>(cx: Scope, target: NodeRef<T>, handler: F)
+where
+    T: ElementDescriptor + Clone + 'static,
+    F: Fn(web_sys::Event) + 'static,
+{
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    target.on_load(cx, move |el| {
+        let el: web_sys::Element = el.element.as_ref().clone().into();
+        web::attach(cx, el, handler);
+    });
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = cx;
+        _ = target;
+        _ = handler;
+    }
+}
+
+/// A [`Directive`](crate::Directive) that works like [`on_click_outside`],
+/// for use with `use:click_outside=handler` in the `view` macro.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::leptos_dom::dismiss::click_outside;
+///
+/// #[component]
+/// pub fn Popover(cx: Scope, open: RwSignal<bool>) -> impl IntoView {
+///     view! { cx,
+///         <div class="popover" use:click_outside=move |_| open.set(false)>
+///             "…"
+///         </div>
+///     }
+/// }
+/// ```
+pub fn click_outside<F>(cx: Scope, el: web_sys::Element, handler: F)
+where
+    F: Fn(web_sys::Event) + 'static,
+{
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    web::attach(cx, el, handler);
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = cx;
+        _ = el;
+        _ = handler;
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) use web::is_inside;
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use leptos_reactive::{on_cleanup, Scope};
+    use std::{rc::Rc, time::Duration};
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+    pub(super) fn attach(
+        cx: Scope,
+        el: web_sys::Element,
+        handler: impl Fn(web_sys::Event) + 'static,
+    ) {
+        let handler = Rc::new(handler);
+        let on_outside = {
+            let el = el.clone();
+            let handler = Rc::clone(&handler);
+            move |ev: web_sys::Event| {
+                if !is_inside(&el, &ev) {
+                    handler(ev);
+                }
+            }
+        };
+
+        let document: web_sys::EventTarget = crate::document().into();
+        // listening in the capture phase means that handlers that stop
+        // propagation can't keep the element open
+        listen(cx, &document, "pointerdown", true, on_outside.clone());
+        listen(cx, &document, "focusin", true, on_outside);
+
+        // focus moving into an iframe blurs the window, and the iframe only
+        // becomes the active element once the blur has been handled
+        let window: web_sys::EventTarget = crate::window().into();
+        listen(cx, &window, "blur", false, move |ev| {
+            let el = el.clone();
+            let handler = Rc::clone(&handler);
+            crate::helpers::set_timeout(
+                move || {
+                    let active = crate::document().active_element();
+                    if let Some(active) = active {
+                        if active.tag_name().eq_ignore_ascii_case("iframe")
+                            && !contains(&el, &active)
+                        {
+                            handler(ev);
+                        }
+                    }
+                },
+                Duration::ZERO,
+            );
+        });
+    }
+
+    fn listen(
+        cx: Scope,
+        target: &web_sys::EventTarget,
+        event_name: &'static str,
+        capture: bool,
+        cb: impl Fn(web_sys::Event) + 'static,
+    ) {
+        let cb = Closure::wrap(Box::new(cb) as Box<dyn FnMut(web_sys::Event)>)
+            .into_js_value();
+        _ = target.add_event_listener_with_callback_and_bool(
+            wasm_bindgen::intern(event_name),
+            cb.unchecked_ref(),
+            capture,
+        );
+        let target = target.clone();
+        on_cleanup(cx, move || {
+            _ = target.remove_event_listener_with_callback_and_bool(
+                event_name,
+                cb.unchecked_ref(),
+                capture,
+            );
+        });
+    }
+
+    /// Whether the event happened inside `el`, or inside a portal in it.
+    pub(crate) fn is_inside(
+        el: &web_sys::Element,
+        ev: &web_sys::Event,
+    ) -> bool {
+        // the composed path includes the elements inside shadow roots, which
+        // the target is retargeted away from
+        let path = ev.composed_path();
+        if path.includes(el, 0) {
+            return true;
+        }
+        let target = match path.get(0) {
+            node if node.is_undefined() => {
+                ev.target().map(JsValue::from).unwrap_or(JsValue::NULL)
+            }
+            node => node,
+        };
+        target
+            .dyn_into::<web_sys::Node>()
+            .map(|node| contains(el, &node))
+            .unwrap_or(false)
+    }
+
+    /// Whether `node` is inside `el`, counting the content of a portal as
+    /// being where the portal is.
+    fn contains(el: &web_sys::Element, node: &web_sys::Node) -> bool {
+        if el.contains(Some(node)) {
+            return true;
+        }
+        let mut ancestor = Some(node.clone());
+        while let Some(node) = ancestor {
+            if let Some(owner) = crate::portal_owner(&node) {
+                return contains(el, &owner);
+            }
+            ancestor = node.parent_node();
+        }
+        false
+    }
+}
@@ -1,31 +1,437 @@
 pub mod typed;
 
-use std::{borrow::Cow, cell::RefCell, collections::HashSet};
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use leptos_reactive::{on_cleanup, Scope};
+use std::{borrow::Cow, cell::RefCell, collections::HashSet, ops::BitOr};
+use wasm_bindgen::JsCast;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::{
-    convert::FromWasmAbi, intern, prelude::Closure, JsCast, JsValue,
-    UnwrapThrowExt,
+    convert::FromWasmAbi, intern, prelude::Closure, JsValue, UnwrapThrowExt,
 };
 
+/// Tells the browser what to do with an event once a handler has run.
+///
+/// A handler passed to [`HtmlElement::on`](crate::HtmlElement::on), or to
+/// `on:` in the `view` macro, can return this rather than calling the
+/// methods on the event itself. This works the same way for delegated and
+/// undelegated events: stopping propagation also stops any handlers that were
+/// delegated to ancestors of the element.
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let form = view! { cx,
+///     <form on:submit=|_| EventFlow::PREVENT_DEFAULT>
+///         <a
+///             href="/elsewhere"
+///             on:click=|_| {
+///                 EventFlow::PREVENT_DEFAULT | EventFlow::STOP_PROPAGATION
+///             }
+///         >
+///             "Stay here"
+///         </a>
+///     </form>
+/// };
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventFlow {
+    /// Whether to call [`preventDefault()`](https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault)
+    /// on the event, e.g., so that a form is not submitted.
+    pub prevent_default: bool,
+    /// Whether to call [`stopPropagation()`](https://developer.mozilla.org/en-US/docs/Web/API/Event/stopPropagation)
+    /// on the event, so that handlers on ancestors of the element don't run.
+    pub stop_propagation: bool,
+}
+
+impl EventFlow {
+    /// Lets the event continue as normal.
+    pub const CONTINUE: Self = Self {
+        prevent_default: false,
+        stop_propagation: false,
+    };
+
+    /// Prevents the browser's default action for the event.
+    pub const PREVENT_DEFAULT: Self = Self {
+        prevent_default: true,
+        stop_propagation: false,
+    };
+
+    /// Stops the event from reaching handlers on ancestors of the element.
+    pub const STOP_PROPAGATION: Self = Self {
+        prevent_default: false,
+        stop_propagation: true,
+    };
+
+    fn apply(self, event: &web_sys::Event) {
+        if self.prevent_default {
+            event.prevent_default();
+        }
+        if self.stop_propagation {
+            event.stop_propagation();
+        }
+    }
+}
+
+impl BitOr for EventFlow {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            prevent_default: self.prevent_default || rhs.prevent_default,
+            stop_propagation: self.stop_propagation || rhs.stop_propagation,
+        }
+    }
+}
+
+/// Options for an event listener added with
+/// [`HtmlElement::on_with_options`](crate::HtmlElement::on_with_options), or
+/// with modifiers like `on:click:prevent` in the `view` macro.
+///
+/// `capture`, `passive` and `once` are passed to
+/// [`addEventListener`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener),
+/// so a listener that uses them is attached to the element itself rather
+/// than delegated. The other options filter which events reach the handler,
+/// and change the flow of those that do before it runs, as with
+/// [`EventFlow`]. Options can be combined with `|`.
+///
+/// | Modifier     | Option                               |
+/// |--------------|--------------------------------------|
+/// | `:prevent`   | [`prevent_default`](Self::prevent_default) |
+/// | `:stop`      | [`stop_propagation`](Self::stop_propagation) |
+/// | `:self`      | [`self_only`](Self::self_only)       |
+/// | `:outside`   | [`outside`](Self::outside)           |
+/// | `:enter`     | [`key("Enter")`](Self::key)          |
+/// | `:esc`       | [`key("Escape")`](Self::key)         |
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// html::div(cx).on_with_options(
+///     ev::scroll,
+///     |_| log!("scrolled"),
+///     EventOptions::passive(),
+/// );
+/// html::form(cx).on_with_options(
+///     ev::submit,
+///     |_| log!("submitted"),
+///     EventOptions::prevent_default() | EventOptions::stop_propagation(),
+/// );
+/// html::input(cx).on_with_options(
+///     ev::keydown,
+///     |_| log!("search"),
+///     EventOptions::key("Enter"),
+/// );
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventOptions {
+    /// Whether the listener runs in the capture phase, before listeners on
+    /// the element's descendants.
+    pub capture: bool,
+    /// Whether the listener promises not to call `preventDefault()`, which
+    /// lets the browser scroll without waiting for it.
+    pub passive: bool,
+    /// Whether the listener is removed after it runs once.
+    pub once: bool,
+    /// Whether to call `preventDefault()` on the event.
+    pub prevent_default: bool,
+    /// Whether to call `stopPropagation()` on the event.
+    pub stop_propagation: bool,
+    /// Whether to ignore events that were dispatched to a descendant of the
+    /// element, rather than to the element itself.
+    pub self_only: bool,
+    /// Whether to listen for the event outside the element instead, e.g.,
+    /// for a click anywhere else on the page. The content of a
+    /// [`Portal`](crate::Portal) inside the element counts as inside it.
+    pub outside: bool,
+    /// If set, keyboard events are ignored unless their
+    /// [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key)
+    /// is this one.
+    pub key: Option<&'static str>,
+}
+
+impl EventOptions {
+    const NONE: Self = Self {
+        capture: false,
+        passive: false,
+        once: false,
+        prevent_default: false,
+        stop_propagation: false,
+        self_only: false,
+        outside: false,
+        key: None,
+    };
+
+    /// Listens in the capture phase.
+    pub const fn capture() -> Self {
+        Self {
+            capture: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Listens passively, without blocking scrolling.
+    pub const fn passive() -> Self {
+        Self {
+            passive: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Removes the listener after its first event.
+    pub const fn once() -> Self {
+        Self {
+            once: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Prevents the browser's default action for the event.
+    pub const fn prevent_default() -> Self {
+        Self {
+            prevent_default: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Stops the event from reaching handlers on ancestors of the element.
+    pub const fn stop_propagation() -> Self {
+        Self {
+            stop_propagation: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Only handles events dispatched to the element itself.
+    pub const fn self_only() -> Self {
+        Self {
+            self_only: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Handles the event when it happens outside the element.
+    pub const fn outside() -> Self {
+        Self {
+            outside: true,
+            ..Self::NONE
+        }
+    }
+
+    /// Only handles keyboard events for the given key, like `"Enter"`.
+    pub const fn key(key: &'static str) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::NONE
+        }
+    }
+
+    /// Whether the listener has to be attached to its own target, because
+    /// delegation can't honor its options.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn needs_own_listener(&self) -> bool {
+        self.capture || self.passive || self.once || self.outside
+    }
+
+    /// The [`web_sys::AddEventListenerOptions`] for these options, added to
+    /// those of the event itself.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn listener_options(
+        &self,
+        event_options: &Option<web_sys::AddEventListenerOptions>,
+    ) -> Option<web_sys::AddEventListenerOptions> {
+        if !(self.capture || self.passive || self.once) {
+            return event_options.clone();
+        }
+        let mut options = event_options
+            .clone()
+            .unwrap_or_else(web_sys::AddEventListenerOptions::new);
+        if self.capture {
+            options.capture(true);
+        }
+        if self.passive {
+            options.passive(true);
+        }
+        if self.once {
+            options.once(true);
+        }
+        Some(options)
+    }
+
+    /// Wraps the handler for a listener on `el`, so that it only sees the
+    /// events these options let through, and their flow is changed before
+    /// it runs.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn wrap_handler<Ev: JsCast + 'static>(
+        &self,
+        el: &web_sys::Element,
+        mut handler: Box<dyn FnMut(Ev)>,
+    ) -> Box<dyn FnMut(Ev)> {
+        let options = *self;
+        let flow = EventFlow {
+            prevent_default: options.prevent_default,
+            stop_propagation: options.stop_propagation,
+        };
+        if flow == EventFlow::CONTINUE
+            && !options.self_only
+            && !options.outside
+            && options.key.is_none()
+        {
+            return handler;
+        }
+
+        #[cfg(debug_assertions)]
+        if options.passive && options.prevent_default {
+            crate::warn!(
+                "An event listener is both `passive` and `prevent_default`, \
+                 but browsers ignore `preventDefault()` in passive listeners."
+            );
+        }
+
+        let el = el.clone();
+        Box::new(move |ev: Ev| {
+            let event = ev.unchecked_ref::<web_sys::Event>();
+            let target = event.target();
+            if options.self_only
+                && target.as_ref().map(AsRef::<JsValue>::as_ref)
+                    != Some(AsRef::<JsValue>::as_ref(&el))
+            {
+                return;
+            }
+            if options.outside && crate::dismiss::is_inside(&el, event) {
+                return;
+            }
+            if let Some(key) = options.key {
+                let matches = event
+                    .dyn_ref::<web_sys::KeyboardEvent>()
+                    .map(|event| event.key() == key)
+                    .unwrap_or(false);
+                if !matches {
+                    return;
+                }
+            }
+
+            flow.apply(event);
+            handler(ev);
+        })
+    }
+}
+
+impl BitOr for EventOptions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            capture: self.capture || rhs.capture,
+            passive: self.passive || rhs.passive,
+            once: self.once || rhs.once,
+            prevent_default: self.prevent_default || rhs.prevent_default,
+            stop_propagation: self.stop_propagation || rhs.stop_propagation,
+            self_only: self.self_only || rhs.self_only,
+            outside: self.outside || rhs.outside,
+            key: rhs.key.or(self.key),
+        }
+    }
+}
+
+/// A value that can be returned from an event handler: either `()`, or an
+/// [`EventFlow`].
+pub trait EventHandlerReturn<Ev>: Sized {
+    /// Converts a handler returning this type into one that applies it.
+    #[doc(hidden)]
+    fn into_handler(
+        handler: impl FnMut(Ev) -> Self + 'static,
+    ) -> Box<dyn FnMut(Ev)>;
+}
+
+impl<Ev> EventHandlerReturn<Ev> for () {
+    #[inline(always)]
+    fn into_handler(
+        handler: impl FnMut(Ev) -> Self + 'static,
+    ) -> Box<dyn FnMut(Ev)> {
+        Box::new(handler)
+    }
+}
+
+impl<Ev: JsCast> EventHandlerReturn<Ev> for EventFlow {
+    fn into_handler(
+        mut handler: impl FnMut(Ev) -> Self + 'static,
+    ) -> Box<dyn FnMut(Ev)> {
+        Box::new(move |ev: Ev| {
+            // the handler takes the event, so keep another reference to it
+            let event = ev.unchecked_ref::<web_sys::Event>().clone();
+            handler(ev).apply(&event);
+        })
+    }
+}
+
 thread_local! {
     pub(crate) static GLOBAL_EVENTS: RefCell<HashSet<Cow<'static, str>>> = RefCell::new(HashSet::new());
 }
 
+#[cfg(debug_assertions)]
+thread_local! {
+    static LIVE_LISTENERS: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+/// Returns the number of per-element event listeners that are currently
+/// registered and have not yet been cleaned up.
+///
+/// Listeners are removed when the [`Scope`](leptos_reactive::Scope) that
+/// created their element is disposed, so this number should return to its
+/// previous value once a view is torn down. Listeners added without a scope
+/// are never removed, so they aren't counted. Only available in debug builds.
+#[cfg(debug_assertions)]
+pub fn live_event_listeners() -> usize {
+    LIVE_LISTENERS.with(|count| count.get())
+}
+
+/// Ties the listener's [`Closure`] to the scope that created its element:
+/// when the scope is cleaned up, the listener is detached from the element
+/// and the closure is dropped. Listeners created without a scope live as
+/// long as the page.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn own_listener<E: FromWasmAbi + 'static>(
+    cx: Option<Scope>,
+    closure: Closure<dyn FnMut(E)>,
+    detach: impl FnOnce(&Closure<dyn FnMut(E)>) + 'static,
+) {
+    match cx {
+        Some(cx) => {
+            #[cfg(debug_assertions)]
+            LIVE_LISTENERS.with(|count| count.set(count.get() + 1));
+
+            on_cleanup(cx, move || {
+                detach(&closure);
+                drop(closure);
+
+                #[cfg(debug_assertions)]
+                LIVE_LISTENERS.with(|count| count.set(count.get() - 1));
+            })
+        }
+        None => closure.forget(),
+    }
+}
+
 // Used in template macro
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[inline(always)]
-pub fn add_event_helper<E: crate::ev::EventDescriptor + 'static>(
+pub fn add_event_helper<E: crate::ev::EventDescriptor + 'static, R>(
+    cx: Scope,
     target: &web_sys::Element,
     event: E,
-    #[allow(unused_mut)] // used for tracing in debug
-    mut event_handler: impl FnMut(E::EventType) + 'static,
-) {
+    event_handler: impl FnMut(E::EventType) -> R + 'static,
+) where
+    R: EventHandlerReturn<E::EventType>,
+{
     let event_name = event.name();
-    let event_handler = Box::new(event_handler);
+    let event_handler = R::into_handler(event_handler);
 
     if E::BUBBLES {
         add_event_listener(
+            Some(cx),
             target,
             event.event_delegation_key(),
             event_name,
@@ -34,6 +440,7 @@ pub fn add_event_helper<E: crate::ev::EventDescriptor + 'static>(
         );
     } else {
         add_event_listener_undelegated(
+            Some(cx),
             target,
             &event_name,
             event_handler,
@@ -46,6 +453,7 @@ pub fn add_event_helper<E: crate::ev::EventDescriptor + 'static>(
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub fn add_event_listener<E>(
+    cx: Option<Scope>,
     target: &web_sys::Element,
     key: Cow<'static, str>,
     event_name: Cow<'static, str>,
@@ -67,15 +475,22 @@ pub fn add_event_listener<E>(
       }
     }
 
-    let cb = Closure::wrap(cb as Box<dyn FnMut(E)>).into_js_value();
+    let cb = Closure::wrap(cb as Box<dyn FnMut(E)>);
     let key = intern(&key);
-    _ = js_sys::Reflect::set(target, &JsValue::from_str(&key), &cb);
-    add_delegated_event_listener(&key, event_name, options);
+    _ = js_sys::Reflect::set(target, &JsValue::from_str(key), cb.as_ref());
+    add_delegated_event_listener(key, event_name, options);
+
+    let target = target.clone();
+    let key = key.to_owned();
+    own_listener(cx, cb, move |_| {
+        _ = js_sys::Reflect::delete_property(&target, &JsValue::from_str(&key));
+    });
 }
 
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub(crate) fn add_event_listener_undelegated<E>(
+    cx: Option<Scope>,
     target: &web_sys::Element,
     event_name: &str,
     #[cfg(debug_assertions)] mut cb: Box<dyn FnMut(E)>,
@@ -97,18 +512,38 @@ pub(crate) fn add_event_listener_undelegated<E>(
     }
 
     let event_name = intern(event_name);
-    let cb = Closure::wrap(cb as Box<dyn FnMut(E)>).into_js_value();
+    let cb = Closure::wrap(cb as Box<dyn FnMut(E)>);
     if let Some(options) = options {
         _ = target
             .add_event_listener_with_callback_and_add_event_listener_options(
                 event_name,
-                cb.unchecked_ref(),
+                cb.as_ref().unchecked_ref(),
                 options,
             );
     } else {
-        _ = target
-            .add_event_listener_with_callback(event_name, cb.unchecked_ref());
+        _ = target.add_event_listener_with_callback(
+            event_name,
+            cb.as_ref().unchecked_ref(),
+        );
     }
+
+    // the listener can only be removed with the same `capture` flag
+    let capture = options
+        .as_ref()
+        .and_then(|options| {
+            js_sys::Reflect::get(options, &JsValue::from_str("capture")).ok()
+        })
+        .map(|capture| capture.is_truthy())
+        .unwrap_or(false);
+    let target = target.clone();
+    let event_name = event_name.to_owned();
+    own_listener(cx, cb, move |cb| {
+        _ = target.remove_event_listener_with_callback_and_bool(
+            &event_name,
+            cb.as_ref().unchecked_ref(),
+            capture,
+        );
+    });
 }
 
 // cf eventHandler in ryansolid/dom-expressions
@@ -134,8 +569,6 @@ pub(crate) fn add_delegated_event_listener(
 
                 // TODO reverse Shadow DOM retargetting
 
-                // TODO simulate currentTarget
-
                 while !node.is_null() {
                     let node_is_disabled = js_sys::Reflect::get(
                         &node,
@@ -149,10 +582,11 @@ pub(crate) fn add_delegated_event_listener(
                         if !maybe_handler.is_undefined() {
                             let f = maybe_handler
                                 .unchecked_ref::<js_sys::Function>();
+                            set_current_target(&ev, &node);
                             let _ = f.call1(&node, &ev);
 
                             if ev.cancel_bubble() {
-                                return;
+                                break;
                             }
                         }
                     }
@@ -174,6 +608,8 @@ pub(crate) fn add_delegated_event_listener(
                         node = JsValue::null()
                     }
                 }
+
+                clear_current_target(&ev);
             };
 
             cfg_if::cfg_if! {
@@ -188,14 +624,17 @@ pub(crate) fn add_delegated_event_listener(
 
             let handler = Box::new(handler) as Box<dyn FnMut(web_sys::Event)>;
             let handler = Closure::wrap(handler).into_js_value();
+            // listening on the document, rather than the window, means that
+            // delegated handlers run before any listeners on the window, like
+            // the router's, which can then see if they prevented the default
             if let Some(options) = options {
-                _ = crate::window().add_event_listener_with_callback_and_add_event_listener_options(
+                _ = crate::document().add_event_listener_with_callback_and_add_event_listener_options(
                     &event_name,
                     handler.unchecked_ref(),
                     options,
                 );
             } else {
-                _ = crate::window().add_event_listener_with_callback(
+                _ = crate::document().add_event_listener_with_callback(
                     &event_name,
                     handler.unchecked_ref(),
                 );
@@ -207,3 +646,34 @@ pub(crate) fn add_delegated_event_listener(
         }
     })
 }
+
+/// Delegated events are all dispatched from the document, so this makes
+/// `currentTarget` return the element whose handler is running instead, as
+/// it would for a listener added to that element, until
+/// [`clear_current_target`] is called.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn set_current_target(ev: &web_sys::Event, node: &JsValue) {
+    let descriptor = js_sys::Object::new();
+    _ = js_sys::Reflect::set(&descriptor, &JsValue::from_str("value"), node);
+    _ = js_sys::Reflect::set(
+        &descriptor,
+        &JsValue::from_str("configurable"),
+        &JsValue::TRUE,
+    );
+    js_sys::Object::define_property(
+        ev.unchecked_ref(),
+        &JsValue::from_str("currentTarget"),
+        &descriptor,
+    );
+}
+
+/// Removes the `currentTarget` set by [`set_current_target`] once delegated
+/// dispatch is over, so that listeners that run later, and code that keeps
+/// the event, see the browser's own value again.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn clear_current_target(ev: &web_sys::Event) {
+    _ = js_sys::Reflect::delete_property(
+        ev.unchecked_ref(),
+        &JsValue::from_str("currentTarget"),
+    );
+}
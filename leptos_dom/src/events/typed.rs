@@ -54,6 +54,7 @@ impl<Ev: EventDescriptor> EventDescriptor for undelegated<Ev> {
 /// A custom event.
 pub struct Custom<E: FromWasmAbi = web_sys::Event> {
     name: Cow<'static, str>,
+    key: Cow<'static, str>,
     options: Option<web_sys::AddEventListenerOptions>,
     _event_type: PhantomData<E>,
 }
@@ -62,6 +63,7 @@ impl<E: FromWasmAbi> Clone for Custom<E> {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
+            key: self.key.clone(),
             options: self.options.clone(),
             _event_type: PhantomData,
         }
@@ -76,7 +78,7 @@ impl<E: FromWasmAbi> EventDescriptor for Custom<E> {
     }
 
     fn event_delegation_key(&self) -> Cow<'static, str> {
-        format!("$$${}", self.name).into()
+        self.key.clone()
     }
 
     const BUBBLES: bool = false;
@@ -92,8 +94,11 @@ impl<E: FromWasmAbi> Custom<E> {
     /// [`HtmlElement::on`](crate::HtmlElement::on), for events
     /// which are not covered in the [`ev`](crate::ev) module.
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        let name = crate::intern_name(name.into());
+        let key = crate::intern_name(format!("$$${name}").into());
         Self {
-            name: name.into(),
+            name,
+            key,
             options: None,
             _event_type: PhantomData,
         }
@@ -0,0 +1,242 @@
+//! Loads data from a URL with the browser’s
+//! [`fetch`](https://developer.mozilla.org/en-US/docs/Web/API/fetch) API,
+//! returning a [`Resource`].
+//!
+//! Whenever the URL changes, the previous request is aborted and a new one is
+//! made. Any request still in flight is also aborted when the scope is
+//! disposed.
+//!
+//! ```
+//! # use leptos::*;
+//! use leptos::leptos_dom::fetch::use_fetch_json;
+//!
+//! #[derive(Clone, serde::Deserialize)]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! #[component]
+//! pub fn UserName(cx: Scope, id: ReadSignal<u32>) -> impl IntoView {
+//!     let user = use_fetch_json::<User>(cx, move || format!("/api/users/{}", id.get()));
+//!
+//!     view! { cx,
+//!       <ErrorBoundary fallback=|cx, _| view! { cx, <p>"Could not load user."</p> }>
+//!         <Suspense fallback=|| "Loading...">
+//!           {move || user.read(cx).map(|user| user.map(|user| user.name))}
+//!         </Suspense>
+//!       </ErrorBoundary>
+//!     }
+//! }
+//! ```
+//!
+//! The requests are only made in the browser. During server-side rendering,
+//! the resources are never resolved, so the `<Suspense/>` fallback is rendered,
+//! and the data is loaded once the app is hydrated.
+
+use leptos_reactive::{
+    create_local_resource_with_cancellation, CancellationToken, Resource, Scope,
+};
+use std::{fmt, future::Future};
+
+/// An error that occurs while fetching with [`use_fetch_text`] or
+/// [`use_fetch_json`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FetchError {
+    /// `fetch` is not available in this environment.
+    Unsupported,
+    /// The request could not be made, for example because the network is
+    /// unavailable.
+    Network(String),
+    /// The server responded with a status code outside the range 200–299.
+    Status(u16),
+    /// The response body could not be decoded.
+    Decode(String),
+    /// The request was aborted because the URL changed or the scope was
+    /// disposed.
+    Aborted,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Unsupported => {
+                write!(f, "fetch is not supported in this environment")
+            }
+            FetchError::Network(e) => write!(f, "network error: {e}"),
+            FetchError::Status(status) => {
+                write!(f, "server responded with status {status}")
+            }
+            FetchError::Decode(e) => {
+                write!(f, "could not decode response: {e}")
+            }
+            FetchError::Aborted => write!(f, "request was aborted"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Fetches the URL returned by `url`, resolving to the response body as text.
+/// The request is repeated whenever `url` changes.
+pub fn use_fetch_text(
+    cx: Scope,
+    url: impl Fn() -> String + 'static,
+) -> Resource<String, Result<String, FetchError>> {
+    use_fetch_with(cx, url, Ok)
+}
+
+/// Fetches the URL returned by `url`, resolving to the response body decoded
+/// as JSON. The request is repeated whenever `url` changes.
+pub fn use_fetch_json<T>(
+    cx: Scope,
+    url: impl Fn() -> String + 'static,
+) -> Resource<String, Result<T, FetchError>>
+where
+    T: serde::de::DeserializeOwned + 'static,
+{
+    use_fetch_with(cx, url, |body| {
+        serde_json::from_str(&body)
+            .map_err(|e| FetchError::Decode(e.to_string()))
+    })
+}
+
+fn use_fetch_with<T>(
+    cx: Scope,
+    url: impl Fn() -> String + 'static,
+    decode: fn(String) -> Result<T, FetchError>,
+) -> Resource<String, Result<T, FetchError>>
+where
+    T: 'static,
+{
+    fetch_resource(cx, url, fetch_text, decode)
+}
+
+// takes the request as a parameter so that it can be replaced in tests
+fn fetch_resource<T, Fu>(
+    cx: Scope,
+    url: impl Fn() -> String + 'static,
+    fetch: impl Fn(String, CancellationToken) -> Fu + 'static,
+    decode: fn(String) -> Result<T, FetchError>,
+) -> Resource<String, Result<T, FetchError>>
+where
+    T: 'static,
+    Fu: Future<Output = Result<String, FetchError>> + 'static,
+{
+    // the resource only keeps the result of the latest request, so a request
+    // that is aborted once the URL changes can't overwrite the newer result
+    create_local_resource_with_cancellation(cx, url, move |url, token| {
+        let body = fetch(url, token);
+        async move { decode(body.await?) }
+    })
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+async fn fetch_text(
+    url: String,
+    token: CancellationToken,
+) -> Result<String, FetchError> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    fn js_error(e: wasm_bindgen::JsValue) -> String {
+        e.dyn_ref::<js_sys::Error>()
+            .map(|e| String::from(e.message()))
+            .unwrap_or_else(|| format!("{e:?}"))
+    }
+
+    let controller = web_sys::AbortController::new()
+        .map_err(|e| FetchError::Network(js_error(e)))?;
+    token.on_cancel({
+        let controller = controller.clone();
+        move || controller.abort()
+    });
+
+    let signal = controller.signal();
+    let mut init = web_sys::RequestInit::new();
+    init.signal(Some(&signal));
+
+    let error = |e: wasm_bindgen::JsValue| {
+        if signal.aborted() {
+            FetchError::Aborted
+        } else {
+            FetchError::Network(js_error(e))
+        }
+    };
+    let response: web_sys::Response =
+        JsFuture::from(crate::window().fetch_with_str_and_init(&url, &init))
+            .await
+            .map_err(error)?
+            .unchecked_into();
+    if !response.ok() {
+        return Err(FetchError::Status(response.status()));
+    }
+    let body = JsFuture::from(response.text().map_err(error)?)
+        .await
+        .map_err(error)?;
+    body.as_string()
+        .ok_or_else(|| FetchError::Decode("body is not text".to_string()))
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+async fn fetch_text(
+    _url: String,
+    _token: CancellationToken,
+) -> Result<String, FetchError> {
+    Err(FetchError::Unsupported)
+}
+
+// resources don't load on the server
+#[cfg(all(test, not(feature = "ssr")))]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, create_scope};
+    use std::{cell::Cell, rc::Rc};
+
+    // requests finish immediately, so one that should overlap with the next
+    // starts it before it has finished
+
+    #[test]
+    fn aborted_request_does_not_overwrite_the_newer_one() {
+        create_scope(create_runtime(), |cx| {
+            let this = Rc::new(Cell::new(None::<Resource<_, _>>));
+            let calls = Rc::new(Cell::new(0));
+            let fetch = {
+                let this = Rc::clone(&this);
+                move |url: String, token: CancellationToken| {
+                    calls.set(calls.get() + 1);
+                    let call = calls.get();
+                    let this = this.get();
+                    async move {
+                        if call == 2 {
+                            if let Some(this) = this {
+                                this.refetch();
+                            }
+                        }
+                        if token.is_cancelled() {
+                            Err(FetchError::Aborted)
+                        } else {
+                            Ok(format!("{url} #{call}"))
+                        }
+                    }
+                }
+            };
+            let resource =
+                fetch_resource(cx, || "/users".to_string(), fetch, Ok);
+            this.set(Some(resource));
+            assert_eq!(resource.read(cx), Some(Ok("/users #1".to_string())));
+
+            resource.refetch();
+            assert_eq!(resource.read(cx), Some(Ok("/users #3".to_string())));
+        })
+        .dispose()
+    }
+
+    #[test]
+    fn fetching_is_unsupported_outside_the_browser() {
+        create_scope(create_runtime(), |cx| {
+            let resource = use_fetch_text(cx, || "/users".to_string());
+            assert_eq!(resource.read(cx), Some(Err(FetchError::Unsupported)));
+        })
+        .dispose()
+    }
+}
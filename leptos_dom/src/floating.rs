@@ -0,0 +1,348 @@
+//! Positioning a floating element, like a tooltip or dropdown, next to a
+//! reference element.
+//!
+//! [`compute_position`] places the floating element on one side of the
+//! reference, flips it to the opposite side if it would overflow the
+//! viewport, and shifts it along that side to keep it in view.
+//! [`use_floating`] runs it reactively as either element is resized or
+//! anything is scrolled.
+
+use crate::{
+    helpers::{use_element_bounding, ElementBounding},
+    html::ElementDescriptor,
+    NodeRef,
+};
+use leptos_reactive::{create_memo, Memo, Scope, SignalGet};
+use std::fmt;
+
+/// The side of the reference element the floating element is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// Above the reference.
+    Top,
+    /// To the right of the reference.
+    Right,
+    /// Below the reference.
+    #[default]
+    Bottom,
+    /// To the left of the reference.
+    Left,
+}
+
+impl Side {
+    /// Returns the opposite side.
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// How the floating element is aligned along the side of the reference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Aligned with the left or top edge of the reference.
+    Start,
+    /// Centered on the reference.
+    #[default]
+    Center,
+    /// Aligned with the right or bottom edge of the reference.
+    End,
+}
+
+/// Where to place the floating element relative to the reference.
+///
+/// Displayed as `side` or `side-alignment`, e.g., `bottom` or `top-start`,
+/// which is useful for styling an arrow with a `data-placement` attribute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The side of the reference.
+    pub side: Side,
+    /// The alignment along that side.
+    pub alignment: Alignment,
+}
+
+impl Placement {
+    /// Creates a placement on `side`, with `alignment`.
+    pub const fn new(side: Side, alignment: Alignment) -> Self {
+        Self { side, alignment }
+    }
+}
+
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        };
+        match self.alignment {
+            Alignment::Start => write!(f, "{side}-start"),
+            Alignment::Center => f.write_str(side),
+            Alignment::End => write!(f, "{side}-end"),
+        }
+    }
+}
+
+/// Options for [`compute_position`] and [`use_floating`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingOptions {
+    /// The preferred placement.
+    pub placement: Placement,
+    /// The gap between the reference and the floating element, in pixels.
+    pub offset: f64,
+    /// Whether to move the floating element to the opposite side when there
+    /// isn’t enough space on the preferred side.
+    pub flip: bool,
+    /// Whether to move the floating element along its side to keep it
+    /// within the viewport.
+    pub shift: bool,
+    /// The space to keep between the floating element and the edges of the
+    /// viewport, in pixels.
+    pub padding: f64,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::default(),
+            offset: 0.0,
+            flip: true,
+            shift: true,
+            padding: 0.0,
+        }
+    }
+}
+
+/// A rectangle, in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    /// The left edge.
+    pub x: f64,
+    /// The top edge.
+    pub y: f64,
+    /// The width.
+    pub width: f64,
+    /// The height.
+    pub height: f64,
+}
+
+impl From<ElementBounding> for Rect {
+    fn from(bounding: ElementBounding) -> Self {
+        Self {
+            x: bounding.x,
+            y: bounding.y,
+            width: bounding.width,
+            height: bounding.height,
+        }
+    }
+}
+
+/// Where to put a floating element, as computed by [`compute_position`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FloatingPosition {
+    /// The left edge of the floating element.
+    pub x: f64,
+    /// The top edge of the floating element.
+    pub y: f64,
+    /// The placement that was used, which may have been flipped to the
+    /// opposite side of the one that was asked for.
+    pub placement: Placement,
+}
+
+/// Computes where to put a floating element of size `floating` next to
+/// `reference`, keeping it within `viewport`.
+///
+/// All of the rectangles must use the same coordinates; for elements with
+/// `position: fixed`, use their client rects and the window’s size.
+///
+/// ```
+/// # use leptos_dom::floating::*;
+/// let viewport = Rect { x: 0.0, y: 0.0, width: 800.0, height: 600.0 };
+/// let tooltip = Rect { width: 100.0, height: 40.0, ..Default::default() };
+/// let options = FloatingOptions {
+///     offset: 8.0,
+///     ..Default::default()
+/// };
+///
+/// // there's room below the button, so the tooltip is centered under it
+/// let button = Rect { x: 100.0, y: 100.0, width: 60.0, height: 30.0 };
+/// let position = compute_position(button, tooltip, viewport, &options);
+/// assert_eq!((position.x, position.y), (80.0, 138.0));
+///
+/// // at the bottom of the screen, it flips above the button
+/// let button = Rect { y: 560.0, ..button };
+/// let position = compute_position(button, tooltip, viewport, &options);
+/// assert_eq!(position.placement.side, Side::Top);
+/// assert_eq!(position.y, 512.0);
+///
+/// // at the left of the screen, it's shifted to stay in view
+/// let button = Rect { x: 0.0, ..button };
+/// let position = compute_position(button, tooltip, viewport, &options);
+/// assert_eq!(position.x, 0.0);
+/// ```
+pub fn compute_position(
+    reference: Rect,
+    floating: Rect,
+    viewport: Rect,
+    options: &FloatingOptions,
+) -> FloatingPosition {
+    let mut placement = options.placement;
+
+    if options.flip {
+        let space = |side| available_space(reference, viewport, side);
+        let needed = |side: Side| {
+            options.offset
+                + options.padding
+                + if side.is_vertical() {
+                    floating.height
+                } else {
+                    floating.width
+                }
+        };
+        let side = placement.side;
+        let opposite = side.opposite();
+        // only flip if it helps: if neither side has room, use the one with
+        // more space
+        if space(side) < needed(side)
+            && (space(opposite) >= needed(opposite)
+                || space(opposite) > space(side))
+        {
+            placement.side = opposite;
+        }
+    }
+
+    let (mut x, mut y) = coords(reference, floating, placement, options.offset);
+
+    if options.shift {
+        if placement.side.is_vertical() {
+            x = clamp_to(
+                x,
+                floating.width,
+                viewport.x + options.padding,
+                viewport.x + viewport.width - options.padding,
+            );
+        } else {
+            y = clamp_to(
+                y,
+                floating.height,
+                viewport.y + options.padding,
+                viewport.y + viewport.height - options.padding,
+            );
+        }
+    }
+
+    FloatingPosition { x, y, placement }
+}
+
+fn coords(
+    reference: Rect,
+    floating: Rect,
+    placement: Placement,
+    offset: f64,
+) -> (f64, f64) {
+    let align =
+        |start: f64, reference_size: f64, floating_size: f64| match placement
+            .alignment
+        {
+            Alignment::Start => start,
+            Alignment::Center => start + (reference_size - floating_size) / 2.0,
+            Alignment::End => start + reference_size - floating_size,
+        };
+    let x = align(reference.x, reference.width, floating.width);
+    let y = align(reference.y, reference.height, floating.height);
+
+    match placement.side {
+        Side::Top => (x, reference.y - floating.height - offset),
+        Side::Bottom => (x, reference.y + reference.height + offset),
+        Side::Left => (reference.x - floating.width - offset, y),
+        Side::Right => (reference.x + reference.width + offset, y),
+    }
+}
+
+fn available_space(reference: Rect, viewport: Rect, side: Side) -> f64 {
+    match side {
+        Side::Top => reference.y - viewport.y,
+        Side::Bottom => {
+            viewport.y + viewport.height - (reference.y + reference.height)
+        }
+        Side::Left => reference.x - viewport.x,
+        Side::Right => {
+            viewport.x + viewport.width - (reference.x + reference.width)
+        }
+    }
+}
+
+/// Moves `start` so that `start..start + size` is within `min..max`,
+/// preferring `min` if it doesn't fit.
+fn clamp_to(start: f64, size: f64, min: f64, max: f64) -> f64 {
+    start.min(max - size).max(min)
+}
+
+/// Positions the element loaded into `floating` next to the element loaded
+/// into `reference`, with [`compute_position`].
+///
+/// The position is relative to the viewport, so the floating element should
+/// have `position: fixed`. It is computed again whenever either element
+/// moves or is resized, as tracked by [`use_element_bounding`]. On the
+/// server, the position is always at the origin.
+///
+/// The `<Floating/>` component in `leptos` does this for you.
+pub fn use_floating<R, F>(
+    cx: Scope,
+    reference: NodeRef<R>,
+    floating: NodeRef<F>,
+    options: FloatingOptions,
+) -> Memo<FloatingPosition>
+where
+    R: ElementDescriptor + Clone + 'static,
+    F: ElementDescriptor + Clone + 'static,
+{
+    let reference = use_element_bounding(cx, reference);
+    let floating = use_element_bounding(cx, floating);
+
+    create_memo(cx, move |_| {
+        let reference = Rect::from(reference.get());
+        let floating = Rect::from(floating.get());
+        compute_position(reference, floating, viewport(), &options)
+    })
+}
+
+fn viewport() -> Rect {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        let window = crate::window();
+        // the document element's client size excludes scrollbars
+        let document_element = crate::document().document_element();
+        let size = |client: Option<i32>, inner: Option<f64>| {
+            client.map(f64::from).filter(|size| *size > 0.0).or(inner)
+        };
+        let width = size(
+            document_element.as_ref().map(|el| el.client_width()),
+            window.inner_width().ok().and_then(|w| w.as_f64()),
+        );
+        let height = size(
+            document_element.as_ref().map(|el| el.client_height()),
+            window.inner_height().ok().and_then(|h| h.as_f64()),
+        );
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: width.unwrap_or_default(),
+            height: height.unwrap_or_default(),
+        }
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        Rect::default()
+    }
+}
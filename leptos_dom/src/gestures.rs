@@ -0,0 +1,434 @@
+//! Recognizes common pointer gestures (press, long-press, swipe, and pinch)
+//! on an element.
+//!
+//! Gestures are built on [`PointerEvent`](web_sys::PointerEvent)s, so they
+//! work the same way for mouse, pen, and touch input. Each pointer is captured
+//! by the element when it goes down, so a swipe that ends outside the element
+//! is still recognized.
+//!
+//! For touch input, the element should usually have `touch-action: none`
+//! set, so that the browser does not treat swipes and pinches as scrolling
+//! or zooming.
+
+use crate::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{create_signal, ReadSignal, Scope, SignalSet};
+use std::time::Duration;
+
+/// A gesture recognized by [`use_gestures`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Gesture {
+    /// A single pointer was pressed and released without moving further than
+    /// [`GestureOptions::press_tolerance`].
+    Press {
+        /// The client `x` coordinate where the pointer was released.
+        x: f64,
+        /// The client `y` coordinate where the pointer was released.
+        y: f64,
+    },
+    /// A single pointer was held down for [`GestureOptions::long_press_delay`]
+    /// without moving further than [`GestureOptions::press_tolerance`].
+    ///
+    /// This fires while the pointer is still down. No [`Gesture::Press`] is
+    /// recognized when that pointer is released.
+    LongPress {
+        /// The client `x` coordinate where the pointer went down.
+        x: f64,
+        /// The client `y` coordinate where the pointer went down.
+        y: f64,
+    },
+    /// A single pointer moved at least [`GestureOptions::swipe_threshold`]
+    /// before being released.
+    Swipe {
+        /// The main direction of the movement.
+        direction: SwipeDirection,
+        /// The distance moved along the main direction, in CSS pixels.
+        distance: f64,
+        /// The average speed of the movement, in CSS pixels per millisecond.
+        velocity: f64,
+    },
+    /// Two pointers moved relative to one another. This fires on every move
+    /// while both pointers are down.
+    Pinch {
+        /// The current distance between the pointers, divided by their
+        /// distance when the second pointer went down.
+        scale: f64,
+        /// The client `x` coordinate halfway between the pointers.
+        center_x: f64,
+        /// The client `y` coordinate halfway between the pointers.
+        center_y: f64,
+    },
+}
+
+/// The main direction of a [`Gesture::Swipe`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// Toward the left edge of the screen.
+    Left,
+    /// Toward the right edge of the screen.
+    Right,
+    /// Toward the top of the screen.
+    Up,
+    /// Toward the bottom of the screen.
+    Down,
+}
+
+/// Thresholds used to tell gestures apart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GestureOptions {
+    /// How long a pointer must be held down to be a long-press.
+    ///
+    /// Defaults to 500ms.
+    pub long_press_delay: Duration,
+    /// How far, in CSS pixels, a pointer can move and still be a press or
+    /// long-press.
+    ///
+    /// Defaults to `10.0`.
+    pub press_tolerance: f64,
+    /// How far, in CSS pixels, a pointer must move to be a swipe.
+    ///
+    /// Defaults to `50.0`.
+    pub swipe_threshold: f64,
+}
+
+impl Default for GestureOptions {
+    fn default() -> Self {
+        Self {
+            long_press_delay: Duration::from_millis(500),
+            press_tolerance: 10.0,
+            swipe_threshold: 50.0,
+        }
+    }
+}
+
+/// Calls `on_gesture` whenever a [`Gesture`] is recognized on the element
+/// loaded into `node_ref`.
+///
+/// The pointer listeners and any pending long-press timer are removed when
+/// `cx` is disposed. This does nothing on the server.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{
+///     html::Div,
+///     leptos_dom::gestures::{
+///         use_gestures, Gesture, GestureOptions, SwipeDirection,
+///     },
+/// };
+///
+/// #[component]
+/// pub fn Carousel(cx: Scope) -> impl IntoView {
+///     let (index, set_index) = create_signal(cx, 0);
+///     let carousel = create_node_ref::<Div>(cx);
+///
+///     use_gestures(cx, carousel, GestureOptions::default(), move |gesture| {
+///         match gesture {
+///             Gesture::Swipe {
+///                 direction: SwipeDirection::Left,
+///                 ..
+///             } => set_index.update(|n| *n += 1),
+///             Gesture::Swipe {
+///                 direction: SwipeDirection::Right,
+///                 ..
+///             } => set_index.update(|n| *n -= 1),
+///             _ => {}
+///         }
+///     });
+///
+///     view! { cx,
+///       <div _ref=carousel style="touch-action: none">{index}</div>
+///     }
+/// }
+/// ```
+pub fn use_gestures<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+    options: GestureOptions,
+    on_gesture: impl Fn(Gesture) + 'static,
+) {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use std::{cell::RefCell, rc::Rc};
+
+        let on_gesture: Rc<dyn Fn(Gesture)> = Rc::new(on_gesture);
+        let state = Rc::new(RefCell::new(GestureState::default()));
+
+        leptos_reactive::on_cleanup(cx, {
+            let state = Rc::clone(&state);
+            move || state.borrow_mut().cancel_long_press()
+        });
+
+        node_ref.on_load(cx, move |el| {
+            let el: web_sys::Element = el.element.as_ref().clone().into();
+            web::attach(cx, el, options, state, on_gesture);
+        });
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = cx;
+        _ = node_ref;
+        _ = options;
+        _ = on_gesture;
+    }
+}
+
+/// Like [`use_gestures`], but exposes the most recently recognized
+/// [`Gesture`] as a signal instead of calling a callback.
+///
+/// The signal is `None` until the first gesture is recognized.
+pub fn use_gesture_signal<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+    options: GestureOptions,
+) -> ReadSignal<Option<Gesture>> {
+    let (gesture, set_gesture) = create_signal(cx, None);
+    use_gestures(cx, node_ref, options, move |g| set_gesture.set(Some(g)));
+    gesture
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+struct TrackedPointer {
+    id: i32,
+    start_x: f64,
+    start_y: f64,
+    start_time: f64,
+    x: f64,
+    y: f64,
+}
+
+#[derive(Default)]
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+struct GestureState {
+    pointers: Vec<TrackedPointer>,
+    /// Distance between the pointers when a pinch started.
+    pinch_start: Option<f64>,
+    /// Set once a long-press or pinch has been recognized, so releasing the
+    /// pointers does not also produce a press or swipe.
+    consumed: bool,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    long_press: Option<crate::helpers::TimeoutHandle>,
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+impl GestureState {
+    fn cancel_long_press(&mut self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if let Some(handle) = self.long_press.take() {
+            handle.clear();
+        }
+    }
+
+    fn pinch_distance(&self) -> Option<f64> {
+        match self.pointers.as_slice() {
+            [a, b, ..] => Some((a.x - b.x).hypot(a.y - b.y)),
+            _ => None,
+        }
+    }
+
+    /// Recognizes a press or swipe from a single pointer being released.
+    fn release(
+        &self,
+        pointer: &TrackedPointer,
+        time: f64,
+        options: &GestureOptions,
+    ) -> Option<Gesture> {
+        if self.consumed {
+            return None;
+        }
+
+        let dx = pointer.x - pointer.start_x;
+        let dy = pointer.y - pointer.start_y;
+        let distance = dx.abs().max(dy.abs());
+
+        if distance >= options.swipe_threshold {
+            let direction = if dx.abs() >= dy.abs() {
+                if dx < 0.0 {
+                    SwipeDirection::Left
+                } else {
+                    SwipeDirection::Right
+                }
+            } else if dy < 0.0 {
+                SwipeDirection::Up
+            } else {
+                SwipeDirection::Down
+            };
+            let elapsed = (time - pointer.start_time).max(1.0);
+
+            Some(Gesture::Swipe {
+                direction,
+                distance,
+                velocity: distance / elapsed,
+            })
+        } else if dx.hypot(dy) <= options.press_tolerance {
+            Some(Gesture::Press {
+                x: pointer.x,
+                y: pointer.y,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use super::{Gesture, GestureOptions, GestureState, TrackedPointer};
+    use leptos_reactive::{on_cleanup, Scope};
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    pub(super) fn attach(
+        cx: Scope,
+        el: web_sys::Element,
+        options: GestureOptions,
+        state: Rc<RefCell<GestureState>>,
+        on_gesture: Rc<dyn Fn(Gesture)>,
+    ) {
+        listen(cx, &el, "pointerdown", {
+            let el = el.clone();
+            let state = Rc::clone(&state);
+            let on_gesture = Rc::clone(&on_gesture);
+            move |ev| {
+                _ = el.set_pointer_capture(ev.pointer_id());
+
+                let mut s = state.borrow_mut();
+                if s.pointers.is_empty() {
+                    s.consumed = false;
+                }
+                s.pointers.push(TrackedPointer {
+                    id: ev.pointer_id(),
+                    start_x: ev.client_x() as f64,
+                    start_y: ev.client_y() as f64,
+                    start_time: ev.time_stamp(),
+                    x: ev.client_x() as f64,
+                    y: ev.client_y() as f64,
+                });
+
+                match s.pointers.len() {
+                    1 => {
+                        let (x, y) =
+                            (ev.client_x() as f64, ev.client_y() as f64);
+                        let state = Rc::clone(&state);
+                        let on_gesture = Rc::clone(&on_gesture);
+                        s.long_press = crate::helpers::set_timeout_with_handle(
+                            move || {
+                                let mut s = state.borrow_mut();
+                                s.long_press = None;
+                                s.consumed = true;
+                                drop(s);
+                                on_gesture(Gesture::LongPress { x, y });
+                            },
+                            options.long_press_delay,
+                        )
+                        .ok();
+                    }
+                    2 => {
+                        s.cancel_long_press();
+                        s.pinch_start = s.pinch_distance();
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        listen(cx, &el, "pointermove", {
+            let state = Rc::clone(&state);
+            let on_gesture = Rc::clone(&on_gesture);
+            move |ev| {
+                let mut s = state.borrow_mut();
+                let Some(pointer) =
+                    s.pointers.iter_mut().find(|p| p.id == ev.pointer_id())
+                else {
+                    return;
+                };
+                pointer.x = ev.client_x() as f64;
+                pointer.y = ev.client_y() as f64;
+                let moved = (pointer.x - pointer.start_x)
+                    .hypot(pointer.y - pointer.start_y);
+
+                if moved > options.press_tolerance {
+                    s.cancel_long_press();
+                }
+
+                if let (Some(start), Some(current)) =
+                    (s.pinch_start, s.pinch_distance())
+                {
+                    s.consumed = true;
+                    let (a, b) = (&s.pointers[0], &s.pointers[1]);
+                    let gesture = Gesture::Pinch {
+                        scale: if start > 0.0 { current / start } else { 1.0 },
+                        center_x: (a.x + b.x) / 2.0,
+                        center_y: (a.y + b.y) / 2.0,
+                    };
+                    drop(s);
+                    on_gesture(gesture);
+                }
+            }
+        });
+
+        listen(cx, &el, "pointerup", {
+            let el = el.clone();
+            let state = Rc::clone(&state);
+            move |ev| {
+                _ = el.release_pointer_capture(ev.pointer_id());
+
+                let mut s = state.borrow_mut();
+                let Some(idx) =
+                    s.pointers.iter().position(|p| p.id == ev.pointer_id())
+                else {
+                    return;
+                };
+                s.cancel_long_press();
+                let pointer = s.pointers.remove(idx);
+                if s.pointers.len() < 2 {
+                    s.pinch_start = None;
+                }
+                let gesture = if s.pointers.is_empty() {
+                    s.release(&pointer, ev.time_stamp(), &options)
+                } else {
+                    None
+                };
+                drop(s);
+
+                if let Some(gesture) = gesture {
+                    on_gesture(gesture);
+                }
+            }
+        });
+
+        listen(cx, &el, "pointercancel", move |ev| {
+            let mut s = state.borrow_mut();
+            s.cancel_long_press();
+            s.pointers.retain(|p| p.id != ev.pointer_id());
+            s.pinch_start = None;
+            s.consumed = true;
+        });
+    }
+
+    /// Adds a pointer event listener to `el` that is removed when `cx` is
+    /// disposed.
+    fn listen(
+        cx: Scope,
+        el: &web_sys::Element,
+        event_name: &'static str,
+        cb: impl Fn(web_sys::PointerEvent) + 'static,
+    ) {
+        let cb = Closure::wrap(
+            Box::new(cb) as Box<dyn FnMut(web_sys::PointerEvent)>
+        )
+        .into_js_value();
+        _ = el.add_event_listener_with_callback(
+            wasm_bindgen::intern(event_name),
+            cb.unchecked_ref(),
+        );
+
+        let el = el.clone();
+        on_cleanup(cx, move || {
+            _ = el.remove_event_listener_with_callback(
+                event_name,
+                cb.unchecked_ref(),
+            );
+        });
+    }
+}
@@ -1,7 +1,9 @@
 //! A variety of DOM utility functions.
 
-use crate::{events::typed as ev, is_server, window};
-use leptos_reactive::{on_cleanup, Scope};
+use crate::{
+    events::typed as ev, html::ElementDescriptor, is_server, window, NodeRef,
+};
+use leptos_reactive::{create_signal, on_cleanup, ReadSignal, Scope};
 use std::time::Duration;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
@@ -448,3 +450,517 @@ pub fn ssr_event_listener<E: crate::ev::EventDescriptor + 'static>(
     _ = event;
     _ = event_handler;
 }
+
+#[doc(hidden)]
+/// This exists only to enable type inference on directives when in SSR mode.
+pub fn ssr_directive<T: ?Sized, P>(
+    handler: impl crate::Directive<T, P>,
+    param: P,
+) {
+    _ = handler;
+    _ = param;
+}
+
+/// Adds an event listener to the `Document` that is removed when `cx` is
+/// disposed.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn document_event_listener_scoped(
+    cx: Scope,
+    event_name: &'static str,
+    cb: impl Fn(web_sys::Event) + 'static,
+) {
+    let cb = Closure::wrap(Box::new(cb) as Box<dyn FnMut(web_sys::Event)>)
+        .into_js_value();
+    _ = crate::document().add_event_listener_with_callback(
+        wasm_bindgen::intern(event_name),
+        cb.unchecked_ref(),
+    );
+    on_cleanup(cx, move || {
+        _ = crate::document().remove_event_listener_with_callback(
+            event_name,
+            cb.unchecked_ref(),
+        );
+    });
+}
+
+/// Fullscreen controls for the element loaded into a [`NodeRef`], created
+/// with [`use_fullscreen`].
+pub struct Fullscreen<T: ElementDescriptor + 'static> {
+    #[cfg_attr(
+        not(all(target_arch = "wasm32", feature = "web")),
+        allow(dead_code)
+    )]
+    node_ref: NodeRef<T>,
+    is_active: ReadSignal<bool>,
+}
+
+impl<T: ElementDescriptor + 'static> Clone for Fullscreen<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ElementDescriptor + 'static> Copy for Fullscreen<T> {}
+
+impl<T: ElementDescriptor + Clone + 'static> Fullscreen<T> {
+    /// Whether the referenced element is currently displayed fullscreen.
+    ///
+    /// This is kept in sync with the document’s `fullscreenchange` event, so
+    /// it also updates when the user leaves fullscreen with the `Esc` key.
+    #[inline(always)]
+    pub fn is_active(&self) -> ReadSignal<bool> {
+        self.is_active
+    }
+
+    /// Requests that the referenced element be displayed fullscreen.
+    /// See [`Element.requestFullscreen()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/requestFullscreen).
+    ///
+    /// Browsers only honor this request while handling a user gesture, such
+    /// as a click. Does nothing if the [`NodeRef`] has not been loaded yet.
+    pub fn enter(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if let Some(el) = self.node_ref.get_untracked() {
+            _ = el.element.as_ref().request_fullscreen();
+        }
+    }
+
+    /// Exits fullscreen, if the referenced element is the one currently
+    /// displayed fullscreen.
+    /// See [`Document.exitFullscreen()`](https://developer.mozilla.org/en-US/docs/Web/API/Document/exitFullscreen).
+    pub fn exit(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if is_fullscreen_element(&self.node_ref) {
+            crate::document().exit_fullscreen();
+        }
+    }
+
+    /// Enters fullscreen if it is not active, or exits it if it is.
+    pub fn toggle(&self) {
+        use leptos_reactive::SignalGetUntracked;
+
+        if self.is_active.get_untracked() {
+            self.exit();
+        } else {
+            self.enter();
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn is_fullscreen_element<T: ElementDescriptor + Clone + 'static>(
+    node_ref: &NodeRef<T>,
+) -> bool {
+    match (
+        node_ref.get_untracked(),
+        crate::document().fullscreen_element(),
+    ) {
+        (Some(el), Some(active)) => {
+            active == *el.element.as_ref().unchecked_ref::<web_sys::Element>()
+        }
+        _ => false,
+    }
+}
+
+/// Creates [`Fullscreen`] controls for the element loaded into `node_ref`.
+///
+/// The active state is tracked with a document-level `fullscreenchange`
+/// listener, which is removed when `cx` is disposed. On the server, the
+/// state is always `false` and the controls do nothing.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{html::Video, leptos_dom::helpers::use_fullscreen};
+///
+/// #[component]
+/// pub fn Player(cx: Scope) -> impl IntoView {
+///     let video = create_node_ref::<Video>(cx);
+///     let fullscreen = use_fullscreen(cx, video);
+///
+///     view! { cx,
+///       <video _ref=video src="movie.mp4"/>
+///       <button on:click=move |_| fullscreen.toggle()>
+///         {move || if fullscreen.is_active().get() { "Exit" } else { "Fullscreen" }}
+///       </button>
+///     }
+/// }
+/// ```
+pub fn use_fullscreen<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+) -> Fullscreen<T> {
+    let (is_active, set_is_active) = create_signal(cx, false);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use leptos_reactive::SignalSet;
+
+        let sync = move || set_is_active.set(is_fullscreen_element(&node_ref));
+        document_event_listener_scoped(cx, "fullscreenchange", move |_| sync());
+        node_ref.on_load(cx, move |_| sync());
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    _ = set_is_active;
+
+    Fullscreen {
+        node_ref,
+        is_active,
+    }
+}
+
+/// Pointer lock controls for the element loaded into a [`NodeRef`], created
+/// with [`use_pointer_lock`].
+pub struct PointerLock<T: ElementDescriptor + 'static> {
+    #[cfg_attr(
+        not(all(target_arch = "wasm32", feature = "web")),
+        allow(dead_code)
+    )]
+    node_ref: NodeRef<T>,
+    is_active: ReadSignal<bool>,
+}
+
+impl<T: ElementDescriptor + 'static> Clone for PointerLock<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ElementDescriptor + 'static> Copy for PointerLock<T> {}
+
+impl<T: ElementDescriptor + Clone + 'static> PointerLock<T> {
+    /// Whether the pointer is currently locked to the referenced element.
+    ///
+    /// This is kept in sync with the document’s `pointerlockchange` event.
+    #[inline(always)]
+    pub fn is_active(&self) -> ReadSignal<bool> {
+        self.is_active
+    }
+
+    /// Requests that the pointer be locked to the referenced element.
+    /// See [`Element.requestPointerLock()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/requestPointerLock).
+    ///
+    /// Does nothing if the [`NodeRef`] has not been loaded yet.
+    pub fn lock(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if let Some(el) = self.node_ref.get_untracked() {
+            el.element.as_ref().request_pointer_lock();
+        }
+    }
+
+    /// Releases the pointer lock, if it is held by the referenced element.
+    /// See [`Document.exitPointerLock()`](https://developer.mozilla.org/en-US/docs/Web/API/Document/exitPointerLock).
+    pub fn unlock(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if is_pointer_lock_element(&self.node_ref) {
+            crate::document().exit_pointer_lock();
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn is_pointer_lock_element<T: ElementDescriptor + Clone + 'static>(
+    node_ref: &NodeRef<T>,
+) -> bool {
+    match (
+        node_ref.get_untracked(),
+        crate::document().pointer_lock_element(),
+    ) {
+        (Some(el), Some(active)) => {
+            active == *el.element.as_ref().unchecked_ref::<web_sys::Element>()
+        }
+        _ => false,
+    }
+}
+
+/// Creates [`PointerLock`] controls for the element loaded into `node_ref`.
+///
+/// The active state is tracked with a document-level `pointerlockchange`
+/// listener, which is removed when `cx` is disposed. On the server, the
+/// state is always `false` and the controls do nothing.
+pub fn use_pointer_lock<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+) -> PointerLock<T> {
+    let (is_active, set_is_active) = create_signal(cx, false);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use leptos_reactive::SignalSet;
+
+        document_event_listener_scoped(cx, "pointerlockchange", move |_| {
+            set_is_active.set(is_pointer_lock_element(&node_ref))
+        });
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    _ = set_is_active;
+
+    PointerLock {
+        node_ref,
+        is_active,
+    }
+}
+
+/// Tracks whether the element loaded into `node_ref` is visible within the
+/// viewport, using an [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver).
+///
+/// Returns a signal that is `true` while any part of the element is visible,
+/// and a signal containing the fraction of the element that is visible, from
+/// `0.0` to `1.0` (updated in steps of `0.1`). The observer is disconnected
+/// when `cx` is disposed. On the server, the element is never visible.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{html::Div, leptos_dom::helpers::use_element_visibility};
+///
+/// #[component]
+/// pub fn Banner(cx: Scope) -> impl IntoView {
+///     let banner = create_node_ref::<Div>(cx);
+///     let (is_visible, _ratio) = use_element_visibility(cx, banner);
+///
+///     create_effect(cx, move |_| {
+///         if is_visible.get() {
+///             log!("banner was seen");
+///         }
+///     });
+///
+///     view! { cx, <div _ref=banner>"Buy now!"</div> }
+/// }
+/// ```
+pub fn use_element_visibility<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+) -> (ReadSignal<bool>, ReadSignal<f64>) {
+    let (is_visible, set_is_visible) = create_signal(cx, false);
+    let (ratio, set_ratio) = create_signal(cx, 0.0);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use leptos_reactive::SignalSet;
+        use std::{cell::RefCell, rc::Rc};
+
+        let observer =
+            Rc::new(RefCell::new(None::<web_sys::IntersectionObserver>));
+
+        node_ref.on_load(cx, {
+            let observer = Rc::clone(&observer);
+            move |el| {
+                let cb = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+                    if let Some(entry) = entries.iter().last() {
+                        let entry = entry
+                            .unchecked_into::<web_sys::IntersectionObserverEntry>();
+                        set_is_visible.set(entry.is_intersecting());
+                        set_ratio.set(entry.intersection_ratio());
+                    }
+                })
+                    as Box<dyn FnMut(js_sys::Array)>)
+                .into_js_value();
+
+                let thresholds = (0..=10)
+                    .map(|step| JsValue::from_f64(step as f64 / 10.0))
+                    .collect::<js_sys::Array>();
+                let mut options = web_sys::IntersectionObserverInit::new();
+                options.threshold(&thresholds);
+
+                if let Ok(new_observer) =
+                    web_sys::IntersectionObserver::new_with_options(
+                        cb.unchecked_ref(),
+                        &options,
+                    )
+                {
+                    new_observer.observe(el.element.as_ref());
+                    *observer.borrow_mut() = Some(new_observer);
+                }
+            }
+        });
+
+        on_cleanup(cx, move || {
+            if let Some(observer) = observer.take() {
+                observer.disconnect();
+            }
+        });
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = node_ref;
+        _ = set_is_visible;
+        _ = set_ratio;
+    }
+
+    (is_visible, ratio)
+}
+
+/// The size and position of an element relative to the viewport, as given by
+/// [`getBoundingClientRect()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElementBounding {
+    /// The distance from the left of the viewport to the element’s origin.
+    pub x: f64,
+    /// The distance from the top of the viewport to the element’s origin.
+    pub y: f64,
+    /// The element’s width, including padding and borders.
+    pub width: f64,
+    /// The element’s height, including padding and borders.
+    pub height: f64,
+    /// The distance from the top of the viewport to the element’s top edge.
+    pub top: f64,
+    /// The distance from the left of the viewport to the element’s right
+    /// edge.
+    pub right: f64,
+    /// The distance from the top of the viewport to the element’s bottom
+    /// edge.
+    pub bottom: f64,
+    /// The distance from the left of the viewport to the element’s left edge.
+    pub left: f64,
+}
+
+/// Tracks the size and position of the element loaded into `node_ref`,
+/// relative to the viewport, e.g., to position a popover or tooltip next to
+/// its anchor.
+///
+/// The element is measured again when it is resized, using a
+/// [`ResizeObserver`](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver),
+/// or when the window or any element that contains it is scrolled or
+/// resized. Measurements are throttled to one per animation frame, and the
+/// signal only changes when the bounding box does. Everything is cleaned up
+/// when `cx` is disposed. On the server, every field is `0.0`.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{html::Button, leptos_dom::helpers::use_element_bounding};
+///
+/// #[component]
+/// pub fn WithTooltip(cx: Scope) -> impl IntoView {
+///     let anchor = create_node_ref::<Button>(cx);
+///     let bounding = use_element_bounding(cx, anchor);
+///     let position = move || {
+///         let bounding = bounding.get();
+///         format!(
+///             "position: fixed; top: {}px; left: {}px",
+///             bounding.bottom, bounding.left
+///         )
+///     };
+///
+///     view! { cx,
+///         <button _ref=anchor>"Save"</button>
+///         <div role="tooltip" style=position>
+///             "Saves a copy of this file"
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_element_bounding<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+) -> ReadSignal<ElementBounding> {
+    let (bounding, set_bounding) =
+        create_signal(cx, ElementBounding::default());
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use leptos_reactive::{SignalGetUntracked, SignalSet};
+        use std::{
+            cell::{Cell, RefCell},
+            rc::Rc,
+        };
+
+        let element = Rc::new(RefCell::new(None::<web_sys::Element>));
+        let scheduled = Rc::new(Cell::new(false));
+        let update = Rc::new({
+            let element = Rc::clone(&element);
+            move || {
+                if scheduled.replace(true) {
+                    return;
+                }
+                let element = Rc::clone(&element);
+                let scheduled = Rc::clone(&scheduled);
+                request_animation_frame(move || {
+                    scheduled.set(false);
+                    let Some(rect) = element
+                        .borrow()
+                        .as_ref()
+                        .map(|el| el.get_bounding_client_rect())
+                    else {
+                        return;
+                    };
+                    let new = ElementBounding {
+                        x: rect.x(),
+                        y: rect.y(),
+                        width: rect.width(),
+                        height: rect.height(),
+                        top: rect.top(),
+                        right: rect.right(),
+                        bottom: rect.bottom(),
+                        left: rect.left(),
+                    };
+                    // the scope may have been disposed since the frame was
+                    // requested
+                    if bounding
+                        .try_get_untracked()
+                        .map_or(false, |old| old != new)
+                    {
+                        set_bounding.set(new);
+                    }
+                });
+            }
+        });
+
+        let on_change = Closure::<dyn FnMut()>::new({
+            let update = Rc::clone(&update);
+            move || update()
+        });
+        // scroll events don't bubble, so capture them to hear about any
+        // scrolling ancestor, not just the window
+        let mut options = web_sys::AddEventListenerOptions::new();
+        options.capture(true).passive(true);
+        _ = window()
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                "scroll",
+                on_change.as_ref().unchecked_ref(),
+                &options,
+            );
+        _ = window().add_event_listener_with_callback(
+            "resize",
+            on_change.as_ref().unchecked_ref(),
+        );
+        let observer =
+            web_sys::ResizeObserver::new(on_change.as_ref().unchecked_ref())
+                .ok();
+
+        node_ref.on_load(cx, {
+            let element = Rc::clone(&element);
+            let observer = observer.clone();
+            move |el| {
+                let el: &web_sys::HtmlElement = el.element.as_ref();
+                let el: web_sys::Element = el.clone().unchecked_into();
+                if let Some(observer) = &observer {
+                    observer.observe(&el);
+                }
+                *element.borrow_mut() = Some(el);
+                update();
+            }
+        });
+
+        on_cleanup(cx, move || {
+            let callback = on_change.as_ref().unchecked_ref();
+            _ = window().remove_event_listener_with_callback_and_bool(
+                "scroll", callback, true,
+            );
+            _ = window()
+                .remove_event_listener_with_callback("resize", callback);
+            if let Some(observer) = observer {
+                observer.disconnect();
+            }
+            element.take();
+        });
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = node_ref;
+        _ = set_bounding;
+    }
+
+    bounding
+}
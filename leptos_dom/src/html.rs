@@ -6,7 +6,7 @@ cfg_if! {
   if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
     use crate::events::*;
     use crate::macro_helpers::*;
-    use crate::{mount_child, MountKind};
+    use crate::{mount_child, mount_children, MountKind};
     use once_cell::unsync::Lazy as LazyCell;
     use std::cell::Cell;
     use wasm_bindgen::JsCast;
@@ -77,7 +77,26 @@ pub trait ElementDescriptor: ElementDescriptorBounds {
     /// Determines if the tag is void, i.e., `<input>` and `<br>`.
     #[inline(always)]
     fn is_void(&self) -> bool {
-        false
+        self.metadata().map(|meta| meta.is_void).unwrap_or(false)
+    }
+
+    /// Static metadata for the tag, if it is known at compile time.
+    ///
+    /// All of the typed element constructors (`div`, `svg::circle`, etc.)
+    /// return their metadata here; dynamically-named elements like
+    /// [`Custom`] return `None`.
+    #[inline(always)]
+    fn metadata(&self) -> Option<&'static TagMetadata> {
+        None
+    }
+
+    /// The namespace the element is created in, which is HTML unless the
+    /// tag's [`metadata`](ElementDescriptor::metadata) says otherwise.
+    #[inline(always)]
+    fn namespace(&self) -> ElementNamespace {
+        self.metadata()
+            .map(|meta| meta.namespace)
+            .unwrap_or(ElementNamespace::Html)
     }
 
     /// A unique `id` that should be generated for each new instance of
@@ -86,6 +105,250 @@ pub trait ElementDescriptor: ElementDescriptorBounds {
     fn hydration_id(&self) -> &HydrationKey;
 }
 
+/// Metadata about an element tag that is known at compile time.
+///
+/// Each typed element constructor carries a `const` instance of this, so
+/// looking up a tag's name or void-ness never allocates and doesn't need to
+/// be stored on every element.
+///
+/// ```
+/// use leptos_dom::html::{DefaultDisplay, Div, Input};
+///
+/// assert!(Input::METADATA.is_void);
+/// assert_eq!(Input::METADATA.display, DefaultDisplay::InlineBlock);
+/// assert_eq!(Div::METADATA.name, "div");
+/// assert_eq!(Div::METADATA.display, DefaultDisplay::Block);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TagMetadata {
+    /// The tag name, i.e., `div`, `circle`, `mrow`.
+    pub name: &'static str,
+    /// Whether the tag is void, i.e., `<input>` and `<br>`.
+    pub is_void: bool,
+    /// The namespace the element is created in.
+    pub namespace: ElementNamespace,
+    /// The display the user agent stylesheet gives the element.
+    pub display: DefaultDisplay,
+}
+
+impl TagMetadata {
+    /// Creates metadata for an HTML tag.
+    pub const fn html(name: &'static str, is_void: bool) -> Self {
+        Self {
+            name,
+            is_void,
+            namespace: ElementNamespace::Html,
+            display: DefaultDisplay::for_html_tag(name),
+        }
+    }
+
+    /// Creates metadata for an SVG tag.
+    pub const fn svg(name: &'static str, is_void: bool) -> Self {
+        Self {
+            name,
+            is_void,
+            namespace: ElementNamespace::Svg,
+            display: DefaultDisplay::Inline,
+        }
+    }
+
+    /// Creates metadata for a MathML tag.
+    pub const fn math(name: &'static str, is_void: bool) -> Self {
+        Self {
+            name,
+            is_void,
+            namespace: ElementNamespace::MathMl,
+            display: DefaultDisplay::Inline,
+        }
+    }
+}
+
+/// The namespace an element belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementNamespace {
+    /// `http://www.w3.org/1999/xhtml`
+    Html,
+    /// `http://www.w3.org/2000/svg`
+    Svg,
+    /// `http://www.w3.org/1998/Math/MathML`
+    MathMl,
+}
+
+impl ElementNamespace {
+    /// The namespace URI.
+    pub const fn uri(&self) -> &'static str {
+        match self {
+            Self::Html => "http://www.w3.org/1999/xhtml",
+            Self::Svg => "http://www.w3.org/2000/svg",
+            Self::MathMl => "http://www.w3.org/1998/Math/MathML",
+        }
+    }
+}
+
+/// The default `display` of an element, as given by the user agent
+/// stylesheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefaultDisplay {
+    /// `display: inline`
+    Inline,
+    /// `display: inline-block`
+    InlineBlock,
+    /// `display: block`
+    Block,
+    /// `display: list-item`
+    ListItem,
+    /// `display: table` and its internal table displays.
+    Table,
+    /// `display: none`
+    None,
+}
+
+impl DefaultDisplay {
+    const BLOCK: &'static [&'static str] = &[
+        "html",
+        "body",
+        "address",
+        "article",
+        "aside",
+        "blockquote",
+        "dd",
+        "details",
+        "dialog",
+        "div",
+        "dl",
+        "dt",
+        "fieldset",
+        "figcaption",
+        "figure",
+        "footer",
+        "form",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "header",
+        "hgroup",
+        "hr",
+        "legend",
+        "main",
+        "menu",
+        "nav",
+        "ol",
+        "optgroup",
+        "p",
+        "pre",
+        "search",
+        "section",
+        "summary",
+        "ul",
+    ];
+    const INLINE_BLOCK: &'static [&'static str] =
+        &["button", "input", "meter", "progress", "select", "textarea"];
+    const TABLE: &'static [&'static str] = &[
+        "table", "caption", "colgroup", "col", "thead", "tbody", "tfoot", "tr",
+        "td", "th",
+    ];
+    const NONE: &'static [&'static str] = &[
+        "head", "base", "link", "meta", "noscript", "script", "style",
+        "template", "title", "datalist", "param", "slot",
+    ];
+
+    /// The default display of the HTML tag with the given name.
+    pub const fn for_html_tag(name: &str) -> Self {
+        if Self::contains(Self::BLOCK, name) {
+            Self::Block
+        } else if Self::contains(Self::INLINE_BLOCK, name) {
+            Self::InlineBlock
+        } else if Self::contains(Self::TABLE, name) {
+            Self::Table
+        } else if Self::contains(Self::NONE, name) {
+            Self::None
+        } else if str_eq(name, "li") {
+            Self::ListItem
+        } else {
+            Self::Inline
+        }
+    }
+
+    const fn contains(list: &[&str], name: &str) -> bool {
+        let mut idx = 0;
+        while idx < list.len() {
+            if str_eq(list[idx], name) {
+                return true;
+            }
+            idx += 1;
+        }
+        false
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < a.len() {
+        if a[idx] != b[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// The tag of an [`AnyElement`], either pointing at the static
+/// [`TagMetadata`] of a typed element or holding a runtime name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TagName {
+    Static(&'static TagMetadata),
+    Dynamic(Cow<'static, str>, bool),
+}
+
+impl TagName {
+    #[inline(always)]
+    pub(crate) fn of<El: ElementDescriptor>(el: &El) -> Self {
+        match el.metadata() {
+            Some(meta) => Self::Static(meta),
+            None => Self::Dynamic(el.name(), el.is_void()),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Static(meta) => Cow::Borrowed(meta.name),
+            Self::Dynamic(name, _) => name.clone(),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Self::Static(meta) => meta.name,
+            Self::Dynamic(name, _) => name,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_void(&self) -> bool {
+        match self {
+            Self::Static(meta) => meta.is_void,
+            Self::Dynamic(_, is_void) => *is_void,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn metadata(&self) -> Option<&'static TagMetadata> {
+        match self {
+            Self::Static(meta) => Some(meta),
+            Self::Dynamic(..) => None,
+        }
+    }
+}
+
 /// Trait for converting any type which impl [`AsRef<web_sys::Element>`]
 /// to [`HtmlElement`].
 pub trait ToHtmlElement {
@@ -103,8 +366,7 @@ where
             let el = self.as_ref().clone().unchecked_into();
 
             let element = AnyElement {
-                name: "".into(),
-                is_void: false,
+                tag: TagName::Dynamic("".into(), false),
                 element: el,
             };
 
@@ -130,10 +392,9 @@ where
 /// Represents potentially any element.
 #[derive(Clone, Debug)]
 pub struct AnyElement {
-    pub(crate) name: Cow<'static, str>,
+    pub(crate) tag: TagName,
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     pub(crate) element: web_sys::HtmlElement,
-    pub(crate) is_void: bool,
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub(crate) id: HydrationKey,
 }
@@ -164,12 +425,17 @@ impl std::convert::AsRef<web_sys::HtmlElement> for AnyElement {
 
 impl ElementDescriptor for AnyElement {
     fn name(&self) -> Cow<'static, str> {
-        self.name.clone()
+        self.tag.name()
     }
 
     #[inline(always)]
     fn is_void(&self) -> bool {
-        self.is_void
+        self.tag.is_void()
+    }
+
+    #[inline(always)]
+    fn metadata(&self) -> Option<&'static TagMetadata> {
+        self.tag.metadata()
     }
 
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -183,6 +449,8 @@ impl ElementDescriptor for AnyElement {
 #[derive(Clone, Debug)]
 pub struct Custom {
     name: Cow<'static, str>,
+    namespace: ElementNamespace,
+    is_void: bool,
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     element: web_sys::HtmlElement,
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -192,67 +460,85 @@ pub struct Custom {
 impl Custom {
     /// Creates a new custom element with the given tag name.
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new_ns(name, ElementNamespace::Html)
+    }
+
+    /// Creates a new element with the given tag name in `namespace`, e.g.,
+    /// an SVG element that doesn't have a typed builder in [`svg`](crate::svg).
+    ///
+    /// Elements created with [`Custom::new`] are always HTML elements, so
+    /// browsers wouldn't render them inside an `<svg>` or `<math>`.
+    pub fn new_ns(
+        name: impl Into<Cow<'static, str>>,
+        namespace: ElementNamespace,
+    ) -> Self {
         let name = name.into();
         let id = HydrationCtx::id();
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
-        let element = if HydrationCtx::is_hydrating() {
-            if let Some(el) =
-                crate::document().get_element_by_id(&format!("_{id}"))
-            {
-                #[cfg(debug_assertions)]
-                assert_eq!(
-                    el.node_name().to_ascii_uppercase(),
-                    name.to_ascii_uppercase(),
-                    "SSR and CSR elements have the same `TopoId` but \
-                     different node kinds. This is either a discrepancy \
-                     between SSR and CSR rendering
-                    logic, which is considered a bug, or it can also be a \
-                     leptos hydration issue."
-                );
-
-                el.remove_attribute("id").unwrap();
+        let create_element = || match namespace {
+            ElementNamespace::Html => {
+                crate::document().create_element(&name).unwrap()
+            }
+            _ => crate::document()
+                .create_element_ns(Some(namespace.uri()), &name)
+                .unwrap(),
+        };
 
-                el.unchecked_into()
-            } else if let Ok(Some(el)) =
-                crate::document().query_selector(&format!("[leptos-hk=_{id}]"))
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        let element = if HydrationCtx::is_hydrating() {
+            if let Some(el) = crate::hydration::take_element(&format!("_{id}"))
             {
                 #[cfg(debug_assertions)]
                 assert_eq!(
                     el.node_name().to_ascii_uppercase(),
                     name.to_ascii_uppercase(),
                     "SSR and CSR elements have the same `TopoId` but \
-                     different node kinds. This is either a discrepancy \
+                     different node kinds{}. This is either a discrepancy \
                      between SSR and CSR rendering
                     logic, which is considered a bug, or it can also be a \
-                     leptos hydration issue."
+                     leptos hydration issue.",
+                    crate::source_location::describe_next()
                 );
 
-                el.remove_attribute("leptos-hk").unwrap();
-
                 el.unchecked_into()
             } else {
                 if !is_meta_tag() {
                     crate::warn!(
-                        "element with id {id} not found, ignoring it for \
-                         hydration"
+                        "element with id {id} not found{}, ignoring it for \
+                         hydration",
+                        crate::source_location::describe_next()
                     );
                 }
 
-                crate::document().create_element(&name).unwrap()
+                create_element()
             }
         } else {
-            crate::document().create_element(&name).unwrap()
+            create_element()
         };
 
         Self {
             name,
+            namespace,
+            is_void: false,
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
             element: element.unchecked_into(),
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             id,
         }
     }
+
+    /// Sets whether the element is void, like `<input>`, so that it is
+    /// rendered without a closing tag on the server. Custom elements aren't
+    /// void by default, and a void element's children are never rendered.
+    ///
+    /// This is only needed for obscure or proprietary tags that the HTML
+    /// parser treats as void: web components can never be void.
+    #[inline(always)]
+    pub fn void(mut self, is_void: bool) -> Self {
+        self.is_void = is_void;
+        self
+    }
 }
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -278,6 +564,16 @@ impl ElementDescriptor for Custom {
         self.name.clone()
     }
 
+    #[inline(always)]
+    fn is_void(&self) -> bool {
+        self.is_void
+    }
+
+    #[inline(always)]
+    fn namespace(&self) -> ElementNamespace {
+        self.namespace
+    }
+
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     #[inline(always)]
     fn hydration_id(&self) -> &HydrationKey {
@@ -357,10 +653,27 @@ where
     }
 }
 
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl<El: ElementDescriptor> AsRef<web_sys::HtmlElement> for HtmlElement<El> {
+    /// Returns the underlying DOM element, without its specific type.
+    fn as_ref(&self) -> &web_sys::HtmlElement {
+        self.element.as_ref()
+    }
+}
+
 impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     pub(crate) fn new(cx: Scope, element: El) -> Self {
+        // the location set by `view!` for this element, if any
+        #[cfg(debug_assertions)]
+        let location = crate::source_location::take_next();
+
         cfg_if! {
           if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            #[cfg(debug_assertions)]
+            if let Some(location) = location {
+              crate::source_location::attach(element.as_ref(), location);
+            }
+
             Self {
               cx,
               element,
@@ -370,6 +683,9 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               view_marker: None
             }
           } else {
+            #[cfg(debug_assertions)]
+            _ = location;
+
             Self {
               cx,
               attrs: smallvec![],
@@ -423,9 +739,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             HtmlElement {
               cx,
               element: AnyElement {
-                name: element.name(),
+                tag: TagName::of(&element),
                 element: element.as_ref().clone(),
-                is_void: element.is_void(),
               },
               #[cfg(debug_assertions)]
               span,
@@ -447,8 +762,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               attrs,
               children,
               element: AnyElement {
-                name: element.name(),
-                is_void: element.is_void(),
+                tag: TagName::of(&element),
                 id: *element.hydration_id()
               },
               #[cfg(debug_assertions)]
@@ -459,7 +773,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Adds an `id` to the element.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn id(self, id: impl Into<Cow<'static, str>>) -> Self {
         let id = id.into();
@@ -486,7 +800,20 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
-    /// Binds the element reference to [`NodeRef`].
+    /// Loads this element into `node_ref` once it has been created, so the
+    /// underlying DOM element can be used elsewhere, e.g., to focus it or
+    /// measure it. This is what the `node_ref` attribute in `view!` does.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let input = create_node_ref::<html::Input>(cx);
+    /// input.on_load(cx, |input| {
+    ///     _ = input.focus();
+    /// });
+    /// html::input(cx).attr("type", "text").node_ref(input);
+    /// # });
+    /// ```
     #[inline(always)]
     pub fn node_ref(self, node_ref: NodeRef<El>) -> Self
     where
@@ -566,6 +893,34 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         self
     }
 
+    /// Attaches a [`Directive`](crate::Directive) to this element, which
+    /// runs with the element's [`Scope`] and `param` once the element has
+    /// been mounted. This is what `use:directive=param` does in the `view`
+    /// macro.
+    ///
+    /// Directives only run in the browser.
+    pub fn directive<T: ?Sized, P: 'static>(
+        self,
+        handler: impl crate::Directive<T, P> + 'static,
+        param: P,
+    ) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let cx = self.cx;
+            self.on_mount(move |this| {
+                let el: &web_sys::Element = this.element.as_ref();
+                handler.run(cx, el.clone(), param);
+            })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = handler;
+            _ = param;
+            self
+        }
+    }
+
     /// Checks to see if this element is mounted to the DOM as a child
     /// of `body`.
     ///
@@ -587,14 +942,29 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Adds an attribute to this element.
-    #[track_caller]
+    ///
+    /// If `attr` is a closure or a signal, it is run in a render effect, and
+    /// only this attribute is updated when its value changes.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (disabled, set_disabled) = create_signal(cx, false);
+    /// html::button(cx)
+    ///     .attr("disabled", disabled)
+    ///     .attr("aria-label", move || {
+    ///         if disabled.get() { "Unavailable" } else { "Submit" }
+    ///     });
+    /// # });
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[cfg_attr(all(target_arch = "wasm32", feature = "web"), inline(always))]
     pub fn attr(
         self,
         name: impl Into<Cow<'static, str>>,
         attr: impl IntoAttribute,
     ) -> Self {
-        let name = name.into();
+        let name = crate::intern_name(name.into());
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
@@ -647,13 +1017,28 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     /// This directly uses the browser’s `classList` API, which means it will throw
     /// a runtime error if you pass more than a single class name. If you want to
     /// pass more than one class name at a time, you can use [HtmlElement::classes].
-    #[track_caller]
+    ///
+    /// If `class` is a closure or a signal, only this class is added or
+    /// removed whenever its value changes, so the rest of the `class`
+    /// attribute isn't rebuilt.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (selected, set_selected) = create_signal(cx, false);
+    /// html::li(cx)
+    ///     .attr("class", "item")
+    ///     .class("selected", selected)
+    ///     .on_click(move |_| set_selected.update(|s| *s = !*s));
+    /// # });
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn class(
         self,
         name: impl Into<Cow<'static, str>>,
         class: impl IntoClass,
     ) -> Self {
-        let name = name.into();
+        let name = crate::intern_name(name.into());
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
@@ -700,14 +1085,14 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Adds a list of classes separated by ASCII whitespace to an element.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn classes(self, classes: impl Into<Cow<'static, str>>) -> Self {
         self.classes_inner(&classes.into())
     }
 
     /// Sets the class on the element as the class signal changes.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn dyn_classes<I, C>(
         self,
         classes_signal: impl Fn() -> I + 'static,
@@ -806,10 +1191,26 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
     /// Sets a style on an element.
     ///
+    /// The property is set with `style.setProperty()`, so other properties in
+    /// the element’s `style` are left alone. If `style` is a closure or a
+    /// signal, the property is updated whenever its value changes, and
+    /// removed with `style.removeProperty()` while it is `None`.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (progress, set_progress) = create_signal(cx, None::<u8>);
+    /// html::div(cx)
+    ///     .attr("style", "height: 4px; background: teal")
+    ///     .style("width", move || progress.get().map(|p| format!("{p}%")));
+    /// # _ = set_progress;
+    /// # });
+    /// ```
+    ///
     /// **Note**: In the builder syntax, this will be overwritten by the `style`
     /// attribute if you use `.attr("style", /* */)`. In the `view` macro, they
     /// are automatically re-ordered so that this over-writing does not happen.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn style(
         self,
         name: impl Into<Cow<'static, str>>,
@@ -868,7 +1269,25 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Sets a property on an element.
-    #[track_caller]
+    ///
+    /// Unlike [`attr`](Self::attr), this sets the property of the DOM
+    /// object itself. This matters for state the user can change, like an
+    /// input's `value` or `checked`, or `indeterminate`, which has no
+    /// attribute: the attribute only sets the initial state, so a controlled
+    /// input needs the property. If `value` is a closure or a signal, the
+    /// property is updated whenever it changes. Properties are not rendered
+    /// on the server.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (name, set_name) = create_signal(cx, String::new());
+    /// html::input(cx)
+    ///     .prop("value", name)
+    ///     .on_input(move |ev| set_name.set(event_target_value(&ev)));
+    /// # });
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn prop(
         self,
         name: impl Into<Cow<'static, str>>,
@@ -876,7 +1295,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     ) -> Self {
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
-            let name = name.into();
+            let name = crate::intern_name(name.into());
             let value = value.into_property(self.cx);
             let el = self.element.as_ref();
             property_helper(el, name, value);
@@ -892,14 +1311,35 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Adds an event listener to this element.
-    #[track_caller]
+    ///
+    /// The handler can return an [`EventFlow`](crate::EventFlow) to prevent
+    /// the event's default action or stop its propagation. It is dropped
+    /// when the element's [`Scope`] is disposed.
+    ///
+    /// Common events also have typed shorthands, like
+    /// [`on_click`](Self::on_click) and [`on_input`](Self::on_input).
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (count, set_count) = create_signal(cx, 0);
+    /// html::button(cx)
+    ///     .on_click(move |ev: ev::MouseEvent| {
+    ///         set_count.update(|n| *n += ev.detail())
+    ///     })
+    ///     .child(move || count.get());
+    /// # });
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
-    pub fn on<E: EventDescriptor + 'static>(
+    pub fn on<E: EventDescriptor + 'static, R>(
         self,
         event: E,
-        #[allow(unused_mut)] // used for tracing in debug
-        mut event_handler: impl FnMut(E::EventType) + 'static,
-    ) -> Self {
+        event_handler: impl FnMut(E::EventType) -> R + 'static,
+    ) -> Self
+    where
+        R: crate::EventHandlerReturn<E::EventType>,
+    {
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
             cfg_if! {
@@ -916,10 +1356,11 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             let event_name = event.name();
 
             let key = event.event_delegation_key();
-            let event_handler = Box::new(event_handler);
+            let event_handler = R::into_handler(event_handler);
 
             if E::BUBBLES {
                 add_event_listener(
+                    Some(self.cx),
                     self.element.as_ref(),
                     key,
                     event_name,
@@ -928,6 +1369,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
                 );
             } else {
                 add_event_listener_undelegated(
+                    Some(self.cx),
                     self.element.as_ref(),
                     &event_name,
                     event_handler,
@@ -947,6 +1389,98 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Adds an event listener to this element, with [`EventOptions`] like
+    /// `passive` or `once`, or modifiers that filter the events the handler
+    /// sees, or prevent their default action or stop their propagation
+    /// before it runs. These are the options used by modifiers like
+    /// `on:click:prevent` in the `view` macro.
+    ///
+    /// Listeners that are `capture`, `passive` or `once` are attached to the
+    /// element itself, rather than delegated. A listener with
+    /// [`outside`](crate::EventOptions::outside) is attached to the
+    /// document's root element, and ignores events inside this element.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let (scrolls, set_scrolls) = create_signal(cx, 0);
+    /// html::div(cx).on_with_options(
+    ///     ev::scroll,
+    ///     move |_| set_scrolls.update(|n| *n += 1),
+    ///     EventOptions::passive(),
+    /// );
+    /// # });
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn on_with_options<E: EventDescriptor + 'static, R>(
+        self,
+        event: E,
+        event_handler: impl FnMut(E::EventType) -> R + 'static,
+        options: crate::EventOptions,
+    ) -> Self
+    where
+        E::EventType: wasm_bindgen::JsCast,
+        R: crate::EventHandlerReturn<E::EventType>,
+    {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            cfg_if! {
+                if #[cfg(debug_assertions)] {
+                    let onspan = ::tracing::span!(
+                        parent: &self.span,
+                        ::tracing::Level::TRACE,
+                        "on",
+                        event = %event.name()
+                    );
+                    let _onguard = onspan.enter();
+                }
+            }
+            let event_name = event.name();
+            let el: &web_sys::HtmlElement = self.element.as_ref();
+            let event_handler =
+                options.wrap_handler(el, R::into_handler(event_handler));
+            let root;
+            let target: &web_sys::Element = if options.outside {
+                root = crate::document()
+                    .document_element()
+                    .expect("document to have a root element");
+                &root
+            } else {
+                el
+            };
+
+            if E::BUBBLES && !options.needs_own_listener() {
+                add_event_listener(
+                    Some(self.cx),
+                    target,
+                    event.event_delegation_key(),
+                    event_name,
+                    event_handler,
+                    event.options(),
+                );
+            } else {
+                add_event_listener_undelegated(
+                    Some(self.cx),
+                    target,
+                    &event_name,
+                    event_handler,
+                    &options.listener_options(event.options()),
+                );
+            }
+
+            self
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = event;
+            _ = event_handler;
+            _ = options;
+
+            self
+        }
+    }
+
     /// Optionally adds an event listener to this element.
     ///
     /// ## Example
@@ -966,7 +1500,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     /// }
     /// #
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn optional_event<E: EventDescriptor + 'static>(
         self,
@@ -982,7 +1516,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     }
 
     /// Adds a child to this element.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn child(self, child: impl IntoView) -> Self {
         let child = child.into_view(self.cx);
 
@@ -1009,7 +1543,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
                     *children = ElementChildren::Children(vec![child]);
                 }
                 ElementChildren::Children(ref mut children) => {
-                    children.push(child);
+                    push_merging_text(children, child);
                 }
                 _ => {
                     crate::debug_warn!(
@@ -1024,20 +1558,32 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
-    /// Sets the inner HTML of this element from the provided
-    /// string slice.
+    /// Adds several children to this element at once.
     ///
-    /// # Security
-    /// Be very careful when using this method. Always remember to
-    /// sanitize the input to avoid a cross-site scripting (XSS)
-    /// vulnerability.
-    #[inline(always)]
-    pub fn inner_html(self, html: impl Into<Cow<'static, str>>) -> Self {
-        let html = html.into();
+    /// In the browser, the children are collected into a single
+    /// [`DocumentFragment`](web_sys::DocumentFragment) and appended to the
+    /// element in one operation, rather than one insertion per child.
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn children(self, children: impl IntoIterator<Item = View>) -> Self {
+        let mut merged = Vec::new();
+        for child in children {
+            push_merging_text(&mut merged, child);
+        }
+        let children = merged;
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
-            self.element.as_ref().set_inner_html(&html);
+            if !HydrationCtx::is_hydrating() {
+                #[cfg(debug_assertions)]
+                for child in &children {
+                    warn_on_ambiguous_a(self.element.as_ref(), child);
+                }
+
+                mount_children(
+                    MountKind::Append(self.element.as_ref()),
+                    &children,
+                );
+            }
 
             self
         }
@@ -1045,12 +1591,262 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         {
             let mut this = self;
+            let existing = &mut this.children;
+
+            match existing {
+                ElementChildren::Empty => {
+                    *existing = ElementChildren::Children(children);
+                }
+                ElementChildren::Children(ref mut existing) => {
+                    for child in children {
+                        push_merging_text(existing, child);
+                    }
+                }
+                _ => {
+                    crate::debug_warn!(
+                        "Don’t call .children() on an HtmlElement if you’ve \
+                         already called .inner_html() or \
+                         HtmlElement::from_chunks()."
+                    );
+                }
+            }
+
+            this
+        }
+    }
+
+    /// Sets the inner HTML of this element, replacing its children, e.g., to
+    /// render rich text or the output of a Markdown renderer.
+    ///
+    /// If `html` is a function, like `move || render_markdown(&text.get())`,
+    /// the inner HTML is replaced whenever it changes. On the server, the
+    /// HTML is written into the element as it is, so this can't be combined
+    /// with [`child`](HtmlElement::child) or
+    /// [`children`](HtmlElement::children). The `view` macro's `inner_html`
+    /// attribute does the same thing.
+    ///
+    /// # Security
+    /// The HTML is **not escaped**. Be very careful when using this method.
+    /// Always remember to sanitize the input to avoid a cross-site scripting
+    /// (XSS) vulnerability.
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn inner_html(self, html: impl IntoAttribute) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            // not through `attribute_helper`, which interns every value, so
+            // each distinct HTML string would be kept forever
+            let el = self.element.as_ref().clone();
+            match html.into_attribute(self.cx) {
+                Attribute::Fn(cx, f) => {
+                    leptos_reactive::create_render_effect(cx, move |old| {
+                        let new = f().as_nameless_value_string();
+                        if old.as_ref() != Some(&new) {
+                            el.set_inner_html(
+                                new.as_deref().unwrap_or_default(),
+                            );
+                        }
+                        new
+                    });
+                }
+                html => el.set_inner_html(
+                    &html.as_nameless_value_string().unwrap_or_default(),
+                ),
+            }
+
+            self
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let mut this = self;
+
+            if !matches!(this.children, ElementChildren::Empty) {
+                crate::debug_warn!(
+                    "Don’t call .inner_html() on an HtmlElement that already \
+                     has children: the inner HTML replaces them."
+                );
+            }
+            let html = html.into_attribute(this.cx);
+            this.children = ElementChildren::InnerHtml(
+                html.as_nameless_value_string().unwrap_or_default(),
+            );
+
+            this
+        }
+    }
 
-            this.children = ElementChildren::InnerHtml(html);
+    /// Adds children that never change, given as HTML that the `view` macro
+    /// has already rendered and escaped.
+    ///
+    /// In the browser, the HTML is parsed into a `<template>` the first time
+    /// it is used, and each element gets a deep clone of its content, which
+    /// is much faster than creating the elements one by one. The macro only
+    /// uses this in client-side rendering mode, as hydration needs to walk
+    /// each element.
+    #[doc(hidden)]
+    pub fn static_children(self, html: &'static str) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            if !HydrationCtx::is_hydrating() {
+                #[cfg(feature = "debug-dom")]
+                crate::debug_dom::Owner::current().log("mount", html);
 
+                self.element
+                    .as_ref()
+                    .append_child(&clone_static_html(html))
+                    .unwrap();
+            }
+
+            self
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let mut this = self;
+            this.children = ElementChildren::InnerHtml(Cow::Borrowed(html));
             this
         }
     }
+
+    /// Attaches a shadow root to this element and renders `children` into
+    /// it.
+    ///
+    /// On the server, the shadow root is rendered as declarative shadow DOM,
+    /// i.e., a `<template shadowrootmode="open">`, so its contents are
+    /// visible before any JS loads. When hydrating, an open shadow root is
+    /// taken over from the server-rendered HTML. A closed shadow root can't
+    /// be reached from JS, so its contents are rendered again instead.
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn shadow_root<N: IntoView>(
+        self,
+        mode: ShadowRootMode,
+        children: impl FnOnce(Scope) -> N,
+    ) -> Self {
+        // the server renders the `<template>` as an element, so it takes a
+        // hydration key on both sides, to keep the keys in sync
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            _ = HydrationCtx::id();
+
+            let el: &web_sys::HtmlElement = self.element.as_ref();
+            let existing = HydrationCtx::is_hydrating()
+                .then(|| el.shadow_root())
+                .flatten();
+            let (root, hydrating) = match existing {
+                Some(root) => (root, true),
+                None => (
+                    el.attach_shadow(&web_sys::ShadowRootInit::new(
+                        mode.into(),
+                    ))
+                    .expect("element to support shadow roots"),
+                    false,
+                ),
+            };
+
+            let render = || children(self.cx).into_view(self.cx);
+            if hydrating {
+                crate::hydration::collect_shadow_root(&root);
+                _ = render();
+            } else {
+                let child = HydrationCtx::without_hydrating(render);
+                mount_child(MountKind::Append(root.unchecked_ref()), &child);
+            }
+
+            self
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let cx = self.cx;
+            let template = crate::html::template(cx)
+                .attr("shadowrootmode", mode.as_str())
+                .child(children(cx).into_view(cx));
+            self.child(template)
+        }
+    }
+}
+
+macro_rules! typed_event_methods {
+    ($($method:ident => $event:ident),* $(,)?) => {
+        impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+            $(
+                #[doc = concat!(
+                    "Adds a listener for the [`", stringify!($event),
+                    "`](crate::ev::", stringify!($event), ") event. ",
+                    "Shorthand for `.on(ev::", stringify!($event), ", f)`."
+                )]
+                #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+                #[inline(always)]
+                pub fn $method<R>(
+                    self,
+                    event_handler: impl FnMut(
+                            <crate::ev::$event as EventDescriptor>::EventType,
+                        ) -> R
+                        + 'static,
+                ) -> Self
+                where
+                    R: crate::EventHandlerReturn<
+                        <crate::ev::$event as EventDescriptor>::EventType,
+                    >,
+                {
+                    self.on(crate::ev::$event, event_handler)
+                }
+            )*
+        }
+    };
+}
+
+typed_event_methods! {
+    on_click => click,
+    on_dblclick => dblclick,
+    on_contextmenu => contextmenu,
+    on_mousedown => mousedown,
+    on_mouseup => mouseup,
+    on_mousemove => mousemove,
+    on_pointerdown => pointerdown,
+    on_pointerup => pointerup,
+    on_pointermove => pointermove,
+    on_keydown => keydown,
+    on_keyup => keyup,
+    on_input => input,
+    on_change => change,
+    on_submit => submit,
+    on_focus => focus,
+    on_blur => blur,
+    on_focusin => focusin,
+    on_focusout => focusout,
+    on_scroll => scroll,
+    on_wheel => wheel,
+}
+
+/// Whether a shadow root's contents can be reached from outside of it.
+/// See [`HtmlElement::shadow_root`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShadowRootMode {
+    /// The shadow root can be reached with `element.shadowRoot`.
+    Open,
+    /// The shadow root is hidden from JS outside of it.
+    Closed,
+}
+
+impl ShadowRootMode {
+    /// The value of the `shadowrootmode` attribute.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl From<ShadowRootMode> for web_sys::ShadowRootMode {
+    fn from(mode: ShadowRootMode) -> Self {
+        match mode {
+            ShadowRootMode::Open => Self::Open,
+            ShadowRootMode::Closed => Self::Closed,
+        }
+    }
 }
 
 impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
@@ -1107,12 +1903,36 @@ impl<El: ElementDescriptor, const N: usize> IntoView for [HtmlElement<El>; N] {
     }
 }
 
-/// Creates any custom element, such as `<my-element>`.
+/// Creates any custom element, such as `<my-element>`, from a [`Custom`] or
+/// any other [`ElementDescriptor`].
+///
+/// This is what the `view` macro uses for tags with a `-` in their name, and
+/// it can be used directly for web components or tags that don't have a
+/// typed builder.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::html::{custom, Custom};
+///
+/// # run_scope(create_runtime(), |cx| {
+/// let widget = custom(cx, Custom::new("my-widget"))
+///     .attr("size", "large")
+///     .child("Hello!");
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let html = widget.into_view(cx).render_to_string(cx);
+/// assert!(html.starts_with("<my-widget"));
+/// assert!(html.contains(r#"size="large""#));
+/// assert!(html.ends_with(">Hello!</my-widget>"));
+/// # }
+/// # });
+/// ```
 pub fn custom<El: ElementDescriptor>(cx: Scope, el: El) -> HtmlElement<Custom> {
     HtmlElement::new(
         cx,
         Custom {
             name: el.name(),
+            namespace: el.namespace(),
+            is_void: el.is_void(),
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
             element: el.as_ref().clone(),
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -1208,10 +2028,23 @@ macro_rules! generate_html_tags {
           }
         }
 
+        impl [<$tag:camel $($trailing_)?>] {
+          /// Static metadata for this tag.
+          pub const METADATA: TagMetadata = TagMetadata::html(
+            stringify!($tag),
+            generate_html_tags! { @void $($void)? },
+          );
+        }
+
         impl ElementDescriptor for [<$tag:camel $($trailing_)?>] {
           #[inline(always)]
           fn name(&self) -> Cow<'static, str> {
-            stringify!($tag).into()
+            Cow::Borrowed(Self::METADATA.name)
+          }
+
+          #[inline(always)]
+          fn metadata(&self) -> Option<&'static TagMetadata> {
+            Some(&Self::METADATA)
           }
 
           #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -1219,8 +2052,6 @@ macro_rules! generate_html_tags {
           fn hydration_id(&self) -> &HydrationKey {
             &self.id
           }
-
-          generate_html_tags! { @void $($void)? }
         }
 
         #[$meta]
@@ -1241,13 +2072,8 @@ macro_rules! generate_html_tags {
       )*
     }
   };
-  (@void) => {};
-  (@void void) => {
-    #[inline(always)]
-    fn is_void(&self) -> bool {
-      true
-    }
-  }
+  (@void) => { false };
+  (@void void) => { true };
 }
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -1262,43 +2088,26 @@ fn create_leptos_element(
     }
 
     if HydrationCtx::is_hydrating() {
-        if let Some(el) = crate::document().get_element_by_id(&format!("_{id}"))
-        {
+        if let Some(el) = crate::hydration::take_element(&format!("_{id}")) {
             #[cfg(debug_assertions)]
             assert_eq!(
                 &el.node_name().to_ascii_uppercase(),
                 tag,
                 "SSR and CSR elements have the same `TopoId` but different \
-                 node kinds. This is either a discrepancy between SSR and CSR \
-                 rendering
+                 node kinds{}. This is either a discrepancy between SSR and \
+                 CSR rendering
             logic, which is considered a bug, or it can also be a leptos \
-                 hydration issue."
+                 hydration issue.",
+                crate::source_location::describe_next()
             );
 
-            el.remove_attribute("id").unwrap();
-
-            el.unchecked_into()
-        } else if let Ok(Some(el)) =
-            crate::document().query_selector(&format!("[leptos-hk=_{id}]"))
-        {
-            #[cfg(debug_assertions)]
-            assert_eq!(
-                el.node_name().to_ascii_uppercase(),
-                tag,
-                "SSR and CSR elements have the same `TopoId` but different \
-                 node kinds. This is either a discrepancy between SSR and CSR \
-                 rendering
-            logic, which is considered a bug, or it can also be a leptos \
-                 hydration issue."
-            );
-
-            el.remove_attribute("leptos-hk").unwrap();
-
             el.unchecked_into()
         } else {
             if !is_meta_tag() {
                 crate::warn!(
-                    "element with id {id} not found, ignoring it for hydration"
+                    "element with id {id} not found{}, ignoring it for \
+                     hydration",
+                    crate::source_location::describe_next()
                 );
             }
 
@@ -1309,6 +2118,46 @@ fn create_leptos_element(
     }
 }
 
+/// Deep-clones the content of the `<template>` for the given static HTML,
+/// creating the template the first time the HTML is used.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn clone_static_html(html: &'static str) -> web_sys::Node {
+    use std::{cell::RefCell, collections::HashMap};
+    use web_sys::HtmlTemplateElement;
+
+    thread_local! {
+        // keyed by address, as each string comes from a `view` macro call
+        static TEMPLATES: RefCell<HashMap<(usize, usize), HtmlTemplateElement>> =
+            Default::default();
+    }
+
+    TEMPLATES.with(|templates| {
+        let mut templates = templates.borrow_mut();
+        let template = templates
+            .entry((html.as_ptr() as usize, html.len()))
+            .or_insert_with(|| {
+                let template: HtmlTemplateElement = crate::document()
+                    .create_element("template")
+                    .unwrap()
+                    .unchecked_into();
+                template.set_inner_html(html);
+                template
+            });
+        template.content().clone_node_with_deep(true).unwrap()
+    })
+}
+
+/// Pushes a child view, merging it into the previous child if both are
+/// static text, so that each run of text is rendered as a single node.
+fn push_merging_text(children: &mut Vec<View>, child: View) {
+    match (children.last_mut(), child) {
+        (Some(View::Text(last)), View::Text(text)) => {
+            last.push_str(&text.content)
+        }
+        (_, child) => children.push(child),
+    }
+}
+
 #[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
 fn warn_on_ambiguous_a(parent: &web_sys::Element, child: &View) {
     if let View::Element(el) = &child {
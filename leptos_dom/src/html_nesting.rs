@@ -0,0 +1,508 @@
+//! HTML nesting checks, run by the server-side renderer in debug builds.
+//!
+//! Some element nestings can't be represented in parsed HTML: a `<div>`
+//! inside a `<p>` closes the paragraph, and a `<tr>` directly inside a
+//! `<table>` gets wrapped in a `<tbody>`. The browser silently re-parents
+//! these nodes when it parses the server's HTML, so the DOM no longer
+//! matches the view, and hydration breaks in confusing ways. While
+//! rendering, the stack of open elements is tracked, and each element is
+//! checked against its parent and against the ancestors that the browser
+//! would close early, like the `<p>` in `<p><span><div>`. Each invalid
+//! nesting is logged as a warning, along with the component it was rendered
+//! in.
+
+use crate::SourceLocation;
+use std::{borrow::Cow, cell::RefCell, collections::HashSet};
+
+enum Frame {
+    Element(String),
+    Component(Cow<'static, str>, Option<SourceLocation>),
+}
+
+thread_local! {
+    static STACK: RefCell<Vec<Frame>> = Default::default();
+    static REPORTED: RefCell<HashSet<String>> = Default::default();
+}
+
+/// Elements whose content is inserted as-is, without being parsed as HTML.
+const RAW_TEXT: &[&str] = &["script", "style", "textarea", "title"];
+
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "param", "source", "track", "wbr",
+];
+
+/// Elements that close an open `<p>` when the parser reaches them.
+const CLOSES_P: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Interactive elements, which can't be nested inside a `<button>` or `<a>`.
+const INTERACTIVE: &[&str] = &[
+    "a", "button", "details", "embed", "iframe", "input", "label", "select",
+    "textarea",
+];
+
+/// Starts a separate nesting context for one render, restoring the outer
+/// one when dropped, so that views rendered to a string while another view
+/// is being rendered aren't checked against its elements.
+pub(crate) struct Isolated(Vec<Frame>);
+
+impl Isolated {
+    pub(crate) fn new() -> Self {
+        Self(STACK.with(|stack| std::mem::take(&mut *stack.borrow_mut())))
+    }
+}
+
+impl Drop for Isolated {
+    fn drop(&mut self) {
+        let outer = std::mem::take(&mut self.0);
+        STACK.with(|stack| *stack.borrow_mut() = outer);
+    }
+}
+
+pub(crate) fn enter_component(
+    name: &Cow<'static, str>,
+    location: Option<SourceLocation>,
+) {
+    STACK.with(|stack| {
+        stack
+            .borrow_mut()
+            .push(Frame::Component(name.clone(), location))
+    });
+}
+
+pub(crate) fn leave_component() {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        if let Some(index) = stack
+            .iter()
+            .rposition(|frame| matches!(frame, Frame::Component(..)))
+        {
+            stack.truncate(index);
+        }
+    });
+}
+
+/// Checks an element against its parent and the other open elements, then
+/// makes it the parent of the nodes that follow, until it is closed.
+pub(crate) fn open_element(tag: &str) {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let ancestors = ancestors(&stack);
+        if let Some(&parent) = ancestors.last() {
+            if let Some(problem) = invalid_child(parent, tag) {
+                report(&stack, parent, &format!("<{tag}>"), problem);
+            } else if let Some(ancestor) = closed_ancestor(&ancestors, tag) {
+                report(
+                    &stack,
+                    ancestor,
+                    &format!("<{tag}>"),
+                    &format!("the browser closes the <{ancestor}> before it"),
+                );
+            }
+        }
+        if !VOID.contains(&tag) {
+            stack.push(Frame::Element(tag.to_string()));
+        }
+    });
+}
+
+/// Closes the innermost open element with this tag.
+pub(crate) fn close_element(tag: &str) {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        if let Some(index) = stack.iter().rposition(
+            |frame| matches!(frame, Frame::Element(open) if open == tag),
+        ) {
+            stack.truncate(index);
+        }
+    });
+}
+
+/// Checks a text node against its parent.
+pub(crate) fn text(text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    STACK.with(|stack| {
+        let stack = stack.borrow();
+        if let Some(parent) = parent(&stack) {
+            if is_table_structure(parent) {
+                report(
+                    &stack,
+                    parent,
+                    "text",
+                    "the browser moves it out of the table",
+                );
+            }
+        }
+    });
+}
+
+/// Checks a chunk of static HTML, as generated by the `view!` macro for the
+/// server, keeping track of the elements it opens and closes.
+pub(crate) fn scan(html: &str) {
+    let mut rest = html;
+    while !rest.is_empty() {
+        // the content of a raw text element is only ended by its closing tag
+        let raw_text = STACK.with(|stack| match parent(&stack.borrow()) {
+            Some(parent) if RAW_TEXT.contains(&parent) => {
+                Some(format!("</{parent}"))
+            }
+            _ => None,
+        });
+        if let Some(closing) = raw_text {
+            match rest.find(&closing) {
+                Some(end) => rest = &rest[end..],
+                None => return,
+            }
+        }
+
+        let Some(start) = rest.find('<') else {
+            text(rest);
+            return;
+        };
+        text(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map(|end| &comment[end + 3..])
+                .unwrap_or_default();
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let end = closing.find('>').unwrap_or(closing.len());
+            close_element(closing[..end].trim());
+            rest = closing.get(end + 1..).unwrap_or_default();
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let name_end = rest[1..]
+                .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .map(|end| end + 1)
+                .unwrap_or(rest.len());
+            let tag = &rest[1..name_end];
+            let tag_end = tag_end(&rest[name_end..]).map(|end| name_end + end);
+            let self_closing = tag_end
+                .map(|end| rest[..end].ends_with('/'))
+                .unwrap_or(false);
+            open_element(tag);
+            if self_closing {
+                close_element(tag);
+            }
+            rest = tag_end
+                .and_then(|end| rest.get(end + 1..))
+                .unwrap_or_default();
+        } else {
+            text("<");
+            rest = &rest[1..];
+        }
+    }
+}
+
+/// Finds the `>` that ends a start tag, skipping over quoted attributes.
+fn tag_end(attrs: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in attrs.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if open == c => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The tags of the open elements, from the outermost.
+fn ancestors(stack: &[Frame]) -> Vec<&str> {
+    stack
+        .iter()
+        .filter_map(|frame| match frame {
+            Frame::Element(tag) => Some(tag.as_str()),
+            Frame::Component(..) => None,
+        })
+        .collect()
+}
+
+fn parent(stack: &[Frame]) -> Option<&str> {
+    stack.iter().rev().find_map(|frame| match frame {
+        Frame::Element(tag) => Some(tag.as_str()),
+        Frame::Component(..) => None,
+    })
+}
+
+fn is_table_structure(tag: &str) -> bool {
+    matches!(
+        tag,
+        "table" | "thead" | "tbody" | "tfoot" | "tr" | "colgroup"
+    )
+}
+
+/// Describes why `child` can't be nested directly inside `parent`, if it
+/// can't.
+fn invalid_child(parent: &str, child: &str) -> Option<&'static str> {
+    // these can be used anywhere
+    if matches!(child, "script" | "style" | "template") {
+        return None;
+    }
+    match (parent, child) {
+        ("p", _) if CLOSES_P.contains(&child) => {
+            Some("the browser closes the <p> before it")
+        }
+        ("button" | "a", _) if INTERACTIVE.contains(&child) => {
+            Some("interactive content can't be nested")
+        }
+        ("button", _) if CLOSES_P.contains(&child) => {
+            Some("a <button> can only contain phrasing content")
+        }
+        ("form", "form") => Some("the browser ignores the nested <form>"),
+        ("li", "li")
+        | ("dt" | "dd", "dt" | "dd")
+        | ("option", "option" | "optgroup")
+        | ("optgroup", "optgroup") => {
+            Some("the browser closes the parent before it")
+        }
+        ("table", "caption" | "colgroup" | "thead" | "tbody" | "tfoot")
+        | ("thead" | "tbody" | "tfoot", "tr")
+        | ("tr", "td" | "th")
+        | ("colgroup", "col") => None,
+        ("table", "tr") => {
+            Some("the browser wraps it in a <tbody>, so add one yourself")
+        }
+        ("table", _) => Some("a <table> can only contain table sections"),
+        ("thead" | "tbody" | "tfoot", _) => {
+            Some("table sections can only contain <tr>s")
+        }
+        ("tr", _) => Some("a <tr> can only contain <td>s and <th>s"),
+        ("colgroup", _) => Some("a <colgroup> can only contain <col>s"),
+        (_, "tr") => {
+            Some("a <tr> must be inside a <thead>, <tbody> or <tfoot>")
+        }
+        (_, "td" | "th") => Some("table cells must be inside a <tr>"),
+        (_, "thead" | "tbody" | "tfoot" | "caption" | "colgroup") => {
+            Some("table sections must be inside a <table>")
+        }
+        (_, "col") => Some("a <col> must be inside a <colgroup>"),
+        _ => None,
+    }
+}
+
+/// Finds an element above the parent of `child` that the browser closes when
+/// it reaches `child`, like the `<p>` in `<p><span><div>` or the outer `<li>`
+/// in `<li><div><li>`. The parent itself is checked by [invalid_child].
+fn closed_ancestor<'a>(ancestors: &[&'a str], child: &str) -> Option<&'a str> {
+    let closes = |open: &str| {
+        (open == "p" && CLOSES_P.contains(&child))
+            || match child {
+                "li" => open == "li",
+                "dd" | "dt" => matches!(open, "dd" | "dt"),
+                "a" | "button" | "form" => open == child,
+                _ => false,
+            }
+    };
+    // the elements that stop the browser from looking further out
+    let is_boundary = |open: &str| match child {
+        // a nested form is ignored, however deep it is
+        "form" => open == "template",
+        _ => {
+            matches!(
+                open,
+                "applet"
+                    | "caption"
+                    | "marquee"
+                    | "object"
+                    | "table"
+                    | "td"
+                    | "template"
+                    | "th"
+            ) || match child {
+                "li" => matches!(open, "ol" | "ul" | "menu"),
+                "dd" | "dt" => open == "dl",
+                "a" | "button" => false,
+                _ => open == "button",
+            }
+        }
+    };
+
+    for (depth, &open) in ancestors.iter().rev().enumerate() {
+        if depth > 0 && closes(open) {
+            return Some(open);
+        }
+        if is_boundary(open) {
+            break;
+        }
+    }
+    None
+}
+
+fn report(stack: &[Frame], parent: &str, child: &str, problem: &str) {
+    let component = stack.iter().rev().find_map(|frame| match frame {
+        Frame::Component(name, location) => Some((name, location)),
+        Frame::Element(_) => None,
+    });
+    let location = match component {
+        Some((name, Some(location))) => {
+            format!(" (in <{name}/>, used at {location})")
+        }
+        Some((name, None)) => format!(" (in <{name}/>)"),
+        None => String::new(),
+    };
+
+    let message = format!(
+        "{child} inside <{parent}>{location}: {problem}. The browser will \
+         change the structure of the server-rendered HTML, so it won't \
+         hydrate correctly."
+    );
+    let is_new =
+        REPORTED.with(|reported| reported.borrow_mut().insert(message.clone()));
+    if is_new {
+        crate::warn!("[html] {message}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scans `html` in a fresh nesting context, returning the problems it
+    /// reports and the elements left open.
+    fn scan_html(html: &str) -> (Vec<String>, Vec<String>) {
+        let _isolated = Isolated::new();
+        REPORTED.with(|reported| reported.borrow_mut().clear());
+        scan(html);
+        let reported = REPORTED.with(|reported| {
+            let mut reported =
+                reported.borrow().iter().cloned().collect::<Vec<_>>();
+            reported.sort();
+            reported
+        });
+        let open = STACK.with(|stack| {
+            ancestors(&stack.borrow())
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+        });
+        (reported, open)
+    }
+
+    #[test]
+    fn invalid_children_are_described() {
+        assert!(invalid_child("p", "div").is_some());
+        assert!(invalid_child("p", "span").is_none());
+        assert!(invalid_child("a", "button").is_some());
+        assert!(invalid_child("li", "li").is_some());
+        assert!(invalid_child("ul", "li").is_none());
+        assert!(invalid_child("table", "tr").is_some());
+        assert!(invalid_child("tbody", "tr").is_none());
+        assert!(invalid_child("tr", "td").is_none());
+        assert!(invalid_child("div", "td").is_some());
+        // these can go anywhere
+        assert!(invalid_child("table", "script").is_none());
+        assert!(invalid_child("tr", "template").is_none());
+    }
+
+    #[test]
+    fn closed_ancestors_are_found_above_the_parent() {
+        assert_eq!(closed_ancestor(&["p", "span"], "div"), Some("p"));
+        assert_eq!(closed_ancestor(&["p", "em", "span"], "ul"), Some("p"));
+        assert_eq!(closed_ancestor(&["a", "span"], "a"), Some("a"));
+        assert_eq!(closed_ancestor(&["li", "div"], "li"), Some("li"));
+        assert_eq!(closed_ancestor(&["dt", "span"], "dd"), Some("dt"));
+        assert_eq!(
+            closed_ancestor(&["form", "table", "tbody", "tr", "td"], "form"),
+            Some("form")
+        );
+
+        // the parent is left to `invalid_child`
+        assert_eq!(closed_ancestor(&["p"], "div"), None);
+        // and some elements start a new scope
+        assert_eq!(closed_ancestor(&["ul", "li", "ul"], "li"), None);
+        assert_eq!(closed_ancestor(&["p", "button", "span"], "div"), None);
+        assert_eq!(
+            closed_ancestor(&["a", "table", "tbody", "tr", "td"], "a"),
+            None
+        );
+        assert_eq!(closed_ancestor(&["div", "span"], "div"), None);
+    }
+
+    #[test]
+    fn tag_end_skips_quoted_attributes() {
+        assert_eq!(tag_end(">"), Some(0));
+        assert_eq!(tag_end(r#" title="a > b">"#), Some(14));
+        assert_eq!(tag_end(r#" title='"x>'>"#), Some(12));
+        assert_eq!(tag_end(r#" title="unterminated>"#), None);
+    }
+
+    #[test]
+    fn scan_tracks_the_open_elements() {
+        let (reported, open) = scan_html("<div><p class=\"a>b\">text");
+        assert!(reported.is_empty());
+        assert_eq!(open, ["div", "p"]);
+
+        // void and self-closing elements aren't left open
+        let (_, open) = scan_html("<div><img src=\"a.png\"><br/><my-el/>");
+        assert_eq!(open, ["div"]);
+
+        let (reported, open) =
+            scan_html("<ul><li><ul><li>a</li></ul></li></ul><!-- <div> -->");
+        assert!(reported.is_empty(), "{reported:?}");
+        assert!(open.is_empty());
+    }
+
+    #[test]
+    fn scan_reports_nestings_the_browser_changes() {
+        let (reported, _) = scan_html("<p><span><div>x</div></span></p>");
+        assert_eq!(reported.len(), 1);
+        assert!(reported[0].starts_with("<div> inside <p>"), "{reported:?}");
+
+        let (reported, _) =
+            scan_html("<a href=\"/\"><span><a>x</a></span></a>");
+        assert_eq!(reported.len(), 1);
+        assert!(reported[0].starts_with("<a> inside <a>"), "{reported:?}");
+
+        let (reported, _) = scan_html("<table><tr><td>x</td></tr></table>");
+        assert_eq!(reported.len(), 1);
+        assert!(reported[0].starts_with("<tr> inside <table>"));
+
+        let (reported, _) = scan_html("<table>text</table>");
+        assert!(reported[0].starts_with("text inside <table>"));
+    }
+
+    #[test]
+    fn raw_text_is_not_scanned() {
+        let (reported, open) =
+            scan_html("<table><tbody><script>if (a < b) { x = \"<div>\"; }</script></tbody></table>");
+        assert!(reported.is_empty(), "{reported:?}");
+        assert!(open.is_empty());
+    }
+}
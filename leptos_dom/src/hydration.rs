@@ -7,61 +7,191 @@ cfg_if! {
     use std::collections::HashMap;
     use wasm_bindgen::JsCast;
 
-    // We can tell if we start in hydration mode by checking to see if the
-    // id "_0-0-0" is present in the DOM. If it is, we know we are hydrating from
-    // the server, if not, we are starting off in CSR
-    thread_local! {
-      static HYDRATION_COMMENTS: LazyCell<HashMap<String, web_sys::Comment>> = LazyCell::new(|| {
-        let document = crate::document();
-        let body = document.body().unwrap();
-        let walker = document
-          .create_tree_walker_with_what_to_show(&body, 128)
+    /// Every node the server rendered with a hydration key, collected with a
+    /// single [`web_sys::TreeWalker`] pass over the document, rather than
+    /// querying the DOM once per hydrated node.
+    #[derive(Default)]
+    struct HydrationNodes {
+      elements: HashMap<String, web_sys::Element>,
+      comments: HashMap<String, web_sys::Comment>,
+      #[cfg(debug_assertions)]
+      view_markers: HashMap<String, web_sys::Comment>,
+    }
+
+    impl HydrationNodes {
+      // `NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_COMMENT`
+      const WHAT_TO_SHOW: u32 = 0x1 | 0x80;
+
+      fn collect() -> Self {
+        let mut nodes = Self::default();
+        nodes.collect_from(&crate::document().body().unwrap());
+        nodes
+      }
+
+      fn collect_from(&mut self, root: &web_sys::Node) {
+        // the `id`s that aren't hydration keys, to check they're unique
+        #[cfg(debug_assertions)]
+        let mut ids = HashMap::<String, usize>::new();
+
+        let walker = crate::document()
+          .create_tree_walker_with_what_to_show(root, Self::WHAT_TO_SHOW)
           .unwrap();
-        let mut map = HashMap::new();
         while let Ok(Some(node)) = walker.next_node() {
-          if let Some(content) = node.text_content() {
+          if node.node_type() == web_sys::Node::ELEMENT_NODE {
+            let el = node.unchecked_into::<web_sys::Element>();
+            let hk = match el.get_attribute("id") {
+              Some(id) if id.starts_with('_') => Some(id),
+              #[cfg(debug_assertions)]
+              Some(id) => {
+                *ids.entry(id).or_default() += 1;
+                el.get_attribute("leptos-hk")
+              }
+              _ => el.get_attribute("leptos-hk"),
+            };
+            if let Some(hk) = hk {
+              #[cfg(debug_assertions)]
+              check_element_key(&hk, &el, self.elements.get(&hk));
+              self.elements.insert(hk, el);
+            }
+          } else if let Some(content) = node.text_content() {
             if let Some(hk) = content.strip_prefix("hk=") {
               if let Some(hk) = hk.split('|').next() {
-                map.insert(hk.into(), node.unchecked_into());
+                #[cfg(debug_assertions)]
+                if self.comments.contains_key(hk) {
+                  crate::warn!(
+                    "[hydration] the marker <!--hk={hk}--> appears more \
+                     than once in the server-rendered HTML, so the nodes \
+                     around it may be hydrated in the wrong place. This \
+                     usually means that two parts of the page were rendered \
+                     separately and then combined, or that the HTML was \
+                     cached and mixed with a newer render."
+                  );
+                }
+                self.comments.insert(hk.into(), node.unchecked_into());
+              }
+            } else {
+              #[cfg(debug_assertions)]
+              if let Some(id) = content.strip_prefix("leptos-view|") {
+                self.view_markers.insert(id.into(), node.unchecked_into());
               }
             }
           }
         }
-        map
-      });
 
-      #[cfg(debug_assertions)]
-      pub(crate) static VIEW_MARKERS: LazyCell<HashMap<String, web_sys::Comment>> = LazyCell::new(|| {
-        let document = crate::document();
-        let body = document.body().unwrap();
-        let walker = document
-          .create_tree_walker_with_what_to_show(&body, 128)
-          .unwrap();
-        let mut map = HashMap::new();
-        while let Ok(Some(node)) = walker.next_node() {
-          if let Some(content) = node.text_content() {
-            if let Some(id) = content.strip_prefix("leptos-view|") {
-              map.insert(id.into(), node.unchecked_into());
-            }
+        #[cfg(debug_assertions)]
+        for (id, count) in ids {
+          if count > 1 {
+            crate::warn!(
+              "[hydration] {count} elements in the server-rendered HTML \
+               have the id \"{id}\". IDs must be unique, or the browser \
+               and scripts will find the wrong element."
+            );
           }
         }
-        map
-      });
+      }
+    }
+
+    /// Warns about an element whose hydration key is malformed or was
+    /// already used by another element, before hydration begins.
+    #[cfg(debug_assertions)]
+    fn check_element_key(
+      hk: &str,
+      el: &web_sys::Element,
+      existing: Option<&web_sys::Element>,
+    ) {
+      let tag = el.tag_name().to_lowercase();
+      if !is_element_key(hk) {
+        crate::warn!(
+          "[hydration] <{tag} id=\"{hk}\"> has an id starting with `_`, \
+           which is reserved for hydration keys. Rename it, or it will be \
+           mistaken for the server-rendered element with that key."
+        );
+      } else if let Some(existing) = existing {
+        crate::warn!(
+          "[hydration] the hydration key {hk} is used by both <{}> and \
+           <{tag}> in the server-rendered HTML, so only one of them will be \
+           hydrated. Make sure no element has an `id` that looks like a \
+           hydration key, and that the HTML comes from a single render.",
+          existing.tag_name().to_lowercase()
+        );
+      }
+    }
+
+    /// Whether `hk` has the form of an element's hydration key,
+    /// `_{fragment}-{id}`.
+    #[cfg(debug_assertions)]
+    fn is_element_key(hk: &str) -> bool {
+      let is_number =
+        |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+      hk.strip_prefix('_')
+        .and_then(|hk| hk.split_once('-'))
+        .map(|(fragment, id)| is_number(fragment) && is_number(id))
+        .unwrap_or(false)
+    }
+
+    // We can tell if we start in hydration mode by checking to see if the
+    // id "_0-0-0" is present in the DOM. If it is, we know we are hydrating from
+    // the server, if not, we are starting off in CSR
+    thread_local! {
+      static HYDRATION_NODES: LazyCell<RefCell<HydrationNodes>> =
+        LazyCell::new(|| RefCell::new(HydrationNodes::collect()));
 
       static IS_HYDRATING: RefCell<LazyCell<bool>> = RefCell::new(LazyCell::new(|| {
         #[cfg(debug_assertions)]
         return crate::document().get_element_by_id("_0-1").is_some()
           || crate::document().get_element_by_id("_0-1o").is_some()
-          || HYDRATION_COMMENTS.with(|comments| comments.get("_0-1o").is_some());
+          || get_marker("_0-1o").is_some();
 
         #[cfg(not(debug_assertions))]
         return crate::document().get_element_by_id("_0-1").is_some()
-          || HYDRATION_COMMENTS.with(|comments| comments.get("_0-1").is_some());
+          || get_marker("_0-1").is_some();
       }));
     }
 
+    /// Collects the server-rendered nodes inside a declarative shadow root,
+    /// which the walk over the document does not reach.
+    pub(crate) fn collect_shadow_root(root: &web_sys::ShadowRoot) {
+      HYDRATION_NODES
+        .with(|nodes| nodes.borrow_mut().collect_from(root.unchecked_ref()));
+    }
+
     pub(crate) fn get_marker(id: &str) -> Option<web_sys::Comment> {
-      HYDRATION_COMMENTS.with(|comments| comments.get(id).cloned())
+      HYDRATION_NODES.with(|nodes| nodes.borrow().comments.get(id).cloned())
+    }
+
+    #[cfg(debug_assertions)]
+    #[allow(dead_code)]
+    pub(crate) fn get_view_marker(id: &str) -> Option<web_sys::Comment> {
+      HYDRATION_NODES
+        .with(|nodes| nodes.borrow().view_markers.get(id).cloned())
+    }
+
+    /// Takes the server-rendered element with the given hydration id,
+    /// removing the attribute that was used to find it.
+    ///
+    /// Elements that were streamed in after the initial walk, or that live
+    /// outside the `<body>`, are looked up in the document directly, as are
+    /// elements found by the walk that have since been removed from the
+    /// document, e.g., a `<Suspense/>` fallback replaced by streamed HTML.
+    pub(crate) fn take_element(id: &str) -> Option<web_sys::Element> {
+      let el = HYDRATION_NODES
+        .with(|nodes| nodes.borrow_mut().elements.remove(id))
+        .filter(|el| el.is_connected())
+        .or_else(|| crate::document().get_element_by_id(id))
+        .or_else(|| {
+          crate::document()
+            .query_selector(&format!("[leptos-hk={id}]"))
+            .ok()
+            .flatten()
+        })?;
+
+      if el.get_attribute("id").as_deref() == Some(id) {
+        el.remove_attribute("id").unwrap();
+      } else {
+        el.remove_attribute("leptos-hk").unwrap();
+      }
+
+      Some(el)
     }
   }
 }
@@ -83,6 +213,10 @@ impl Display for HydrationKey {
 
 thread_local!(static ID: RefCell<HydrationKey> = Default::default());
 
+// the fragment that hydration keys start from, set by `with_seeded_ids`
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+thread_local!(static SEED: std::cell::Cell<Option<usize>> = Default::default());
+
 /// Control and utility methods for hydration.
 pub struct HydrationCtx;
 
@@ -114,7 +248,39 @@ impl HydrationCtx {
     #[doc(hidden)]
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub fn reset_id() {
-        ID.with(|id| *id.borrow_mut() = Default::default());
+        let fragment = SEED.with(|seed| seed.get()).unwrap_or_default();
+        ID.with(|id| *id.borrow_mut() = HydrationKey { id: 0, fragment });
+    }
+
+    /// Runs `f` with hydration keys starting from the fragment `seed`, both
+    /// now and at the start of every render inside it, rather than from
+    /// zero. Used by [`with_seeded_ids`](crate::ssr::with_seeded_ids).
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub(crate) fn with_seed<T>(seed: usize, f: impl FnOnce() -> T) -> T {
+        // restores the previous seed and key even if `f` panics
+        struct Restore(Option<usize>, HydrationKey);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SEED.with(|s| s.set(self.0));
+                HydrationCtx::continue_from(self.1);
+            }
+        }
+
+        let _restore =
+            Restore(SEED.with(|s| s.replace(Some(seed))), Self::peek());
+        Self::reset_id();
+        f()
+    }
+
+    /// Generates a name for an element that a component creates, like a
+    /// class name or an `id`, from `prefix` and the current hydration key.
+    ///
+    /// As it is derived from the hydration key, the name is the same on the
+    /// server and in the browser, and starts from the seed inside
+    /// [`with_seeded_ids`](crate::ssr::with_seeded_ids).
+    pub fn generated_name(prefix: &str) -> String {
+        format!("{prefix}-{}", Self::peek())
     }
 
     /// Resumes hydration from the provided `id`. Useful for
@@ -130,6 +296,21 @@ impl HydrationCtx {
         })
     }
 
+    /// Runs `f` with hydration paused, so that any nodes it creates are
+    /// rendered fresh rather than taken from the server-rendered HTML.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn without_hydrating<T>(f: impl FnOnce() -> T) -> T {
+        let was_hydrating = Self::is_hydrating();
+        Self::stop_hydrating();
+        let value = f();
+        if was_hydrating {
+            IS_HYDRATING.with(|is_hydrating| {
+                *is_hydrating.borrow_mut() = LazyCell::new(|| true)
+            });
+        }
+        value
+    }
+
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     pub(crate) fn is_hydrating() -> bool {
         IS_HYDRATING.with(|is_hydrating| **is_hydrating.borrow())
@@ -0,0 +1,45 @@
+use std::{borrow::Cow, cell::RefCell, collections::HashSet};
+
+/// The most names that will be interned. Names are never freed once they are
+/// interned, so this bounds the memory used if names are generated
+/// dynamically.
+const MAX_INTERNED_NAMES: usize = 1024;
+
+thread_local! {
+    static NAMES: RefCell<HashSet<&'static str>> = Default::default();
+}
+
+/// Returns a copy of an attribute, class, property, or event name that shares
+/// its storage with every other copy of the same name.
+///
+/// Names written as string literals are already `'static` and are returned as-is.
+/// Names built at runtime (like `"data-" + key`, or the individual class names
+/// split out of [`HtmlElement::classes`](crate::HtmlElement::classes)) would
+/// otherwise be allocated separately for every element they're used on.
+///
+/// Interned names are never freed, so this should only be used for names, which
+/// come from a small set, and not for values. After a fixed number of distinct
+/// names has been interned, new names are returned unchanged.
+/// ```
+/// # use leptos_dom::intern_name;
+/// let a = intern_name(format!("data-{}", "id").into());
+/// let b = intern_name("data-id".to_string().into());
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// ```
+pub fn intern_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    match name {
+        Cow::Borrowed(_) => name,
+        Cow::Owned(name) => NAMES.with(|names| {
+            let mut names = names.borrow_mut();
+            if let Some(interned) = names.get(name.as_str()) {
+                Cow::Borrowed(*interned)
+            } else if names.len() < MAX_INTERNED_NAMES {
+                let interned: &'static str = Box::leak(name.into_boxed_str());
+                names.insert(interned);
+                Cow::Borrowed(interned)
+            } else {
+                Cow::Owned(name)
+            }
+        }),
+    }
+}
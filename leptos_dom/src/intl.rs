@@ -0,0 +1,726 @@
+//! Formatting numbers and dates for a locale, with
+//! [`Intl.NumberFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat)
+//! and
+//! [`Intl.DateTimeFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat).
+//!
+//! The locale comes from [`provide_intl_locale`], so that everything is
+//! formatted again when it changes; a `<Router/>` with `locales` provides
+//! its active locale. In the browser, the `Intl` formatters are created once
+//! for each locale and set of options, and then reused. On the server, a
+//! small pure-Rust formatter is used instead, which knows the separators of
+//! common locales but otherwise formats like `en`: dates are formatted as
+//! `2023-06-01` and times as `14:05`, in UTC, and relative times as, e.g.,
+//! `3 days ago`. While the app is being hydrated, the formatters from
+//! [`use_number_format`] and [`use_date_time_format`] format like the server
+//! too, so the text matches the server-rendered HTML, and switch to `Intl`
+//! once hydration is done.
+
+use leptos_reactive::{
+    create_rw_signal, provide_context, use_context, Scope, Signal, SignalGet,
+    SignalSet,
+};
+
+/// The locale used by [`use_number_format`] and [`use_date_time_format`].
+#[derive(Copy, Clone, Debug)]
+struct IntlLocale(Signal<String>);
+
+/// Provides the locale, e.g., `"de-AT"`, that numbers and dates are
+/// formatted for in this [`Scope`] and its descendants.
+pub fn provide_intl_locale(cx: Scope, locale: impl Into<Signal<String>>) {
+    provide_context(cx, IntlLocale(locale.into()));
+}
+
+/// Returns the locale from the nearest [`provide_intl_locale`].
+///
+/// If there is none, this is the browser’s preferred language, or `"en"` on
+/// the server and while the app is being hydrated.
+pub fn use_intl_locale(cx: Scope) -> Signal<String> {
+    match use_context::<IntlLocale>(cx) {
+        Some(locale) => locale.0,
+        None => {
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            let locale = crate::window()
+                .navigator()
+                .language()
+                .unwrap_or_else(|| "en".to_string());
+            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+            let locale = "en".to_string();
+            let hydrated = use_intl_hydrated(cx);
+            Signal::derive(cx, move || {
+                if hydrated.get() {
+                    locale.clone()
+                } else {
+                    "en".to_string()
+                }
+            })
+        }
+    }
+}
+
+/// Returns `false` while the app is being hydrated, and `true` once it’s
+/// done, or if it isn’t being hydrated at all.
+///
+/// Until then, text should be formatted the same way as on the server, so
+/// that it matches the server-rendered HTML; the formatters from
+/// [`use_number_format`] and [`use_date_time_format`] already do this.
+pub fn use_intl_hydrated(cx: Scope) -> Signal<bool> {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    let hydrating = crate::HydrationCtx::is_hydrating();
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    let hydrating = false;
+
+    hydration_switch(cx, hydrating)
+}
+
+/// Returns a signal that is `true` if the app isn't `hydrating`, or turns
+/// `true` once it's done.
+fn hydration_switch(cx: Scope, hydrating: bool) -> Signal<bool> {
+    let hydrated = create_rw_signal(cx, !hydrating);
+    if hydrating {
+        // the app has been hydrated once it's mounted, synchronously
+        leptos_reactive::queue_microtask(move || hydrated.set(true));
+    }
+    hydrated.into()
+}
+
+/// How a number is formatted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumberStyle {
+    /// A plain number, e.g., `1,234.5`.
+    #[default]
+    Decimal,
+    /// A fraction as a percentage, e.g., `0.25` as `25%`.
+    Percent,
+    /// An amount of money in a currency, given as its ISO 4217 code, e.g.,
+    /// `"EUR"`.
+    Currency(&'static str),
+}
+
+/// Options for [`use_number_format`] and [`format_number`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormatOptions {
+    /// How the number is formatted. Defaults to [`NumberStyle::Decimal`].
+    pub style: NumberStyle,
+    /// The fewest digits to show after the decimal separator. Defaults to
+    /// `0`, or `2` for currencies.
+    pub minimum_fraction_digits: Option<u8>,
+    /// The most digits to show after the decimal separator. Defaults to `3`,
+    /// `0` for percentages, or `2` for currencies.
+    pub maximum_fraction_digits: Option<u8>,
+    /// Whether to separate groups of thousands. Defaults to `true`.
+    pub use_grouping: bool,
+}
+
+impl Default for NumberFormatOptions {
+    fn default() -> Self {
+        Self {
+            style: NumberStyle::default(),
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+            use_grouping: true,
+        }
+    }
+}
+
+impl NumberFormatOptions {
+    fn fraction_digits(&self) -> (usize, usize) {
+        let (min, max) = match self.style {
+            NumberStyle::Decimal => (0, 3),
+            NumberStyle::Percent => (0, 0),
+            NumberStyle::Currency(_) => (2, 2),
+        };
+        let min = self.minimum_fraction_digits.map_or(min, usize::from);
+        let max = self.maximum_fraction_digits.map_or(max, usize::from);
+        (min, max.max(min))
+    }
+}
+
+/// How much detail to show for a date or time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateTimeStyle {
+    /// E.g., `Thursday, June 1, 2023`.
+    Full,
+    /// E.g., `June 1, 2023`.
+    Long,
+    /// E.g., `Jun 1, 2023`.
+    Medium,
+    /// E.g., `6/1/23`.
+    Short,
+}
+
+impl DateTimeStyle {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn as_str(self) -> &'static str {
+        match self {
+            DateTimeStyle::Full => "full",
+            DateTimeStyle::Long => "long",
+            DateTimeStyle::Medium => "medium",
+            DateTimeStyle::Short => "short",
+        }
+    }
+}
+
+/// Options for [`use_date_time_format`] and [`format_date_time`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeFormatOptions {
+    /// How to format the date, or `None` to leave it out. Defaults to
+    /// [`DateTimeStyle::Medium`].
+    pub date_style: Option<DateTimeStyle>,
+    /// How to format the time, or `None` to leave it out. Defaults to
+    /// `None`.
+    pub time_style: Option<DateTimeStyle>,
+    /// The IANA time zone to show the time in, e.g., `"Europe/Vienna"`.
+    /// Defaults to the browser’s time zone, or UTC on the server.
+    pub time_zone: Option<&'static str>,
+}
+
+impl Default for DateTimeFormatOptions {
+    fn default() -> Self {
+        Self {
+            date_style: Some(DateTimeStyle::Medium),
+            time_style: None,
+            time_zone: None,
+        }
+    }
+}
+
+/// Formats numbers for the locale from [`use_intl_locale`], from
+/// [`use_number_format`].
+#[derive(Copy, Clone, Debug)]
+pub struct NumberFormatter {
+    locale: Signal<String>,
+    hydrated: Signal<bool>,
+    options: NumberFormatOptions,
+}
+
+impl NumberFormatter {
+    /// Formats `value`. Used in a reactive context, this is formatted again
+    /// when the locale changes.
+    pub fn format(&self, value: f64) -> String {
+        let locale = self.locale.get();
+        if self.hydrated.get() {
+            format_number(&locale, value, &self.options)
+        } else {
+            fallback::format_number(&locale, value, &self.options)
+        }
+    }
+}
+
+/// Formats dates and times for the locale from [`use_intl_locale`], from
+/// [`use_date_time_format`].
+#[derive(Copy, Clone, Debug)]
+pub struct DateTimeFormatter {
+    locale: Signal<String>,
+    hydrated: Signal<bool>,
+    options: DateTimeFormatOptions,
+}
+
+impl DateTimeFormatter {
+    /// Formats a time, in milliseconds since the Unix epoch, like
+    /// `Date.now()`. Used in a reactive context, this is formatted again
+    /// when the locale changes.
+    pub fn format(&self, timestamp: f64) -> String {
+        let locale = self.locale.get();
+        if self.hydrated.get() {
+            format_date_time(&locale, timestamp, &self.options)
+        } else {
+            fallback::format_date_time(timestamp, &self.options)
+        }
+    }
+}
+
+/// Creates a [`NumberFormatter`] for the current locale.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::leptos_dom::intl::{
+///     provide_intl_locale, use_number_format, NumberFormatOptions,
+///     NumberStyle,
+/// };
+///
+/// #[component]
+/// fn Price(cx: Scope, amount: ReadSignal<f64>) -> impl IntoView {
+///     let price = use_number_format(
+///         cx,
+///         NumberFormatOptions {
+///             style: NumberStyle::Currency("EUR"),
+///             ..Default::default()
+///         },
+///     );
+///     view! { cx, <span>{move || price.format(amount.get())}</span> }
+/// }
+///
+/// #[component]
+/// fn App(cx: Scope) -> impl IntoView {
+///     let (locale, _) = create_signal(cx, "de-AT".to_string());
+///     provide_intl_locale(cx, locale);
+///     let (amount, _) = create_signal(cx, 1234.5);
+///     view! { cx, <Price amount=amount/> }
+/// }
+/// ```
+pub fn use_number_format(
+    cx: Scope,
+    options: NumberFormatOptions,
+) -> NumberFormatter {
+    NumberFormatter {
+        locale: use_intl_locale(cx),
+        hydrated: use_intl_hydrated(cx),
+        options,
+    }
+}
+
+/// Creates a [`DateTimeFormatter`] for the current locale.
+pub fn use_date_time_format(
+    cx: Scope,
+    options: DateTimeFormatOptions,
+) -> DateTimeFormatter {
+    DateTimeFormatter {
+        locale: use_intl_locale(cx),
+        hydrated: use_intl_hydrated(cx),
+        options,
+    }
+}
+
+/// Formats `value` for `locale`.
+///
+/// ```
+/// # use leptos_dom::intl::*;
+/// let options = NumberFormatOptions::default();
+/// assert_eq!(format_number("en-US", 1234.5, &options), "1,234.5");
+/// assert_eq!(format_number("de-DE", 1234.5, &options), "1.234,5");
+/// ```
+pub fn format_number(
+    locale: &str,
+    value: f64,
+    options: &NumberFormatOptions,
+) -> String {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        web::format_number(locale, value, options)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        fallback::format_number(locale, value, options)
+    }
+}
+
+/// Formats a time, in milliseconds since the Unix epoch, for `locale`.
+pub fn format_date_time(
+    locale: &str,
+    timestamp: f64,
+    options: &DateTimeFormatOptions,
+) -> String {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        web::format_date_time(locale, timestamp, options)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = locale;
+        fallback::format_date_time(timestamp, options)
+    }
+}
+
+/// Formats how long ago, or in how long, something happens for `locale`,
+/// given the number of `seconds` from now, e.g., `-90.0` as “2 minutes
+/// ago” or `3600.0` as “in 1 hour”.
+///
+/// The unit is chosen by how far away the time is, from seconds to years.
+///
+/// ```
+/// # use leptos_dom::intl::*;
+/// assert_eq!(format_relative_time("en", -90.0), "2 minutes ago");
+/// assert_eq!(format_relative_time("en", 3 * 86_400.0), "in 3 days");
+/// ```
+pub fn format_relative_time(locale: &str, seconds: f64) -> String {
+    let (value, unit) = relative_time_unit(seconds);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        web::format_relative_time(locale, value, unit)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = locale;
+        fallback::format_relative_time(value, unit)
+    }
+}
+
+/// Converts a number of seconds to a rounded number of the largest unit
+/// that fits, and the name of that unit.
+fn relative_time_unit(seconds: f64) -> (f64, &'static str) {
+    const UNITS: [(f64, &str); 6] = [
+        (60.0, "minute"),
+        (3_600.0, "hour"),
+        (86_400.0, "day"),
+        (7.0 * 86_400.0, "week"),
+        (30.0 * 86_400.0, "month"),
+        (365.0 * 86_400.0, "year"),
+    ];
+    let (length, unit) = UNITS
+        .iter()
+        .rev()
+        .find(|(length, _)| seconds.abs() >= *length)
+        .copied()
+        .unwrap_or((1.0, "second"));
+    // avoids formatting "-0"
+    ((seconds / length).round() + 0.0, unit)
+}
+
+/// Formats a time, in milliseconds since the Unix epoch, in UTC as, e.g.,
+/// `2023-06-01T14:05:09Z`, which is how it is written in the `datetime`
+/// attribute of a `<time>`.
+///
+/// ```
+/// # use leptos_dom::intl::*;
+/// assert_eq!(to_iso_string(1_685_628_309_000.0), "2023-06-01T14:05:09Z");
+/// ```
+pub fn to_iso_string(timestamp: f64) -> String {
+    let (year, month, day, millis) = fallback::split_timestamp(timestamp);
+    let seconds = (millis / 1000.0).floor() as u32;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use super::{DateTimeFormatOptions, NumberFormatOptions, NumberStyle};
+    use js_sys::{Array, Function, Intl, Object, Reflect};
+    use std::{cell::RefCell, collections::HashMap};
+    use wasm_bindgen::JsValue;
+
+    thread_local! {
+        static NUMBER_FORMATS: RefCell<
+            HashMap<(String, NumberFormatOptions), Function>,
+        > = Default::default();
+        static DATE_TIME_FORMATS: RefCell<
+            HashMap<(String, DateTimeFormatOptions), Function>,
+        > = Default::default();
+        static RELATIVE_TIME_FORMATS: RefCell<
+            HashMap<String, Intl::RelativeTimeFormat>,
+        > = Default::default();
+    }
+
+    fn set(options: &Object, key: &str, value: impl Into<JsValue>) {
+        _ = Reflect::set(options, &key.into(), &value.into());
+    }
+
+    pub(super) fn format_number(
+        locale: &str,
+        value: f64,
+        options: &NumberFormatOptions,
+    ) -> String {
+        let format = NUMBER_FORMATS.with(|formats| {
+            formats
+                .borrow_mut()
+                .entry((locale.to_string(), *options))
+                .or_insert_with(|| {
+                    let js_options = Object::new();
+                    match options.style {
+                        NumberStyle::Decimal => {
+                            set(&js_options, "style", "decimal")
+                        }
+                        NumberStyle::Percent => {
+                            set(&js_options, "style", "percent")
+                        }
+                        NumberStyle::Currency(currency) => {
+                            set(&js_options, "style", "currency");
+                            set(&js_options, "currency", currency);
+                        }
+                    }
+                    let (min, max) = options.fraction_digits();
+                    set(&js_options, "minimumFractionDigits", min as u32);
+                    set(&js_options, "maximumFractionDigits", max as u32);
+                    set(&js_options, "useGrouping", options.use_grouping);
+                    Intl::NumberFormat::new(&locales(locale), &js_options)
+                        .format()
+                })
+                .clone()
+        });
+        format
+            .call1(&JsValue::NULL, &value.into())
+            .ok()
+            .and_then(|formatted| formatted.as_string())
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    pub(super) fn format_date_time(
+        locale: &str,
+        timestamp: f64,
+        options: &DateTimeFormatOptions,
+    ) -> String {
+        let format = DATE_TIME_FORMATS.with(|formats| {
+            formats
+                .borrow_mut()
+                .entry((locale.to_string(), *options))
+                .or_insert_with(|| {
+                    let js_options = Object::new();
+                    if let Some(style) = options.date_style {
+                        set(&js_options, "dateStyle", style.as_str());
+                    }
+                    if let Some(style) = options.time_style {
+                        set(&js_options, "timeStyle", style.as_str());
+                    }
+                    if let Some(time_zone) = options.time_zone {
+                        set(&js_options, "timeZone", time_zone);
+                    }
+                    Intl::DateTimeFormat::new(&locales(locale), &js_options)
+                        .format()
+                })
+                .clone()
+        });
+        let date = js_sys::Date::new(&timestamp.into());
+        format
+            .call1(&JsValue::NULL, &date)
+            .ok()
+            .and_then(|formatted| formatted.as_string())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn format_relative_time(
+        locale: &str,
+        value: f64,
+        unit: &str,
+    ) -> String {
+        let format = RELATIVE_TIME_FORMATS.with(|formats| {
+            formats
+                .borrow_mut()
+                .entry(locale.to_string())
+                .or_insert_with(|| {
+                    let js_options = Object::new();
+                    // "1 day ago" rather than "yesterday", like on the server
+                    set(&js_options, "numeric", "always");
+                    Intl::RelativeTimeFormat::new(&locales(locale), &js_options)
+                })
+                .clone()
+        });
+        format.format(value, unit).into()
+    }
+
+    fn locales(locale: &str) -> Array {
+        Array::of1(&locale.into())
+    }
+}
+
+#[cfg_attr(all(target_arch = "wasm32", feature = "web"), allow(dead_code))]
+mod fallback {
+    use super::{DateTimeFormatOptions, NumberFormatOptions, NumberStyle};
+
+    /// The group and decimal separators of `locale`.
+    fn separators(locale: &str) -> (&'static str, &'static str) {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        match (language, locale) {
+            (_, "de-CH") => ("’", "."),
+            ("fr", _) => ("\u{202f}", ","),
+            ("cs" | "fi" | "nb" | "pl" | "ru" | "sv" | "uk", _) => {
+                ("\u{a0}", ",")
+            }
+            ("da" | "de" | "es" | "id" | "it" | "nl" | "pt" | "tr", _) => {
+                (".", ",")
+            }
+            _ => (",", "."),
+        }
+    }
+
+    pub(super) fn format_number(
+        locale: &str,
+        value: f64,
+        options: &NumberFormatOptions,
+    ) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let (group, decimal) = separators(locale);
+        let (min, max) = options.fraction_digits();
+        let value = match options.style {
+            NumberStyle::Percent => value * 100.0,
+            _ => value,
+        };
+
+        let rounded = format!("{:.max$}", value.abs());
+        let (int, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+        let fraction = fraction.trim_end_matches('0');
+        let fraction = format!("{fraction:0<min$}");
+
+        let mut formatted = String::new();
+        if value < 0.0
+            && rounded.chars().any(|c| c.is_ascii_digit() && c != '0')
+        {
+            formatted.push('-');
+        }
+        if let NumberStyle::Currency(currency) = options.style {
+            formatted.push_str(currency);
+            formatted.push('\u{a0}');
+        }
+        for (idx, digit) in int.chars().enumerate() {
+            if options.use_grouping && idx > 0 && (int.len() - idx) % 3 == 0 {
+                formatted.push_str(group);
+            }
+            formatted.push(digit);
+        }
+        if !fraction.is_empty() {
+            formatted.push_str(decimal);
+            formatted.push_str(&fraction);
+        }
+        if options.style == NumberStyle::Percent {
+            formatted.push('%');
+        }
+        formatted
+    }
+
+    pub(super) fn format_date_time(
+        timestamp: f64,
+        options: &DateTimeFormatOptions,
+    ) -> String {
+        let (year, month, day, millis) = split_timestamp(timestamp);
+        let minutes = (millis / 60_000.0).floor() as i64;
+
+        let date = options
+            .date_style
+            .map(|_| format!("{year:04}-{month:02}-{day:02}"));
+        let time = options
+            .time_style
+            .map(|_| format!("{:02}:{:02}", minutes / 60, minutes % 60));
+        match (date, time) {
+            (Some(date), Some(time)) => format!("{date} {time}"),
+            (Some(date), None) => date,
+            (None, Some(time)) => time,
+            (None, None) => String::new(),
+        }
+    }
+
+    pub(super) fn format_relative_time(value: f64, unit: &str) -> String {
+        let plural = if value.abs() == 1.0 { "" } else { "s" };
+        if value.is_sign_negative() {
+            format!("{} {unit}{plural} ago", -value)
+        } else {
+            format!("in {value} {unit}{plural}")
+        }
+    }
+
+    /// Splits a time, in milliseconds since the Unix epoch, into the year,
+    /// month, and day in UTC, and the milliseconds since midnight.
+    pub(super) fn split_timestamp(timestamp: f64) -> (i64, u32, u32, f64) {
+        let millis_per_day = 86_400_000.0;
+        let days = (timestamp / millis_per_day).floor();
+        let millis = timestamp - days * millis_per_day;
+        let (year, month, day) = civil_from_days(days as i64);
+        (year, month, day, millis)
+    }
+
+    /// Converts days since the Unix epoch to a year, month, and day.
+    ///
+    /// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    fn civil_from_days(days: i64) -> (i64, u32, u32) {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        (year, month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, create_scope};
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn nothing_is_hydrating_on_the_server() {
+        _ = create_scope(create_runtime(), |cx| {
+            assert!(use_intl_hydrated(cx).get());
+            assert_eq!(use_intl_locale(cx).get(), "en");
+        });
+    }
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn hydrating_switches_to_intl_once_hydrated() {
+        let ((locale, hydrated), _, _disposer) =
+            leptos_reactive::run_scope_undisposed(create_runtime(), |cx| {
+                let locale = Signal::derive(cx, || "de-DE".to_string());
+                (locale, hydration_switch(cx, true))
+            });
+        let number = NumberFormatter {
+            locale,
+            hydrated,
+            options: Default::default(),
+        };
+        let date = DateTimeFormatter {
+            locale,
+            hydrated,
+            options: Default::default(),
+        };
+        let timestamp = 1_685_628_309_000.0;
+
+        // while hydrating, the text matches the server-rendered HTML
+        assert!(!hydrated.get());
+        let options = Default::default();
+        assert_eq!(
+            number.format(1234.5),
+            fallback::format_number("de-DE", 1234.5, &options)
+        );
+        assert_eq!(
+            date.format(timestamp),
+            fallback::format_date_time(timestamp, &Default::default())
+        );
+
+        // the switch is flipped in a microtask
+        let resolved = js_sys::Promise::resolve(&wasm_bindgen::JsValue::NULL);
+        _ = wasm_bindgen_futures::JsFuture::from(resolved).await;
+        assert!(hydrated.get());
+        assert_eq!(
+            number.format(1234.5),
+            web::format_number("de-DE", 1234.5, &options)
+        );
+        assert_eq!(
+            date.format(timestamp),
+            web::format_date_time("de-DE", timestamp, &Default::default())
+        );
+        // which only shows if `Intl` formats differently from the server
+        assert_ne!(
+            date.format(timestamp),
+            fallback::format_date_time(timestamp, &Default::default())
+        );
+    }
+
+    #[test]
+    fn relative_times_use_the_largest_unit_that_fits() {
+        assert_eq!(relative_time_unit(0.0), (0.0, "second"));
+        assert_eq!(relative_time_unit(-59.0), (-59.0, "second"));
+        assert_eq!(relative_time_unit(-90.0), (-2.0, "minute"));
+        assert_eq!(relative_time_unit(3_600.0), (1.0, "hour"));
+        assert_eq!(relative_time_unit(-86_400.0), (-1.0, "day"));
+        assert_eq!(relative_time_unit(10.0 * 86_400.0), (1.0, "week"));
+        assert_eq!(relative_time_unit(-45.0 * 86_400.0), (-2.0, "month"));
+        assert_eq!(relative_time_unit(400.0 * 86_400.0), (1.0, "year"));
+        // rounding a small negative number doesn't give "-0"
+        assert!(relative_time_unit(-0.4).0.is_sign_positive());
+    }
+
+    #[test]
+    fn relative_times_are_always_numeric() {
+        assert_eq!(format_relative_time("en", -86_400.0), "1 day ago");
+        assert_eq!(format_relative_time("en", 86_400.0), "in 1 day");
+        assert_eq!(format_relative_time("en", 0.0), "in 0 seconds");
+    }
+}
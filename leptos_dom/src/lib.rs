@@ -9,29 +9,60 @@
 #[cfg_attr(any(debug_assertions, feature = "ssr"), macro_use)]
 pub extern crate tracing;
 
+#[cfg(all(
+    debug_assertions,
+    target_arch = "wasm32",
+    feature = "web",
+    feature = "a11y"
+))]
+mod a11y;
+pub mod canvas;
 mod components;
+#[cfg(all(target_arch = "wasm32", feature = "web", feature = "debug-dom"))]
+mod debug_dom;
+mod directive;
+pub mod dismiss;
 mod events;
+pub mod fetch;
+pub mod floating;
+pub mod gestures;
 pub mod helpers;
 pub mod html;
+#[cfg(all(
+    debug_assertions,
+    not(all(target_arch = "wasm32", feature = "web"))
+))]
+mod html_nesting;
+pub mod intl;
 mod hydration;
+mod interning;
 mod logging;
 mod macro_helpers;
 pub mod math;
 mod node_ref;
+pub mod roving_focus;
+pub mod service_worker;
+mod source_location;
 pub mod ssr;
 pub mod ssr_in_order;
 pub mod svg;
 mod transparent;
+pub mod worker;
 use cfg_if::cfg_if;
 pub use components::*;
+pub use directive::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub use events::add_event_helper;
+#[cfg(debug_assertions)]
+pub use events::live_event_listeners;
 pub use events::typed as ev;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use events::{add_event_listener, add_event_listener_undelegated};
+pub use events::{EventFlow, EventHandlerReturn, EventOptions};
 pub use html::HtmlElement;
-use html::{AnyElement, ElementDescriptor};
+use html::{AnyElement, ElementDescriptor, TagName};
 pub use hydration::{HydrationCtx, HydrationKey};
+pub use interning::intern_name;
 use leptos_reactive::Scope;
 #[cfg(feature = "stable")]
 use leptos_reactive::{
@@ -44,6 +75,7 @@ pub use node_ref::*;
 use once_cell::unsync::Lazy as LazyCell;
 #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
 use smallvec::SmallVec;
+pub use source_location::*;
 use std::{borrow::Cow, fmt};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use std::{cell::RefCell, rc::Rc};
@@ -54,12 +86,27 @@ use wasm_bindgen::UnwrapThrowExt;
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 thread_local! {
-  static COMMENT: LazyCell<web_sys::Node> =
-    LazyCell::new(|| document().create_comment("").unchecked_into());
+  static COMMENT: LazyCell<web_sys::Node> = LazyCell::new(create_marker);
   static RANGE: LazyCell<web_sys::Range> =
     LazyCell::new(|| web_sys::Range::new().unwrap());
 }
 
+/// Creates the node that anchors a component or control-flow boundary.
+///
+/// With the `text-markers` feature, release builds use empty text nodes,
+/// which are cheaper to create and clone than comments. Debug builds always
+/// use comments, as their contents describe the component.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn create_marker() -> web_sys::Node {
+    cfg_if! {
+        if #[cfg(all(feature = "text-markers", not(debug_assertions)))] {
+            document().create_text_node("").unchecked_into()
+        } else {
+            document().create_comment("").unchecked_into()
+        }
+    }
+}
+
 /// Converts the value into a [`View`].
 pub trait IntoView {
     /// Converts the value into [`View`].
@@ -89,6 +136,30 @@ pub trait Mountable {
 
     /// Get's the closing marker node.
     fn get_closing_node(&self) -> web_sys::Node;
+
+    /// Removes this view's nodes from the DOM.
+    ///
+    /// Views never touch the DOM when they are dropped, so they can be
+    /// freely cloned and stored; control-flow components call this
+    /// explicitly when a view should no longer be displayed. Components
+    /// keep their nodes in their own [`web_sys::DocumentFragment`], so they
+    /// can be mounted again later.
+    fn unmount(&self) {
+        let end = self.get_closing_node();
+        let mut sibling = self.get_opening_node();
+
+        loop {
+            let next_sibling = sibling.next_sibling();
+            let is_end = sibling == end;
+
+            sibling.unchecked_ref::<web_sys::Element>().remove();
+
+            match next_sibling {
+                Some(next_sibling) if !is_end => sibling = next_sibling,
+                _ => break,
+            }
+        }
+    }
 }
 
 impl IntoView for () {
@@ -127,7 +198,7 @@ where
         any(debug_assertions, feature = "ssr"),
         instrument(level = "info", name = "Fn() -> impl IntoView", skip_all)
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn into_view(self, cx: Scope) -> View {
         DynChild::new(self).into_view(cx)
     }
@@ -243,6 +314,13 @@ cfg_if! {
       pub view_marker: Option<String>
     }
 
+    impl Element {
+      #[cfg(debug_assertions)]
+      fn tag_name(&self) -> &str {
+        &self.name
+      }
+    }
+
     impl fmt::Debug for Element {
       fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let html = self.element.outer_html();
@@ -256,8 +334,7 @@ cfg_if! {
     /// HTML element.
     #[derive(Clone, PartialEq, Eq)]
     pub struct Element {
-      name: Cow<'static, str>,
-      is_void: bool,
+      tag: TagName,
       attrs: SmallVec<[(Cow<'static, str>, Cow<'static, str>); 4]>,
       children: ElementChildren,
       id: HydrationKey,
@@ -266,6 +343,13 @@ cfg_if! {
       pub view_marker: Option<String>
     }
 
+    impl Element {
+      #[cfg(debug_assertions)]
+      fn tag_name(&self) -> &str {
+        self.tag.as_str()
+      }
+    }
+
     impl fmt::Debug for Element {
       fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use fmt::Write;
@@ -273,10 +357,10 @@ cfg_if! {
         let attrs =
           self.attrs.iter().map(|(n, v)| format!(" {n}=\"{v}\"")).collect::<String>();
 
-        if self.is_void {
-          write!(f, "<{}{attrs} />", self.name)
+        if self.tag.is_void() {
+          write!(f, "<{}{attrs} />", self.tag.as_str())
         } else {
-          writeln!(f, "<{}{attrs}>", self.name)?;
+          writeln!(f, "<{}{attrs}>", self.tag.as_str())?;
 
           let mut pad_adapter = pad_adapter::PadAdapter::new(f);
 
@@ -286,7 +370,7 @@ cfg_if! {
             }
           }
 
-          write!(f, "</{}>", self.name)
+          write!(f, "</{}>", self.tag.as_str())
         }
 
       }
@@ -309,9 +393,8 @@ impl Element {
             let name = element.node_name().to_ascii_lowercase();
 
             let element = AnyElement {
-                name: name.into(),
+                tag: TagName::Dynamic(name.into(), false),
                 element,
-                is_void: false,
             };
 
             HtmlElement {
@@ -327,8 +410,7 @@ impl Element {
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         {
             let Self {
-                name,
-                is_void,
+                tag,
                 attrs,
                 children,
                 id,
@@ -336,7 +418,7 @@ impl Element {
                 view_marker,
             } = self;
 
-            let element = AnyElement { name, is_void, id };
+            let element = AnyElement { tag, id };
 
             HtmlElement {
                 cx,
@@ -351,14 +433,14 @@ impl Element {
 }
 
 impl IntoView for Element {
-    #[cfg_attr(debug_assertions, instrument(level = "info", name = "<Element />", skip_all, fields(tag = %self.name)))]
+    #[cfg_attr(debug_assertions, instrument(level = "info", name = "<Element />", skip_all, fields(tag = %self.tag_name())))]
     fn into_view(self, _: Scope) -> View {
         View::Element(self)
     }
 }
 
 impl Element {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn new<El: ElementDescriptor>(el: El) -> Self {
         cfg_if! {
           if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
@@ -372,8 +454,7 @@ impl Element {
           }
           else {
             Self {
-              name: el.name(),
-              is_void: el.is_void(),
+              tag: TagName::of(&el),
               attrs: Default::default(),
               children: Default::default(),
               id: *el.hydration_id(),
@@ -479,6 +560,14 @@ impl Text {
             content,
         }
     }
+
+    /// Appends more static text to this node.
+    pub(crate) fn push_str(&mut self, text: &str) {
+        self.content.to_mut().push_str(text);
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        self.node.set_text_content(Some(&self.content));
+    }
 }
 
 /// A leptos view which can be mounted to the DOM.
@@ -609,6 +698,27 @@ impl Mountable for View {
             }
         }
     }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn unmount(&self) {
+        #[cfg(feature = "debug-dom")]
+        crate::debug_dom::Owner::current()
+            .log("unmount", self.get_opening_node().node_name());
+
+        match self {
+            Self::Text(t) => {
+                t.node.unchecked_ref::<web_sys::Element>().remove()
+            }
+            Self::Element(el) => el.element.remove(),
+            Self::CoreComponent(c) | Self::Suspense(_, c) => match c {
+                CoreComponent::DynChild(dc) => dc.unmount(),
+                CoreComponent::Each(e) => e.unmount(),
+                CoreComponent::Unit(u) => u.unmount(),
+            },
+            Self::Component(c) => c.unmount(),
+            Self::Transparent(_) => {}
+        }
+    }
 }
 
 impl View {
@@ -683,12 +793,20 @@ impl View {
     ///
     /// This method will attach an event listener to **all** child
     /// [`HtmlElement`] children.
+    ///
+    /// Unlike [`HtmlElement::on`], these listeners are not owned by a
+    /// [`Scope`], so they are kept for as long as the page is alive.
     #[inline(always)]
-    pub fn on<E: ev::EventDescriptor + 'static>(
+    pub fn on<E: ev::EventDescriptor + 'static, R>(
         self,
         event: E,
-        #[allow(unused_mut)] mut event_handler: impl FnMut(E::EventType) + 'static,
-    ) -> Self {
+        event_handler: impl FnMut(E::EventType) -> R + 'static,
+    ) -> Self
+    where
+        R: EventHandlerReturn<E::EventType>,
+    {
+        #[allow(unused_mut)] // used for tracing in debug
+        let mut event_handler = R::into_handler(event_handler);
         cfg_if::cfg_if! {
           if #[cfg(debug_assertions)] {
             trace!("calling on() {}", event.name());
@@ -713,9 +831,10 @@ impl View {
             match &self {
               Self::Element(el) => {
                 if E::BUBBLES {
-                  add_event_listener(&el.element, event.event_delegation_key(), event.name(), event_handler, &None);
+                  add_event_listener(None, &el.element, event.event_delegation_key(), event.name(), event_handler, &None);
                 } else {
                   add_event_listener_undelegated(
+                    None,
                     &el.element,
                     &event.name(),
                     event_handler,
@@ -750,7 +869,7 @@ impl View {
 }
 
 #[cfg_attr(debug_assertions, instrument)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 pub fn mount_child<GWSN: Mountable + fmt::Debug>(
@@ -759,42 +878,69 @@ pub fn mount_child<GWSN: Mountable + fmt::Debug>(
 ) {
     let child = child.get_mountable_node();
 
+    #[cfg(feature = "debug-dom")]
+    crate::debug_dom::Owner::current().log("mount", child.node_name());
+
+    insert_node(kind, &child);
+}
+
+/// Mounts several siblings at once. When there is more than one child,
+/// they are first collected into a single [`DocumentFragment`], so that the
+/// browser only performs one insertion.
+///
+/// [DocumentFragment]: web_sys::DocumentFragment
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[doc(hidden)]
+pub fn mount_children<GWSN: Mountable + fmt::Debug>(
+    kind: MountKind,
+    children: &[GWSN],
+) {
+    match children {
+        [] => {}
+        [child] => mount_child(kind, child),
+        children => {
+            let fragment = document().create_document_fragment();
+
+            for child in children {
+                let child = child.get_mountable_node();
+
+                #[cfg(feature = "debug-dom")]
+                crate::debug_dom::Owner::current()
+                    .log("mount", child.node_name());
+
+                fragment
+                    .append_child(&child)
+                    .expect("append operation to not err");
+            }
+
+            insert_node(kind, &fragment);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[inline(always)]
+fn insert_node(kind: MountKind, node: &web_sys::Node) {
     match kind {
         MountKind::Append(el) => {
-            el.append_child(&child)
-                .expect("append operation to not err");
+            el.append_child(node).expect("append operation to not err");
         }
         MountKind::Before(closing) => {
             closing
                 .unchecked_ref::<web_sys::Element>()
-                .before_with_node_1(&child)
+                .before_with_node_1(node)
                 .expect("before to not err");
         }
     }
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-#[track_caller]
-fn unmount_child(start: &web_sys::Node, end: &web_sys::Node) {
-    let mut sibling = start.clone();
-
-    while sibling != *end {
-        if let Some(next_sibling) = sibling.next_sibling() {
-            sibling.unchecked_ref::<web_sys::Element>().remove();
-
-            sibling = next_sibling;
-        } else {
-            break;
-        }
-    }
-}
-
-/// Similar to [`unmount_child`], but instead of removing entirely
+/// Similar to [`Mountable::unmount`], but instead of removing entirely
 /// from the DOM, it inserts all child nodes into the [`DocumentFragment`].
 ///
 /// [DocumentFragment]: web_sys::DocumentFragment
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 fn prepare_to_move(
     frag: &web_sys::DocumentFragment,
     opening: &web_sys::Node,
@@ -828,8 +974,64 @@ pub enum MountKind<'a> {
     Append(&'a web_sys::Node),
 }
 
+/// A view mounted with [`mount_to_with_handle`] or
+/// [`mount_to_body_with_handle`], which can be removed again with
+/// [`dispose`](MountHandle::dispose).
+///
+/// Dropping the handle leaves the view mounted, so an app that runs until the
+/// page is closed can ignore it.
+///
+/// ```
+/// # use leptos::*;
+/// # if false {
+/// let handle = mount_to_body_with_handle(|cx| view! { cx, <p>"Hello!"</p> });
+/// // later, e.g., at the end of a test
+/// handle.dispose();
+/// # }
+/// ```
+pub struct MountHandle {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    view: std::mem::ManuallyDrop<View>,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    disposer: leptos_reactive::ScopeDisposer,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    runtime: leptos_reactive::RuntimeId,
+}
+
+impl MountHandle {
+    /// Removes the view’s nodes from the DOM and disposes of its reactive
+    /// [`Scope`] and the runtime it was created in, running any cleanup
+    /// functions.
+    pub fn dispose(self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let view = std::mem::ManuallyDrop::into_inner(self.view);
+            view.unmount();
+            self.disposer.dispose();
+            self.runtime.dispose();
+        }
+    }
+}
+
+impl fmt::Debug for MountHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MountHandle").finish()
+    }
+}
+
 /// Runs the provided closure and mounts the result to the `<body>`.
 pub fn mount_to_body<F, N>(f: F)
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    _ = mount_to_body_with_handle(f);
+}
+
+/// Runs the provided closure and mounts the result to the `<body>`, like
+/// [`mount_to_body`], returning a [`MountHandle`] that can unmount the view
+/// again.
+pub fn mount_to_body_with_handle<F, N>(f: F) -> MountHandle
 where
     F: FnOnce(Scope) -> N + 'static,
     N: IntoView,
@@ -845,44 +1047,161 @@ where
 
     cfg_if! {
       if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
-        mount_to(crate::document().body().expect("body element to exist"), f)
+        mount_to_with_handle(
+          crate::document().body().expect("body element to exist"),
+          f,
+        )
       } else {
         _ = f;
         crate::warn!("`mount_to_body` should not be called outside the browser.");
+        MountHandle {}
       }
     }
 }
 
-/// Runs the provided closure and mounts the result to the provided element.
+/// Runs the provided closure in a new root [`Scope`] and mounts the result to
+/// the provided element.
 pub fn mount_to<F, N>(parent: web_sys::HtmlElement, f: F)
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    _ = mount_to_with_handle(parent, f);
+}
+
+/// Runs the provided closure in a new root [`Scope`] and mounts the result to
+/// the provided element, like [`mount_to`], returning a [`MountHandle`] that
+/// can unmount the view again.
+pub fn mount_to_with_handle<F, N>(
+    parent: web_sys::HtmlElement,
+    f: F,
+) -> MountHandle
 where
     F: FnOnce(Scope) -> N + 'static,
     N: IntoView,
 {
     cfg_if! {
       if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
-        let disposer = leptos_reactive::create_scope(
-          leptos_reactive::create_runtime(),
+        let runtime = leptos_reactive::create_runtime();
+        let (view, _, disposer) = leptos_reactive::run_scope_undisposed(
+          runtime,
           move |cx| {
+            #[cfg(feature = "metrics")]
+            let (started, hydrating) =
+              (leptos_reactive::metrics::now(), HydrationCtx::is_hydrating());
+
             let node = f(cx).into_view(cx);
 
             HydrationCtx::stop_hydrating();
 
-            parent.append_child(&node.get_mountable_node()).unwrap();
+            let child = node.get_mountable_node();
+
+            #[cfg(feature = "debug-dom")]
+            crate::debug_dom::Owner::current().log("mount", child.node_name());
+
+            parent.append_child(&child).unwrap();
 
-            std::mem::forget(node);
+            #[cfg(feature = "metrics")]
+            report_mount_metrics(&parent, started, hydrating);
+
+            #[cfg(all(debug_assertions, feature = "a11y"))]
+            a11y::watch(&parent);
+
+            node
           },
         );
 
-        std::mem::forget(disposer);
+        MountHandle {
+          view: std::mem::ManuallyDrop::new(view),
+          disposer,
+          runtime,
+        }
       } else {
         _ = parent;
         _ = f;
         crate::warn!("`mount_to` should not be called outside the browser.");
+        MountHandle {}
+      }
+    }
+}
+
+/// Runs the provided closure and hydrates the server-rendered HTML in the
+/// `<body>`. See [`hydrate`].
+pub fn hydrate_body<F, N>(f: F)
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        hydrate(crate::document().body().expect("body element to exist"), f)
+      } else {
+        _ = f;
+        crate::warn!("`hydrate_body` should not be called outside the browser.");
       }
     }
 }
 
+/// Runs the provided closure and hydrates the server-rendered HTML in
+/// `parent`: rather than creating new nodes, the view takes over the elements
+/// and text the server rendered, using their hydration keys and the comment
+/// markers around each component to find them, and adds its event listeners
+/// and reactive updates to them.
+///
+/// [`mount_to`] also hydrates whenever it finds server-rendered HTML. Calling
+/// `hydrate` instead says that there should be some, and warns if there
+/// isn’t, e.g., because the page was rendered without the `ssr` feature. In
+/// that case, the view is rendered from scratch, as with `mount_to`.
+pub fn hydrate<F, N>(parent: web_sys::HtmlElement, f: F)
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        if !HydrationCtx::is_hydrating() {
+          crate::warn!(
+            "`hydrate` found no server-rendered HTML to hydrate, so the view \
+             will be rendered from scratch. Make sure the page was rendered \
+             by the server with the `ssr` feature, and that the app is built \
+             with `hydrate` rather than `csr`."
+          );
+        }
+        mount_to(parent, f)
+      } else {
+        _ = parent;
+        _ = f;
+        crate::warn!("`hydrate` should not be called outside the browser.");
+      }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web", feature = "metrics"))]
+fn report_mount_metrics(
+    parent: &web_sys::HtmlElement,
+    started: f64,
+    hydrating: bool,
+) {
+    use leptos_reactive::metrics::{now, report, Metric};
+
+    let finished = now();
+    if hydrating {
+        report(Metric::Hydration {
+            duration: finished - started,
+        });
+    }
+    let id = parent.id();
+    let root = if id.is_empty() {
+        parent.tag_name().to_lowercase()
+    } else {
+        format!("#{id}")
+    };
+    report(Metric::Interactive {
+        root,
+        time: finished,
+    });
+}
+
 thread_local! {
     pub(crate) static WINDOW: web_sys::Window = web_sys::window().unwrap_throw();
 
@@ -1,4 +1,8 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 use std::{borrow::Cow, rc::Rc};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::UnwrapThrowExt;
@@ -292,9 +296,41 @@ attr_type!(f32);
 attr_type!(f64);
 attr_type!(char);
 
+// on nightly, signals are `Fn() -> T`, so they're covered by the impl for
+// functions above
+#[cfg(feature = "stable")]
+macro_rules! attr_signal_type {
+    ($signal_type:ty) => {
+        impl<T> IntoAttribute for $signal_type
+        where
+            T: IntoAttribute + Clone,
+        {
+            fn into_attribute(self, cx: Scope) -> Attribute {
+                let modified_fn =
+                    Rc::new(move || self.get().into_attribute(cx));
+                Attribute::Fn(cx, modified_fn)
+            }
+
+            impl_into_attr_boxed! {}
+        }
+    };
+}
+
+#[cfg(feature = "stable")]
+attr_signal_type!(ReadSignal<T>);
+#[cfg(feature = "stable")]
+attr_signal_type!(RwSignal<T>);
+#[cfg(feature = "stable")]
+attr_signal_type!(Memo<T>);
+#[cfg(feature = "stable")]
+attr_signal_type!(Signal<T>);
+#[cfg(feature = "stable")]
+attr_signal_type!(MaybeSignal<T>);
+
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 #[inline(never)]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn attribute_helper(
     el: &web_sys::Element,
     name: Cow<'static, str>,
@@ -304,9 +340,20 @@ pub fn attribute_helper(
     match value {
         Attribute::Fn(cx, f) => {
             let el = el.clone();
+            #[cfg(feature = "debug-dom")]
+            let owner = crate::debug_dom::Owner::current();
             create_render_effect(cx, move |old| {
                 let new = f();
                 if old.as_ref() != Some(&new) {
+                    #[cfg(feature = "debug-dom")]
+                    {
+                        if old.is_some() {
+                            owner.log(
+                                "attribute",
+                                format_args!("{name}={new:?}"),
+                            );
+                        }
+                    }
                     attribute_expression(&el, &name, new.clone(), true);
                 }
                 new
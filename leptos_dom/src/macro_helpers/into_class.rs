@@ -1,4 +1,8 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 
 /// Represents the different possible values a single class on an element could have,
 /// allowing you to do fine-grained updates to single items
@@ -38,6 +42,32 @@ where
     }
 }
 
+// on nightly, signals are `Fn() -> bool`, so they're covered by the impl
+// for functions above
+#[cfg(feature = "stable")]
+macro_rules! class_signal_type {
+    ($signal_type:ty) => {
+        impl IntoClass for $signal_type {
+            #[inline(always)]
+            fn into_class(self, cx: Scope) -> Class {
+                let modified_fn = Box::new(move || self.get());
+                Class::Fn(cx, modified_fn)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "stable")]
+class_signal_type!(ReadSignal<bool>);
+#[cfg(feature = "stable")]
+class_signal_type!(RwSignal<bool>);
+#[cfg(feature = "stable")]
+class_signal_type!(Memo<bool>);
+#[cfg(feature = "stable")]
+class_signal_type!(Signal<bool>);
+#[cfg(feature = "stable")]
+class_signal_type!(MaybeSignal<bool>);
+
 impl Class {
     /// Converts the class to its HTML value at that moment so it can be rendered on the server.
     pub fn as_value_string(&self, class_name: &'static str) -> &'static str {
@@ -74,6 +104,7 @@ use std::borrow::Cow;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 #[inline(never)]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn class_helper(
     el: &web_sys::Element,
     name: Cow<'static, str>,
@@ -84,9 +115,17 @@ pub fn class_helper(
     let class_list = el.class_list();
     match value {
         Class::Fn(cx, f) => {
+            #[cfg(feature = "debug-dom")]
+            let owner = crate::debug_dom::Owner::current();
             create_render_effect(cx, move |old| {
                 let new = f();
                 if old.as_ref() != Some(&new) && (old.is_some() || new) {
+                    #[cfg(feature = "debug-dom")]
+                    {
+                        if old.is_some() {
+                            owner.log("class", format_args!("{name}={new}"));
+                        }
+                    }
                     class_expression(&class_list, &name, new, true)
                 }
                 new
@@ -1,4 +1,8 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 use wasm_bindgen::JsValue;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::UnwrapThrowExt;
@@ -80,11 +84,40 @@ prop_type!(f32);
 prop_type!(f64);
 prop_type!(bool);
 
+// on nightly, signals are `Fn() -> T`, so they're covered by the impl for
+// functions above
+#[cfg(feature = "stable")]
+macro_rules! prop_signal_type {
+    ($signal_type:ty) => {
+        impl<T> IntoProperty for $signal_type
+        where
+            T: Into<JsValue> + Clone,
+        {
+            fn into_property(self, cx: Scope) -> Property {
+                let modified_fn = Box::new(move || self.get().into());
+                Property::Fn(cx, modified_fn)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "stable")]
+prop_signal_type!(ReadSignal<T>);
+#[cfg(feature = "stable")]
+prop_signal_type!(RwSignal<T>);
+#[cfg(feature = "stable")]
+prop_signal_type!(Memo<T>);
+#[cfg(feature = "stable")]
+prop_signal_type!(Signal<T>);
+#[cfg(feature = "stable")]
+prop_signal_type!(MaybeSignal<T>);
+
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use std::borrow::Cow;
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[inline(never)]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub(crate) fn property_helper(
     el: &web_sys::Element,
     name: Cow<'static, str>,
@@ -95,8 +128,18 @@ pub(crate) fn property_helper(
     match value {
         Property::Fn(cx, f) => {
             let el = el.clone();
-            create_render_effect(cx, move |_| {
+            #[cfg(feature = "debug-dom")]
+            let owner = crate::debug_dom::Owner::current();
+            create_render_effect(cx, move |old: Option<JsValue>| {
                 let new = f();
+                #[cfg(feature = "debug-dom")]
+                {
+                    if old.is_some() {
+                        owner.log("property", format_args!("{name}={new:?}"));
+                    }
+                }
+                #[cfg(not(feature = "debug-dom"))]
+                _ = old;
                 let prop_name = wasm_bindgen::intern(&name);
                 property_expression(&el, prop_name, new.clone());
                 new
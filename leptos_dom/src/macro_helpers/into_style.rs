@@ -1,7 +1,18 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 use std::{borrow::Cow, rc::Rc};
 
-/// todo docs
+/// Represents the different possible values a single property in an
+/// element’s [`style`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/style)
+/// could have, allowing you to do fine-grained updates to single properties
+/// with `setProperty()` and `removeProperty()`, without touching the others.
+///
+/// This mostly exists for the [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
+/// macro’s use. You usually won't need to interact with it directly, but it can be useful for defining
+/// permissive APIs for certain components.
 #[derive(Clone)]
 pub enum Style {
     /// A plain string value.
@@ -67,6 +78,20 @@ impl IntoStyle for Option<String> {
     }
 }
 
+impl IntoStyle for Cow<'static, str> {
+    #[inline(always)]
+    fn into_style(self, _cx: Scope) -> Style {
+        Style::Value(self)
+    }
+}
+
+impl IntoStyle for Option<Cow<'static, str>> {
+    #[inline(always)]
+    fn into_style(self, _cx: Scope) -> Style {
+        Style::Option(self)
+    }
+}
+
 impl<T, U> IntoStyle for T
 where
     T: Fn() -> U + 'static,
@@ -79,6 +104,34 @@ where
     }
 }
 
+// on nightly, signals are `Fn() -> T`, so they're covered by the impl for
+// functions above
+#[cfg(feature = "stable")]
+macro_rules! style_signal_type {
+    ($signal_type:ty) => {
+        impl<T> IntoStyle for $signal_type
+        where
+            T: IntoStyle + Clone,
+        {
+            fn into_style(self, cx: Scope) -> Style {
+                let modified_fn = Rc::new(move || self.get().into_style(cx));
+                Style::Fn(cx, modified_fn)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "stable")]
+style_signal_type!(ReadSignal<T>);
+#[cfg(feature = "stable")]
+style_signal_type!(RwSignal<T>);
+#[cfg(feature = "stable")]
+style_signal_type!(Memo<T>);
+#[cfg(feature = "stable")]
+style_signal_type!(Signal<T>);
+#[cfg(feature = "stable")]
+style_signal_type!(MaybeSignal<T>);
+
 impl Style {
     /// Converts the style to its HTML value at that moment so it can be rendered on the server.
     pub fn as_value_string(
@@ -113,6 +166,7 @@ impl<T: IntoStyle> IntoStyle for (Scope, T) {
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 #[inline(never)]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn style_helper(
     el: &web_sys::Element,
     name: Cow<'static, str>,
@@ -125,6 +179,8 @@ pub fn style_helper(
     let style_list = el.style();
     match value {
         Style::Fn(cx, f) => {
+            #[cfg(feature = "debug-dom")]
+            let owner = crate::debug_dom::Owner::current();
             create_render_effect(cx, move |old| {
                 let mut new = f();
                 while let Style::Fn(_, f) = new {
@@ -136,6 +192,12 @@ pub fn style_helper(
                     _ => unreachable!(),
                 };
                 if old.as_ref() != Some(&new) {
+                    #[cfg(feature = "debug-dom")]
+                    {
+                        if old.is_some() {
+                            owner.log("style", format_args!("{name}={new:?}"));
+                        }
+                    }
                     style_expression(&style_list, &name, new.as_ref(), true)
                 }
                 new
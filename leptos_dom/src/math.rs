@@ -1,6 +1,28 @@
 //! Exports types for working with MathML elements.
+//!
+//! These elements are created in the MathML namespace, so browsers render
+//! them as formulas. The `view` macro uses them for any of these tags, and
+//! creates custom elements inside a `<math>` in the same namespace. See
+//! [MathML Core](https://w3c.github.io/mathml-core/) for which elements and
+//! attributes browsers support.
+//!
+//! ```
+//! # use leptos::*;
+//! # run_scope(create_runtime(), |cx| {
+//! // x²
+//! view! { cx,
+//!     <math>
+//!         <msup>
+//!             <mi>"x"</mi>
+//!             <mn>"2"</mn>
+//!         </msup>
+//!     </math>
+//! }
+//! # ;
+//! # });
+//! ```
 
-use super::{ElementDescriptor, HtmlElement};
+use super::{html::TagMetadata, ElementDescriptor, HtmlElement};
 use crate::HydrationCtx;
 use cfg_if::cfg_if;
 use leptos_reactive::Scope;
@@ -60,43 +82,27 @@ macro_rules! generate_math_tags {
 
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
             let element = if HydrationCtx::is_hydrating() {
-              if let Some(el) = crate::document().get_element_by_id(
-                &format!("_{id}")
-              ) {
+              if let Some(el) =
+                crate::hydration::take_element(&format!("_{id}"))
+              {
                 #[cfg(debug_assertions)]
                 assert_eq!(
                   el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
+                  Self::METADATA.name.to_ascii_uppercase(),
                   "SSR and CSR elements have the same `TopoId` \
                     but different node kinds. This is either a \
                     discrepancy between SSR and CSR rendering
                     logic, which is considered a bug, or it \
-                    can also be a leptos hydration issue."
+                    can also be a leptos hydration issue.{}",
+                  crate::source_location::describe_next()
                 );
 
-                el.remove_attribute("id").unwrap();
-
-                el.unchecked_into()
-              } else if let Ok(Some(el)) = crate::document().query_selector(
-                &format!("[leptos-hk=_{id}]")
-              ) {
-                #[cfg(debug_assertions)]
-                assert_eq!(
-                  el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
-                  "SSR and CSR elements have the same `TopoId` \
-                    but different node kinds. This is either a \
-                    discrepancy between SSR and CSR rendering
-                    logic, which is considered a bug, or it \
-                    can also be a leptos hydration issue."
-                );
-
-                el.remove_attribute("leptos-hk").unwrap();
-
                 el.unchecked_into()
               } else {
                 crate::warn!(
-                  "element with id {id} not found, ignoring it for hydration"
+                  "element with id {id} not found{}, ignoring it for \
+                   hydration",
+                  crate::source_location::describe_next()
                 );
 
                 [<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]
@@ -149,17 +155,35 @@ macro_rules! generate_math_tags {
           }
         }
 
+        impl [<$tag:camel $($second:camel $($third:camel)?)?>] {
+          /// Static metadata for this tag.
+          pub const METADATA: TagMetadata = TagMetadata::math(
+            concat![
+              stringify!($tag),
+              $(
+                "-", stringify!($second),
+                $(
+                  "-", stringify!($third)
+                )?
+              )?
+            ],
+            generate_math_tags! { @void $($void)? },
+          );
+        }
+
         impl ElementDescriptor for [<$tag:camel $($second:camel $($third:camel)?)?>] {
           fn name(&self) -> Cow<'static, str> {
-            stringify!($tag).into()
+            Cow::Borrowed(Self::METADATA.name)
+          }
+
+          fn metadata(&self) -> Option<&'static TagMetadata> {
+            Some(&Self::METADATA)
           }
 
           #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
           fn hydration_id(&self) -> &HydrationKey {
             &self.id
           }
-
-          generate_math_tags! { @void $($void)? }
         }
 
         #[$meta]
@@ -169,78 +193,95 @@ macro_rules! generate_math_tags {
       )*
     }
   };
-  (@void) => {};
-  (@void void) => {
-    fn is_void(&self) -> bool {
-      true
-    }
-  };
+  (@void) => { false };
+  (@void void) => { true };
 }
 
 generate_math_tags![
-    /// MathML element.
+    /// The `<math>` element is the top-level element of a formula. Set
+    /// `display="block"` to render it on its own line.
     math,
-    /// MathML element.
+    /// The `<mi>` element is an identifier, such as a variable or function
+    /// name.
     mi,
-    /// MathML element.
+    /// The `<mn>` element is a numeric literal.
     mn,
-    /// MathML element.
+    /// The `<mo>` element is an operator, such as `+`, or a fence, separator,
+    /// or accent.
     mo,
-    /// MathML element.
+    /// The `<ms>` element is a string literal.
     ms,
-    /// MathML element.
+    /// The `<mspace>` element is a blank space, sized by its `width`, `height`,
+    /// and `depth`.
     mspace,
-    /// MathML element.
+    /// The `<mtext>` element is text that has no notational meaning, such as a
+    /// comment.
     mtext,
-    /// MathML element.
+    /// The `<menclose>` element draws a notation, like a box or a strike-
+    /// through, around its content. It isn't part of MathML Core, so not every
+    /// browser supports it.
     menclose,
-    /// MathML element.
+    /// The `<merror>` element displays its content as an error message.
     merror,
-    /// MathML element.
+    /// The `<mfenced>` element surrounds its content with fences, like
+    /// parentheses. It is deprecated: use `<mrow>` with `<mo>` fences instead.
     mfenced,
-    /// MathML element.
+    /// The `<mfrac>` element is a fraction of its first child over its second.
     mfrac,
-    /// MathML element.
+    /// The `<mpadded>` element adjusts the space around its content.
     mpadded,
-    /// MathML element.
+    /// The `<mphantom>` element takes up the space of its content without
+    /// showing it.
     mphantom,
-    /// MathML element.
+    /// The `<mroot>` element is a root of its first child, with the index given
+    /// by its second.
     mroot,
-    /// MathML element.
+    /// The `<mrow>` element groups other elements into a single expression.
     mrow,
-    /// MathML element.
+    /// The `<msqrt>` element is the square root of its content.
     msqrt,
-    /// MathML element.
+    /// The `<mstyle>` element sets styles that are inherited by its content.
     mstyle,
-    /// MathML element.
+    /// The `<mmultiscripts>` element attaches any number of pre- and
+    /// postscripts to a base, like tensor indices.
     mmultiscripts,
-    /// MathML element.
+    /// The `<mover>` element puts its second child above its first, like an
+    /// accent.
     mover,
-    /// MathML element.
+    /// The `<mprescripts>` element separates the postscripts from the
+    /// prescripts in an `<mmultiscripts>`.
     mprescripts,
-    /// MathML element.
+    /// The `<msub>` element attaches its second child to its first as a
+    /// subscript.
     msub,
-    /// MathML element.
+    /// The `<msubsup>` element attaches its second and third children to its
+    /// first as a subscript and a superscript.
     msubsup,
-    /// MathML element.
+    /// The `<msup>` element attaches its second child to its first as a
+    /// superscript, like an exponent.
     msup,
-    /// MathML element.
+    /// The `<munder>` element puts its second child below its first.
     munder,
-    /// MathML element.
+    /// The `<munderover>` element puts its second child below its first, and
+    /// its third above it, like the limits of a sum.
     munderover,
-    /// MathML element.
+    /// The `<mtable>` element is a table or matrix, made of `<mtr>` rows.
     mtable,
-    /// MathML element.
+    /// The `<mtd>` element is a cell in an `<mtr>`.
     mtd,
-    /// MathML element.
+    /// The `<mtr>` element is a row in an `<mtable>`.
     mtr,
-    /// MathML element.
+    /// The `<maction>` element binds an action to a subexpression. Browsers
+    /// only show its first child.
     maction,
-    /// MathML element.
+    /// The `<annotation>` element is an alternative text representation of the
+    /// formula in a `<semantics>`, such as its TeX source.
     annotation,
-    /// MathML element.
+    /// The `<annotation-xml>` element is an alternative XML representation of
+    /// the formula in a `<semantics>`, such as content MathML.
     annotation
         - xml,
-    /// MathML element.
+    /// The `<semantics>` element associates annotations, like `<annotation>`,
+    /// with the formula that is its first child.
     semantics,
 ];
@@ -28,7 +28,7 @@ use std::cell::Cell;
 ///       cx,
 ///       <div>
 ///       // `node_ref` loads the input
-///       <input _ref=input_ref type="text"/>
+///       <input node_ref=input_ref type="text"/>
 ///       // the button consumes it
 ///       <button on:click=on_click>"Click me"</button>
 ///       </div>
@@ -64,7 +64,7 @@ pub struct NodeRef<T: ElementDescriptor + 'static>(
 ///       cx,
 ///       <div>
 ///       // `node_ref` loads the input
-///       <input _ref=input_ref type="text"/>
+///       <input node_ref=input_ref type="text"/>
 ///       // the button consumes it
 ///       <button on:click=on_click>"Click me"</button>
 ///       </div>
@@ -84,7 +84,7 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     /// This tracks reactively, so that node references can be used in effects.
     /// Initially, the value will be `None`, but once it is loaded the effect
     /// will rerun and its value will be `Some(Element)`.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn get(&self) -> Option<HtmlElement<T>>
     where
@@ -96,7 +96,7 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     /// Gets the element that is currently stored in the reference.
     ///
     /// This **does not** track reactively.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub fn get_untracked(&self) -> Option<HtmlElement<T>>
     where
@@ -105,11 +105,36 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
         self.0.get_untracked()
     }
 
+    /// Gets the underlying DOM element that is currently stored in the
+    /// reference, without its specific type, e.g., to pass it to a
+    /// `web_sys` API that takes any [`web_sys::Element`].
+    ///
+    /// Like [`get`](Self::get), this tracks reactively. On the server, this
+    /// is always `None`.
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn get_raw(&self) -> Option<web_sys::Element> {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            self.0.with(|el| {
+                el.as_ref().map(|el| {
+                    let el: &web_sys::HtmlElement = el.as_ref();
+                    el.clone().into()
+                })
+            })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            self.0.track();
+            None
+        }
+    }
+
     #[doc(hidden)]
     /// Loads an element into the reference. This tracks reactively,
     /// so that effects that use the node reference will rerun once it is loaded,
     /// i.e., effects can be forward-declared.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn load(&self, node: &HtmlElement<T>)
     where
         T: Clone,
@@ -128,7 +153,10 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     }
 
     /// Runs the provided closure when the `NodeRef` has been connected
-    /// with it's [`HtmlElement`].
+    /// with its [`HtmlElement`].
+    ///
+    /// The closure runs at most once. On the server, elements are never
+    /// loaded, so it never runs.
     #[inline(always)]
     pub fn on_load<F>(self, cx: Scope, f: F)
     where
@@ -139,7 +167,10 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
 
         create_effect(cx, move |_| {
             if let Some(node_ref) = self.get() {
-                f.take().unwrap()(node_ref);
+                // the element can be loaded again, e.g., under a `<Show/>`
+                if let Some(f) = f.take() {
+                    f(node_ref);
+                }
             }
         });
     }
@@ -153,6 +184,12 @@ impl<T: ElementDescriptor> Clone for NodeRef<T> {
 
 impl<T: ElementDescriptor + 'static> Copy for NodeRef<T> {}
 
+impl<T: ElementDescriptor + 'static> std::fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NodeRef").field(&self.0).finish()
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(not(feature = "stable"))] {
         impl<T: Clone + ElementDescriptor + 'static> FnOnce<()> for NodeRef<T> {
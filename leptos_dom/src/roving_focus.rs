@@ -0,0 +1,504 @@
+//! Keyboard navigation for composite widgets, like toolbars, menus,
+//! listboxes, and tab lists.
+//!
+//! In these widgets only one item is in the tab order at a time (a “roving
+//! tabindex”): <kbd>Tab</kbd> moves focus into and out of the widget as a
+//! whole, and the arrow keys move focus between its items. See the
+//! [ARIA Authoring Practices](https://www.w3.org/WAI/ARIA/apg/practices/keyboard-interface/#kbd_roving_tabindex).
+
+use crate::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{create_signal, ReadSignal, Scope};
+
+/// The items that [`RovingOptions::item_selector`] matches by default.
+pub const DEFAULT_ITEM_SELECTOR: &str = "[role=menuitem], \
+     [role=menuitemcheckbox], [role=menuitemradio], [role=option], \
+     [role=tab], [role=radio], [role=treeitem], [data-roving-item]";
+
+/// Which arrow keys move between items.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// <kbd>←</kbd> and <kbd>→</kbd>, e.g., for a toolbar or tab list.
+    Horizontal,
+    /// <kbd>↑</kbd> and <kbd>↓</kbd>, e.g., for a menu or listbox.
+    Vertical,
+    /// All four arrow keys.
+    #[default]
+    Both,
+}
+
+/// Options for [`use_roving_tabindex`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RovingOptions {
+    /// Which arrow keys move between items. Defaults to
+    /// [`Orientation::Both`].
+    pub orientation: Orientation,
+    /// Whether moving past the last item goes back to the first, and vice
+    /// versa. Defaults to `true`.
+    pub wrap: bool,
+    /// Whether typing characters moves to the next item whose text starts
+    /// with them. Defaults to `true`.
+    pub typeahead: bool,
+    /// A CSS selector for the items within the container. Items with a
+    /// `disabled` attribute or `aria-disabled="true"` are skipped. Defaults to
+    /// [`DEFAULT_ITEM_SELECTOR`].
+    pub item_selector: &'static str,
+}
+
+impl Default for RovingOptions {
+    fn default() -> Self {
+        Self {
+            orientation: Orientation::default(),
+            wrap: true,
+            typeahead: true,
+            item_selector: DEFAULT_ITEM_SELECTOR,
+        }
+    }
+}
+
+/// Manages a roving tabindex for the items within the element loaded into
+/// `container`, returning a signal of the index of the active item.
+///
+/// The active item has `tabindex="0"`, and every other item has
+/// `tabindex="-1"`. Initially, the active item is the first one that was
+/// rendered with `tabindex="0"`, e.g., the selected tab, or else the first
+/// item. Within the container:
+/// - the arrow keys move focus to the previous or next item,
+/// - <kbd>Home</kbd> and <kbd>End</kbd> move focus to the first or last item,
+/// - typing moves focus to the next item whose text starts with the typed
+///   characters, and
+/// - clicking or otherwise focusing an item makes it the active one.
+///
+/// Items that are added or removed later are picked up automatically. The
+/// listeners are removed when `cx` is disposed. On the server, the active
+/// index is always `0`, and no `tabindex` is set.
+///
+/// ```
+/// # use leptos::*;
+/// use leptos::{
+///     html::Div,
+///     leptos_dom::roving_focus::{
+///         use_roving_tabindex, Orientation, RovingOptions,
+///     },
+/// };
+///
+/// #[component]
+/// pub fn Toolbar(cx: Scope) -> impl IntoView {
+///     let toolbar = create_node_ref::<Div>(cx);
+///     use_roving_tabindex(
+///         cx,
+///         toolbar,
+///         RovingOptions {
+///             orientation: Orientation::Horizontal,
+///             item_selector: "button",
+///             ..Default::default()
+///         },
+///     );
+///
+///     view! { cx,
+///         <div node_ref=toolbar role="toolbar" aria-label="Formatting">
+///             <button>"Bold"</button>
+///             <button>"Italic"</button>
+///             <button>"Underline"</button>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_roving_tabindex<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    container: NodeRef<T>,
+    options: RovingOptions,
+) -> ReadSignal<usize> {
+    let (active, set_active) = create_signal(cx, 0);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    container.on_load(cx, move |el| {
+        let el: web_sys::Element = el.element.as_ref().clone().into();
+        web::attach(cx, el, options, set_active);
+    });
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = container;
+        _ = options;
+        _ = set_active;
+    }
+
+    active
+}
+
+/// Tracks whether the element loaded into `node_ref` has focus that should
+/// be shown, i.e., it matches
+/// [`:focus-visible`](https://developer.mozilla.org/en-US/docs/Web/CSS/:focus-visible).
+///
+/// Browsers show focus after keyboard navigation but usually not after a
+/// click, and this lets you do the same for styles or effects that can’t be
+/// written in CSS. On the server, this is always `false`.
+pub fn use_focus_visible<T: ElementDescriptor + Clone + 'static>(
+    cx: Scope,
+    node_ref: NodeRef<T>,
+) -> ReadSignal<bool> {
+    let (focus_visible, set_focus_visible) = create_signal(cx, false);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    node_ref.on_load(cx, move |el| {
+        use leptos_reactive::SignalSet;
+
+        let el: web_sys::Element = el.element.as_ref().clone().into();
+        web::listen(cx, &el, "focus", {
+            let el = el.clone();
+            move |_| {
+                // browsers without `:focus-visible` show focus everywhere
+                let visible = el.matches(":focus-visible").unwrap_or(true);
+                set_focus_visible.set(visible);
+            }
+        });
+        web::listen(cx, &el, "blur", move |_| set_focus_visible.set(false));
+    });
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = node_ref;
+        _ = set_focus_visible;
+    }
+
+    focus_visible
+}
+
+/// Returns the index of the item to move to when `key` is pressed, if it’s
+/// a navigation key.
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+fn navigate(
+    key: &str,
+    current: usize,
+    len: usize,
+    options: &RovingOptions,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let previous = || match current.checked_sub(1) {
+        Some(idx) => Some(idx.min(last)),
+        None if options.wrap => Some(last),
+        None => Some(0),
+    };
+    let next = || match current {
+        idx if idx < last => Some(idx + 1),
+        _ if options.wrap => Some(0),
+        _ => Some(last),
+    };
+    let horizontal = options.orientation != Orientation::Vertical;
+    let vertical = options.orientation != Orientation::Horizontal;
+
+    match key {
+        "Home" => Some(0),
+        "End" => Some(last),
+        "ArrowLeft" if horizontal => previous(),
+        "ArrowRight" if horizontal => next(),
+        "ArrowUp" if vertical => previous(),
+        "ArrowDown" if vertical => next(),
+        _ => None,
+    }
+}
+
+/// Returns the index of the next item whose text starts with `typed`, the
+/// lowercase characters typed so far, given the `texts` of the items.
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+fn typeahead_target(
+    typed: &str,
+    active: usize,
+    texts: &[String],
+) -> Option<usize> {
+    // typing the same character again cycles through the items that
+    // start with it
+    let mut chars = typed.chars();
+    let first = chars.next()?;
+    let repeated = chars.all(|c| c == first);
+    let (search, start) = if repeated {
+        (first.to_string(), active + 1)
+    } else {
+        (typed.to_string(), active)
+    };
+
+    let len = texts.len();
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|idx| texts[*idx].trim().to_lowercase().starts_with(&search))
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use super::{navigate, typeahead_target, RovingOptions};
+    use leptos_reactive::{on_cleanup, Scope, SignalSet, WriteSignal};
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    /// How long to wait after a keypress before typeahead starts over, in
+    /// milliseconds.
+    const TYPEAHEAD_TIMEOUT: f64 = 500.0;
+
+    #[derive(Default)]
+    struct State {
+        active: usize,
+        typed: String,
+        last_typed: f64,
+    }
+
+    pub(super) fn attach(
+        cx: Scope,
+        container: web_sys::Element,
+        options: RovingOptions,
+        set_active: WriteSignal<usize>,
+    ) {
+        let state = Rc::new(RefCell::new(State::default()));
+
+        let activate = {
+            let container = container.clone();
+            let state = Rc::clone(&state);
+            move |idx: Option<usize>, focus: bool| {
+                let items = items(&container, &options);
+                let mut state = state.borrow_mut();
+                let idx = idx
+                    .unwrap_or(state.active)
+                    .min(items.len().saturating_sub(1));
+                for (item_idx, item) in items.iter().enumerate() {
+                    let tabindex = if item_idx == idx { "0" } else { "-1" };
+                    if item.get_attribute("tabindex").as_deref()
+                        != Some(tabindex)
+                    {
+                        _ = item.set_attribute("tabindex", tabindex);
+                    }
+                }
+                if focus {
+                    if let Some(item) = items.get(idx) {
+                        _ = item.focus();
+                    }
+                }
+                if state.active != idx {
+                    state.active = idx;
+                    drop(state);
+                    set_active.set(idx);
+                }
+            }
+        };
+        let activate = Rc::new(activate);
+        // start from an item that was rendered as the active one
+        let initial = items(&container, &options).iter().position(|item| {
+            item.get_attribute("tabindex").as_deref() == Some("0")
+        });
+        activate(initial, false);
+
+        // pick up items that are added or removed
+        let on_mutation = Closure::<dyn FnMut()>::new({
+            let activate = Rc::clone(&activate);
+            move || activate(None, false)
+        });
+        if let Ok(observer) =
+            web_sys::MutationObserver::new(on_mutation.as_ref().unchecked_ref())
+        {
+            let mut init = web_sys::MutationObserverInit::new();
+            init.child_list(true).subtree(true);
+            _ = observer.observe_with_options(&container, &init);
+            on_cleanup(cx, move || {
+                observer.disconnect();
+                drop(on_mutation);
+            });
+        }
+
+        listen(cx, &container, "focusin", {
+            let container = container.clone();
+            let activate = Rc::clone(&activate);
+            move |ev| {
+                let items = items(&container, &options);
+                let target = ev.target();
+                if let Some(idx) = items.iter().position(|item| {
+                    target.as_ref().map_or(false, |target| {
+                        js_sys::Object::is(target, item)
+                    })
+                }) {
+                    activate(Some(idx), false);
+                }
+            }
+        });
+
+        listen(cx, &container, "keydown", move |ev| {
+            let ev = ev.unchecked_into::<web_sys::KeyboardEvent>();
+            if ev.default_prevented()
+                || ev.ctrl_key()
+                || ev.meta_key()
+                || ev.alt_key()
+            {
+                return;
+            }
+
+            let items = items(&container, &options);
+            let key = ev.key();
+            let current = state.borrow().active;
+
+            let next =
+                navigate(&key, current, items.len(), &options).or_else(|| {
+                    if !options.typeahead || key.chars().count() != 1 {
+                        return None;
+                    }
+                    typeahead(&state, &items, &key, ev.time_stamp())
+                });
+
+            if let Some(next) = next {
+                ev.prevent_default();
+                activate(Some(next), true);
+            }
+        });
+    }
+
+    /// Returns the index of the next item whose text starts with the
+    /// characters typed so far.
+    fn typeahead(
+        state: &RefCell<State>,
+        items: &[web_sys::HtmlElement],
+        key: &str,
+        time: f64,
+    ) -> Option<usize> {
+        let mut state = state.borrow_mut();
+        if time - state.last_typed > TYPEAHEAD_TIMEOUT {
+            state.typed.clear();
+        }
+        state.last_typed = time;
+        state.typed.push_str(&key.to_lowercase());
+
+        let texts = items
+            .iter()
+            .map(|item| item.text_content().unwrap_or_default())
+            .collect::<Vec<_>>();
+        typeahead_target(&state.typed, state.active, &texts)
+    }
+
+    fn items(
+        container: &web_sys::Element,
+        options: &RovingOptions,
+    ) -> Vec<web_sys::HtmlElement> {
+        let Ok(nodes) = container.query_selector_all(options.item_selector)
+        else {
+            return Vec::new();
+        };
+        (0..nodes.length())
+            .filter_map(|idx| nodes.item(idx))
+            .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+            .filter(|item| {
+                !item.has_attribute("disabled")
+                    && item.get_attribute("aria-disabled").as_deref()
+                        != Some("true")
+            })
+            .collect()
+    }
+
+    /// Adds an event listener to `el` that is removed when `cx` is disposed.
+    pub(super) fn listen(
+        cx: Scope,
+        el: &web_sys::Element,
+        event_name: &'static str,
+        cb: impl Fn(web_sys::Event) + 'static,
+    ) {
+        let cb = Closure::wrap(Box::new(cb) as Box<dyn FnMut(web_sys::Event)>)
+            .into_js_value();
+        _ = el.add_event_listener_with_callback(
+            wasm_bindgen::intern(event_name),
+            cb.unchecked_ref(),
+        );
+
+        let el = el.clone();
+        on_cleanup(cx, move || {
+            _ = el.remove_event_listener_with_callback(
+                event_name,
+                cb.unchecked_ref(),
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(orientation: Orientation, wrap: bool) -> RovingOptions {
+        RovingOptions {
+            orientation,
+            wrap,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn arrow_keys_follow_the_orientation() {
+        let horizontal = options(Orientation::Horizontal, true);
+        assert_eq!(navigate("ArrowRight", 0, 3, &horizontal), Some(1));
+        assert_eq!(navigate("ArrowLeft", 1, 3, &horizontal), Some(0));
+        assert_eq!(navigate("ArrowDown", 0, 3, &horizontal), None);
+
+        let vertical = options(Orientation::Vertical, true);
+        assert_eq!(navigate("ArrowDown", 0, 3, &vertical), Some(1));
+        assert_eq!(navigate("ArrowUp", 1, 3, &vertical), Some(0));
+        assert_eq!(navigate("ArrowRight", 0, 3, &vertical), None);
+
+        let both = options(Orientation::Both, true);
+        assert_eq!(navigate("ArrowRight", 0, 3, &both), Some(1));
+        assert_eq!(navigate("ArrowDown", 1, 3, &both), Some(2));
+        assert_eq!(navigate("a", 0, 3, &both), None);
+    }
+
+    #[test]
+    fn home_and_end_go_to_either_end() {
+        let both = options(Orientation::Both, false);
+        assert_eq!(navigate("Home", 2, 3, &both), Some(0));
+        assert_eq!(navigate("End", 0, 3, &both), Some(2));
+        assert_eq!(navigate("End", 0, 0, &both), None);
+    }
+
+    #[test]
+    fn wrapping_is_optional() {
+        let wrap = options(Orientation::Both, true);
+        assert_eq!(navigate("ArrowDown", 2, 3, &wrap), Some(0));
+        assert_eq!(navigate("ArrowUp", 0, 3, &wrap), Some(2));
+
+        let stop = options(Orientation::Both, false);
+        assert_eq!(navigate("ArrowDown", 2, 3, &stop), Some(2));
+        assert_eq!(navigate("ArrowUp", 0, 3, &stop), Some(0));
+    }
+
+    #[test]
+    fn items_that_went_away_are_skipped() {
+        // the active item was past the end of a list that has shrunk
+        let both = options(Orientation::Both, false);
+        assert_eq!(navigate("ArrowUp", 5, 3, &both), Some(2));
+        assert_eq!(navigate("ArrowDown", 5, 3, &both), Some(2));
+    }
+
+    #[test]
+    fn typeahead_finds_the_next_match() {
+        let texts = ["Apple", " banana", "Blueberry", "cherry"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(typeahead_target("b", 0, &texts), Some(1));
+        assert_eq!(typeahead_target("bl", 1, &texts), Some(2));
+        assert_eq!(typeahead_target("c", 0, &texts), Some(3));
+        assert_eq!(typeahead_target("z", 0, &texts), None);
+        assert_eq!(typeahead_target("", 0, &texts), None);
+    }
+
+    #[test]
+    fn repeating_a_character_cycles_through_matches() {
+        let texts = ["Apple", "banana", "Blueberry", "cherry"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(typeahead_target("bb", 1, &texts), Some(2));
+        assert_eq!(typeahead_target("bbb", 2, &texts), Some(1));
+    }
+
+    #[test]
+    fn nothing_is_active_on_the_server() {
+        use leptos_reactive::{create_runtime, create_scope, SignalGet};
+
+        _ = create_scope(create_runtime(), |cx| {
+            let container = crate::create_node_ref::<crate::html::Div>(cx);
+            let active =
+                use_roving_tabindex(cx, container, RovingOptions::default());
+            assert_eq!(active.get(), 0);
+        });
+    }
+}
@@ -0,0 +1,238 @@
+//! Registers a [service worker](https://developer.mozilla.org/en-US/docs/Web/API/Service_Worker_API)
+//! and tracks when a new version of it is waiting to take over.
+//!
+//! ```
+//! # use leptos::*;
+//! use leptos::leptos_dom::service_worker::register_service_worker;
+//!
+//! #[component]
+//! pub fn UpdateBanner(cx: Scope) -> impl IntoView {
+//!     let sw = register_service_worker(cx, "/sw.js");
+//!
+//!     view! { cx,
+//!       <Show when=move || sw.update_available().get() fallback=|_| ()>
+//!         <button on:click=move |_| sw.skip_waiting_and_reload()>
+//!           "A new version is available. Reload?"
+//!         </button>
+//!       </Show>
+//!     }
+//! }
+//! ```
+
+use leptos_reactive::{
+    create_signal, store_value, ReadSignal, Scope, StoredValue,
+};
+
+/// The message posted to the waiting service worker by
+/// [`ServiceWorker::skip_waiting_and_reload`].
+///
+/// The service worker script should respond by calling `self.skipWaiting()`:
+/// ```js
+/// self.addEventListener("message", (event) => {
+///   if (event.data && event.data.type === "SKIP_WAITING") {
+///     self.skipWaiting();
+///   }
+/// });
+/// ```
+pub const SKIP_WAITING_MESSAGE: &str = "SKIP_WAITING";
+
+/// The state of a service worker registration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistrationState {
+    /// Service workers are not supported in this environment, including
+    /// during server-side rendering.
+    Unsupported,
+    /// Registration has started but not yet finished.
+    Registering,
+    /// The service worker was registered successfully.
+    Registered,
+    /// Registration failed with the given error.
+    Failed(String),
+}
+
+/// A service worker registration, created with [`register_service_worker`].
+#[derive(Copy, Clone)]
+pub struct ServiceWorker {
+    state: ReadSignal<RegistrationState>,
+    update_available: ReadSignal<bool>,
+    #[cfg_attr(
+        not(all(target_arch = "wasm32", feature = "web")),
+        allow(dead_code)
+    )]
+    registration: StoredValue<Option<web_sys::ServiceWorkerRegistration>>,
+}
+
+impl ServiceWorker {
+    /// The current state of the registration.
+    #[inline(always)]
+    pub fn state(&self) -> ReadSignal<RegistrationState> {
+        self.state
+    }
+
+    /// Whether a new version of the service worker has been installed and
+    /// is waiting for the current one to release control of the page.
+    #[inline(always)]
+    pub fn update_available(&self) -> ReadSignal<bool> {
+        self.update_available
+    }
+
+    /// Asks the browser to check the server for a new version of the
+    /// service worker script.
+    pub fn check_for_update(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        self.registration.with_value(|registration| {
+            if let Some(registration) = registration {
+                _ = registration.update();
+            }
+        });
+    }
+
+    /// Tells the waiting service worker to take control by posting it
+    /// [`SKIP_WAITING_MESSAGE`], and reloads the page once it has done so.
+    ///
+    /// Does nothing if no update is available.
+    pub fn skip_waiting_and_reload(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use wasm_bindgen::{prelude::Closure, JsCast};
+
+            let Some(waiting) = self
+                .registration
+                .with_value(|r| r.as_ref().and_then(|r| r.waiting()))
+            else {
+                return;
+            };
+
+            let container = crate::window().navigator().service_worker();
+            let reload = Closure::once_into_js(|| {
+                _ = crate::helpers::location().reload();
+            });
+            let mut options = web_sys::AddEventListenerOptions::new();
+            options.once(true);
+            _ = container
+                .add_event_listener_with_callback_and_add_event_listener_options(
+                    "controllerchange",
+                    reload.unchecked_ref(),
+                    &options,
+                );
+
+            let message = js_sys::Object::new();
+            _ = js_sys::Reflect::set(
+                &message,
+                &"type".into(),
+                &SKIP_WAITING_MESSAGE.into(),
+            );
+            _ = waiting.post_message(&message);
+        }
+    }
+}
+
+/// Registers the service worker script at `script_url`, returning a
+/// [`ServiceWorker`] whose signals track the registration and whether an
+/// update is waiting.
+///
+/// On the server, or in browsers without service worker support, the state
+/// is [`RegistrationState::Unsupported`] and no update is ever available.
+pub fn register_service_worker(cx: Scope, script_url: &str) -> ServiceWorker {
+    let (state, set_state) = create_signal(cx, RegistrationState::Unsupported);
+    let (update_available, set_update_available) = create_signal(cx, false);
+    let registration = store_value(cx, None);
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use leptos_reactive::SignalSet;
+        use wasm_bindgen::JsValue;
+
+        let navigator = crate::window().navigator();
+        let supported = js_sys::Reflect::has(
+            &navigator,
+            &JsValue::from_str("serviceWorker"),
+        )
+        .unwrap_or(false);
+
+        if supported {
+            set_state.set(RegistrationState::Registering);
+            let promise = navigator.service_worker().register(script_url);
+
+            leptos_reactive::spawn_local(async move {
+                match wasm_bindgen_futures::JsFuture::from(promise).await {
+                    Ok(reg) => {
+                        use wasm_bindgen::JsCast;
+
+                        let reg = reg
+                            .unchecked_into::<web_sys::ServiceWorkerRegistration>();
+                        watch_for_updates(&reg, move || {
+                            set_update_available.set(true)
+                        });
+                        registration.set_value(Some(reg));
+                        set_state.set(RegistrationState::Registered);
+                    }
+                    Err(e) => set_state.set(RegistrationState::Failed(
+                        e.as_string().unwrap_or_else(|| format!("{e:?}")),
+                    )),
+                }
+            });
+        }
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = script_url;
+        _ = set_state;
+        _ = set_update_available;
+    }
+
+    ServiceWorker {
+        state,
+        update_available,
+        registration,
+    }
+}
+
+/// Calls `on_update` when a new service worker has installed while an older
+/// one still controls the page, either already or in the future.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn watch_for_updates(
+    reg: &web_sys::ServiceWorkerRegistration,
+    on_update: impl Fn() + Clone + 'static,
+) {
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    fn has_controller() -> bool {
+        crate::window()
+            .navigator()
+            .service_worker()
+            .controller()
+            .is_some()
+    }
+
+    if reg.waiting().is_some() && has_controller() {
+        on_update();
+    }
+
+    let on_update_found = Closure::wrap(Box::new({
+        let reg = reg.clone();
+        move || {
+            let Some(installing) = reg.installing() else {
+                return;
+            };
+            let on_state_change = Closure::wrap(Box::new({
+                let installing = installing.clone();
+                let on_update = on_update.clone();
+                move || {
+                    if installing.state()
+                        == web_sys::ServiceWorkerState::Installed
+                        && has_controller()
+                    {
+                        on_update();
+                    }
+                }
+            })
+                as Box<dyn FnMut()>)
+            .into_js_value();
+            installing.set_onstatechange(Some(on_state_change.unchecked_ref()));
+        }
+    }) as Box<dyn FnMut()>)
+    .into_js_value();
+    reg.set_onupdatefound(Some(on_update_found.unchecked_ref()));
+}
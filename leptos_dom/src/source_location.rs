@@ -0,0 +1,109 @@
+//! Source locations of the elements and components created by `view!`.
+//!
+//! In debug builds, the `view!` macro records the file, line and column of
+//! every element and component it creates. Each location is attached to the
+//! DOM node that was created, so it can be looked up with
+//! [`source_location`], and is included in hydration error messages.
+
+#[cfg(debug_assertions)]
+use std::cell::Cell;
+use std::fmt;
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+use {std::cell::RefCell, std::collections::HashMap, wasm_bindgen::JsValue};
+
+/// A location in a `view!` block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The file containing the `view!` block.
+    pub file: &'static str,
+    /// The line, starting at 1.
+    pub line: u32,
+    /// The column, starting at 1.
+    pub column: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static NEXT: Cell<Option<SourceLocation>> = Cell::new(None);
+}
+
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+thread_local! {
+    // DOM nodes store an index into this list, so the locations can be
+    // handed back out as `&'static str`s
+    static LOCATIONS: RefCell<(
+        Vec<SourceLocation>,
+        HashMap<SourceLocation, usize>,
+    )> = Default::default();
+}
+
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+const PROPERTY: &str = "$$leptos_source";
+
+/// Sets the location of the next element or component to be created.
+/// Does nothing in release builds.
+#[doc(hidden)]
+#[inline(always)]
+pub fn set_next_source_location(location: SourceLocation) {
+    #[cfg(debug_assertions)]
+    NEXT.with(|next| next.set(Some(location)));
+    #[cfg(not(debug_assertions))]
+    _ = location;
+}
+
+/// Takes the location set by the `view!` macro for the node being created.
+#[cfg(debug_assertions)]
+pub(crate) fn take_next() -> Option<SourceLocation> {
+    NEXT.with(Cell::take)
+}
+
+/// Describes the location of the node being created, for error messages.
+/// Always empty in release builds.
+#[allow(dead_code)] // not used on the server
+pub(crate) fn describe_next() -> String {
+    #[cfg(debug_assertions)]
+    return NEXT
+        .with(Cell::get)
+        .map(|location| format!(" (created at {location})"))
+        .unwrap_or_default();
+
+    #[cfg(not(debug_assertions))]
+    String::new()
+}
+
+/// Attaches a location to a DOM node, so it can be found with
+/// [`source_location`].
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+pub(crate) fn attach(node: &web_sys::Node, location: SourceLocation) {
+    let index = LOCATIONS.with(|locations| {
+        let (list, indices) = &mut *locations.borrow_mut();
+        *indices.entry(location).or_insert_with(|| {
+            list.push(location);
+            list.len() - 1
+        })
+    });
+    _ = js_sys::Reflect::set(
+        node,
+        &JsValue::from_str(PROPERTY),
+        &JsValue::from_f64(index as f64),
+    );
+}
+
+/// Returns the location in a `view!` block at which this DOM node was
+/// created, if it was created by the `view!` macro in a debug build.
+///
+/// Component locations are attached to the component's opening marker.
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+pub fn source_location(node: &web_sys::Node) -> Option<SourceLocation> {
+    let index = js_sys::Reflect::get(node, &JsValue::from_str(PROPERTY))
+        .ok()?
+        .as_f64()?;
+    LOCATIONS
+        .with(|locations| locations.borrow().0.get(index as usize).copied())
+}
@@ -8,9 +8,8 @@ use crate::{
 };
 use cfg_if::cfg_if;
 use futures::{stream::FuturesUnordered, Future, Stream, StreamExt};
-use itertools::Itertools;
 use leptos_reactive::*;
-use std::{borrow::Cow, pin::Pin};
+use std::{borrow::Cow, fmt, io, pin::Pin};
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
 
@@ -47,6 +46,124 @@ where
     html.into()
 }
 
+/// Runs `f` in a mode meant for snapshot tests of server-rendered HTML,
+/// where every ID the renderer generates comes from a counter that starts at
+/// `seed`, so the output is the same byte for byte across runs.
+///
+/// Hydration keys are reset to `seed` at the start of each render inside
+/// `f`, and also right away, so that calling
+/// [`View::render_to_string`] directly is stable too. The class names and
+/// `id`s that components generate with [`HydrationCtx::generated_name`] are
+/// derived from the hydration keys, so they are seeded as well. Resource IDs
+/// are already numbered from the start of each [runtime](create_runtime), and
+/// the serialized contexts and `<Suspense/>` fragments of a stream are
+/// always written in a stable order.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// let render = || {
+///     leptos::ssr::with_seeded_ids(7, || {
+///         leptos::ssr::render_to_string(|cx| view! { cx, <p>"Hello!"</p> })
+///     })
+/// };
+/// assert_eq!(render(), render());
+/// assert!(render().contains("_7-1"));
+/// # }}
+/// ```
+pub fn with_seeded_ids<T>(seed: usize, f: impl FnOnce() -> T) -> T {
+    HydrationCtx::with_seed(seed, f)
+}
+
+/// Renders the given function as HTML into any [`fmt::Write`], such as a
+/// `String` you have already allocated.
+///
+/// This writes each element directly into `writer`, rather than building
+/// a new string for each element and component, so it avoids repeated
+/// reallocations when rendering large pages.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// let mut html = String::with_capacity(4096);
+/// leptos::ssr::render_to_writer(
+///     |cx| view! { cx, <p>"Hello, world!"</p> },
+///     &mut html,
+/// )
+/// .unwrap();
+/// assert!(html.contains("Hello, world!</p>"));
+/// # }}
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_writer<F, N, W>(f: F, writer: &mut W) -> fmt::Result
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+    W: fmt::Write,
+{
+    let runtime = leptos_reactive::create_runtime();
+    HydrationCtx::reset_id();
+
+    // `run_scope` requires a `'static` closure, which can't borrow `writer`
+    let (view, _, disposer) =
+        leptos_reactive::run_scope_undisposed(runtime, |cx| {
+            f(cx).into_view(cx)
+        });
+    let res = view.render_to_writer(writer);
+
+    disposer.dispose();
+    runtime.dispose();
+
+    res
+}
+
+/// Renders the given function as HTML into any [`io::Write`], such as a
+/// file or a socket.
+///
+/// See [`render_to_writer`]. Wrap unbuffered writers in a
+/// [`BufWriter`](std::io::BufWriter), as the HTML is written in many small
+/// pieces.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_io_writer<F, N, W>(f: F, writer: &mut W) -> io::Result<()>
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+    W: io::Write,
+{
+    let mut adapter = IoAdapter {
+        inner: writer,
+        error: None,
+    };
+    match render_to_writer(f, &mut adapter) {
+        Ok(()) => Ok(()),
+        Err(fmt::Error) => Err(adapter.error.unwrap_or_else(|| {
+            io::Error::new(io::ErrorKind::Other, "formatter error")
+        })),
+    }
+}
+
+/// Forwards [`fmt::Write`] to an [`io::Write`], holding on to the
+/// underlying error, since [`fmt::Error`] can't carry one.
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
 /// Renders a function to a stream of HTML strings.
 ///
 /// This renders:
@@ -59,6 +176,11 @@ where
 ///    it is waiting for a resource to resolve from the server, it doesn't run it initially.
 /// 3) HTML fragments to replace each `<Suspense/>` fallback with its actual data as the resources
 ///    read under that `<Suspense/>` resolve.
+///
+/// The shell is sent as a single chunk as soon as it has been rendered (and
+/// any blocking resources have loaded). After that, fragments and resource
+/// data are sent in whatever order they resolve, so one slow `<Suspense/>`
+/// doesn’t hold up the rest of the page.
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     instrument(level = "info", skip_all,)
@@ -185,37 +307,154 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
     additional_context: impl FnOnce(Scope) + 'static,
     replace_blocks: bool,
 ) -> (impl Stream<Item = String>, RuntimeId, ScopeId) {
+    render_to_stream_out_of_order(
+        view,
+        prefix,
+        additional_context,
+        replace_blocks,
+        None,
+    )
+}
+
+/// A time limit for rendering a page with
+/// [`render_to_stream_with_timeout`].
+///
+/// The timer is any future that resolves when time is up, like
+/// `tokio::time::sleep(duration)`, so that it can come from whichever
+/// runtime the server uses.
+pub struct RenderTimeout {
+    timer: PinnedFuture<()>,
+    error_page: Option<Cow<'static, str>>,
+}
+
+impl RenderTimeout {
+    /// Creates a timeout that expires when `timer` resolves.
+    ///
+    /// By default, when the timeout expires, any `<Suspense/>` that has not
+    /// resolved keeps showing its fallback, and the resources it was waiting
+    /// for are loaded by the client instead.
+    pub fn new(timer: impl Future<Output = ()> + 'static) -> Self {
+        Self {
+            timer: Box::pin(timer),
+            error_page: None,
+        }
+    }
+
+    /// If the timeout expires before anything has been sent (while waiting
+    /// for blocking resources), send this HTML instead of the page.
+    pub fn error_page(mut self, html: impl Into<Cow<'static, str>>) -> Self {
+        self.error_page = Some(html.into());
+        self
+    }
+}
+
+impl std::fmt::Debug for RenderTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderTimeout")
+            .field("error_page", &self.error_page)
+            .finish()
+    }
+}
+
+/// Renders a function to a stream of HTML strings, like
+/// [`render_to_stream_with_prefix_undisposed_with_context_and_block_replacement`],
+/// but stops waiting for async data once the `timeout` expires, so that one
+/// slow resource can’t hold up the whole response.
+///
+/// When the timeout expires:
+/// 1) if the shell is still waiting for blocking resources, it is sent with
+///    the fallbacks in place of the blocked fragments, or the timeout’s
+///    [error page](RenderTimeout::error_page) is sent instead if it has one;
+/// 2) any `<Suspense/>` fragments that have not been sent keep showing their
+///    fallbacks; and
+/// 3) the stream ends with a script telling the client to load any resources
+///    the server did not finish itself.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_stream_with_timeout(
+    view: impl FnOnce(Scope) -> View + 'static,
+    prefix: impl FnOnce(Scope) -> Cow<'static, str> + 'static,
+    additional_context: impl FnOnce(Scope) + 'static,
+    replace_blocks: bool,
+    timeout: RenderTimeout,
+) -> (impl Stream<Item = String>, RuntimeId, ScopeId) {
+    render_to_stream_out_of_order(
+        view,
+        prefix,
+        additional_context,
+        replace_blocks,
+        Some(timeout),
+    )
+}
+
+fn render_to_stream_out_of_order(
+    view: impl FnOnce(Scope) -> View + 'static,
+    prefix: impl FnOnce(Scope) -> Cow<'static, str> + 'static,
+    additional_context: impl FnOnce(Scope) + 'static,
+    replace_blocks: bool,
+    timeout: Option<RenderTimeout>,
+) -> (impl Stream<Item = String>, RuntimeId, ScopeId) {
+    use futures::{future::Either, FutureExt};
+    use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
     HydrationCtx::reset_id();
 
     // create the runtime
     let runtime = create_runtime();
 
-    let ((shell, pending_resources, pending_fragments, serializers), scope, _) =
-        run_scope_undisposed(runtime, {
-            move |cx| {
-                // Add additional context items
-                additional_context(cx);
-                // the actual app body/template code
-                // this does NOT contain any of the data being loaded asynchronously in resources
-                let shell = view(cx).render_to_string(cx);
-
-                let resources = cx.pending_resources();
-                let pending_resources =
-                    serde_json::to_string(&resources).unwrap();
-
-                (
-                    shell,
-                    pending_resources,
-                    cx.pending_fragments(),
-                    cx.serialization_resolvers(),
-                )
-            }
-        });
+    let (
+        (
+            shell,
+            resource_ids,
+            pending_resources,
+            serialized_contexts,
+            pending_fragments,
+            serializers,
+        ),
+        scope,
+        _,
+    ) = run_scope_undisposed(runtime, {
+        move |cx| {
+            // Add additional context items
+            additional_context(cx);
+            // the actual app body/template code
+            // this does NOT contain any of the data being loaded asynchronously in resources
+            let shell = view(cx).render_to_string(cx);
+
+            let resources = cx.pending_resources();
+            let pending_resources = serde_json::to_string(&resources).unwrap();
+
+            (
+                shell,
+                resources,
+                pending_resources,
+                cx.serialized_contexts(),
+                cx.pending_fragments(),
+                cx.serialization_resolvers(),
+            )
+        }
+    });
     let cx = Scope { runtime, id: scope };
 
+    let (timer, error_page) = match timeout {
+        Some(RenderTimeout { timer, error_page }) => (Some(timer), error_page),
+        None => (None, None),
+    };
+    // with no timeout, the timer never fires
+    let timer = timer
+        .unwrap_or_else(|| Box::pin(futures::future::pending()))
+        .shared();
+
     let mut blocking_fragments = FuturesUnordered::new();
     let fragments = FuturesUnordered::new();
 
+    // queued in a stable order, so fragments that are ready at the same time
+    // are always streamed in the same order
+    let mut pending_fragments =
+        pending_fragments.into_iter().collect::<Vec<_>>();
+    pending_fragments.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
     for (fragment_id, data) in pending_fragments {
         if data.should_block {
             blocking_fragments
@@ -229,60 +468,126 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
     // resources and fragments
     // stream HTML for each <Suspense/> as it resolves
     let fragments = fragments_to_chunks(fragments);
-    // stream data for each Resource as it resolves
-    let resources = render_serializers(serializers);
+    // stream data for each Resource as it resolves, keeping track of which
+    // have been sent in case the timeout expires
+    let resolved = Rc::new(RefCell::new(HashSet::new()));
+    let resources = render_serializers(serializers.inspect({
+        let resolved = Rc::clone(&resolved);
+        move |(id, _)| {
+            resolved.borrow_mut().insert(*id);
+        }
+    }));
 
     // HTML for the view function and script to store resources
-    let stream = futures::stream::once(async move {
-        let resolvers = format!(
-            "<script>__LEPTOS_PENDING_RESOURCES = \
-             {pending_resources};__LEPTOS_RESOLVED_RESOURCES = new \
-             Map();__LEPTOS_RESOURCE_RESOLVERS = new Map();</script>"
-        );
+    let stream = futures::stream::once({
+        let timer = timer.clone();
+        async move {
+            let resolvers = format!(
+                "<script>__LEPTOS_PENDING_RESOURCES = \
+                 {pending_resources};__LEPTOS_RESOLVED_RESOURCES = new \
+                 Map();__LEPTOS_RESOURCE_RESOLVERS = new \
+                 Map();__LEPTOS_SERIALIZED_CONTEXT = \
+                 {serialized_contexts};</script>"
+            );
 
-        if replace_blocks {
             let mut blocks = Vec::with_capacity(blocking_fragments.len());
-            while let Some((blocked_id, blocked_fragment)) =
-                blocking_fragments.next().await
-            {
-                blocks.push((blocked_id, blocked_fragment));
+            let all_blocks_ready = {
+                let collect_blocks = Box::pin(async {
+                    while let Some((blocked_id, blocked_fragment)) =
+                        blocking_fragments.next().await
+                    {
+                        blocks.push((blocked_id, blocked_fragment));
+                    }
+                });
+                matches!(
+                    futures::future::select(collect_blocks, timer).await,
+                    Either::Left(_)
+                )
+            };
+            if !all_blocks_ready {
+                if let Some(error_page) = error_page {
+                    return error_page.into_owned();
+                }
             }
 
             let prefix = prefix(cx);
 
-            let mut shell = shell;
+            if replace_blocks {
+                let mut shell = shell;
 
-            for (blocked_id, blocked_fragment) in blocks {
-                let open = format!("<!--suspense-open-{blocked_id}-->");
-                let close = format!("<!--suspense-close-{blocked_id}-->");
-                let (first, rest) = shell.split_once(&open).unwrap_or_default();
-                let (_fallback, rest) =
-                    rest.split_once(&close).unwrap_or_default();
+                for (blocked_id, blocked_fragment) in blocks {
+                    let open = format!("<!--suspense-open-{blocked_id}-->");
+                    let close = format!("<!--suspense-close-{blocked_id}-->");
+                    let (first, rest) =
+                        shell.split_once(&open).unwrap_or_default();
+                    let (_fallback, rest) =
+                        rest.split_once(&close).unwrap_or_default();
 
-                shell = format!("{first}{blocked_fragment}{rest}").into();
-            }
+                    shell = format!("{first}{blocked_fragment}{rest}").into();
+                }
 
-            format!("{prefix}{shell}{resolvers}")
-        } else {
-            let mut blocking = String::new();
-            let mut blocking_fragments =
-                fragments_to_chunks(blocking_fragments);
+                format!("{prefix}{shell}{resolvers}")
+            } else {
+                let mut blocking = String::new();
+                let mut blocking_fragments =
+                    fragments_to_chunks(futures::stream::iter(blocks));
 
-            while let Some(fragment) = blocking_fragments.next().await {
-                blocking.push_str(&fragment);
+                while let Some(fragment) = blocking_fragments.next().await {
+                    blocking.push_str(&fragment);
+                }
+                format!("{prefix}{shell}{resolvers}{blocking}")
             }
-            let prefix = prefix(cx);
-            format!("{prefix}{shell}{resolvers}{blocking}")
         }
     })
-    // TODO these should be combined again in a way that chains them appropriately
-    // such that individual resources can resolve before all fragments are done
-    .chain(fragments)
-    .chain(resources);
+    // fragments and resource data are independent of one another, so each
+    // chunk is sent as soon as it's ready, rather than holding back every
+    // resource until the slowest fragment has resolved. The shell itself is
+    // always sent, even if the timer fires while it's waiting.
+    .chain(
+        futures::stream::select(fragments, resources).take_until(timer.clone()),
+    )
+    .chain(
+        futures::stream::once(async move {
+            // if the timer has fired, hand any unresolved resources over to the client
+            if timer.peek().is_none() {
+                return None;
+            }
+            let resolved = resolved.borrow();
+            let unresolved = resource_ids
+                .iter()
+                .filter(|id| !resolved.contains(*id))
+                .collect::<Vec<_>>();
+            (!unresolved.is_empty())
+                .then(|| render_unresolved_resources(&unresolved))
+        })
+        .filter_map(futures::future::ready),
+    );
 
     (stream, runtime, scope)
 }
 
+/// Tells the client to load the given resources itself, rather than waiting
+/// for the server to send them.
+fn render_unresolved_resources(ids: &[&ResourceId]) -> String {
+    let ids = serde_json::to_string(ids).unwrap();
+    format!(
+        r#"<script>
+              (function() {{
+                  var keys = {ids}.map(function(id) {{ return JSON.stringify(id); }});
+                  __LEPTOS_PENDING_RESOURCES = __LEPTOS_PENDING_RESOURCES.filter(function(id) {{
+                      return keys.indexOf(JSON.stringify(id)) === -1;
+                  }});
+                  keys.forEach(function(key) {{
+                      var resolve = __LEPTOS_RESOURCE_RESOLVERS[key];
+                      if(resolve) {{
+                          resolve(undefined);
+                      }}
+                  }});
+              }})();
+          </script>"#
+    )
+}
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     instrument(level = "trace", skip_all,)
@@ -345,259 +650,345 @@ impl View {
         self,
         dont_escape_text: bool,
     ) -> Cow<'static, str> {
+        let mut html = String::with_capacity(self.size_hint());
+        #[cfg(debug_assertions)]
+        let _nesting = crate::html_nesting::Isolated::new();
+        self.write_html(&mut html, dont_escape_text)
+            .expect("writing to a String to not fail");
+        html.into()
+    }
+
+    /// Consumes the node and writes it as HTML into the given writer.
+    ///
+    /// Unlike [`View::render_to_string`], this does not build up
+    /// intermediate strings for each element and component, so large pages
+    /// can be written straight into a response buffer.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "info", skip_all,)
+    )]
+    pub fn render_to_writer(self, writer: &mut impl fmt::Write) -> fmt::Result {
+        #[cfg(debug_assertions)]
+        let _nesting = crate::html_nesting::Isolated::new();
+        self.write_html(writer, false)
+    }
+
+    /// A rough estimate of the length of this view's HTML, used to
+    /// pre-allocate buffers. Static template chunks and text are counted
+    /// exactly; dynamic parts are estimated.
+    pub fn size_hint(&self) -> usize {
+        // the length of a hydration marker comment, i.e., `<!--hk=_0-1c-->`
+        const MARKER: usize = 16;
+
+        match self {
+            View::Text(node) => node.content.len(),
+            View::Component(node) => {
+                2 * MARKER
+                    + node.children.iter().map(View::size_hint).sum::<usize>()
+            }
+            View::CoreComponent(node) | View::Suspense(_, node) => match node {
+                CoreComponent::Unit(_) => MARKER,
+                CoreComponent::DynChild(node) => {
+                    2 * MARKER
+                        + node
+                            .child
+                            .borrow()
+                            .as_ref()
+                            .as_ref()
+                            .map(View::size_hint)
+                            .unwrap_or_default()
+                }
+                CoreComponent::Each(node) => {
+                    2 * MARKER
+                        + node
+                            .children
+                            .borrow()
+                            .iter()
+                            .flatten()
+                            .map(|item| 2 * MARKER + item.child.size_hint())
+                            .sum::<usize>()
+                }
+            },
+            View::Element(el) => {
+                let tag = 2 * el.tag.as_str().len() + 5;
+                let attrs = el
+                    .attrs
+                    .iter()
+                    .map(|(name, value)| name.len() + value.len() + 4)
+                    .sum::<usize>();
+                let children = match &el.children {
+                    ElementChildren::Empty => 0,
+                    ElementChildren::Children(c) => {
+                        c.iter().map(View::size_hint).sum()
+                    }
+                    ElementChildren::InnerHtml(h) => h.len(),
+                    ElementChildren::Chunks(chunks) => chunks
+                        .iter()
+                        .map(|chunk| match chunk {
+                            StringOrView::String(s) => s.len(),
+                            // dynamic chunks are only known once rendered
+                            StringOrView::View(_) => MARKER,
+                        })
+                        .sum(),
+                };
+                tag + attrs + children
+            }
+            View::Transparent(_) => 0,
+        }
+    }
+
+    fn write_html(
+        self,
+        w: &mut impl fmt::Write,
+        dont_escape_text: bool,
+    ) -> fmt::Result {
         match self {
             View::Text(node) => {
+                #[cfg(debug_assertions)]
+                crate::html_nesting::text(&node.content);
+
                 if dont_escape_text {
-                    node.content
+                    w.write_str(&node.content)
                 } else {
-                    html_escape::encode_safe(&node.content).to_string().into()
+                    w.write_str(&html_escape::encode_safe(&node.content))
                 }
             }
             View::Component(node) => {
-                let content = || {
-                    node.children
-                        .into_iter()
-                        .map(|node| {
-                            node.render_to_string_helper(dont_escape_text)
-                        })
-                        .join("")
-                };
+                #[cfg(debug_assertions)]
+                if let Some(id) = &node.view_marker {
+                    write!(w, "<!--leptos-view|{id}|open-->")?;
+                }
+                #[cfg(debug_assertions)]
+                write!(
+                    w,
+                    "<!--hk={}|leptos-{}-start-->",
+                    HydrationCtx::to_string(&node.id, false),
+                    to_kebab_case(&node.name)
+                )?;
+                #[cfg(debug_assertions)]
+                crate::html_nesting::enter_component(
+                    &node.name,
+                    node.source_location(),
+                );
+
+                for child in node.children {
+                    child.write_html(w, dont_escape_text)?;
+                }
+
+                #[cfg(debug_assertions)]
+                crate::html_nesting::leave_component();
+
                 cfg_if! {
                   if #[cfg(debug_assertions)] {
-                    let content = format!(r#"<!--hk={}|leptos-{name}-start-->{}<!--hk={}|leptos-{name}-end-->"#,
-                      HydrationCtx::to_string(&node.id, false),
-                      content(),
+                    write!(
+                      w,
+                      "<!--hk={}|leptos-{}-end-->",
                       HydrationCtx::to_string(&node.id, true),
-                      name = to_kebab_case(&node.name)
-                    );
-                    if let Some(id) = node.view_marker {
-                        format!("<!--leptos-view|{id}|open-->{content}<!--leptos-view|{id}|close-->").into()
-                    } else {
-                        content.into()
+                      to_kebab_case(&node.name)
+                    )?;
+                    if let Some(id) = &node.view_marker {
+                      write!(w, "<!--leptos-view|{id}|close-->")?;
                     }
+                    Ok(())
                   } else {
-                    format!(
-                      r#"{}<!--hk={}-->"#,
-                      content(),
+                    write!(
+                      w,
+                      "<!--hk={}-->",
                       HydrationCtx::to_string(&node.id, true)
-                    ).into()
+                    )
                   }
                 }
             }
-            View::Suspense(id, node) => format!(
-                "<!--suspense-open-{id}-->{}<!--suspense-close-{id}-->",
-                View::CoreComponent(node)
-                    .render_to_string_helper(dont_escape_text)
-            )
-            .into(),
+            View::Suspense(id, node) => {
+                write!(w, "<!--suspense-open-{id}-->")?;
+                View::CoreComponent(node).write_html(w, dont_escape_text)?;
+                write!(w, "<!--suspense-close-{id}-->")
+            }
             View::CoreComponent(node) => {
-                let (id, name, wrap, content) = match node {
-                    CoreComponent::Unit(u) => (
-                        u.id,
-                        "",
-                        false,
-                        Box::new(move || {
-                            #[cfg(debug_assertions)]
-                            {
-                                format!(
-                                    "<!--hk={}|leptos-unit-->",
-                                    HydrationCtx::to_string(&u.id, true)
-                                )
-                                .into()
-                            }
+                let (id, name) = match &node {
+                    CoreComponent::Unit(u) => {
+                        cfg_if! {
+                          if #[cfg(debug_assertions)] {
+                            return write!(
+                              w,
+                              "<!--hk={}|leptos-unit-->",
+                              HydrationCtx::to_string(&u.id, true)
+                            );
+                          } else {
+                            return write!(
+                              w,
+                              "<!--hk={}-->",
+                              HydrationCtx::to_string(&u.id, true)
+                            );
+                          }
+                        }
+                    }
+                    CoreComponent::DynChild(node) => (node.id, "dyn-child"),
+                    CoreComponent::Each(node) => (node.id, "each"),
+                };
 
-                            #[cfg(not(debug_assertions))]
-                            format!(
-                                "<!--hk={}-->",
-                                HydrationCtx::to_string(&u.id, true)
-                            )
-                            .into()
-                        })
-                            as Box<dyn FnOnce() -> Cow<'static, str>>,
-                    ),
+                #[cfg(debug_assertions)]
+                write!(
+                    w,
+                    "<!--hk={}|leptos-{name}-start-->",
+                    HydrationCtx::to_string(&id, false),
+                )?;
+                #[cfg(not(debug_assertions))]
+                let _ = name;
+
+                match node {
+                    CoreComponent::Unit(_) => unreachable!(),
                     CoreComponent::DynChild(node) => {
-                        let child = node.child.take();
-                        (
-                            node.id,
-                            "dyn-child",
-                            true,
-                            Box::new(move || {
-                                if let Some(child) = *child {
-                                    // On debug builds, `DynChild` has two marker nodes,
-                                    // so there is no way for the text to be merged with
-                                    // surrounding text when the browser parses the HTML,
-                                    // but in release, `DynChild` only has a trailing marker,
-                                    // and the browser automatically merges the dynamic text
-                                    // into one single node, so we need to artificially make the
-                                    // browser create the dynamic text as it's own text node
-                                    if let View::Text(t) = child {
-                                        if !cfg!(debug_assertions) {
-                                            format!("<!>{}", t.content).into()
-                                        } else {
-                                            t.content
-                                        }
-                                    } else {
-                                        child.render_to_string_helper(
-                                            dont_escape_text,
-                                        )
-                                    }
+                        if let Some(child) = *node.child.take() {
+                            // On debug builds, `DynChild` has two marker nodes,
+                            // so there is no way for the text to be merged with
+                            // surrounding text when the browser parses the HTML,
+                            // but in release, `DynChild` only has a trailing marker,
+                            // and the browser automatically merges the dynamic text
+                            // into one single node, so we need to artificially make the
+                            // browser create the dynamic text as it's own text node
+                            if let View::Text(t) = child {
+                                #[cfg(debug_assertions)]
+                                crate::html_nesting::text(&t.content);
+                                if !cfg!(debug_assertions) {
+                                    w.write_str("<!>")?;
+                                }
+                                if dont_escape_text {
+                                    w.write_str(&t.content)?;
                                 } else {
-                                    "".into()
+                                    w.write_str(&html_escape::encode_safe(
+                                        &t.content,
+                                    ))?;
                                 }
-                            })
-                                as Box<dyn FnOnce() -> Cow<'static, str>>,
-                        )
+                            } else {
+                                child.write_html(w, dont_escape_text)?;
+                            }
+                        }
                     }
                     CoreComponent::Each(node) => {
-                        let children = node.children.take();
-                        (
-                            node.id,
-                            "each",
-                            true,
-                            Box::new(move || {
-                                children
-                                    .into_iter()
-                                    .flatten()
-                                    .map(|node| {
-                                        let id = node.id;
-
-                                        let content = || {
-                                            node.child.render_to_string_helper(
-                                                dont_escape_text,
-                                            )
-                                        };
-
-                                        #[cfg(debug_assertions)]
-                                        {
-                                            format!(
-                        "<!--hk={}|leptos-each-item-start-->{}<!\
-                         --hk={}|leptos-each-item-end-->",
-                        HydrationCtx::to_string(&id, false),
-                        content(),
-                        HydrationCtx::to_string(&id, true),
-                      )
-                                        }
-
-                                        #[cfg(not(debug_assertions))]
-                                        format!(
-                                            "{}<!--hk={}-->",
-                                            content(),
-                                            HydrationCtx::to_string(&id, true)
-                                        )
-                                    })
-                                    .join("")
-                                    .into()
-                            })
-                                as Box<dyn FnOnce() -> Cow<'static, str>>,
-                        )
-                    }
-                };
+                        for node in node.children.take().into_iter().flatten() {
+                            let id = node.id;
 
-                if wrap {
-                    cfg_if! {
-                      if #[cfg(debug_assertions)] {
-                        format!(
-                          r#"<!--hk={}|leptos-{name}-start-->{}<!--hk={}|leptos-{name}-end-->"#,
-                          HydrationCtx::to_string(&id, false),
-                          content(),
-                          HydrationCtx::to_string(&id, true),
-                        ).into()
-                      } else {
-                        let _ = name;
-
-                        format!(
-                          r#"{}<!--hk={}-->"#,
-                          content(),
-                          HydrationCtx::to_string(&id, true)
-                        ).into()
-                      }
+                            #[cfg(debug_assertions)]
+                            write!(
+                                w,
+                                "<!--hk={}|leptos-each-item-start-->",
+                                HydrationCtx::to_string(&id, false),
+                            )?;
+
+                            node.child.write_html(w, dont_escape_text)?;
+
+                            #[cfg(debug_assertions)]
+                            write!(
+                                w,
+                                "<!--hk={}|leptos-each-item-end-->",
+                                HydrationCtx::to_string(&id, true),
+                            )?;
+                            #[cfg(not(debug_assertions))]
+                            write!(
+                                w,
+                                "<!--hk={}-->",
+                                HydrationCtx::to_string(&id, true)
+                            )?;
+                        }
                     }
-                } else {
-                    content()
+                }
+
+                cfg_if! {
+                  if #[cfg(debug_assertions)] {
+                    write!(
+                      w,
+                      "<!--hk={}|leptos-{name}-end-->",
+                      HydrationCtx::to_string(&id, true),
+                    )
+                  } else {
+                    write!(w, "<!--hk={}-->", HydrationCtx::to_string(&id, true))
+                  }
                 }
             }
             View::Element(el) => {
+                #[cfg(debug_assertions)]
+                if let Some(id) = &el.view_marker {
+                    write!(w, "<!--leptos-view|{id}|open-->")?;
+                }
+
                 let is_script_or_style =
-                    el.name == "script" || el.name == "style";
-                let el_html = if let ElementChildren::Chunks(chunks) =
-                    el.children
-                {
-                    chunks
-                        .into_iter()
-                        .map(|chunk| match chunk {
-                            StringOrView::String(string) => string,
-                            StringOrView::View(view) => view()
-                                .render_to_string_helper(is_script_or_style),
-                        })
-                        .join("")
-                        .into()
+                    matches!(el.tag.as_str(), "script" | "style");
+
+                if let ElementChildren::Chunks(chunks) = el.children {
+                    for chunk in chunks {
+                        match chunk {
+                            StringOrView::String(string) => {
+                                #[cfg(debug_assertions)]
+                                crate::html_nesting::scan(&string);
+                                w.write_str(&string)?
+                            }
+                            StringOrView::View(view) => {
+                                view().write_html(w, is_script_or_style)?
+                            }
+                        }
+                    }
                 } else {
-                    let tag_name = el.name;
+                    let tag_name = el.tag.as_str();
+                    #[cfg(debug_assertions)]
+                    crate::html_nesting::open_element(tag_name);
 
+                    write!(w, "<{tag_name}")?;
                     let mut inner_html = None;
+                    for (name, value) in &el.attrs {
+                        if name == "inner_html" {
+                            inner_html = Some(value);
+                        } else if value.is_empty() {
+                            write!(w, " {name}")?;
+                        } else {
+                            write!(
+                                w,
+                                " {name}=\"{}\"",
+                                html_escape::encode_double_quoted_attribute(
+                                    value
+                                )
+                            )?;
+                        }
+                    }
 
-                    let attrs = el
-                        .attrs
-                        .into_iter()
-                        .filter_map(
-                            |(name, value)| -> Option<Cow<'static, str>> {
-                                if value.is_empty() {
-                                    Some(format!(" {name}").into())
-                                } else if name == "inner_html" {
-                                    inner_html = Some(value);
-                                    None
-                                } else {
-                                    Some(
-                                        format!(
-                    " {name}=\"{}\"",
-                    html_escape::encode_double_quoted_attribute(&value)
-                  )
-                                        .into(),
-                                    )
-                                }
-                            },
-                        )
-                        .join("");
-
-                    if el.is_void {
-                        format!("<{tag_name}{attrs}/>").into()
-                    } else if let Some(inner_html) = inner_html {
-                        format!("<{tag_name}{attrs}>{inner_html}</{tag_name}>")
-                            .into()
+                    if el.tag.is_void() {
+                        w.write_str("/>")?;
                     } else {
-                        let children = match el.children {
-                            ElementChildren::Empty => "".into(),
-                            ElementChildren::Children(c) => c
-                                .into_iter()
-                                .map(|v| {
-                                    v.render_to_string_helper(
-                                        is_script_or_style,
-                                    )
-                                })
-                                .join("")
-                                .into(),
-                            ElementChildren::InnerHtml(h) => h,
-                            // already handled this case above
-                            ElementChildren::Chunks(_) => unreachable!(),
-                        };
-
-                        format!("<{tag_name}{attrs}>{children}</{tag_name}>")
-                            .into()
-                    }
-                };
-                cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        if let Some(id) = el.view_marker {
-                            format!("<!--leptos-view|{id}|open-->{el_html}<!--leptos-view|{id}|close-->").into()
+                        w.write_char('>')?;
+                        if let Some(inner_html) = inner_html {
+                            w.write_str(inner_html)?;
                         } else {
-                            el_html
+                            match el.children {
+                                ElementChildren::Empty => {}
+                                ElementChildren::Children(c) => {
+                                    for child in c {
+                                        child.write_html(
+                                            w,
+                                            is_script_or_style,
+                                        )?;
+                                    }
+                                }
+                                ElementChildren::InnerHtml(h) => {
+                                    w.write_str(&h)?
+                                }
+                                // already handled this case above
+                                ElementChildren::Chunks(_) => unreachable!(),
+                            }
                         }
-                    } else {
-                        el_html
+                        write!(w, "</{tag_name}>")?;
+                        #[cfg(debug_assertions)]
+                        crate::html_nesting::close_element(tag_name);
                     }
                 }
+
+                #[cfg(debug_assertions)]
+                if let Some(id) = &el.view_marker {
+                    write!(w, "<!--leptos-view|{id}|close-->")?;
+                }
+
+                Ok(())
             }
-            View::Transparent(_) => Default::default(),
+            View::Transparent(_) => Ok(()),
         }
     }
 }
@@ -642,7 +1033,7 @@ pub(crate) fn to_kebab_case(name: &str) -> String {
     instrument(level = "trace", skip_all,)
 )]
 pub(crate) fn render_serializers(
-    serializers: FuturesUnordered<PinnedFuture<(ResourceId, String)>>,
+    serializers: impl Stream<Item = (ResourceId, String)>,
 ) -> impl Stream<Item = String> {
     serializers.map(|(id, json)| {
         let id = serde_json::to_string(&id).unwrap();
@@ -667,3 +1058,152 @@ where
 {
     html_escape::encode_double_quoted_attribute(value)
 }
+
+/// A stream of rendered HTML chunks, as returned by the SSR renderers, that
+/// can be plugged into the response body type of any HTTP framework.
+///
+/// The stream is pulled, not pushed: nothing more is rendered until the next
+/// chunk is requested, so a slow client naturally applies backpressure to the
+/// renderer. If the stream owns the reactive runtime it was rendered in,
+/// that runtime is disposed once the stream ends or is dropped.
+///
+/// Because reactive scopes are not `Send`, the stream must be polled on the
+/// thread that rendered it. Use [`HtmlStream::into_send`] to move the chunks
+/// onto a `Send` stream for frameworks that require one.
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// use futures::StreamExt;
+/// use leptos::ssr::{render_to_stream_with_prefix_undisposed, IntoHtmlStream};
+///
+/// let stream = render_to_stream_with_prefix_undisposed(
+///     |cx| view! { cx, <p>"Hello"</p> }.into_view(cx),
+///     |_| "".into(),
+/// )
+/// .into_html_stream()
+///     // most frameworks accept a stream of `Result<impl Into<Bytes>, E>`
+///     .map(|chunk| Ok::<_, std::io::Error>(chunk.into_bytes()));
+/// # }}
+/// ```
+pub struct HtmlStream {
+    stream: Pin<Box<dyn Stream<Item = String>>>,
+    runtime: Option<RuntimeId>,
+}
+
+impl HtmlStream {
+    /// Wraps a stream of HTML chunks.
+    pub fn new(stream: impl Stream<Item = String> + 'static) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            runtime: None,
+        }
+    }
+
+    /// Wraps a stream of HTML chunks, disposing of the `runtime` it was
+    /// rendered in once the stream ends or is dropped.
+    pub fn with_runtime(
+        stream: impl Stream<Item = String> + 'static,
+        runtime: RuntimeId,
+    ) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            runtime: Some(runtime),
+        }
+    }
+
+    /// Splits the stream into a future that drives the renderer, which must
+    /// be spawned on a thread-local executor, and a `Send` stream of the
+    /// chunks it produces.
+    ///
+    /// At most `buffer` chunks are rendered ahead of the consumer; once the
+    /// buffer is full, rendering waits until the consumer catches up.
+    pub fn into_send(
+        mut self,
+        buffer: usize,
+    ) -> (impl Future<Output = ()>, impl Stream<Item = String> + Send) {
+        use futures::SinkExt;
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(buffer);
+        let render = async move {
+            while let Some(chunk) = self.next().await {
+                // the consumer has gone away, so stop rendering
+                if tx.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+        };
+        (render, rx)
+    }
+}
+
+impl Stream for HtmlStream {
+    type Item = String;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let next = self.stream.as_mut().poll_next(cx);
+        if let std::task::Poll::Ready(None) = next {
+            if let Some(runtime) = self.runtime.take() {
+                runtime.dispose();
+            }
+        }
+        next
+    }
+}
+
+impl Drop for HtmlStream {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.dispose();
+        }
+    }
+}
+
+impl std::fmt::Debug for HtmlStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlStream")
+            .field("runtime", &self.runtime)
+            .finish()
+    }
+}
+
+/// Converts the output of an SSR renderer into an [`HtmlStream`].
+pub trait IntoHtmlStream {
+    /// Converts the value into an [`HtmlStream`].
+    fn into_html_stream(self) -> HtmlStream;
+}
+
+impl IntoHtmlStream for HtmlStream {
+    fn into_html_stream(self) -> HtmlStream {
+        self
+    }
+}
+
+impl IntoHtmlStream for String {
+    fn into_html_stream(self) -> HtmlStream {
+        HtmlStream::new(futures::stream::once(async move { self }))
+    }
+}
+
+impl<S> IntoHtmlStream for Pin<Box<S>>
+where
+    S: Stream<Item = String> + ?Sized + 'static,
+{
+    fn into_html_stream(self) -> HtmlStream {
+        HtmlStream::new(self)
+    }
+}
+
+/// The output of the `_undisposed` renderers, like
+/// [`render_to_stream_with_prefix_undisposed`]: the runtime is disposed when
+/// the stream ends.
+impl<S> IntoHtmlStream for (S, RuntimeId, ScopeId)
+where
+    S: Stream<Item = String> + 'static,
+{
+    fn into_html_stream(self) -> HtmlStream {
+        HtmlStream::with_runtime(self.0, self.1)
+    }
+}
@@ -99,6 +99,7 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             chunks,
             prefix,
             pending_resources,
+            serialized_contexts,
             serializers,
         ),
         scope_id,
@@ -115,6 +116,7 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             view.into_stream_chunks(cx),
             prefix,
             serde_json::to_string(&cx.pending_resources()).unwrap(),
+            cx.serialized_contexts(),
             cx.serialization_resolvers(),
         )
     });
@@ -142,6 +144,7 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             __LEPTOS_PENDING_RESOURCES = {pending_resources};
             __LEPTOS_RESOLVED_RESOURCES = new Map();
             __LEPTOS_RESOURCE_RESOLVERS = new Map();
+            __LEPTOS_SERIALIZED_CONTEXT = {serialized_contexts};
         </script>
       "#
         )
@@ -224,6 +227,8 @@ impl View {
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn into_stream_chunks(self, cx: Scope) -> VecDeque<StreamChunk> {
         let mut chunks = VecDeque::new();
+        #[cfg(debug_assertions)]
+        let _nesting = crate::html_nesting::Isolated::new();
         self.into_stream_chunks_helper(cx, &mut chunks, false);
         chunks
     }
@@ -252,6 +257,8 @@ impl View {
                 }
             }
             View::Text(node) => {
+                #[cfg(debug_assertions)]
+                crate::html_nesting::text(&node.content);
                 chunks.push_back(StreamChunk::Sync(node.content))
             }
             View::Component(node) => {
@@ -259,9 +266,11 @@ impl View {
                   if #[cfg(debug_assertions)] {
                     let name = crate::ssr::to_kebab_case(&node.name);
                     chunks.push_back(StreamChunk::Sync(format!(r#"<!--hk={}|leptos-{name}-start-->"#, HydrationCtx::to_string(&node.id, false)).into()));
+                    crate::html_nesting::enter_component(&node.name, node.source_location());
                     for child in node.children {
                         child.into_stream_chunks_helper(cx, chunks, dont_escape_text);
                     }
+                    crate::html_nesting::leave_component();
                     chunks.push_back(StreamChunk::Sync(format!(r#"<!--hk={}|leptos-{name}-end-->"#, HydrationCtx::to_string(&node.id, true)).into()));
                   } else {
                     for child in node.children {
@@ -273,7 +282,7 @@ impl View {
             }
             View::Element(el) => {
                 let is_script_or_style =
-                    el.name == "script" || el.name == "style";
+                    matches!(el.tag.as_str(), "script" | "style");
 
                 #[cfg(debug_assertions)]
                 if let Some(id) = &el.view_marker {
@@ -285,6 +294,8 @@ impl View {
                     for chunk in el_chunks {
                         match chunk {
                             StringOrView::String(string) => {
+                                #[cfg(debug_assertions)]
+                                crate::html_nesting::scan(&string);
                                 chunks.push_back(StreamChunk::Sync(string))
                             }
                             StringOrView::View(view) => {
@@ -297,7 +308,9 @@ impl View {
                         }
                     }
                 } else {
-                    let tag_name = el.name;
+                    let tag_name = el.tag.name();
+                    #[cfg(debug_assertions)]
+                    crate::html_nesting::open_element(&tag_name);
 
                     let mut inner_html = None;
 
@@ -306,11 +319,11 @@ impl View {
                         .into_iter()
                         .filter_map(
                             |(name, value)| -> Option<Cow<'static, str>> {
-                                if value.is_empty() {
-                                    Some(format!(" {name}").into())
-                                } else if name == "inner_html" {
+                                if name == "inner_html" {
                                     inner_html = Some(value);
                                     None
+                                } else if value.is_empty() {
+                                    Some(format!(" {name}").into())
                                 } else {
                                     Some(
                                         format!(
@@ -324,7 +337,7 @@ impl View {
                         )
                         .join("");
 
-                    if el.is_void {
+                    if el.tag.is_void() {
                         chunks.push_back(StreamChunk::Sync(
                             format!("<{tag_name}{attrs}/>").into(),
                         ));
@@ -362,6 +375,8 @@ impl View {
                             format!("</{tag_name}>").into(),
                         ));
                     }
+                    #[cfg(debug_assertions)]
+                    crate::html_nesting::close_element(&tag_name);
                 }
                 #[cfg(debug_assertions)]
                 if let Some(id) = &el.view_marker {
@@ -417,6 +432,10 @@ impl View {
                                         // into one single node, so we need to artificially make the
                                         // browser create the dynamic text as it's own text node
                                         if let View::Text(t) = child {
+                                            #[cfg(debug_assertions)]
+                                            crate::html_nesting::text(
+                                                &t.content,
+                                            );
                                             let content = if dont_escape_text {
                                                 t.content
                                             } else {
@@ -1,8 +1,8 @@
 //! Exports types for working with SVG elements.
 
+use super::{html::TagMetadata, ElementDescriptor, HtmlElement};
 #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
 use super::{html::HTML_ELEMENT_DEREF_UNIMPLEMENTED_MSG, HydrationKey};
-use super::{ElementDescriptor, HtmlElement};
 use crate::HydrationCtx;
 use leptos_reactive::Scope;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -57,9 +57,9 @@ macro_rules! generate_svg_tags {
 
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
             let element = if HydrationCtx::is_hydrating() {
-              if let Some(el) = crate::document().get_element_by_id(
-                &format!("_{id}")
-              ) {
+              if let Some(el) =
+                crate::hydration::take_element(&format!("_{id}"))
+              {
                 #[cfg(debug_assertions)]
                 assert_eq!(
                   el.node_name().to_ascii_uppercase(),
@@ -68,32 +68,16 @@ macro_rules! generate_svg_tags {
                     but different node kinds. This is either a \
                     discrepancy between SSR and CSR rendering
                     logic, which is considered a bug, or it \
-                    can also be a leptos hydration issue."
+                    can also be a leptos hydration issue.{}",
+                  crate::source_location::describe_next()
                 );
 
-                el.remove_attribute("id").unwrap();
-
-                el.unchecked_into()
-              } else if let Ok(Some(el)) = crate::document().query_selector(
-                &format!("[leptos-hk=_{id}]")
-              ) {
-                #[cfg(debug_assertions)]
-                assert_eq!(
-                  el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
-                  "SSR and CSR elements have the same `TopoId` \
-                    but different node kinds. This is either a \
-                    discrepancy between SSR and CSR rendering
-                    logic, which is considered a bug, or it \
-                    can also be a leptos hydration issue."
-                );
-
-                el.remove_attribute("leptos-hk").unwrap();
-
                 el.unchecked_into()
               } else {
                 crate::warn!(
-                  "element with id {id} not found, ignoring it for hydration"
+                  "element with id {id} not found{}, ignoring it for \
+                   hydration",
+                  crate::source_location::describe_next()
                 );
 
                 [<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]
@@ -146,17 +130,27 @@ macro_rules! generate_svg_tags {
           }
         }
 
+        impl [<$tag:camel $($second:camel $($third:camel)?)?>] {
+          /// Static metadata for this tag.
+          pub const METADATA: TagMetadata = TagMetadata::svg(
+            stringify!($tag),
+            generate_svg_tags! { @void $($void)? },
+          );
+        }
+
         impl ElementDescriptor for [<$tag:camel $($second:camel $($third:camel)?)?>] {
           fn name(&self) -> Cow<'static, str> {
-            stringify!($tag).into()
+            Cow::Borrowed(Self::METADATA.name)
+          }
+
+          fn metadata(&self) -> Option<&'static TagMetadata> {
+            Some(&Self::METADATA)
           }
 
           #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
           fn hydration_id(&self) -> &HydrationKey {
             &self.id
           }
-
-          generate_svg_tags! { @void $($void)? }
         }
 
         #[$meta]
@@ -167,12 +161,8 @@ macro_rules! generate_svg_tags {
       )*
     }
   };
-  (@void) => {};
-  (@void void) => {
-    fn is_void(&self) -> bool {
-      true
-    }
-  };
+  (@void) => { false };
+  (@void void) => { true };
 }
 
 generate_svg_tags![
@@ -0,0 +1,491 @@
+//! Runs expensive computations in a
+//! [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API),
+//! so they don’t block the main thread.
+//!
+//! The worker runs a second instance of your app’s own Wasm binary. Work is
+//! described by types implementing [`WorkerTask`], which are registered on the
+//! worker side and called by name from the main thread. Inputs and outputs
+//! are sent across the boundary using the same [`Serializable`] formats as
+//! resources.
+//!
+//! ```ignore
+//! use leptos::{leptos_dom::worker::*, *};
+//! use wasm_bindgen::prelude::wasm_bindgen;
+//!
+//! struct Fibonacci;
+//!
+//! impl WorkerTask for Fibonacci {
+//!     const NAME: &'static str = "fibonacci";
+//!     type Input = u64;
+//!     type Output = u64;
+//!
+//!     fn run(n: u64) -> u64 {
+//!         if n < 2 { n } else { Self::run(n - 1) + Self::run(n - 2) }
+//!     }
+//! }
+//!
+//! // the entry point called inside the worker
+//! #[wasm_bindgen]
+//! pub fn worker_main() {
+//!     register_task::<Fibonacci>();
+//!     run_worker();
+//! }
+//!
+//! #[component]
+//! fn App(cx: Scope) -> impl IntoView {
+//!     // the URL of the JS glue generated by `wasm-bindgen --target web`
+//!     let worker = spawn_worker(cx, "/pkg/app.js", "worker_main").unwrap();
+//!     let (n, set_n) = create_signal(cx, 30);
+//!     let fib = worker.mirror::<Fibonacci>(cx, move || n.get());
+//!
+//!     view! { cx,
+//!       <input type="number" on:input=move |ev| {
+//!         set_n(event_target_value(&ev).parse().unwrap_or_default())
+//!       }/>
+//!       <p>{move || fib.get().map(|n| n.to_string())}</p>
+//!     }
+//! }
+//! ```
+
+use leptos_reactive::{Scope, Serializable, SerializationError};
+use std::fmt;
+
+/// A unit of work that can be run inside a worker started with
+/// [`spawn_worker`].
+pub trait WorkerTask: 'static {
+    /// A name for the task, which must be unique among all tasks registered
+    /// with [`register_task`].
+    const NAME: &'static str;
+
+    /// The argument sent from the main thread.
+    type Input: Serializable + 'static;
+
+    /// The result sent back to the main thread.
+    type Output: Serializable + 'static;
+
+    /// Runs the task. This is only ever called inside the worker.
+    fn run(input: Self::Input) -> Self::Output;
+}
+
+/// Errors that can occur while running a [`WorkerTask`].
+#[derive(Debug, Clone)]
+pub enum WorkerError {
+    /// The worker could not be started.
+    Spawn(String),
+    /// The input or output could not be serialized.
+    Serialization(SerializationError),
+    /// The worker reported an error, for example because no task with the
+    /// given name was registered.
+    Task(String),
+    /// The worker crashed, for example because a task panicked or the app
+    /// could not be loaded. Every pending and later task fails with this.
+    Crashed(String),
+    /// The worker was terminated before the task finished.
+    Terminated,
+}
+
+impl fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn(e) => write!(f, "error starting worker: {e}"),
+            Self::Serialization(e) => write!(f, "{e}"),
+            Self::Task(e) => write!(f, "error running worker task: {e}"),
+            Self::Crashed(e) => write!(f, "worker crashed: {e}"),
+            Self::Terminated => f.write_str("worker was terminated"),
+        }
+    }
+}
+
+impl std::error::Error for WorkerError {}
+
+impl From<SerializationError> for WorkerError {
+    fn from(e: SerializationError) -> Self {
+        Self::Serialization(e)
+    }
+}
+
+/// Sent by the worker once it is ready to handle requests.
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+const READY: &str = "leptos-worker-ready";
+
+type TaskFn = Box<dyn Fn(&str) -> Result<String, String>>;
+
+thread_local! {
+    static TASKS: std::cell::RefCell<std::collections::HashMap<&'static str, TaskFn>> =
+        Default::default();
+}
+
+/// Registers a [`WorkerTask`] so that it can be called from the main thread.
+///
+/// This should be called inside the worker, before [`run_worker`].
+pub fn register_task<T: WorkerTask>() {
+    TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(
+            T::NAME,
+            Box::new(|input| {
+                let input = T::Input::de(input).map_err(|e| e.to_string())?;
+                T::run(input).ser().map_err(|e| e.to_string())
+            }),
+        );
+    });
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+fn run_task(name: &str, input: &str) -> Result<String, String> {
+    TASKS.with(|tasks| match tasks.borrow().get(name) {
+        Some(task) => task(input),
+        None => Err(format!("no worker task named {name:?} was registered")),
+    })
+}
+
+/// Starts handling requests from the main thread, using the tasks added
+/// with [`register_task`].
+///
+/// This should be called from the entry point passed to [`spawn_worker`].
+pub fn run_worker() {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+        let scope = js_sys::global()
+            .unchecked_into::<web_sys::DedicatedWorkerGlobalScope>();
+        let on_message = Closure::wrap(Box::new({
+            let scope = scope.clone();
+            move |ev: web_sys::MessageEvent| {
+                let msg = js_sys::Array::from(&ev.data());
+                let id = msg.get(0);
+                let name = msg.get(1).as_string().unwrap_or_default();
+                let input = msg.get(2).as_string().unwrap_or_default();
+
+                let (ok, payload) = match run_task(&name, &input) {
+                    Ok(output) => (true, output),
+                    Err(e) => (false, e),
+                };
+                let response = js_sys::Array::of3(
+                    &id,
+                    &JsValue::from_bool(ok),
+                    &JsValue::from_str(&payload),
+                );
+                _ = scope.post_message(&response);
+            }
+        })
+            as Box<dyn FnMut(web_sys::MessageEvent)>)
+        .into_js_value();
+        scope.set_onmessage(Some(on_message.unchecked_ref()));
+        // requests sent while the app was loading are queued until now
+        _ = scope.post_message(&JsValue::from_str(READY));
+    }
+}
+
+/// Starts a worker running a new instance of the app’s Wasm binary, and
+/// returns a [`WorkerHandle`] used to send it tasks.
+///
+/// `glue_url` is the URL of the JavaScript module generated by
+/// `wasm-bindgen --target web` for the app, and `entry` is the name of an
+/// exported `#[wasm_bindgen]` function that registers tasks and calls
+/// [`run_worker`]. Tasks can be sent right away: they are queued until the
+/// worker is ready. The worker is terminated when `cx` is disposed.
+///
+/// Returns an error if the worker cannot be started, including on the server.
+pub fn spawn_worker(
+    cx: Scope,
+    glue_url: &str,
+    entry: &str,
+) -> Result<WorkerHandle, WorkerError> {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        let handle = web::WorkerHandleInner::spawn(glue_url, entry)?;
+        leptos_reactive::on_cleanup(cx, {
+            let handle = handle.clone();
+            move || handle.terminate()
+        });
+        Ok(WorkerHandle { inner: handle })
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    {
+        _ = cx;
+        _ = glue_url;
+        _ = entry;
+        Err(WorkerError::Spawn(
+            "workers can only be spawned in the browser".into(),
+        ))
+    }
+}
+
+/// A handle to a worker started with [`spawn_worker`].
+#[derive(Clone)]
+pub struct WorkerHandle {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    inner: std::rc::Rc<web::WorkerHandleInner>,
+}
+
+impl WorkerHandle {
+    /// Runs the task `T` in the worker, resolving to its output.
+    pub async fn run<T: WorkerTask>(
+        &self,
+        input: &T::Input,
+    ) -> Result<T::Output, WorkerError> {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let output = self.inner.request(T::NAME, input.ser()?).await?;
+            Ok(T::Output::de(&output)?)
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = input;
+            Err(WorkerError::Terminated)
+        }
+    }
+
+    /// Creates a signal that mirrors the output of running `T` in the worker
+    /// with the latest value of `input`.
+    ///
+    /// Whenever the signals read in `input` change, the task is run again
+    /// with the new value. The signal holds `None` until the first result
+    /// arrives, and results of superseded runs are ignored. Errors are
+    /// logged as warnings and leave the previous value in place.
+    pub fn mirror<T: WorkerTask>(
+        &self,
+        cx: Scope,
+        input: impl Fn() -> T::Input + 'static,
+    ) -> leptos_reactive::ReadSignal<Option<T::Output>> {
+        use leptos_reactive::{create_effect, create_signal, SignalSet};
+        use std::{cell::Cell, rc::Rc};
+
+        let (output, set_output) = create_signal(cx, None);
+        let latest = Rc::new(Cell::new(0_u64));
+        let this = self.clone();
+
+        create_effect(cx, move |_| {
+            let input = input();
+            let run = latest.get() + 1;
+            latest.set(run);
+
+            let this = this.clone();
+            let latest = Rc::clone(&latest);
+            leptos_reactive::spawn_local(async move {
+                let result = this.run::<T>(&input).await;
+                if latest.get() == run {
+                    match result {
+                        Ok(value) => set_output.set(Some(value)),
+                        Err(e) => crate::warn!("{e}"),
+                    }
+                }
+            });
+        });
+
+        output
+    }
+
+    /// Terminates the worker immediately. Any pending tasks resolve with
+    /// [`WorkerError::Terminated`].
+    pub fn terminate(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        self.inner.terminate();
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+mod web {
+    use super::{WorkerError, READY};
+    use futures::channel::oneshot;
+    use std::{
+        cell::{Cell, RefCell},
+        collections::HashMap,
+        rc::Rc,
+    };
+    use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+    type Pending = HashMap<u32, oneshot::Sender<Result<String, WorkerError>>>;
+
+    enum State {
+        /// The worker is still loading, so requests are held back.
+        Starting(Vec<js_sys::Array>),
+        Ready,
+        /// The worker crashed or was terminated, so it can't handle any
+        /// more requests.
+        Stopped(WorkerError),
+    }
+
+    struct Shared {
+        worker: web_sys::Worker,
+        pending: RefCell<Pending>,
+        state: RefCell<State>,
+    }
+
+    impl Shared {
+        fn on_ready(&self) {
+            let state =
+                std::mem::replace(&mut *self.state.borrow_mut(), State::Ready);
+            match state {
+                State::Starting(queued) => {
+                    for msg in queued {
+                        self.post(&msg);
+                    }
+                }
+                // a worker that has stopped stays stopped
+                state => *self.state.borrow_mut() = state,
+            }
+        }
+
+        fn post(&self, msg: &js_sys::Array) {
+            if let Err(e) = self.worker.post_message(msg) {
+                let id = msg.get(0).as_f64().unwrap_or_default() as u32;
+                if let Some(tx) = self.pending.borrow_mut().remove(&id) {
+                    _ = tx.send(Err(WorkerError::Task(format!("{e:?}"))));
+                }
+            }
+        }
+
+        fn stop(&self, error: WorkerError) {
+            *self.state.borrow_mut() = State::Stopped(error.clone());
+            let pending = std::mem::take(&mut *self.pending.borrow_mut());
+            for (_, tx) in pending {
+                _ = tx.send(Err(error.clone()));
+            }
+        }
+    }
+
+    pub(super) struct WorkerHandleInner {
+        shared: Rc<Shared>,
+        next_id: Cell<u32>,
+        // keep the closures alive for as long as the worker
+        _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+        _on_error: Closure<dyn FnMut(web_sys::ErrorEvent)>,
+    }
+
+    impl WorkerHandleInner {
+        pub(super) fn spawn(
+            glue_url: &str,
+            entry: &str,
+        ) -> Result<Rc<Self>, WorkerError> {
+            let to_err = |e: JsValue| WorkerError::Spawn(format!("{e:?}"));
+
+            // resolve relative to the page, since the script itself is a blob
+            let glue_url = web_sys::Url::new_with_base(
+                glue_url,
+                &crate::helpers::location().href().map_err(to_err)?,
+            )
+            .map_err(to_err)?
+            .href();
+            let script = format!(
+                "import init, * as wasm from {glue_url:?};\nawait \
+                 init();\nwasm[{entry:?}]();\n"
+            );
+            let parts = js_sys::Array::of1(&JsValue::from_str(&script));
+            let mut blob_options = web_sys::BlobPropertyBag::new();
+            blob_options.type_("text/javascript");
+            let blob = web_sys::Blob::new_with_str_sequence_and_options(
+                &parts,
+                &blob_options,
+            )
+            .map_err(to_err)?;
+            let script_url = web_sys::Url::create_object_url_with_blob(&blob)
+                .map_err(to_err)?;
+
+            let mut worker_options = web_sys::WorkerOptions::new();
+            worker_options.type_(web_sys::WorkerType::Module);
+            let worker =
+                web_sys::Worker::new_with_options(&script_url, &worker_options);
+            _ = web_sys::Url::revoke_object_url(&script_url);
+            let worker = worker.map_err(to_err)?;
+
+            let shared = Rc::new(Shared {
+                worker,
+                pending: Default::default(),
+                state: RefCell::new(State::Starting(Vec::new())),
+            });
+
+            let on_message = Closure::wrap(Box::new({
+                let shared = Rc::clone(&shared);
+                move |ev: web_sys::MessageEvent| {
+                    let data = ev.data();
+                    if data.as_string().as_deref() == Some(READY) {
+                        shared.on_ready();
+                        return;
+                    }
+                    let msg = js_sys::Array::from(&data);
+                    let Some(id) = msg.get(0).as_f64() else {
+                        return;
+                    };
+                    let ok = msg.get(1).is_truthy();
+                    let payload = msg.get(2).as_string().unwrap_or_default();
+                    let tx = shared.pending.borrow_mut().remove(&(id as u32));
+                    if let Some(tx) = tx {
+                        _ = tx.send(if ok {
+                            Ok(payload)
+                        } else {
+                            Err(WorkerError::Task(payload))
+                        });
+                    }
+                }
+            })
+                as Box<dyn FnMut(web_sys::MessageEvent)>);
+            shared
+                .worker
+                .set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+            // an uncaught error means the app failed to load, or a task
+            // panicked and left the Wasm instance unusable
+            let on_error = Closure::wrap(Box::new({
+                let shared = Rc::clone(&shared);
+                move |ev: web_sys::ErrorEvent| {
+                    ev.prevent_default();
+                    shared.stop(WorkerError::Crashed(ev.message()));
+                }
+            })
+                as Box<dyn FnMut(web_sys::ErrorEvent)>);
+            shared
+                .worker
+                .set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+            Ok(Rc::new(Self {
+                shared,
+                next_id: Cell::new(0),
+                _on_message: on_message,
+                _on_error: on_error,
+            }))
+        }
+
+        pub(super) async fn request(
+            &self,
+            name: &str,
+            input: String,
+        ) -> Result<String, WorkerError> {
+            if let State::Stopped(e) = &*self.shared.state.borrow() {
+                return Err(e.clone());
+            }
+
+            let id = self.next_id.get();
+            self.next_id.set(id.wrapping_add(1));
+
+            let (tx, rx) = oneshot::channel();
+            self.shared.pending.borrow_mut().insert(id, tx);
+
+            let msg = js_sys::Array::of3(
+                &JsValue::from_f64(id as f64),
+                &JsValue::from_str(name),
+                &JsValue::from_str(&input),
+            );
+            let unsent = match &mut *self.shared.state.borrow_mut() {
+                State::Starting(queued) => {
+                    queued.push(msg);
+                    None
+                }
+                _ => Some(msg),
+            };
+            if let Some(msg) = unsent {
+                self.shared.post(&msg);
+            }
+
+            rx.await.unwrap_or(Err(WorkerError::Terminated))
+        }
+
+        pub(super) fn terminate(&self) {
+            self.shared.worker.terminate();
+            self.shared.stop(WorkerError::Terminated);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#![cfg(all(target_arch = "wasm32", feature = "web"))]
+
+use leptos::*;
+use leptos_dom::{dismiss::click_outside, mount_to_with_handle, Portal};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{ShadowRootInit, ShadowRootMode};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Creates an element with the given `id`, and appends it to `parent`.
+fn append(parent: &web_sys::Node, tag: &str, id: &str) -> web_sys::HtmlElement {
+    let el = document()
+        .create_element(tag)
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    el.set_id(id);
+    parent.append_child(&el).unwrap();
+    el
+}
+
+fn body() -> web_sys::Node {
+    document().body().unwrap().into()
+}
+
+/// Dispatches a `pointerdown` that bubbles out of shadow roots, like a real
+/// one does.
+fn press(el: &web_sys::Element) {
+    let mut init = web_sys::EventInit::new();
+    init.bubbles(true).composed(true);
+    let ev =
+        web_sys::Event::new_with_event_init_dict("pointerdown", &init).unwrap();
+    el.dispatch_event(&ev).unwrap();
+}
+
+/// Waits until the tasks that are already scheduled have run.
+async fn next_task() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        _ = window().set_timeout_with_callback(&resolve);
+    });
+    _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Calls `click_outside` on `el`, and returns how often it has dismissed,
+/// along with the disposer that removes its listeners.
+fn dismissals(el: &web_sys::Element) -> (Rc<Cell<usize>>, ScopeDisposer) {
+    let el = el.clone();
+    let (dismissed, _, disposer) =
+        run_scope_undisposed(create_runtime(), move |cx| {
+            let dismissed = Rc::new(Cell::new(0));
+            click_outside(cx, el, {
+                let dismissed = Rc::clone(&dismissed);
+                move |_| dismissed.set(dismissed.get() + 1)
+            });
+            dismissed
+        });
+    (dismissed, disposer)
+}
+
+#[wasm_bindgen_test]
+fn presses_outside_dismiss() {
+    let dropdown = append(&body(), "div", "outside-dropdown");
+    let item = append(&dropdown, "button", "outside-item");
+    let other = append(&body(), "button", "outside-other");
+
+    let (dismissed, disposer) = dismissals(&dropdown);
+    press(&item);
+    press(&dropdown);
+    assert_eq!(dismissed.get(), 0);
+    press(&other);
+    assert_eq!(dismissed.get(), 1);
+
+    // the listeners are removed along with the scope
+    disposer.dispose();
+    press(&other);
+    assert_eq!(dismissed.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn presses_inside_a_nested_portal_do_not_dismiss() {
+    let dropdown = append(&body(), "div", "portal-dropdown");
+    let other = append(&body(), "button", "portal-other");
+
+    let dismissed = Rc::new(Cell::new(0));
+    let handle = mount_to_with_handle(dropdown.clone(), {
+        let dropdown: web_sys::Element = dropdown.clone().into();
+        let dismissed = Rc::clone(&dismissed);
+        move |cx| {
+            click_outside(cx, dropdown, move |_| {
+                dismissed.set(dismissed.get() + 1)
+            });
+            Portal::new(|cx| {
+                html::button(cx).attr("id", "portal-item").child("Delete")
+            })
+        }
+    });
+
+    let item = document().get_element_by_id("portal-item").unwrap();
+    // the portal's content is rendered outside of the dropdown...
+    assert!(!dropdown.contains(Some(&item)));
+    // ...but counts as being inside it
+    press(&item);
+    assert_eq!(dismissed.get(), 0);
+    press(&other);
+    assert_eq!(dismissed.get(), 1);
+
+    handle.dispose();
+}
+
+#[wasm_bindgen_test]
+fn presses_are_seen_through_shadow_roots() {
+    let host = append(&body(), "div", "shadow-host");
+    let shadow: web_sys::Node = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .unwrap()
+        .into();
+    let dropdown = append(&shadow, "div", "shadow-dropdown");
+    let item = append(&dropdown, "button", "shadow-item");
+    let other = append(&shadow, "button", "shadow-other");
+
+    let (dismissed, disposer) = dismissals(&dropdown);
+    // the document sees both presses as targeting the host
+    press(&item);
+    assert_eq!(dismissed.get(), 0);
+    press(&other);
+    assert_eq!(dismissed.get(), 1);
+
+    disposer.dispose();
+}
+
+#[wasm_bindgen_test]
+async fn focusing_an_iframe_outside_dismisses() {
+    let dropdown = append(&body(), "div", "iframe-dropdown");
+    let inner = append(&dropdown, "iframe", "iframe-inner");
+    let outer = append(&body(), "iframe", "iframe-outer");
+
+    let (dismissed, disposer) = dismissals(&dropdown);
+    // the iframe's own events don't reach the page, only the window's blur
+    let blur = || {
+        let ev = web_sys::Event::new("blur").unwrap();
+        window().dispatch_event(&ev).unwrap();
+    };
+
+    inner.focus().unwrap();
+    blur();
+    next_task().await;
+    assert_eq!(dismissed.get(), 0);
+
+    outer.focus().unwrap();
+    blur();
+    next_task().await;
+    assert_eq!(dismissed.get(), 1);
+
+    disposer.dispose();
+}
@@ -115,7 +115,8 @@ mod template;
 /// ```
 ///
 /// 5. Event handlers can be added with `on:` attributes. In most cases, the events are given the correct type
-///    based on the event name.
+///    based on the event name. A handler can return an `EventFlow` to prevent the
+///    event’s default action or stop its propagation.
 /// ```rust
 /// # use leptos::*;
 /// # run_scope(create_runtime(), |cx| {
@@ -803,6 +804,15 @@ pub fn slot(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
 ///   Defaults to `"Url"`. If you want to use this server function to power a `<form>` that will
 ///   work without WebAssembly, the encoding must be `"Url"`.
 ///
+/// After these, you can add a `guard = ...` argument, naming a
+/// [`ServerFnGuard`](https://docs.rs/server_fn/latest/server_fn/trait.ServerFnGuard.html)
+/// that runs on the server before the body, e.g.,
+/// `#[server(DeletePost, "/api", guard = require_role("admin"))]`. If the guard
+/// returns an error, the function returns it without running, and the server
+/// responds with a `401` or `403` status for `ServerFnError::Unauthorized` or
+/// `ServerFnError::Forbidden`. A server function with a guard must take a
+/// `Scope` as its first argument, which is passed to the guard.
+///
 /// The server function itself can take any number of arguments, each of which should be serializable
 /// and deserializable with `serde`. Optionally, its first argument can be a Leptos
 /// [Scope](https://docs.rs/leptos/latest/leptos/struct.Scope.html),
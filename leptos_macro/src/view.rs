@@ -255,8 +255,15 @@ fn fragment_to_tokens_ssr(
     } else {
         quote! {}
     };
-    let nodes = nodes.iter().map(|node| {
-        let node = root_node_to_tokens_ssr(cx, node, global_class, None);
+    let nodes = merge_static_text(nodes).into_iter().map(|node| {
+        let node = match node {
+            MergedNode::Text(text) => quote! {
+                leptos::leptos_dom::html::text(#text)
+            },
+            MergedNode::Node(node) => {
+                root_node_to_tokens_ssr(cx, node, global_class, None)
+            }
+        };
         quote! {
             #node.into_view(#cx)
         }
@@ -576,6 +583,12 @@ fn attribute_to_tokens_ssr<'a>(
         exprs_for_compiler.push(quote! {
             leptos::leptos_dom::helpers::ssr_event_listener(::leptos::ev::#event_type, #handler);
         })
+    } else if let Some(name) = name.strip_prefix("use:") {
+        // directives only run in the browser
+        let (directive, param) = directive_parts(attr, name);
+        exprs_for_compiler.push(quote! {
+            leptos::leptos_dom::helpers::ssr_directive(#directive, #param);
+        })
     } else if name.strip_prefix("prop:").is_some()
         || name.strip_prefix("class:").is_some()
         || name.strip_prefix("style:").is_some()
@@ -604,9 +617,13 @@ fn attribute_to_tokens_ssr<'a>(
 
             if let Some(value) = attr.value() {
                 if let Some(value) = value_to_string(value) {
+                    let value =
+                        html_escape::encode_double_quoted_attribute(&value);
                     template.push_str(&name);
                     template.push_str("=\"");
-                    template.push_str(&value);
+                    template.push_str(
+                        &value.replace('{', "\\{").replace('}', "\\}"),
+                    );
                     template.push('"');
                 } else {
                     template.push_str("{}");
@@ -880,17 +897,22 @@ fn fragment_to_tokens(
     let mut slots = HashMap::new();
     let has_slots = parent_slots.is_some();
 
-    let mut nodes = nodes
-        .iter()
+    let mut nodes = merge_static_text(nodes)
+        .into_iter()
         .filter_map(|node| {
-            let node = node_to_tokens(
-                cx,
-                node,
-                parent_type,
-                has_slots.then_some(&mut slots),
-                global_class,
-                None,
-            )?;
+            let node = match node {
+                MergedNode::Text(text) => quote! {
+                    leptos::leptos_dom::html::text(#text)
+                },
+                MergedNode::Node(node) => node_to_tokens(
+                    cx,
+                    node,
+                    parent_type,
+                    has_slots.then_some(&mut slots),
+                    global_class,
+                    None,
+                )?,
+            };
 
             Some(quote! {
                 #node.into_view(#cx)
@@ -949,6 +971,318 @@ fn fragment_to_tokens(
     Some(tokens)
 }
 
+/// A child node, after [`merge_static_text`].
+enum MergedNode<'a> {
+    Text(syn::LitStr),
+    Node(&'a Node),
+}
+
+/// Merges each run of adjacent static text into a single string, so that it
+/// is rendered as one text node. Comments are skipped, as they aren't
+/// rendered and would otherwise split a run.
+fn merge_static_text(nodes: &[Node]) -> Vec<MergedNode<'_>> {
+    fn flush(merged: &mut Vec<MergedNode>, run: &mut Option<(String, Span)>) {
+        if let Some((text, span)) = run.take() {
+            merged.push(MergedNode::Text(syn::LitStr::new(&text, span)));
+        }
+    }
+
+    let mut merged = Vec::with_capacity(nodes.len());
+    let mut run: Option<(String, Span)> = None;
+    for node in nodes {
+        let text = match node {
+            Node::Text(text) => (text.value_string(), text.value.span()),
+            Node::RawText(text) => (text.to_string_best(), text.span()),
+            Node::Comment(_) => continue,
+            _ => {
+                flush(&mut merged, &mut run);
+                merged.push(MergedNode::Node(node));
+                continue;
+            }
+        };
+        match &mut run {
+            Some((run, _)) => run.push_str(&text.0),
+            None => run = Some(text),
+        }
+    }
+    flush(&mut merged, &mut run);
+    merged
+}
+
+/// Renders the children of an element to HTML, if none of them are dynamic,
+/// so that in client-side rendering mode they can be cloned from a
+/// `<template>` instead of being created one by one. Returns `None` if the
+/// children are only text, which is already a single node, or if the browser
+/// would parse the HTML into a different tree than the `view` describes.
+fn static_children_html(node: &NodeElement) -> Option<String> {
+    // hydration walks each element, so it can't skip any
+    if !cfg!(feature = "csr") || cfg!(feature = "hydrate") {
+        return None;
+    }
+    let tag = node.name().to_string();
+    if is_raw_text_element(&tag) || is_svg_element(&tag) {
+        return None;
+    }
+
+    let mut html = String::new();
+    let (mut has_element, mut has_table_parts, mut has_other) =
+        (false, false, false);
+    for child in &node.children {
+        match child {
+            Node::Element(child) => {
+                static_element_html(child, &mut Vec::new(), &mut html)?;
+                has_element = true;
+                if is_table_part(&child.name().to_string()) {
+                    has_table_parts = true;
+                } else {
+                    has_other = true;
+                }
+            }
+            Node::Text(_) | Node::RawText(_) | Node::Comment(_) => {
+                let len = html.len();
+                static_text_html(child, &mut html);
+                has_other |= !html[len..].trim().is_empty();
+            }
+            _ => return None,
+        }
+    }
+    // a `<template>` takes rows or cells, but then moves anything else
+    (has_element && !(has_table_parts && has_other)).then_some(html)
+}
+
+/// Renders `node` to HTML, where `ancestors` are the tags of the elements
+/// it is nested in within the `<template>`, from the outermost.
+fn static_element_html(
+    node: &NodeElement,
+    ancestors: &mut Vec<String>,
+    html: &mut String,
+) -> Option<()> {
+    let tag = node.name().to_string();
+    if is_component_node(node)
+        || tag.contains(':')
+        || is_svg_element(&tag)
+        || is_math_ml_element(&tag)
+        || is_raw_text_element(&tag)
+        || !parses_as_child(ancestors, &tag)
+    {
+        return None;
+    }
+
+    html.push('<');
+    html.push_str(&tag);
+    for attr in node.attributes() {
+        let NodeAttribute::Attribute(attr) = attr else {
+            return None;
+        };
+        let name = attr.key.to_string();
+        let name = name.strip_prefix("attr:").unwrap_or(&name);
+        if name.contains(':')
+            || matches!(name, "ref" | "_ref" | "ref_" | "node_ref")
+            || name == "inner_html"
+        {
+            return None;
+        }
+        html.push(' ');
+        html.push_str(name);
+        match attr.value() {
+            None => {}
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(value),
+                ..
+            })) => {
+                html.push_str("=\"");
+                html.push_str(&html_escape::encode_double_quoted_attribute(
+                    &value.value(),
+                ));
+                html.push('"');
+            }
+            Some(_) => return None,
+        }
+    }
+    html.push('>');
+    if is_self_closing(node) {
+        return Some(());
+    }
+
+    ancestors.push(tag.clone());
+    for child in &node.children {
+        match child {
+            Node::Element(child) => {
+                static_element_html(child, ancestors, html)?
+            }
+            Node::Text(_) | Node::RawText(_) | Node::Comment(_) => {
+                static_text_html(child, html)
+            }
+            _ => return None,
+        }
+    }
+    ancestors.pop();
+    html.push_str("</");
+    html.push_str(&tag);
+    html.push('>');
+    Some(())
+}
+
+fn static_text_html(node: &Node, html: &mut String) {
+    let text = match node {
+        Node::Text(text) => text.value_string(),
+        Node::RawText(text) => text.to_string_best(),
+        _ => return,
+    };
+    html.push_str(&html_escape::encode_text(&text));
+}
+
+/// Elements whose content the HTML parser doesn't treat as markup.
+fn is_raw_text_element(tag: &str) -> bool {
+    matches!(
+        tag,
+        "script"
+            | "style"
+            | "textarea"
+            | "title"
+            | "template"
+            | "noscript"
+            | "iframe"
+            | "xmp"
+    )
+}
+
+fn is_table_part(tag: &str) -> bool {
+    matches!(
+        tag,
+        "tbody"
+            | "thead"
+            | "tfoot"
+            | "caption"
+            | "colgroup"
+            | "col"
+            | "tr"
+            | "td"
+            | "th"
+    )
+}
+
+/// Whether the HTML parser keeps `child` inside its parent, the last of
+/// `ancestors`, rather than closing an ancestor early (a `<div>` anywhere in a
+/// `<p>`, or an `<li>` in another `<li>`) or moving `child` elsewhere (a
+/// `<tr>` directly in a `<table>` gets a `<tbody>`). Without `ancestors`,
+/// `child` is at the top level of the `<template>`, which takes any element.
+fn parses_as_child(ancestors: &[String], child: &str) -> bool {
+    let Some(parent) = ancestors.last().map(String::as_str) else {
+        return true;
+    };
+    match child {
+        "tr" => return matches!(parent, "tbody" | "thead" | "tfoot"),
+        "td" | "th" => return parent == "tr",
+        "tbody" | "thead" | "tfoot" | "caption" | "colgroup" => {
+            return parent == "table"
+        }
+        "col" => return parent == "colgroup",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if is_heading(parent) => {
+            return false
+        }
+        _ if matches!(
+            parent,
+            "table" | "tbody" | "thead" | "tfoot" | "tr" | "colgroup"
+        ) =>
+        {
+            return false
+        }
+        _ => {}
+    }
+
+    // the ancestor that starting `child` would close, if it is open
+    let closes = |open: &str| {
+        (open == "p" && closes_p(child))
+            || match child {
+                "li" => open == "li",
+                "dd" | "dt" => matches!(open, "dd" | "dt"),
+                "option" | "optgroup" => matches!(open, "option" | "optgroup"),
+                "a" | "form" | "button" => open == child,
+                _ => false,
+            }
+    };
+    // the ancestors that stop the parser from looking further out
+    let is_boundary = |open: &str| match child {
+        // a nested form is dropped, however deep it is
+        "form" => open == "template",
+        _ => {
+            matches!(
+                open,
+                "applet"
+                    | "caption"
+                    | "marquee"
+                    | "object"
+                    | "table"
+                    | "td"
+                    | "template"
+                    | "th"
+            ) || match child {
+                "li" => matches!(open, "ol" | "ul" | "menu"),
+                "dd" | "dt" => open == "dl",
+                _ if closes_p(child) => open == "button",
+                _ => false,
+            }
+        }
+    };
+    for open in ancestors.iter().rev() {
+        if closes(open) {
+            return false;
+        }
+        if is_boundary(open) {
+            break;
+        }
+    }
+    true
+}
+
+fn is_heading(tag: &str) -> bool {
+    matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// Whether starting `child` closes an open `<p>`.
+fn closes_p(child: &str) -> bool {
+    is_heading(child)
+        || matches!(
+            child,
+            "address"
+                | "article"
+                | "aside"
+                | "blockquote"
+                | "center"
+                | "dd"
+                | "details"
+                | "dialog"
+                | "dir"
+                | "div"
+                | "dl"
+                | "dt"
+                | "fieldset"
+                | "figcaption"
+                | "figure"
+                | "footer"
+                | "form"
+                | "header"
+                | "hgroup"
+                | "hr"
+                | "li"
+                | "listing"
+                | "main"
+                | "menu"
+                | "nav"
+                | "ol"
+                | "p"
+                | "plaintext"
+                | "pre"
+                | "search"
+                | "section"
+                | "summary"
+                | "table"
+                | "ul"
+                | "xmp"
+        )
+}
+
 fn node_to_tokens(
     cx: &Ident,
     node: &Node,
@@ -1014,7 +1348,21 @@ fn element_to_tokens(
             let name = node.name().to_string();
             // link custom ident to name span for IDE docs
             let custom = Ident::new("custom", name.span());
-            quote! { leptos::leptos_dom::html::#custom(#cx, leptos::leptos_dom::html::Custom::new(#name)) }
+            // e.g., <font-face> inside an <svg> is still an SVG element
+            let namespace = match parent_type {
+                TagType::Svg => quote! { Svg },
+                TagType::Math => quote! { MathMl },
+                TagType::Unknown | TagType::Html => quote! { Html },
+            };
+            quote! {
+                leptos::leptos_dom::html::#custom(
+                    #cx,
+                    leptos::leptos_dom::html::Custom::new_ns(
+                        #name,
+                        leptos::leptos_dom::html::ElementNamespace::#namespace,
+                    ),
+                )
+            }
         } else if is_svg_element(&tag) {
             parent_type = TagType::Svg;
             quote! { leptos::leptos_dom::svg::#name(#cx) }
@@ -1044,6 +1392,14 @@ fn element_to_tokens(
             quote! { leptos::leptos_dom::html::#name(#cx) }
         };
 
+        let source_location = source_location_tokens(node.name().span());
+        let name = quote! {
+            ({
+                #source_location
+                #name
+            })
+        };
+
         if let Some(close_tag) = close_tag {
             ide_helper_close_tag.save_tag_completion(close_tag)
         }
@@ -1104,63 +1460,73 @@ fn element_to_tokens(
                 }
             }
         };
-        let children = node.children.iter().map(|node| {
-            let (child, is_static) = match node {
-                Node::Fragment(fragment) => (
-                    fragment_to_tokens(
-                        cx,
-                        Span::call_site(),
-                        &fragment.children,
-                        true,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or({
-                        let span = Span::call_site();
-                        quote_spanned! {
-                            span => leptos::leptos_dom::Unit
-                        }
-                    }),
-                    false,
-                ),
-                Node::Text(node) => (quote! { #node }, true),
-                Node::RawText(node) => {
-                    let text = node.to_string_best();
-                    let text = syn::LitStr::new(&text, node.span());
-                    (quote! { #text }, true)
-                }
-                Node::Block(node) => (
-                    quote! {
-                       #node
-                    },
-                    false,
-                ),
-                Node::Element(node) => (
-                    element_to_tokens(
-                        cx,
-                        node,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or_default(),
-                    false,
-                ),
-                Node::Comment(_) | Node::Doctype(_) => (quote! {}, false),
+        // children that never change are cloned from a `<template>`
+        let static_children = (global_class.is_none()
+            && matches!(parent_type, TagType::Html | TagType::Unknown))
+        .then(|| static_children_html(node))
+        .flatten();
+        let merged = match static_children {
+            Some(_) => Vec::new(),
+            None => merge_static_text(&node.children),
+        };
+        let children = merged.iter().filter_map(|node| {
+            let node = match node {
+                MergedNode::Text(text) => return Some(quote! { #text }),
+                MergedNode::Node(node) => *node,
             };
-            if is_static {
-                quote! {
-                    .child(#child)
-                }
-            } else {
-                quote! {
-                    .child((#cx, #child))
-                }
-            }
+            let child = match node {
+                Node::Fragment(fragment) => fragment_to_tokens(
+                    cx,
+                    Span::call_site(),
+                    &fragment.children,
+                    true,
+                    parent_type,
+                    None,
+                    global_class,
+                    None,
+                )
+                .unwrap_or({
+                    let span = Span::call_site();
+                    quote_spanned! {
+                        span => leptos::leptos_dom::Unit
+                    }
+                }),
+                // merged into the surrounding text above
+                Node::Text(_) | Node::RawText(_) => unreachable!(),
+                Node::Block(node) => quote! {
+                   #node
+                },
+                Node::Element(node) => element_to_tokens(
+                    cx,
+                    node,
+                    parent_type,
+                    None,
+                    global_class,
+                    None,
+                )
+                .unwrap_or_default(),
+                Node::Comment(_) | Node::Doctype(_) => return None,
+            };
+            Some(quote! { (#cx, #child) })
         });
+        let children = children.collect::<Vec<_>>();
+        // several children are mounted together, so they can be inserted
+        // into the element in a single operation
+        let children = if let Some(html) = static_children {
+            quote! {
+                .static_children(#html)
+            }
+        } else if children.len() > 1 {
+            quote! {
+                .children(::std::vec![
+                    #(leptos::leptos_dom::IntoView::into_view(#children, #cx)),*
+                ])
+            }
+        } else {
+            quote! {
+                #(.child(#children))*
+            }
+        };
         let view_marker = if let Some(marker) = view_marker {
             quote! { .with_view_marker(#marker) }
         } else {
@@ -1175,7 +1541,7 @@ fn element_to_tokens(
                 #(#class_attrs)*
                 #(#style_attrs)*
                 #global_class_expr
-                #(#children)*
+                #children
                 #view_marker
             }
         })
@@ -1257,8 +1623,42 @@ fn attribute_to_tokens(
             quote! { ::leptos::ev::#event_type }
         };
 
+        if let [first, second, ..] = key_modifiers(name)[..] {
+            abort!(
+                node.key,
+                "an event can only have one key modifier, but this one has \
+                 both `:{}` and `:{}`",
+                first,
+                second
+            );
+        }
+        if let Some(options) = event_options(name) {
+            let on = match &node.key {
+                NodeName::Punctuated(parts) => {
+                    let span = parts[0].span();
+                    quote_spanned! { span => .on_with_options }
+                }
+                _ => unreachable!(),
+            };
+            quote! {
+                #on(#event_type, #handler, #options)
+            }
+        } else {
+            quote! {
+                #on(#event_type, #handler)
+            }
+        }
+    } else if let Some(name) = name.strip_prefix("use:") {
+        let (directive, param) = directive_parts(node, name);
+        let directive_fn = match &node.key {
+            NodeName::Punctuated(parts) => {
+                let span = parts[0].span();
+                quote_spanned! { span => .directive }
+            }
+            _ => unreachable!(),
+        };
         quote! {
-            #on(#event_type, #handler)
+            #directive_fn(#directive, #param)
         }
     } else if let Some(name) = name.strip_prefix("prop:") {
         let value = attribute_value(node);
@@ -1353,7 +1753,7 @@ fn attribute_to_tokens(
 }
 
 pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
-    let (name, is_force_undelegated) = parse_event(name);
+    let (name, is_force_undelegated, _) = parse_event(name);
 
     let event_type = TYPED_EVENTS
         .iter()
@@ -1671,16 +2071,19 @@ pub(crate) fn component_to_tokens(
         }
     });
 
+    // the props are built first, so that any elements created while
+    // building them don't take the component's location
+    let source_location = source_location_tokens(span);
     let mut component = quote! {
-        ::leptos::component_view(
-            &#name,
-            #cx,
-            ::leptos::component_props_builder(&#name)
+        ({
+            let props = ::leptos::component_props_builder(&#name)
                 #(#props)*
                 #(#slots)*
                 #children
-                .build()
-        )
+                .build();
+            #source_location
+            ::leptos::component_view(&#name, #cx, props)
+        })
     };
 
     #[cfg(debug_assertions)]
@@ -1696,6 +2099,24 @@ pub(crate) fn component_to_tokens(
     }
 }
 
+/// In debug builds, records where in the `view!` block the next element or
+/// component is created, so runtime diagnostics can point at it.
+fn source_location_tokens(span: Span) -> TokenStream {
+    if cfg!(debug_assertions) {
+        quote_spanned! { span =>
+            leptos::leptos_dom::set_next_source_location(
+                leptos::leptos_dom::SourceLocation {
+                    file: file!(),
+                    line: line!(),
+                    column: column!(),
+                }
+            );
+        }
+    } else {
+        quote! {}
+    }
+}
+
 pub(crate) fn event_from_attribute_node(
     attr: &KeyedAttribute,
     force_undelegated: bool,
@@ -1710,7 +2131,14 @@ pub(crate) fn event_from_attribute_node(
     let handler = attribute_value(attr);
 
     #[allow(unused_variables)]
-    let (name, name_undelegated) = parse_event(&event_name);
+    let (name, name_undelegated, modifiers) = parse_event(&event_name);
+    if !modifiers.is_empty() {
+        abort!(
+            attr.key,
+            "event modifiers like `:{}` can only be used on elements",
+            modifiers[0]
+        );
+    }
 
     let event_type = TYPED_EVENTS
         .iter()
@@ -1948,12 +2376,80 @@ fn is_ambiguous_element(tag: &str) -> bool {
     tag == "a" || tag == "script" || tag == "title"
 }
 
-fn parse_event(event_name: &str) -> (&str, bool) {
-    if let Some(event_name) = event_name.strip_suffix(":undelegated") {
-        (event_name, true)
-    } else {
-        (event_name, false)
+/// The directive of a `use:directive=param` attribute, and its parameter,
+/// which is `()` if there is no value.
+fn directive_parts(attr: &KeyedAttribute, name: &str) -> (Ident, TokenStream) {
+    let span = match &attr.key {
+        NodeName::Punctuated(parts) if parts.len() >= 2 => parts[1].span(),
+        _ => attr.key.span(),
+    };
+    let Ok(directive) = syn::parse_str::<Ident>(name) else {
+        abort!(attr.key, "expected the name of a directive after `use:`");
+    };
+    let directive = Ident::new(&directive.to_string(), span);
+    let param = match &attr.possible_value {
+        Some(value) => {
+            let value = &value.value;
+            quote! { #value }
+        }
+        None => quote! { () },
+    };
+    (directive, param)
+}
+
+/// Splits an event name like `click:undelegated:prevent` into the name of
+/// the event, whether it is undelegated, and its modifiers.
+fn parse_event(event_name: &str) -> (&str, bool, Vec<&str>) {
+    let mut name = event_name;
+    let mut undelegated = false;
+    let mut modifiers = Vec::new();
+    while let Some((rest, suffix)) = name.rsplit_once(':') {
+        if suffix == "undelegated" {
+            undelegated = true;
+        } else if EVENT_MODIFIERS.contains(&suffix) {
+            modifiers.insert(0, suffix);
+        } else {
+            break;
+        }
+        name = rest;
     }
+    (name, undelegated, modifiers)
+}
+
+const EVENT_MODIFIERS: &[&str] = &[
+    "prevent", "stop", "self", "outside", "enter", "esc", "capture",
+    "passive", "once",
+];
+
+/// The modifiers of an event that only let through a particular key. An
+/// event can only check for one key, so it can have at most one of them.
+fn key_modifiers(name: &str) -> Vec<&str> {
+    let (_, _, modifiers) = parse_event(name);
+    modifiers
+        .into_iter()
+        .filter(|modifier| matches!(*modifier, "enter" | "esc"))
+        .collect()
+}
+
+/// The `EventOptions` for the modifiers of an event, if it has any.
+fn event_options(name: &str) -> Option<TokenStream> {
+    let (_, _, modifiers) = parse_event(name);
+    let options = modifiers.into_iter().map(|modifier| match modifier {
+        "prevent" => quote! { prevent_default() },
+        "stop" => quote! { stop_propagation() },
+        "self" => quote! { self_only() },
+        "outside" => quote! { outside() },
+        "enter" => quote! { key("Enter") },
+        "esc" => quote! { key("Escape") },
+        "capture" => quote! { capture() },
+        "passive" => quote! { passive() },
+        "once" => quote! { once() },
+        _ => unreachable!(),
+    });
+    let options = options
+        .map(|option| quote! { ::leptos::EventOptions::#option })
+        .collect::<Vec<_>>();
+    (!options.is_empty()).then(|| quote! { #(#options)|* })
 }
 
 fn fancy_class_name<'a>(
@@ -2186,3 +2682,106 @@ impl IntoIterator for IdeTagHelper {
         self.0.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{event_options, key_modifiers, parse_event, parses_as_child};
+
+    fn parses(ancestors: &[&str], child: &str) -> bool {
+        let ancestors = ancestors
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect::<Vec<_>>();
+        parses_as_child(&ancestors, child)
+    }
+
+    #[test]
+    fn any_element_parses_at_the_top_level() {
+        for child in ["div", "li", "tr", "td", "a", "p"] {
+            assert!(parses(&[], child), "{child}");
+        }
+    }
+
+    #[test]
+    fn blocks_close_a_p_anywhere_above_them() {
+        assert!(!parses(&["p"], "div"));
+        assert!(!parses(&["p", "span"], "div"));
+        assert!(!parses(&["p", "em", "span"], "ul"));
+        assert!(!parses(&["p", "span"], "li"));
+        assert!(parses(&["p", "span"], "em"));
+        // a button is as far as the parser looks for a <p>
+        assert!(parses(&["p", "button"], "div"));
+        assert!(parses(&["div", "span"], "div"));
+    }
+
+    #[test]
+    fn self_nesting_elements_close_their_ancestor() {
+        assert!(!parses(&["a", "span"], "a"));
+        assert!(!parses(&["li", "div"], "li"));
+        assert!(!parses(&["li", "p", "span"], "li"));
+        assert!(!parses(&["dt", "span"], "dd"));
+        assert!(!parses(&["form", "div", "table", "tr", "td"], "form"));
+        assert!(!parses(&["button", "span"], "button"));
+        assert!(!parses(&["a", "button"], "a"));
+        assert!(!parses(&["h1"], "h2"));
+
+        // nested lists start a new scope
+        assert!(parses(&["ul", "li", "ul"], "li"));
+        assert!(parses(&["dl", "dd", "dl"], "dt"));
+        // as do table cells for links
+        assert!(parses(&["a", "table", "tbody", "tr", "td"], "a"));
+    }
+
+    #[test]
+    fn table_parts_need_their_direct_parent() {
+        assert!(parses(&["table"], "tbody"));
+        assert!(parses(&["table", "tbody"], "tr"));
+        assert!(parses(&["table", "tbody", "tr"], "td"));
+        assert!(!parses(&["table"], "tr"));
+        assert!(!parses(&["tbody"], "td"));
+        assert!(!parses(&["table", "tbody", "tr"], "div"));
+        assert!(parses(&["table", "tbody", "tr", "td"], "div"));
+    }
+
+    #[test]
+    fn events_are_split_into_name_and_modifiers() {
+        assert_eq!(parse_event("click"), ("click", false, vec![]));
+        assert_eq!(
+            parse_event("click:prevent:stop"),
+            ("click", false, vec!["prevent", "stop"])
+        );
+        assert_eq!(
+            parse_event("keydown:enter:undelegated:once"),
+            ("keydown", true, vec!["enter", "once"])
+        );
+        // anything that isn't a modifier is part of a custom event's name
+        assert_eq!(
+            parse_event("my:event:prevent"),
+            ("my:event", false, vec!["prevent"])
+        );
+        assert_eq!(parse_event("stop:click"), ("stop:click", false, vec![]));
+    }
+
+    #[test]
+    fn modifiers_are_combined_into_event_options() {
+        assert!(event_options("click").is_none());
+        assert!(event_options("click:undelegated").is_none());
+
+        let options = event_options("keydown:prevent:enter").unwrap();
+        assert_eq!(
+            options.to_string(),
+            quote::quote! {
+                ::leptos::EventOptions::prevent_default()
+                    | ::leptos::EventOptions::key("Enter")
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn events_have_at_most_one_key_modifier() {
+        assert!(key_modifiers("keydown:prevent").is_empty());
+        assert_eq!(key_modifiers("keydown:esc:stop"), ["esc"]);
+        assert_eq!(key_modifiers("keydown:enter:esc"), ["enter", "esc"]);
+    }
+}
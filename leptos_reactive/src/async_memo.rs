@@ -0,0 +1,185 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_isomorphic_effect, create_signal, on_cleanup, spawn_local,
+    ReadSignal, Scope, Signal, SignalGet, SignalGetUntracked, SignalSet,
+    SignalWith, SignalWithUntracked,
+};
+use futures::future::{abortable, AbortHandle};
+use std::{cell::RefCell, future::Future, rc::Rc};
+
+/// Creates a value that is computed asynchronously, and recomputed whenever
+/// the signals it depends on change.
+///
+/// `f` is run in an effect, so any signals it reads are tracked; it should
+/// read them before returning its `Future`, as reads inside the `async`
+/// block happen after the effect has finished. Whenever one of them changes,
+/// `f` is run again, and the `Future` from the previous run is dropped if it
+/// hasn’t finished yet, so that a slow, stale computation can never
+/// overwrite a newer one.
+///
+/// Unlike a [`Resource`](crate::Resource), an async memo has no source to
+/// compare, isn’t serialized from the server to the client, and doesn’t
+/// interact with `<Suspense/>`: it simply holds the latest value, or `None`
+/// until the first computation finishes, and a [`pending`](AsyncMemo::pending)
+/// flag. Like a [`Memo`](crate::Memo), it only notifies its subscribers when
+/// a new value is different from the last one.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (query, set_query) = create_signal(cx, "leptos".to_string());
+/// let results = create_async_memo(cx, move || {
+///     // read signals outside the `async` block, so they're tracked
+///     let query = query.get();
+///     async move {
+///         // e.g., call a search API
+///         format!("results for {query}")
+///     }
+/// });
+///
+/// # if !cfg!(any(feature = "csr", feature = "hydrate", feature = "ssr")) {
+/// assert_eq!(results.get(), Some("results for leptos".to_string()));
+/// set_query.set("rust".to_string());
+/// assert_eq!(results.get(), Some("results for rust".to_string()));
+/// assert!(!results.pending().get());
+/// # }
+/// # }).dispose();
+/// ```
+pub fn create_async_memo<T, Fu>(
+    cx: Scope,
+    f: impl Fn() -> Fu + 'static,
+) -> AsyncMemo<T>
+where
+    T: PartialEq + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    let (value, set_value) = create_signal(cx, None::<T>);
+    let (pending, set_pending) = create_signal(cx, false);
+    let in_flight = Rc::new(RefCell::new(None::<AbortHandle>));
+
+    create_isomorphic_effect(cx, {
+        let in_flight = Rc::clone(&in_flight);
+        move |_| {
+            let (fut, handle) = abortable(f());
+            if let Some(previous) = in_flight.replace(Some(handle)) {
+                previous.abort();
+            }
+            set_pending.set(true);
+
+            let in_flight = Rc::clone(&in_flight);
+            spawn_local(async move {
+                // an aborted future resolves to `Err`, and a newer run has
+                // already taken over
+                if let Ok(new_value) = fut.await {
+                    in_flight.take();
+                    let changed = value
+                        .try_with_untracked(|value| {
+                            value.as_ref() != Some(&new_value)
+                        })
+                        .unwrap_or(false);
+                    if changed {
+                        set_value.try_set(Some(new_value));
+                    }
+                    set_pending.try_set(false);
+                }
+            });
+        }
+    });
+
+    on_cleanup(cx, move || {
+        if let Some(handle) = in_flight.take() {
+            handle.abort();
+        }
+    });
+
+    AsyncMemo { value, pending }
+}
+
+/// A value that is computed asynchronously from other signals; see
+/// [`create_async_memo`].
+pub struct AsyncMemo<T: 'static> {
+    value: ReadSignal<Option<T>>,
+    pending: ReadSignal<bool>,
+}
+
+impl<T> AsyncMemo<T> {
+    /// Whether the value is being computed, either for the first time or
+    /// because a dependency has changed.
+    pub fn pending(&self) -> ReadSignal<bool> {
+        self.pending
+    }
+}
+
+impl<T> Clone for AsyncMemo<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AsyncMemo<T> {}
+
+impl<T> std::fmt::Debug for AsyncMemo<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncMemo")
+            .field("value", &self.value)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<T: Clone> SignalGet<Option<T>> for AsyncMemo<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn get(&self) -> Option<T> {
+        self.value.get()
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_get(&self) -> Option<Option<T>> {
+        self.value.try_get()
+    }
+}
+
+impl<T> SignalWith<Option<T>> for AsyncMemo<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with(f)
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> Option<O> {
+        self.value.try_with(f)
+    }
+}
+
+impl<T: Clone> SignalGetUntracked<Option<T>> for AsyncMemo<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn get_untracked(&self) -> Option<T> {
+        self.value.get_untracked()
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_get_untracked(&self) -> Option<Option<T>> {
+        self.value.try_get_untracked()
+    }
+}
+
+impl<T> SignalWithUntracked<Option<T>> for AsyncMemo<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn with_untracked<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with_untracked(f)
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_with_untracked<O>(
+        &self,
+        f: impl FnOnce(&Option<T>) -> O,
+    ) -> Option<O> {
+        self.value.try_with_untracked(f)
+    }
+}
+
+impl<T> From<AsyncMemo<T>> for Signal<Option<T>> {
+    fn from(memo: AsyncMemo<T>) -> Self {
+        memo.value.into()
+    }
+}
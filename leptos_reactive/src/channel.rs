@@ -0,0 +1,199 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_rw_signal, provide_context, use_context, ReadSignal, RwSignal,
+    Scope, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalWith,
+    SignalWithUntracked,
+};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+/// Provides a set of [`Channel`]s to this [`Scope`] and all of its
+/// descendants, which can then be joined with [`use_channel`].
+///
+/// Call this once, high in the tree (e.g., in the root component): any two
+/// components below it that join the same topic share the same channel, no
+/// matter how far apart they are. The latest value of each channel lives as
+/// long as `cx`.
+pub fn provide_channels(cx: Scope) {
+    provide_context(cx, Channels::new(cx));
+}
+
+/// Joins the channel for `topic`, creating it if no one has joined it yet.
+///
+/// A channel is a lightweight publish/subscribe primitive: any component can
+/// [`publish`](Channel::publish) a value, and every component that reads the
+/// channel is notified, so distant siblings can communicate without passing
+/// callbacks through their shared ancestors. Channels are keyed by both topic
+/// and value type, so channels of different types never collide.
+///
+/// Channels come from the nearest [`provide_channels`]. If there is none,
+/// the channel is only shared within `cx` and its descendants.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// provide_channels(cx);
+///
+/// // in one component...
+/// cx.child_scope(|cx| {
+///     let selected = use_channel::<u32>(cx, "selected-item");
+///     selected.publish(42);
+/// });
+///
+/// // ...and in another
+/// cx.child_scope(|cx| {
+///     let selected = use_channel::<u32>(cx, "selected-item");
+///     assert_eq!(selected.get(), Some(42));
+/// });
+/// # }).dispose();
+/// ```
+pub fn use_channel<T>(
+    cx: Scope,
+    topic: impl Into<Cow<'static, str>>,
+) -> Channel<T>
+where
+    T: 'static,
+{
+    let channels = use_context::<Channels>(cx).unwrap_or_else(|| {
+        crate::macros::debug_warn!(
+            "use_channel() was called without provide_channels() being \
+             called in a parent scope, so the channel won't be shared \
+             outside of this scope."
+        );
+        let channels = Channels::new(cx);
+        provide_context(cx, channels.clone());
+        channels
+    });
+    channels.join(topic.into())
+}
+
+#[derive(Clone)]
+struct Channels {
+    cx: Scope,
+    topics: Rc<RefCell<HashMap<(TypeId, Cow<'static, str>), Box<dyn Any>>>>,
+}
+
+impl Channels {
+    fn new(cx: Scope) -> Self {
+        Self {
+            cx,
+            topics: Default::default(),
+        }
+    }
+
+    fn join<T: 'static>(&self, topic: Cow<'static, str>) -> Channel<T> {
+        let mut topics = self.topics.borrow_mut();
+        let value = topics
+            .entry((TypeId::of::<T>(), topic))
+            .or_insert_with(|| Box::new(create_rw_signal(self.cx, None::<T>)))
+            .downcast_ref::<RwSignal<Option<T>>>()
+            .copied()
+            .expect("channels are keyed by their type");
+        Channel { value }
+    }
+}
+
+/// A topic shared between components, joined with [`use_channel`].
+///
+/// Reading the channel gives the latest value that was published to it, or
+/// `None` if nothing has been published yet, and subscribes to new values.
+pub struct Channel<T>
+where
+    T: 'static,
+{
+    value: RwSignal<Option<T>>,
+}
+
+impl<T> Channel<T> {
+    /// Publishes a new value, notifying everyone who has read the channel.
+    ///
+    /// Subscribers are notified even if the value is the same as the last
+    /// one, so a channel can also be used for events.
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn publish(&self, value: T) {
+        self.value.set(Some(value));
+    }
+
+    /// Returns a read-only signal of the channel's latest value.
+    pub fn latest(&self) -> ReadSignal<Option<T>> {
+        self.value.read_only()
+    }
+}
+
+impl<T> Clone for Channel<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Channel<T> {}
+
+impl<T> std::fmt::Debug for Channel<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Channel")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T: Clone> SignalGet<Option<T>> for Channel<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn get(&self) -> Option<T> {
+        self.value.get()
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_get(&self) -> Option<Option<T>> {
+        self.value.try_get()
+    }
+}
+
+impl<T> SignalWith<Option<T>> for Channel<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with(f)
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> Option<O> {
+        self.value.try_with(f)
+    }
+}
+
+impl<T: Clone> SignalGetUntracked<Option<T>> for Channel<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn get_untracked(&self) -> Option<T> {
+        self.value.get_untracked()
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_get_untracked(&self) -> Option<Option<T>> {
+        self.value.try_get_untracked()
+    }
+}
+
+impl<T> SignalWithUntracked<Option<T>> for Channel<T> {
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn with_untracked<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with_untracked(f)
+    }
+
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    fn try_with_untracked<O>(
+        &self,
+        f: impl FnOnce(&Option<T>) -> O,
+    ) -> Option<O> {
+        self.value.try_with_untracked(f)
+    }
+}
+
+impl<T> From<Channel<T>> for Signal<Option<T>> {
+    fn from(channel: Channel<T>) -> Self {
+        channel.value.into()
+    }
+}
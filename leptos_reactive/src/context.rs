@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 
-use crate::{runtime::with_runtime, Scope};
+use crate::{runtime::with_runtime, Scope, Serializable};
 use std::any::{Any, TypeId};
 
 /// Provides a context value of type `T` to the current reactive [`Scope`](crate::Scope)
@@ -203,3 +203,90 @@ where
         )
     })
 }
+
+/// Provides a context value of type `T`, like [`provide_context`](crate::provide_context),
+/// and also serializes it into the server-rendered HTML so that the client can recover
+/// it with [`use_serialized_context`](crate::use_serialized_context) while hydrating.
+///
+/// This is intended for per-request values computed on the server, like the
+/// authenticated user, feature flags, or the locale. Provide them from the
+/// `additional_context` closure that the server integrations and the
+/// `render_to_stream_*_with_context` functions accept, so they are available to
+/// the whole app and are only computed once.
+///
+/// Values are keyed by their type name, so only one value of each type can be
+/// serialized per request. Only provide values the client is allowed to see: they
+/// are sent to the browser as part of the page.
+/// ```
+/// # use leptos::*;
+/// #[derive(Clone, serde::Serialize, serde::Deserialize)]
+/// struct Locale(String);
+///
+/// # let runtime = create_runtime();
+/// # run_scope(runtime, |cx| {
+/// // on the server, in `additional_context`
+/// provide_serialized_context(cx, Locale("en-US".into()));
+///
+/// // in a component, on either the server or the client
+/// let locale = use_serialized_context::<Locale>(cx);
+/// assert_eq!(locale.map(|l| l.0), Some("en-US".to_string()));
+/// # });
+/// # runtime.dispose();
+/// ```
+pub fn provide_serialized_context<T>(cx: Scope, value: T)
+where
+    T: Serializable + Clone + 'static,
+{
+    match value.ser() {
+        Ok(serialized) => {
+            _ = with_runtime(cx.runtime, |runtime| {
+                runtime
+                    .shared_context
+                    .borrow_mut()
+                    .serialized_contexts
+                    .insert(std::any::type_name::<T>().to_string(), serialized);
+            });
+        }
+        Err(e) => crate::console_warn(&format!(
+            "[provide_serialized_context] could not serialize context of \
+             type {}: {e}",
+            std::any::type_name::<T>()
+        )),
+    }
+    provide_context(cx, value);
+}
+
+/// Extracts a context value of type `T` that was provided with
+/// [`provide_serialized_context`](crate::provide_serialized_context).
+///
+/// If the value has been provided in this scope or one of its parents, it is
+/// returned as with [`use_context`](crate::use_context). Otherwise, while hydrating
+/// a server-rendered page, it is deserialized from the value the server sent.
+pub fn use_serialized_context<T>(cx: Scope) -> Option<T>
+where
+    T: Serializable + Clone + 'static,
+{
+    use_context::<T>(cx).or_else(|| {
+        let serialized = with_runtime(cx.runtime, |runtime| {
+            runtime
+                .shared_context
+                .borrow()
+                .serialized_contexts
+                .get(std::any::type_name::<T>())
+                .cloned()
+        })
+        .ok()
+        .flatten()?;
+        match T::de(&serialized) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                crate::console_warn(&format!(
+                    "[use_serialized_context] could not deserialize context \
+                     of type {}: {e}",
+                    std::any::type_name::<T>()
+                ));
+                None
+            }
+        }
+    })
+}
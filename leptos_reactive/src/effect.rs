@@ -57,7 +57,7 @@ use std::{any::Any, cell::RefCell, marker::PhantomData, rc::Rc};
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 #[inline(always)]
 pub fn create_effect<T>(cx: Scope, f: impl Fn(Option<T>) -> T + 'static)
 where
@@ -113,7 +113,7 @@ where
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 #[inline(always)]
 pub fn create_isomorphic_effect<T>(
     cx: Scope,
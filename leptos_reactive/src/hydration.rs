@@ -9,6 +9,10 @@ pub struct SharedContext {
     pub resolved_resources: HashMap<ResourceId, String>,
     #[allow(clippy::type_complexity)]
     pub pending_fragments: HashMap<String, FragmentData>,
+    /// Context values provided with
+    /// [provide_serialized_context](crate::provide_serialized_context), keyed
+    /// by type name.
+    pub serialized_contexts: HashMap<String, String>,
 }
 
 /// Represents its pending `<Suspense/>` fragment.
@@ -34,6 +38,7 @@ impl PartialEq for SharedContext {
         self.events == other.events
             && self.pending_resources == other.pending_resources
             && self.resolved_resources == other.resolved_resources
+            && self.serialized_contexts == other.serialized_contexts
     }
 }
 
@@ -62,11 +67,21 @@ impl Default for SharedContext {
                 let resolved_resources =
                     serde_wasm_bindgen::from_value(resolved_resources).unwrap_or_default();
 
+                let serialized_contexts = js_sys::Reflect::get(
+                    &web_sys::window().unwrap(),
+                    &wasm_bindgen::JsValue::from_str("__LEPTOS_SERIALIZED_CONTEXT"),
+                )
+                .unwrap_or(wasm_bindgen::JsValue::NULL);
+
+                let serialized_contexts =
+                    serde_wasm_bindgen::from_value(serialized_contexts).unwrap_or_default();
+
                 Self {
                     events: Default::default(),
                     pending_resources,
                     resolved_resources,
                     pending_fragments: Default::default(),
+                    serialized_contexts,
                 }
             } else {
                 Self {
@@ -74,6 +89,7 @@ impl Default for SharedContext {
                     pending_resources: Default::default(),
                     resolved_resources: Default::default(),
                     pending_fragments: Default::default(),
+                    serialized_contexts: Default::default(),
                 }
             }
         }
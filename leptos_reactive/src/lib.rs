@@ -72,12 +72,17 @@ extern crate tracing;
 
 #[macro_use]
 mod signal;
+mod async_memo;
+mod channel;
 mod context;
 #[macro_use]
 mod diagnostics;
 mod effect;
 mod hydration;
+mod list_store;
 mod memo;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod node;
 mod resource;
 mod runtime;
@@ -93,9 +98,12 @@ mod stored_value;
 pub mod suspense;
 mod trigger;
 
+pub use async_memo::*;
+pub use channel::*;
 pub use context::*;
 pub use diagnostics::SpecialNonReactiveZone;
 pub use effect::*;
+pub use list_store::*;
 pub use memo::*;
 pub use resource::*;
 use runtime::*;
@@ -0,0 +1,226 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_trigger, on_cleanup, store_value, Scope, StoredValue, Trigger,
+};
+use std::{fmt, rc::Rc};
+
+/// A single change to a [`ListStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListOp<T> {
+    /// Appends an item to the end of the list.
+    Push(T),
+    /// Inserts an item at the given index.
+    Insert(usize, T),
+    /// Removes the item at the given index.
+    Remove(usize),
+    /// Swaps the items at the two indices.
+    Swap(usize, usize),
+    /// Replaces the item at the given index.
+    Set(usize, T),
+    /// Removes all items.
+    Clear,
+}
+
+impl<T> ListOp<T> {
+    /// Whether this operation can be applied to a list of length `len`.
+    pub fn is_valid_for(&self, len: usize) -> bool {
+        match self {
+            Self::Push(_) | Self::Clear => true,
+            Self::Insert(at, _) => *at <= len,
+            Self::Remove(at) | Self::Set(at, _) => *at < len,
+            Self::Swap(a, b) => *a < len && *b < len,
+        }
+    }
+}
+
+type OpListener<T> = Rc<dyn Fn(&ListOp<T>)>;
+
+/// A reactive list that records each change as a [`ListOp`].
+///
+/// Reading the list is tracked like any other signal, but consumers can
+/// also subscribe to the individual operations with [`ListStore::on_op`].
+/// This lets a renderer apply a `push` or `remove` directly, rather than
+/// diffing a new snapshot of the whole list, which matters for very hot
+/// lists like logs and tickers.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+/// let log = create_list_store(cx, vec!["started"]);
+///
+/// let ops = store_value(cx, Vec::new());
+/// log.on_op(cx, move |op| ops.update_value(|ops| ops.push(op.clone())));
+///
+/// log.push("connected");
+/// log.remove(0);
+///
+/// assert_eq!(log.with(|items| items.to_vec()), vec!["connected"]);
+/// assert_eq!(
+///     ops.get_value(),
+///     vec![ListOp::Push("connected"), ListOp::Remove(0)]
+/// );
+/// # disposer.dispose();
+/// ```
+pub struct ListStore<T: 'static> {
+    items: StoredValue<Vec<T>>,
+    listeners: StoredValue<Vec<OpListener<T>>>,
+    trigger: Trigger,
+}
+
+impl<T> Clone for ListStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListStore<T> {}
+
+impl<T> fmt::Debug for ListStore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListStore")
+            .field("items", &self.items)
+            .field("trigger", &self.trigger)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for ListStore<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<T> Eq for ListStore<T> {}
+
+/// Creates a [`ListStore`] with the given initial items.
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
+pub fn create_list_store<T: 'static>(cx: Scope, items: Vec<T>) -> ListStore<T> {
+    ListStore {
+        items: store_value(cx, items),
+        listeners: store_value(cx, Vec::new()),
+        trigger: create_trigger(cx),
+    }
+}
+
+impl<T: 'static> ListStore<T> {
+    /// Applies a function to the current items, subscribing the running
+    /// effect to any later changes.
+    pub fn with<U>(&self, f: impl FnOnce(&[T]) -> U) -> U {
+        self.trigger.track();
+        self.with_untracked(f)
+    }
+
+    /// Applies a function to the current items, without tracking.
+    pub fn with_untracked<U>(&self, f: impl FnOnce(&[T]) -> U) -> U {
+        self.items.with_value(|items| f(items))
+    }
+
+    /// The number of items in the list. Tracks changes.
+    pub fn len(&self) -> usize {
+        self.with(|items| items.len())
+    }
+
+    /// Whether the list is empty. Tracks changes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an item to the end of the list.
+    pub fn push(&self, item: T) {
+        self.apply(ListOp::Push(item));
+    }
+
+    /// Inserts an item at the given index.
+    pub fn insert(&self, at: usize, item: T) {
+        self.apply(ListOp::Insert(at, item));
+    }
+
+    /// Removes the item at the given index.
+    pub fn remove(&self, at: usize) {
+        self.apply(ListOp::Remove(at));
+    }
+
+    /// Swaps the items at the two indices.
+    pub fn swap(&self, a: usize, b: usize) {
+        self.apply(ListOp::Swap(a, b));
+    }
+
+    /// Replaces the item at the given index.
+    pub fn set(&self, at: usize, item: T) {
+        self.apply(ListOp::Set(at, item));
+    }
+
+    /// Removes all items.
+    pub fn clear(&self) {
+        self.apply(ListOp::Clear);
+    }
+
+    /// Applies an operation to the list, notifying subscribers of the
+    /// operation and then any effects that read the list.
+    ///
+    /// Returns `false`, and leaves the list unchanged, if the operation
+    /// refers to an index that is out of bounds.
+    pub fn apply(&self, op: ListOp<T>) -> bool {
+        let len = self.items.with_value(|items| items.len());
+        if !op.is_valid_for(len) {
+            crate::macros::debug_warn!(
+                "ListStore::apply(): {:?} is out of bounds for a list of \
+                 length {len}",
+                OpName(&op)
+            );
+            return false;
+        }
+
+        // clone the listeners out, so they can read the store themselves
+        let listeners = self.listeners.with_value(|listeners| {
+            listeners.iter().cloned().collect::<Vec<_>>()
+        });
+        for listener in listeners {
+            listener(&op);
+        }
+
+        self.items.update_value(|items| match op {
+            ListOp::Push(item) => items.push(item),
+            ListOp::Insert(at, item) => items.insert(at, item),
+            ListOp::Remove(at) => {
+                items.remove(at);
+            }
+            ListOp::Swap(a, b) => items.swap(a, b),
+            ListOp::Set(at, item) => items[at] = item,
+            ListOp::Clear => items.clear(),
+        });
+        self.trigger.notify();
+
+        true
+    }
+
+    /// Calls `f` with every operation applied to the list, until `cx`
+    /// is cleaned up. Operations are reported before they are applied.
+    pub fn on_op(&self, cx: Scope, f: impl Fn(&ListOp<T>) + 'static) {
+        let listener: OpListener<T> = Rc::new(f);
+        self.listeners
+            .update_value(|listeners| listeners.push(Rc::clone(&listener)));
+
+        let listeners = self.listeners;
+        on_cleanup(cx, move || {
+            _ = listeners.try_update_value(|listeners| {
+                listeners.retain(|other| !Rc::ptr_eq(other, &listener))
+            });
+        });
+    }
+}
+
+struct OpName<'a, T>(&'a ListOp<T>);
+
+impl<T> fmt::Debug for OpName<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ListOp::Push(_) => f.write_str("Push"),
+            ListOp::Insert(at, _) => write!(f, "Insert({at})"),
+            ListOp::Remove(at) => write!(f, "Remove({at})"),
+            ListOp::Swap(a, b) => write!(f, "Swap({a}, {b})"),
+            ListOp::Set(at, _) => write!(f, "Set({at})"),
+            ListOp::Clear => f.write_str("Clear"),
+        }
+    }
+}
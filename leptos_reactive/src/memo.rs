@@ -71,7 +71,7 @@ use std::{any::Any, cell::RefCell, fmt, marker::PhantomData, rc::Rc};
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 #[inline(always)]
 pub fn create_memo<T>(
     cx: Scope,
@@ -328,7 +328,7 @@ impl<T: Clone> SignalGet<T> for Memo<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn get(&self) -> T {
         self.with(T::clone)
@@ -347,7 +347,7 @@ impl<T: Clone> SignalGet<T> for Memo<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn try_get(&self) -> Option<T> {
         self.try_with(T::clone)
@@ -368,7 +368,7 @@ impl<T> SignalWith<T> for Memo<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
         match self.try_with(f) {
             Some(t) => t,
@@ -392,7 +392,7 @@ impl<T> SignalWith<T> for Memo<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         // memo is stored as Option<T>, but will always have T available
         // after latest_value() called, so we can unwrap safely
@@ -505,7 +505,7 @@ where
 
 #[cold]
 #[inline(never)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 fn format_memo_warning(
     msg: &str,
     #[cfg(any(debug_assertions, feature = "ssr"))]
@@ -530,7 +530,7 @@ fn format_memo_warning(
 
 #[cold]
 #[inline(never)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub(crate) fn panic_getting_dead_memo(
     #[cfg(any(debug_assertions, feature = "ssr"))]
     defined_at: &'static std::panic::Location<'static>,
@@ -0,0 +1,328 @@
+#![forbid(unsafe_code)]
+//! Timing metrics for monitoring an app in production.
+//!
+//! When the `metrics` feature is enabled, Leptos measures how long the app
+//! takes to hydrate, when each root becomes interactive, and how long each
+//! resource takes to resolve. [`observe_web_vitals`] adds the browser’s core
+//! web vitals. Every measurement is delivered to the callbacks registered
+//! with [`on_metric`], or sent to an endpoint with [`report_metrics_to`].
+//!
+//! ```
+//! # use leptos_reactive::metrics::*;
+//! on_metric(|metric| {
+//!     println!("{}: {:.1}", metric.name(), metric.value());
+//! });
+//! ```
+
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// A single measurement. All times are in milliseconds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Metric {
+    /// How long it took to hydrate the server-rendered HTML.
+    Hydration {
+        /// The time from the start to the end of hydration.
+        duration: f64,
+    },
+    /// A root mounted with `mount_to` or `mount_to_body` became interactive.
+    Interactive {
+        /// Describes the element the app was mounted to.
+        root: String,
+        /// The time since the page started loading.
+        time: f64,
+    },
+    /// A resource resolved.
+    Resource {
+        /// The type of the resource’s value.
+        type_name: &'static str,
+        /// The time from starting to load the resource to resolving it.
+        duration: f64,
+    },
+    /// One of the browser’s core web vitals.
+    WebVital {
+        /// Which web vital this is.
+        vital: WebVital,
+        /// Its value: a time for most vitals, or a score for
+        /// [`WebVital::CumulativeLayoutShift`].
+        value: f64,
+    },
+}
+
+impl Metric {
+    /// A short name for the metric, e.g., `"hydration"` or `"LCP"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Hydration { .. } => "hydration",
+            Self::Interactive { .. } => "interactive",
+            Self::Resource { .. } => "resource",
+            Self::WebVital { vital, .. } => vital.as_str(),
+        }
+    }
+
+    /// The measured value.
+    pub fn value(&self) -> f64 {
+        match self {
+            Self::Hydration { duration } | Self::Resource { duration, .. } => {
+                *duration
+            }
+            Self::Interactive { time, .. } => *time,
+            Self::WebVital { value, .. } => *value,
+        }
+    }
+
+    /// Serializes the metric as a JSON object, as sent by
+    /// [`report_metrics_to`].
+    pub fn to_json(&self) -> String {
+        let label = match self {
+            Self::Interactive { root, .. } => Some(root.as_str()),
+            Self::Resource { type_name, .. } => Some(*type_name),
+            _ => None,
+        };
+        match label {
+            Some(label) => format!(
+                r#"{{"name":"{}","value":{},"label":{}}}"#,
+                self.name(),
+                self.value(),
+                serde_json::Value::from(label)
+            ),
+            None => format!(
+                r#"{{"name":"{}","value":{}}}"#,
+                self.name(),
+                self.value()
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:.1}", self.name(), self.value())
+    }
+}
+
+/// The core web vitals, as reported by the browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebVital {
+    /// Time to first byte.
+    TimeToFirstByte,
+    /// First contentful paint.
+    FirstContentfulPaint,
+    /// Largest contentful paint.
+    LargestContentfulPaint,
+    /// First input delay.
+    FirstInputDelay,
+    /// Cumulative layout shift.
+    CumulativeLayoutShift,
+}
+
+impl WebVital {
+    /// The usual abbreviation for the web vital, e.g., `"LCP"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TimeToFirstByte => "TTFB",
+            Self::FirstContentfulPaint => "FCP",
+            Self::LargestContentfulPaint => "LCP",
+            Self::FirstInputDelay => "FID",
+            Self::CumulativeLayoutShift => "CLS",
+        }
+    }
+}
+
+type Reporter = Rc<dyn Fn(&Metric)>;
+
+thread_local! {
+    static REPORTERS: RefCell<Vec<Reporter>> = Default::default();
+}
+
+/// Calls `f` with every metric measured from now on.
+pub fn on_metric(f: impl Fn(&Metric) + 'static) {
+    REPORTERS.with(|reporters| reporters.borrow_mut().push(Rc::new(f)));
+}
+
+/// Sends every metric measured from now on to `endpoint` as JSON, using
+/// [`navigator.sendBeacon`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/sendBeacon)
+/// so that metrics measured as the page is closed are not lost.
+///
+/// Does nothing outside the browser.
+pub fn report_metrics_to(endpoint: impl Into<String>) {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        let endpoint = endpoint.into();
+        on_metric(move |metric| {
+            if let Some(window) = web_sys::window() {
+                _ = window.navigator().send_beacon_with_opt_str(
+                    &endpoint,
+                    Some(&metric.to_json()),
+                );
+            }
+        });
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    {
+        _ = endpoint;
+    }
+}
+
+/// Delivers a metric to every callback registered with [`on_metric`].
+#[doc(hidden)]
+pub fn report(metric: Metric) {
+    // clone the callbacks out, so they can register others
+    let reporters = REPORTERS.with(|reporters| reporters.borrow().clone());
+    for reporter in reporters {
+        reporter(&metric);
+    }
+}
+
+/// The current time in milliseconds, for measuring durations. In the
+/// browser, this is the time since the page started loading.
+#[doc(hidden)]
+pub fn now() -> f64 {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    return web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or_default();
+
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    {
+        thread_local! {
+            static START: std::time::Instant = std::time::Instant::now();
+        }
+        START.with(|start| start.elapsed().as_secs_f64() * 1000.0)
+    }
+}
+
+/// Starts reporting the core web vitals with [`Metric::WebVital`], using a
+/// [`PerformanceObserver`](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver).
+///
+/// Browsers that don’t support a given vital don’t report it. Does nothing
+/// outside the browser.
+pub fn observe_web_vitals() {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    web_vitals::observe();
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+mod web_vitals {
+    use super::{report, Metric, WebVital};
+    use std::cell::Cell;
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::{
+        PerformanceObserver, PerformanceObserverEntryList,
+        PerformanceObserverInit,
+    };
+
+    pub(super) fn observe() {
+        let navigation = web_sys::window()
+            .and_then(|window| window.performance())
+            .map(|performance| {
+                performance.get_entries_by_type("navigation").get(0)
+            });
+        if let Some(response_start) = navigation
+            .as_ref()
+            .and_then(|entry| number(entry, "responseStart"))
+        {
+            report_vital(WebVital::TimeToFirstByte, response_start);
+        }
+
+        observe_entries("paint", |entry| {
+            if string(entry, "name").as_deref()
+                == Some("first-contentful-paint")
+            {
+                report_vital(WebVital::FirstContentfulPaint, start(entry));
+            }
+        });
+        observe_entries("largest-contentful-paint", |entry| {
+            report_vital(WebVital::LargestContentfulPaint, start(entry));
+        });
+        observe_entries("first-input", |entry| {
+            if let Some(processing_start) = number(entry, "processingStart") {
+                report_vital(
+                    WebVital::FirstInputDelay,
+                    processing_start - start(entry),
+                );
+            }
+        });
+
+        // layout shift is cumulative, so each shift reports the new total
+        let total = Cell::new(0.0);
+        observe_entries("layout-shift", move |entry| {
+            let recent_input = js_sys::Reflect::get(
+                entry,
+                &JsValue::from_str("hadRecentInput"),
+            )
+            .ok()
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false);
+            if !recent_input {
+                total.set(total.get() + number(entry, "value").unwrap_or(0.0));
+                report_vital(WebVital::CumulativeLayoutShift, total.get());
+            }
+        });
+    }
+
+    fn report_vital(vital: WebVital, value: f64) {
+        report(Metric::WebVital { vital, value });
+    }
+
+    fn observe_entries(kind: &str, on_entry: impl Fn(&JsValue) + 'static) {
+        let callback = Closure::<dyn Fn(PerformanceObserverEntryList)>::new(
+            move |list: PerformanceObserverEntryList| {
+                for entry in list.get_entries().iter() {
+                    on_entry(&entry);
+                }
+            },
+        );
+        let Ok(observer) =
+            PerformanceObserver::new(callback.as_ref().unchecked_ref())
+        else {
+            return;
+        };
+        // `type` (rather than `entryTypes`) is needed to see the entries
+        // from before the observer was created
+        let init = js_sys::Object::new();
+        _ = js_sys::Reflect::set(
+            &init,
+            &JsValue::from_str("type"),
+            &JsValue::from_str(kind),
+        );
+        _ = js_sys::Reflect::set(
+            &init,
+            &JsValue::from_str("buffered"),
+            &JsValue::TRUE,
+        );
+        observer.observe(init.unchecked_ref::<PerformanceObserverInit>());
+        callback.forget();
+    }
+
+    fn number(entry: &JsValue, key: &str) -> Option<f64> {
+        js_sys::Reflect::get(entry, &JsValue::from_str(key))
+            .ok()
+            .and_then(|value| value.as_f64())
+    }
+
+    fn string(entry: &JsValue, key: &str) -> Option<String> {
+        js_sys::Reflect::get(entry, &JsValue::from_str(key))
+            .ok()
+            .and_then(|value| value.as_string())
+    }
+
+    fn start(entry: &JsValue) -> f64 {
+        number(entry, "startTime").unwrap_or(0.0)
+    }
+}
@@ -1,24 +1,27 @@
 #![forbid(unsafe_code)]
 use crate::{
     create_effect, create_isomorphic_effect, create_memo, create_signal,
-    queue_microtask,
+    on_cleanup, queue_microtask,
     runtime::{with_runtime, RuntimeId},
     serialization::Serializable,
     spawn::spawn_local,
     use_context, GlobalSuspenseContext, Memo, ReadSignal, Scope, ScopeProperty,
-    SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SuspenseContext,
-    WriteSignal,
+    SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+    SuspenseContext, WriteSignal,
 };
+use futures::FutureExt;
 use std::{
     any::Any,
     cell::{Cell, RefCell},
     collections::HashSet,
+    error::Error,
     fmt::Debug,
     future::Future,
     marker::PhantomData,
-    panic::Location,
+    panic::{AssertUnwindSafe, Location},
     pin::Pin,
     rc::Rc,
+    sync::Arc,
 };
 
 /// Creates a [`Resource`](crate::Resource), which is a signal that reflects the
@@ -112,7 +115,7 @@ where
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_resource_with_initial_value<S, T, Fu>(
     cx: Scope,
     source: impl Fn() -> S + 'static,
@@ -159,7 +162,7 @@ where
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_blocking_resource<S, T, Fu>(
     cx: Scope,
     source: impl Fn() -> S + 'static,
@@ -179,6 +182,335 @@ where
     )
 }
 
+/// Creates a [`Resource`](crate::Resource) whose fetcher returns a [`Result`],
+/// retrying the fetcher according to the [`RetryPolicy`] when it returns `Err`
+/// or panics.
+///
+/// The resource only resolves to `Err` once every retry has failed. Rendering
+/// that `Err` inside an `<ErrorBoundary/>` surfaces it to the boundary, so the
+/// surrounding `<Suspense/>` resolves and shows the error rather than
+/// continuing to show its fallback. If the last attempt panics, the panic is
+/// reported to the [`ErrorSink`] instead. Calling [`Resource::refetch`] starts
+/// over with a fresh set of retries.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// async fn load_user(id: u32) -> Result<String, String> {
+///     Err(format!("user {id} not found"))
+/// }
+///
+/// # if false {
+/// let user = create_resource_with_retry(
+///     cx,
+///     || 1,
+///     load_user,
+///     RetryPolicy::new(3),
+/// );
+/// # }
+/// # }).dispose();
+/// ```
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
+pub fn create_resource_with_retry<S, T, E, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S) -> Fu + 'static,
+    policy: RetryPolicy,
+) -> Resource<S, Result<T, E>>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    E: 'static,
+    Result<T, E>: Serializable,
+    Fu: Future<Output = Result<T, E>> + 'static,
+{
+    let fetcher = Rc::new(fetcher);
+    create_resource_helper(
+        cx,
+        source,
+        move |source| {
+            let fetcher = Rc::clone(&fetcher);
+            let policy = policy.clone();
+            async move {
+                let mut attempt = 0;
+                loop {
+                    let fetch = async { fetcher(source.clone()).await };
+                    match AssertUnwindSafe(fetch).catch_unwind().await {
+                        Ok(Err(_)) | Err(_) if attempt < policy.retries => {
+                            if let Some(backoff) = &policy.backoff {
+                                backoff(attempt).await;
+                            }
+                            attempt += 1;
+                        }
+                        Ok(result) => return result,
+                        Err(panic) => std::panic::resume_unwind(panic),
+                    }
+                }
+            }
+        },
+        None,
+        ResourceSerialization::Serializable,
+    )
+}
+
+/// Controls how often [`create_resource_with_retry`] retries a fetcher that
+/// has returned an error.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    retries: usize,
+    #[allow(clippy::type_complexity)]
+    backoff: Option<Rc<dyn Fn(usize) -> Pin<Box<dyn Future<Output = ()>>>>>,
+}
+
+impl RetryPolicy {
+    /// Retries a failed fetch up to `retries` times, immediately after each
+    /// failure.
+    pub fn new(retries: usize) -> Self {
+        Self {
+            retries,
+            backoff: None,
+        }
+    }
+
+    /// Waits for the future returned by `backoff` before each retry. It is
+    /// called with the number of retries that have already been made, so it
+    /// can implement an increasing delay.
+    pub fn backoff<Fu>(
+        mut self,
+        backoff: impl Fn(usize) -> Fu + 'static,
+    ) -> Self
+    where
+        Fu: Future<Output = ()> + 'static,
+    {
+        self.backoff = Some(Rc::new(move |attempt| {
+            Box::pin(backoff(attempt)) as Pin<Box<dyn Future<Output = ()>>>
+        }));
+        self
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("retries", &self.retries)
+            .field("backoff", &self.backoff.is_some())
+            .finish()
+    }
+}
+
+/// Receives the errors of [`Resource`]s that fail without producing a value,
+/// because their fetcher panicked.
+///
+/// A resource reports its failure to every `ErrorSink` found in the context of
+/// a place it has been read, and stops holding up the `<Suspense/>` it was
+/// read in. `<ErrorBoundary/>` provides one, so the error is shown by the
+/// nearest boundary instead of the fallback spinning forever.
+#[derive(Clone)]
+pub struct ErrorSink(Rc<dyn Fn(Arc<dyn Error + Send + Sync>)>);
+
+impl ErrorSink {
+    /// Creates a sink that passes each error to `report`.
+    pub fn new(
+        report: impl Fn(Arc<dyn Error + Send + Sync>) + 'static,
+    ) -> Self {
+        Self(Rc::new(report))
+    }
+
+    fn report(&self, error: Arc<dyn Error + Send + Sync>) {
+        (self.0)(error)
+    }
+
+    fn same(&self, other: &ErrorSink) -> bool {
+        std::ptr::eq(
+            Rc::as_ptr(&self.0) as *const u8,
+            Rc::as_ptr(&other.0) as *const u8,
+        )
+    }
+}
+
+impl Debug for ErrorSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorSink").finish_non_exhaustive()
+    }
+}
+
+/// The error a [`Resource`] reports to its [`ErrorSink`]s when its fetcher
+/// panics.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("resource fetcher panicked: {message}")]
+pub struct ResourcePanic {
+    message: String,
+}
+
+impl ResourcePanic {
+    fn new(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string()),
+        };
+        Self { message }
+    }
+
+    /// The message the fetcher panicked with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Creates a [`Resource`](crate::Resource) whose fetcher receives a
+/// [`CancellationToken`], which is cancelled when the fetch is no longer needed:
+/// when the `source` changes or the resource is refetched (starting a new
+/// fetch), or when the scope is disposed.
+///
+/// The fetcher can use the token to stop a long computation early, or to abort
+/// a network request. Otherwise, this is identical to [`create_resource`].
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// # async fn next_chunk(_page: usize) -> Option<Vec<u8>> { None }
+/// # if false {
+/// let (page, set_page) = create_signal(cx, 1);
+/// let data = create_resource_with_cancellation(cx, page, |page, token| async move {
+///     let mut data = Vec::new();
+///     while let Some(chunk) = next_chunk(page).await {
+///         // stop downloading if `page` changed in the meantime
+///         if token.is_cancelled() {
+///             break;
+///         }
+///         data.extend(chunk);
+///     }
+///     data
+/// });
+/// # }
+/// # }).dispose();
+/// ```
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
+pub fn create_resource_with_cancellation<S, T, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, CancellationToken) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_resource_helper(
+        cx,
+        source,
+        cancel_previous_fetch(cx, fetcher),
+        None,
+        ResourceSerialization::Serializable,
+    )
+}
+
+/// Creates a _local_ [`Resource`](crate::Resource) whose fetcher receives a
+/// [`CancellationToken`]. See [`create_resource_with_cancellation`] and
+/// [`create_local_resource`].
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
+pub fn create_local_resource_with_cancellation<S, T, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, CancellationToken) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_local_resource(cx, source, cancel_previous_fetch(cx, fetcher))
+}
+
+// wraps a fetcher so that each call cancels the token given to the previous
+// call, as does disposing the scope
+fn cancel_previous_fetch<S, Fu>(
+    cx: Scope,
+    fetcher: impl Fn(S, CancellationToken) -> Fu + 'static,
+) -> impl Fn(S) -> Fu + 'static
+where
+    S: 'static,
+    Fu: 'static,
+{
+    let current = Rc::new(RefCell::new(None::<CancellationToken>));
+    on_cleanup(cx, {
+        let current = Rc::clone(&current);
+        move || {
+            if let Some(token) = current.take() {
+                token.cancel();
+            }
+        }
+    });
+    move |source| {
+        let token = CancellationToken::default();
+        if let Some(previous) = current.replace(Some(token.clone())) {
+            previous.cancel();
+        }
+        fetcher(source, token)
+    }
+}
+
+/// Signals to a resource’s fetcher that its result is no longer needed; see
+/// [`create_resource_with_cancellation`].
+///
+/// In the browser, this can be connected to an
+/// [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController)
+/// to abort a `fetch` request:
+/// ```ignore
+/// let controller = web_sys::AbortController::new().unwrap();
+/// token.on_cancel({
+///     let controller = controller.clone();
+///     move || controller.abort()
+/// });
+/// let mut init = web_sys::RequestInit::new();
+/// init.signal(Some(&controller.signal()));
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken(Rc<CancellationState>);
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: Cell<bool>,
+    callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+impl CancellationToken {
+    /// Whether the fetch has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.get()
+    }
+
+    /// Calls `f` when the fetch is cancelled, or immediately if it already
+    /// has been.
+    pub fn on_cancel(&self, f: impl FnOnce() + 'static) {
+        if self.is_cancelled() {
+            f();
+        } else {
+            self.0.callbacks.borrow_mut().push(Box::new(f));
+        }
+    }
+
+    /// Cancels the fetch, calling any [`on_cancel`](Self::on_cancel)
+    /// callbacks. Does nothing if it has already been cancelled.
+    pub fn cancel(&self) {
+        if !self.0.cancelled.replace(true) {
+            let callbacks = self.0.callbacks.take();
+            for callback in callbacks {
+                callback();
+            }
+        }
+    }
+}
+
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
 fn create_resource_helper<S, T, Fu>(
     cx: Scope,
     source: impl Fn() -> S + 'static,
@@ -211,8 +543,11 @@ where
         fetcher,
         resolved: Rc::new(Cell::new(resolved)),
         scheduled: Rc::new(Cell::new(false)),
-        preempted: Rc::new(Cell::new(false)),
+        version: Default::default(),
+        in_flight: Default::default(),
+        failure: Default::default(),
         suspense_contexts: Default::default(),
+        error_sinks: Default::default(),
         serializable,
     });
 
@@ -348,8 +683,11 @@ where
         fetcher,
         resolved: Rc::new(Cell::new(resolved)),
         scheduled: Rc::new(Cell::new(false)),
-        preempted: Rc::new(Cell::new(false)),
+        version: Default::default(),
+        in_flight: Default::default(),
+        failure: Default::default(),
         suspense_contexts: Default::default(),
+        error_sinks: Default::default(),
         serializable: ResourceSerialization::Local,
     });
 
@@ -422,19 +760,23 @@ where
             r.set_loading.update(|n| *n = true);
 
             let resolve = {
-                let resolved = r.resolved.clone();
-                let set_value = r.set_value;
-                let set_loading = r.set_loading;
-                move |res: String| {
+                let r = Rc::clone(&r);
+                move |res: Option<String>| {
+                    // the server gave up waiting for this resource, so load it
+                    // on the client instead
+                    let Some(res) = res else {
+                        r.load(false);
+                        return;
+                    };
                     let res = T::de(&res)
                         .expect_throw("could not deserialize Resource JSON");
-                    resolved.set(true);
-                    set_value.update(|n| *n = Some(res));
-                    set_loading.update(|n| *n = false);
+                    r.resolved.set(true);
+                    r.set_value.update(|n| *n = Some(res));
+                    r.set_loading.update(|n| *n = false);
                 }
             };
             let resolve = wasm_bindgen::closure::Closure::wrap(
-                Box::new(resolve) as Box<dyn Fn(String)>,
+                Box::new(resolve) as Box<dyn Fn(Option<String>)>,
             );
             let resource_resolvers = js_sys::Reflect::get(
                 &web_sys::window().unwrap(),
@@ -476,7 +818,7 @@ where
         any(debug_assertions, feature = "ssr"),
         instrument(level = "info", skip_all,)
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn read(&self, cx: Scope) -> Option<T>
     where
         T: Clone,
@@ -502,7 +844,7 @@ where
         any(debug_assertions, feature = "ssr"),
         instrument(level = "info", skip_all,)
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn with<U>(&self, cx: Scope, f: impl FnOnce(&T) -> U) -> Option<U> {
         let location = std::panic::Location::caller();
         with_runtime(self.runtime, |runtime| {
@@ -571,6 +913,121 @@ where
     }
 }
 
+impl<S, T, E> Resource<S, Result<T, E>>
+where
+    S: Clone + 'static,
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    /// Returns the current state of a resource whose fetcher returns a
+    /// [`Result`], and subscribes the running effect to this resource, so that
+    /// views can match on it rather than on an `Option<Result<T, E>>`.
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # create_scope(create_runtime(), |cx| {
+    /// # async fn load_user(id: u32) -> Result<String, String> { todo!() }
+    /// # if false {
+    /// let user = create_resource(cx, || 1, load_user);
+    ///
+    /// let message = move || match user.state(cx) {
+    ///     ResourceStatus::Loading => "Loading...".to_string(),
+    ///     ResourceStatus::Reloading(name) => format!("{name} (refreshing)"),
+    ///     ResourceStatus::Ready(name) => name,
+    ///     ResourceStatus::Errored(e) => format!("Error: {e}"),
+    /// };
+    /// # }
+    /// # }).dispose();
+    /// ```
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
+    pub fn state(&self, cx: Scope) -> ResourceStatus<T, E> {
+        let loading = self.loading().get();
+        match (self.read(cx), loading) {
+            (Some(Ok(value)), true) => ResourceStatus::Reloading(value),
+            (Some(Ok(value)), false) => ResourceStatus::Ready(value),
+            (Some(Err(error)), false) => ResourceStatus::Errored(error),
+            (Some(Err(_)), true) | (None, _) => ResourceStatus::Loading,
+        }
+    }
+}
+
+/// The state of a [`Resource`] whose fetcher returns a [`Result`], returned by
+/// [`Resource::state`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResourceStatus<T, E> {
+    /// The resource is loading, and has no previous value to show. (If the
+    /// previous fetch failed, the error is discarded while reloading.)
+    Loading,
+    /// The resource is loading again, and this is the previous value.
+    Reloading(T),
+    /// The resource has loaded successfully.
+    Ready(T),
+    /// The resource’s fetcher returned an error.
+    Errored(E),
+}
+
+impl<T, E> ResourceStatus<T, E> {
+    /// Returns `true` if the resource is loading, whether or not it has a
+    /// previous value.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, ResourceStatus::Loading | ResourceStatus::Reloading(_))
+    }
+
+    /// Returns the current value, if any, whether or not it is being
+    /// reloaded.
+    pub fn value(self) -> Option<T> {
+        match self {
+            ResourceStatus::Reloading(value) | ResourceStatus::Ready(value) => {
+                Some(value)
+            }
+            ResourceStatus::Loading | ResourceStatus::Errored(_) => None,
+        }
+    }
+
+    /// Applies a function to the value, if any.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ResourceStatus<U, E> {
+        match self {
+            ResourceStatus::Loading => ResourceStatus::Loading,
+            ResourceStatus::Reloading(value) => {
+                ResourceStatus::Reloading(f(value))
+            }
+            ResourceStatus::Ready(value) => ResourceStatus::Ready(f(value)),
+            ResourceStatus::Errored(error) => ResourceStatus::Errored(error),
+        }
+    }
+
+    /// Applies a fallible function to the value, if any, returning
+    /// [`ResourceStatus::Errored`] if it fails.
+    pub fn and_then<U>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> ResourceStatus<U, E> {
+        match self {
+            ResourceStatus::Loading => ResourceStatus::Loading,
+            ResourceStatus::Reloading(value) => match f(value) {
+                Ok(value) => ResourceStatus::Reloading(value),
+                Err(error) => ResourceStatus::Errored(error),
+            },
+            ResourceStatus::Ready(value) => match f(value) {
+                Ok(value) => ResourceStatus::Ready(value),
+                Err(error) => ResourceStatus::Errored(error),
+            },
+            ResourceStatus::Errored(error) => ResourceStatus::Errored(error),
+        }
+    }
+
+    /// Applies a function to the error, if any.
+    pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> ResourceStatus<T, F> {
+        match self {
+            ResourceStatus::Loading => ResourceStatus::Loading,
+            ResourceStatus::Reloading(value) => {
+                ResourceStatus::Reloading(value)
+            }
+            ResourceStatus::Ready(value) => ResourceStatus::Ready(value),
+            ResourceStatus::Errored(error) => ResourceStatus::Errored(f(error)),
+        }
+    }
+}
+
 impl<S, T> SignalUpdate<Option<T>> for Resource<S, T> {
     #[cfg_attr(
         debug_assertions,
@@ -607,8 +1064,10 @@ impl<S, T> SignalUpdate<Option<T>> for Resource<S, T> {
     fn try_update<O>(&self, f: impl FnOnce(&mut Option<T>) -> O) -> Option<O> {
         with_runtime(self.runtime, |runtime| {
             runtime.resource(self.id, |resource: &ResourceState<S, T>| {
-                if resource.loading.get_untracked() {
-                    resource.preempted.set(true);
+                // any loads in flight are now out of date
+                resource.version.set(resource.version.get() + 1);
+                let preempted = resource.in_flight.take();
+                for _ in preempted {
                     for suspense_context in
                         resource.suspense_contexts.borrow().iter()
                     {
@@ -772,8 +1231,14 @@ where
     fetcher: Rc<dyn Fn(S) -> Pin<Box<dyn Future<Output = T>>>>,
     resolved: Rc<Cell<bool>>,
     scheduled: Rc<Cell<bool>>,
-    preempted: Rc<Cell<bool>>,
+    /// Increases with every load, so that a load that finishes after a newer
+    /// one has started, or after the value has been set, can be ignored.
+    version: Rc<Cell<usize>>,
+    /// The versions of the loads that are still holding up suspense.
+    in_flight: Rc<RefCell<Vec<usize>>>,
+    failure: Rc<RefCell<Option<Arc<dyn Error + Send + Sync>>>>,
     suspense_contexts: Rc<RefCell<HashSet<SuspenseContext>>>,
+    error_sinks: Rc<RefCell<Vec<ErrorSink>>>,
     serializable: ResourceSerialization,
 }
 
@@ -798,7 +1263,7 @@ where
         any(debug_assertions, feature = "ssr"),
         instrument(level = "info", skip_all,)
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn read(
         &self,
         cx: Scope,
@@ -814,7 +1279,7 @@ where
         any(debug_assertions, feature = "ssr"),
         instrument(level = "info", skip_all,)
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn with<U>(
         &self,
         cx: Scope,
@@ -823,6 +1288,7 @@ where
     ) -> Option<U> {
         let global_suspense_cx = use_context::<GlobalSuspenseContext>(cx);
         let suspense_cx = use_context::<SuspenseContext>(cx);
+        let error_sink = use_context::<ErrorSink>(cx);
 
         let v = self
             .value
@@ -831,7 +1297,11 @@ where
             .flatten();
 
         let suspense_contexts = self.suspense_contexts.clone();
-        let has_value = v.is_some();
+        let error_sinks = self.error_sinks.clone();
+        let failure = self.failure.clone();
+        // a resource that has failed isn't going to resolve, so it shouldn't
+        // hold up the suspense it's read in
+        let has_value = v.is_some() || failure.borrow().is_some();
 
         let serializable = self.serializable;
         if let Some(suspense_cx) = &suspense_cx {
@@ -902,6 +1372,25 @@ where
                     })
                 }
             }
+
+            if let Some(sink) = &error_sink {
+                let registered =
+                    error_sinks.try_borrow_mut().map(|mut sinks| {
+                        let registered = sinks.iter().any(|s| s.same(sink));
+                        if !registered {
+                            sinks.push(sink.clone());
+                        }
+                        registered
+                    });
+                // a sink that starts listening after the resource has failed
+                // still hears about the failure
+                if let Ok(false) = registered {
+                    let failure = failure.borrow().clone();
+                    if let Some(error) = failure {
+                        sink.report(error);
+                    }
+                }
+            }
         };
 
         create_isomorphic_effect(cx, increment);
@@ -924,11 +1413,14 @@ where
             return;
         }
 
-        self.preempted.set(false);
         self.scheduled.set(false);
+        self.failure.take();
 
         _ = self.source.try_with(|source| {
             let fut = (self.fetcher)(source.clone());
+            let version = self.version.get() + 1;
+            self.version.set(version);
+            self.in_flight.borrow_mut().push(version);
 
             // `scheduled` is true for the rest of this code only
             self.scheduled.set(true);
@@ -955,22 +1447,52 @@ where
 
             // run the Future
             let serializable = self.serializable;
+            #[cfg(feature = "metrics")]
+            let started = crate::metrics::now();
             spawn_local({
                 let resolved = self.resolved.clone();
                 let set_value = self.set_value;
                 let set_loading = self.set_loading;
-                let preempted = self.preempted.clone();
+                let latest = self.version.clone();
+                let in_flight = self.in_flight.clone();
+                let failure = self.failure.clone();
+                let error_sinks = self.error_sinks.clone();
                 async move {
-                    let res = fut.await;
-                    resolved.set(true);
-
-                    if !preempted.get() {
-                        set_value.update(|n| *n = Some(res));
+                    // a fetcher that panics fails the resource, rather than
+                    // leaving it loading forever
+                    let res = AssertUnwindSafe(fut).catch_unwind().await;
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::report(crate::metrics::Metric::Resource {
+                        type_name: std::any::type_name::<T>(),
+                        duration: crate::metrics::now() - started,
+                    });
+
+                    // every load holds up suspense until it finishes, unless
+                    // the value was set in the meantime
+                    let holding_suspense = {
+                        let mut in_flight = in_flight.borrow_mut();
+                        let position =
+                            in_flight.iter().position(|v| *v == version);
+                        position.map(|position| in_flight.remove(position))
+                    };
+
+                    // only the latest load gets to set the value, so that an
+                    // older one (e.g., a cancelled request) that finishes
+                    // later can't overwrite it
+                    if version == latest.get() {
+                        resolved.set(true);
+                        match res {
+                            Ok(res) => set_value.update(|n| *n = Some(res)),
+                            Err(panic) => {
+                                let error: Arc<dyn Error + Send + Sync> =
+                                    Arc::new(ResourcePanic::new(panic));
+                                *failure.borrow_mut() = Some(error);
+                            }
+                        }
+                        set_loading.update(|n| *n = false);
                     }
 
-                    set_loading.update(|n| *n = false);
-
-                    if !preempted.get() {
+                    if holding_suspense.is_some() {
                         for suspense_context in
                             suspense_contexts.borrow().iter()
                         {
@@ -979,7 +1501,16 @@ where
                             );
                         }
                     }
-                    preempted.set(false);
+
+                    if version == latest.get() {
+                        let failure = failure.borrow().clone();
+                        if let Some(error) = failure {
+                            let sinks = error_sinks.borrow().clone();
+                            for sink in sinks {
+                                sink.report(Arc::clone(&error));
+                            }
+                        }
+                    }
                 }
             })
         });
@@ -472,7 +472,7 @@ impl RuntimeId {
         ret
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)] // only because it's placed here to fit in with the other create methods
     pub(crate) fn create_trigger(self) -> Trigger {
         let id = with_runtime(self, |runtime| {
@@ -508,7 +508,7 @@ impl RuntimeId {
         .expect("tried to create a signal in a runtime that has been disposed")
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn create_signal<T>(
         self,
@@ -539,7 +539,7 @@ impl RuntimeId {
         )
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub(crate) fn create_many_signals_with_map<T, U>(
         self,
         cx: Scope,
@@ -595,7 +595,7 @@ impl RuntimeId {
         .expect("tried to create a signal in a runtime that has been disposed")
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn create_rw_signal<T>(self, value: T) -> RwSignal<T>
     where
@@ -661,7 +661,7 @@ impl RuntimeId {
         .expect("tried to create a memo in a runtime that has been disposed")
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn create_effect<T>(
         self,
@@ -681,7 +681,7 @@ impl RuntimeId {
         )
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn create_memo<T>(
         self,
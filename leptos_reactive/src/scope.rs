@@ -460,6 +460,25 @@ impl Scope {
         .unwrap_or_default()
     }
 
+    /// Returns a JSON object containing every context value provided with
+    /// [`provide_serialized_context`](crate::provide_serialized_context),
+    /// for inclusion in server-rendered HTML.
+    pub fn serialized_contexts(&self) -> String {
+        with_runtime(self.runtime, |runtime| {
+            // sorted, so that the same contexts are always written the same way
+            let shared_context = runtime.shared_context.borrow();
+            let contexts = shared_context
+                .serialized_contexts
+                .iter()
+                .collect::<std::collections::BTreeMap<_, _>>();
+            serde_json::to_string(&contexts)
+                .unwrap_or_else(|_| "{}".to_string())
+        })
+        .unwrap_or_else(|_| "{}".to_string())
+        // keep the JSON from closing the <script> tag it is embedded in
+        .replace('<', "\\u003c")
+    }
+
     /// Registers the given [`SuspenseContext`](crate::SuspenseContext) with the current scope,
     /// calling the `resolver` when its resources are all resolved.
     #[cfg_attr(
@@ -116,7 +116,7 @@ pub trait SignalGet<T> {
     ///
     /// # Panics
     /// Panics if you try to access a signal that was created in a [`Scope`] that has been disposed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn get(&self) -> T;
 
     /// Clones and returns the signal value, returning [`Some`] if the signal
@@ -132,7 +132,7 @@ pub trait SignalWith<T> {
     ///
     /// # Panics
     /// Panics if you try to access a signal that was created in a [`Scope`] that has been disposed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O;
 
     /// Applies a function to the current value of the signal, and subscribes
@@ -152,7 +152,7 @@ pub trait SignalSet<T> {
     ///
     /// **Note:** `set()` does not auto-memoize, i.e., it will notify subscribers
     /// even if the value has not actually changed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn set(&self, new_value: T);
 
     /// Sets the signal’s value and notifies subscribers. Returns [`None`]
@@ -170,7 +170,7 @@ pub trait SignalUpdate<T> {
     ///
     /// **Note:** `update()` does not auto-memoize, i.e., it will notify subscribers
     /// even if the value has not actually changed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn update(&self, f: impl FnOnce(&mut T));
 
     /// Applies a function to the current value to mutate it in place
@@ -192,7 +192,7 @@ pub trait SignalGetUntracked<T> {
     ///
     /// # Panics
     /// Panics if you try to access a signal that was created in a [`Scope`] that has been disposed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn get_untracked(&self) -> T;
 
     /// Gets the signal's value without creating a dependency on the
@@ -209,14 +209,14 @@ pub trait SignalWithUntracked<T> {
     ///
     /// # Panics
     /// Panics if you try to access a signal that was created in a [`Scope`] that has been disposed.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O;
 
     /// Runs the provided closure with a reference to the current
     /// value without creating a dependency on the current scope.
     /// Returns [`Some(O)`] if the signal is still valid, [`None`]
     /// otherwise.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O>;
 }
 
@@ -226,12 +226,12 @@ pub trait SignalWithUntracked<T> {
 /// from being run.
 pub trait SignalSetUntracked<T> {
     /// Sets the signal's value without notifying dependents.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn set_untracked(&self, new_value: T);
 
     /// Attempts to set the signal if it's still valid. Returns [`None`]
     /// if the signal was set, [`Some(T)`] otherwise.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn try_set_untracked(&self, new_value: T) -> Option<T>;
 }
 
@@ -240,7 +240,7 @@ pub trait SignalSetUntracked<T> {
 pub trait SignalUpdateUntracked<T> {
     /// Runs the provided closure with a mutable reference to the current
     /// value without notifying dependents.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn update_untracked(&self, f: impl FnOnce(&mut T));
 
     /// Runs the provided closure with a mutable reference to the current
@@ -260,7 +260,7 @@ pub trait SignalStream<T> {
     // We're returning an opaque type until impl trait in trait
     // positions are stabilized, and also so any underlying
     // changes are non-breaking
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn to_stream(&self, cx: Scope) -> Pin<Box<dyn Stream<Item = T>>>;
 }
 
@@ -270,7 +270,7 @@ pub trait SignalDispose {
     /// 1. Detaches the signal from the reactive graph, preventing it from triggering
     ///    further updates; and
     /// 2. Drops the value contained in the signal.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn dispose(self);
 }
 
@@ -323,7 +323,7 @@ pub trait SignalDispose {
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_signal<T>(
     cx: Scope,
     value: T,
@@ -345,7 +345,7 @@ pub fn create_signal<T>(
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_many_signals<T>(
     cx: Scope,
     values: impl IntoIterator<Item = T>,
@@ -365,7 +365,7 @@ pub fn create_many_signals<T>(
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_many_signals_mapped<T, U>(
     cx: Scope,
     values: impl IntoIterator<Item = T>,
@@ -382,6 +382,9 @@ where
 /// If the stream has not yet emitted a value since the signal was created, the signal's
 /// value will be `None`.
 ///
+/// The stream is dropped when `cx` is disposed, so it stops being polled once
+/// the signal can no longer be read.
+///
 /// **Note**: If used on the server side during server rendering, this will return `None`
 /// immediately and not begin driving the stream.
 #[cfg_attr(
@@ -405,15 +408,19 @@ pub fn create_signal_from_stream<T>(
             let (read, _) = create_signal(cx, None);
             read
         } else {
-            use crate::spawn_local;
-            use futures::StreamExt;
+            use crate::{on_cleanup, spawn_local};
+            use futures::{future::abortable, StreamExt};
 
             let (read, write) = create_signal(cx, None);
-            spawn_local(async move {
+            let (fut, handle) = abortable(async move {
                 while let Some(value) = stream.next().await {
                     write.set(Some(value));
                 }
             });
+            on_cleanup(cx, move || handle.abort());
+            spawn_local(async move {
+                _ = fut.await;
+            });
             read
         }
     }
@@ -521,7 +528,7 @@ impl<T: Clone> SignalGetUntracked<T> for ReadSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn try_get_untracked(&self) -> Option<T> {
         with_runtime(self.runtime, |runtime| {
             self.id.try_with_no_subscription(runtime, Clone::clone).ok()
@@ -563,7 +570,7 @@ impl<T> SignalWithUntracked<T> for ReadSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         let diagnostics = diagnostics!(self);
@@ -609,7 +616,7 @@ impl<T> SignalWith<T> for ReadSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
         let diagnostics = diagnostics!(self);
@@ -640,7 +647,7 @@ impl<T> SignalWith<T> for ReadSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         let diagnostics = diagnostics!(self);
@@ -680,7 +687,7 @@ impl<T: Clone> SignalGet<T> for ReadSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn get(&self) -> T {
         let diagnostics = diagnostics!(self);
 
@@ -763,7 +770,7 @@ where
 
     /// Applies the function to the current Signal, if it exists, and subscribes
     /// the running effect.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn try_with<U>(
         &self,
@@ -1134,7 +1141,7 @@ impl<T> Hash for WriteSignal<T> {
         )
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_rw_signal<T>(cx: Scope, value: T) -> RwSignal<T> {
     let s = cx.runtime.create_rw_signal(value);
     cx.push_scope_property(ScopeProperty::Signal(s.id));
@@ -1308,7 +1315,7 @@ impl<T> SignalWithUntracked<T> for RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         let diagnostics = diagnostics!(self);
@@ -1438,7 +1445,7 @@ impl<T> SignalWith<T> for RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
         let diagnostics = diagnostics!(self);
@@ -1469,7 +1476,7 @@ impl<T> SignalWith<T> for RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         let diagnostics = diagnostics!(self);
@@ -1510,7 +1517,7 @@ impl<T: Clone> SignalGet<T> for RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn get(&self) -> T
     where
         T: Clone,
@@ -1543,7 +1550,7 @@ impl<T: Clone> SignalGet<T> for RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn try_get(&self) -> Option<T> {
         let diagnostics = diagnostics!(self);
 
@@ -1726,7 +1733,7 @@ impl<T> RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn read_only(&self) -> ReadSignal<T> {
         ReadSignal {
             runtime: self.runtime,
@@ -1764,7 +1771,7 @@ impl<T> RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn write_only(&self) -> WriteSignal<T> {
         WriteSignal {
             runtime: self.runtime,
@@ -1801,7 +1808,7 @@ impl<T> RwSignal<T> {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn split(&self) -> (ReadSignal<T>, WriteSignal<T>) {
         (
             ReadSignal {
@@ -1833,7 +1840,7 @@ pub(crate) enum SignalError {
 }
 
 impl NodeId {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub(crate) fn subscribe(
         &self,
         runtime: &Runtime,
@@ -1892,7 +1899,7 @@ impl NodeId {
         Ok(node.value())
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn try_with_no_subscription<T, U>(
         &self,
@@ -1911,7 +1918,7 @@ impl NodeId {
         Ok(f(value))
     }
 
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     pub(crate) fn try_with<T, U>(
         &self,
@@ -2042,7 +2049,7 @@ impl NodeId {
 
 #[cold]
 #[inline(never)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 fn format_signal_warning(
     msg: &str,
     #[cfg(any(debug_assertions, feature = "ssr"))]
@@ -2067,7 +2074,7 @@ fn format_signal_warning(
 
 #[cold]
 #[inline(never)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub(crate) fn panic_getting_dead_signal(
     #[cfg(any(debug_assertions, feature = "ssr"))]
     defined_at: &'static std::panic::Location<'static>,
@@ -2084,7 +2091,7 @@ pub(crate) fn panic_getting_dead_signal(
 
 #[cold]
 #[inline(never)]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub(crate) fn warn_updating_dead_signal(
     #[cfg(any(debug_assertions, feature = "ssr"))]
     defined_at: &'static std::panic::Location<'static>,
@@ -353,7 +353,7 @@ where
     /// assert_eq!(above_3(&double_count), true);
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         instrument(
@@ -393,7 +393,7 @@ where
 }
 
 impl<T> From<ReadSignal<T>> for Signal<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn from(value: ReadSignal<T>) -> Self {
         Self {
             inner: SignalTypes::ReadSignal(value),
@@ -404,7 +404,7 @@ impl<T> From<ReadSignal<T>> for Signal<T> {
 }
 
 impl<T> From<RwSignal<T>> for Signal<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn from(value: RwSignal<T>) -> Self {
         Self {
             inner: SignalTypes::ReadSignal(value.read_only()),
@@ -415,7 +415,7 @@ impl<T> From<RwSignal<T>> for Signal<T> {
 }
 
 impl<T> From<Memo<T>> for Signal<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn from(value: Memo<T>) -> Self {
         Self {
             inner: SignalTypes::Memo(value),
@@ -72,7 +72,7 @@ impl<T> Clone for SignalSetter<T> {
 }
 
 impl<T: Default + 'static> Default for SignalSetter<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn default() -> Self {
         Self {
             inner: SignalSetterTypes::Default,
@@ -136,7 +136,7 @@ where
     /// assert_eq!(count(), 8);
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         instrument(
@@ -199,7 +199,7 @@ where
 }
 
 impl<T> From<WriteSignal<T>> for SignalSetter<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn from(value: WriteSignal<T>) -> Self {
         Self {
             inner: SignalSetterTypes::Write(value),
@@ -210,7 +210,7 @@ impl<T> From<WriteSignal<T>> for SignalSetter<T> {
 }
 
 impl<T> From<RwSignal<T>> for SignalSetter<T> {
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     fn from(value: RwSignal<T>) -> Self {
         Self {
             inner: SignalSetterTypes::Write(value.write_only()),
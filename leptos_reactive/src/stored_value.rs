@@ -91,7 +91,7 @@ impl<T> StoredValue<T> {
     /// assert_eq!(data().value, "a");
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn get_value(&self) -> T
     where
         T: Clone,
@@ -100,7 +100,7 @@ impl<T> StoredValue<T> {
     }
 
     /// Same as [`StoredValue::get_value`] but will not panic by default.
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn try_get_value(&self) -> Option<T>
     where
         T: Clone,
@@ -127,7 +127,7 @@ impl<T> StoredValue<T> {
     /// assert_eq!(data.with_value(|data| data.value.clone()), "a");
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     //               track the stored value. This method will also be removed in \
     //               a future version of `leptos`"]
     pub fn with_value<U>(&self, f: impl FnOnce(&T) -> U) -> U {
@@ -184,7 +184,7 @@ impl<T> StoredValue<T> {
     /// assert_eq!(updated, Some(String::from("b")));
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn update_value(&self, f: impl FnOnce(&mut T)) {
         self.try_update_value(f)
             .expect("could not set stored value");
@@ -219,7 +219,7 @@ impl<T> StoredValue<T> {
     /// assert_eq!(data.with_value(|data| data.value.clone()), "b");
     /// # });
     /// ```
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     pub fn set_value(&self, value: T) {
         self.try_set_value(value);
     }
@@ -279,7 +279,7 @@ impl<T> StoredValue<T> {
 /// let callback_b = move || data.with_value(|data| data.value == "b");
 /// # }).dispose();
 /// ```
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn store_value<T>(cx: Scope, value: T) -> StoredValue<T>
 where
     T: 'static,
@@ -98,7 +98,7 @@ impl Trigger {
         fields(scope = ?cx.id)
     )
 )]
-#[track_caller]
+#[cfg_attr(not(feature = "strip-debug"), track_caller)]
 pub fn create_trigger(cx: Scope) -> Trigger {
     let t = cx.runtime.create_trigger();
     cx.push_scope_property(ScopeProperty::Trigger(t.id));
@@ -118,7 +118,7 @@ impl SignalGet<()> for Trigger {
             )
         )
     )]
-    #[track_caller]
+    #[cfg_attr(not(feature = "strip-debug"), track_caller)]
     #[inline(always)]
     fn get(&self) {
         self.track()
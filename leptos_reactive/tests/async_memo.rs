@@ -0,0 +1,54 @@
+#[cfg(not(feature = "ssr"))]
+use leptos_reactive::*;
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn async_memo_recomputes_when_dependencies_change() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 1);
+        let doubled = create_async_memo(cx, move || {
+            let count = count.get();
+            async move { count * 2 }
+        });
+
+        assert_eq!(doubled.get(), Some(2));
+        assert!(!doubled.pending().get());
+
+        set_count.set(5);
+        assert_eq!(doubled.get(), Some(10));
+    })
+    .dispose()
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn async_memo_only_notifies_when_value_changes() {
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 2);
+        let is_even = create_async_memo(cx, move || {
+            let count = count.get();
+            async move { count % 2 == 0 }
+        });
+
+        let runs = Rc::new(Cell::new(0));
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                is_even.track();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        set_count.set(4);
+        assert_eq!(is_even.get(), Some(true));
+        assert_eq!(runs.get(), 1);
+
+        set_count.set(5);
+        assert_eq!(is_even.get(), Some(false));
+        assert_eq!(runs.get(), 2);
+    })
+    .dispose()
+}
@@ -0,0 +1,79 @@
+use leptos_reactive::{
+    create_resource_with_cancellation, create_runtime, create_scope,
+    provide_context, CancellationToken, Resource, SignalGet, SuspenseContext,
+};
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn cancel_runs_callbacks_once() {
+    let token = CancellationToken::default();
+    let calls = Rc::new(Cell::new(0));
+    token.on_cancel({
+        let calls = Rc::clone(&calls);
+        move || calls.set(calls.get() + 1)
+    });
+
+    assert!(!token.is_cancelled());
+    token.clone().cancel();
+    token.cancel();
+    assert!(token.is_cancelled());
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn on_cancel_after_cancel_runs_immediately() {
+    let token = CancellationToken::default();
+    token.cancel();
+
+    let called = Rc::new(Cell::new(false));
+    token.on_cancel({
+        let called = Rc::clone(&called);
+        move || called.set(true)
+    });
+    assert!(called.get());
+}
+
+// fetchers return immediately, so a load that should overlap with another
+// starts it from inside its own future, before it has finished
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn only_the_latest_of_two_loads_is_kept() {
+    create_scope(create_runtime(), |cx| {
+        let suspense = SuspenseContext::new(cx);
+        provide_context(cx, suspense);
+
+        let this = Rc::new(Cell::new(None::<Resource<(), _>>));
+        let calls = Rc::new(Cell::new(0));
+        let resource = create_resource_with_cancellation(cx, || (), {
+            let this = Rc::clone(&this);
+            move |_, token: CancellationToken| {
+                calls.set(calls.get() + 1);
+                let call = calls.get();
+                let this = this.get();
+                async move {
+                    if call == 2 {
+                        if let Some(this) = this {
+                            this.refetch();
+                        }
+                    }
+                    if token.is_cancelled() {
+                        Err::<usize, _>("aborted".to_string())
+                    } else {
+                        Ok(call)
+                    }
+                }
+            }
+        });
+        this.set(Some(resource));
+        assert_eq!(resource.read(cx), Some(Ok(1)));
+
+        // the second load starts a third while it's in flight, and then
+        // finishes as cancelled
+        resource.refetch();
+        assert_eq!(resource.read(cx), Some(Ok(3)));
+        assert!(!resource.loading().get());
+        assert!(suspense.ready());
+    })
+    .dispose()
+}
@@ -0,0 +1,47 @@
+use leptos_reactive::*;
+
+#[test]
+fn channel_notifies_subscribers_in_other_scopes() {
+    use std::{cell::RefCell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        provide_channels(cx);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        cx.child_scope({
+            let seen = Rc::clone(&seen);
+            move |cx| {
+                let messages = use_channel::<String>(cx, "messages");
+                create_isomorphic_effect(cx, move |_| {
+                    seen.borrow_mut().push(messages.get());
+                });
+            }
+        });
+
+        cx.child_scope(|cx| {
+            let messages = use_channel::<String>(cx, "messages");
+            messages.publish("hello".to_string());
+            messages.publish("hello".to_string());
+        });
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![None, Some("hello".to_string()), Some("hello".to_string())]
+        );
+    })
+    .dispose()
+}
+
+#[test]
+fn channels_are_keyed_by_topic_and_type() {
+    create_scope(create_runtime(), |cx| {
+        provide_channels(cx);
+
+        use_channel::<u32>(cx, "count").publish(1);
+        use_channel::<u32>(cx, "other").publish(2);
+
+        assert_eq!(use_channel::<u32>(cx, "count").get(), Some(1));
+        assert_eq!(use_channel::<i64>(cx, "count").get(), None);
+    })
+    .dispose()
+}
@@ -0,0 +1,77 @@
+use leptos_reactive::*;
+
+#[test]
+fn list_store_applies_ops() {
+    let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+
+    let list = create_list_store(cx, vec![1, 2, 3]);
+    list.push(4);
+    list.insert(0, 0);
+    list.swap(1, 4);
+    list.remove(2);
+    list.set(0, 10);
+
+    assert_eq!(list.with(|items| items.to_vec()), vec![10, 4, 3, 1]);
+
+    list.clear();
+    assert!(list.is_empty());
+
+    disposer.dispose();
+}
+
+#[test]
+fn list_store_ignores_out_of_bounds_ops() {
+    let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+
+    let list = create_list_store(cx, vec!["a"]);
+    let ops = store_value(cx, 0);
+    list.on_op(cx, move |_| ops.update_value(|n| *n += 1));
+
+    assert!(!list.apply(ListOp::Remove(1)));
+    assert!(!list.apply(ListOp::Swap(0, 1)));
+    assert!(!list.apply(ListOp::Insert(2, "b")));
+    assert!(list.apply(ListOp::Insert(1, "b")));
+
+    assert_eq!(ops.get_value(), 1);
+    assert_eq!(list.with(|items| items.to_vec()), vec!["a", "b"]);
+
+    disposer.dispose();
+}
+
+#[test]
+fn list_store_notifies_effects() {
+    let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+
+    let list = create_list_store(cx, Vec::new());
+    let (len, set_len) = create_signal(cx, 0);
+    create_isomorphic_effect(cx, move |_| set_len.set(list.len()));
+
+    assert_eq!(len.get(), 0);
+    list.push("a");
+    list.push("b");
+    assert_eq!(len.get(), 2);
+
+    disposer.dispose();
+}
+
+#[test]
+fn list_store_stops_reporting_ops_after_cleanup() {
+    let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+
+    let list = create_list_store(cx, Vec::new());
+    let seen = store_value(cx, Vec::new());
+
+    let child = cx.child_scope(|cx| {
+        list.on_op(cx, move |op| {
+            seen.update_value(|seen| seen.push(op.clone()))
+        });
+    });
+
+    list.push(1);
+    child.dispose();
+    list.push(2);
+
+    assert_eq!(seen.get_value(), vec![ListOp::Push(1)]);
+
+    disposer.dispose();
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "metrics")]
+
+use leptos_reactive::metrics::*;
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn metrics_are_delivered_to_every_callback() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    for _ in 0..2 {
+        let seen = Rc::clone(&seen);
+        on_metric(move |metric| seen.borrow_mut().push(metric.name()));
+    }
+
+    report(Metric::Hydration { duration: 12.5 });
+
+    assert_eq!(*seen.borrow(), vec!["hydration", "hydration"]);
+}
+
+#[test]
+fn metrics_serialize_to_json() {
+    let vital = Metric::WebVital {
+        vital: WebVital::LargestContentfulPaint,
+        value: 1200.0,
+    };
+    assert_eq!(vital.to_json(), r#"{"name":"LCP","value":1200}"#);
+
+    let resource = Metric::Resource {
+        type_name: "alloc::string::String",
+        duration: 3.5,
+    };
+    assert_eq!(
+        resource.to_json(),
+        r#"{"name":"resource","value":3.5,"label":"alloc::string::String"}"#
+    );
+}
@@ -0,0 +1,142 @@
+use leptos_reactive::{
+    create_resource_with_retry, create_runtime, create_scope, provide_context,
+    ErrorSink, RetryPolicy, SuspenseContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+// fetchers return immediately, so the resource resolves as it is created
+
+async fn reset_connection() -> Result<i32, String> {
+    panic!("connection reset")
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn retries_until_the_fetcher_succeeds() {
+    create_scope(create_runtime(), |cx| {
+        let attempts = Rc::new(RefCell::new(0));
+        let resource = create_resource_with_retry(
+            cx,
+            || (),
+            {
+                let attempts = Rc::clone(&attempts);
+                move |_| {
+                    let attempts = Rc::clone(&attempts);
+                    async move {
+                        *attempts.borrow_mut() += 1;
+                        if *attempts.borrow() < 3 {
+                            Err("unavailable".to_string())
+                        } else {
+                            Ok(42)
+                        }
+                    }
+                }
+            },
+            RetryPolicy::new(5),
+        );
+
+        assert_eq!(*attempts.borrow(), 3);
+        assert_eq!(resource.read(cx), Some(Ok(42)));
+    })
+    .dispose()
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn resolves_to_the_last_error_once_retries_run_out() {
+    create_scope(create_runtime(), |cx| {
+        let attempts = Rc::new(RefCell::new(0));
+        let resource = create_resource_with_retry(
+            cx,
+            || (),
+            {
+                let attempts = Rc::clone(&attempts);
+                move |_| {
+                    let attempts = Rc::clone(&attempts);
+                    async move {
+                        *attempts.borrow_mut() += 1;
+                        Err::<i32, _>(format!("attempt {}", attempts.borrow()))
+                    }
+                }
+            },
+            RetryPolicy::new(2),
+        );
+
+        assert_eq!(*attempts.borrow(), 3);
+        assert_eq!(resource.read(cx), Some(Err("attempt 3".to_string())));
+
+        resource.refetch();
+        assert_eq!(*attempts.borrow(), 6);
+    })
+    .dispose()
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn panics_count_as_failed_attempts() {
+    create_scope(create_runtime(), |cx| {
+        let attempts = Rc::new(RefCell::new(0));
+        let resource = create_resource_with_retry(
+            cx,
+            || (),
+            {
+                let attempts = Rc::clone(&attempts);
+                move |_| {
+                    let attempts = Rc::clone(&attempts);
+                    async move {
+                        *attempts.borrow_mut() += 1;
+                        if *attempts.borrow() == 1 {
+                            panic!("connection reset");
+                        }
+                        Ok::<_, String>(*attempts.borrow())
+                    }
+                }
+            },
+            RetryPolicy::new(1),
+        );
+
+        assert_eq!(resource.read(cx), Some(Ok(2)));
+    })
+    .dispose()
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn panic_reaches_the_error_sink_and_releases_suspense() {
+    create_scope(create_runtime(), |cx| {
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        provide_context(
+            cx,
+            ErrorSink::new({
+                let errors = Rc::clone(&errors);
+                move |error| errors.borrow_mut().push(error.to_string())
+            }),
+        );
+        let suspense = SuspenseContext::new(cx);
+        provide_context(cx, suspense);
+
+        let resource = create_resource_with_retry(
+            cx,
+            || (),
+            |_| reset_connection(),
+            RetryPolicy::new(1),
+        );
+
+        assert_eq!(resource.read(cx), None);
+        assert!(suspense.ready());
+        assert_eq!(
+            *errors.borrow(),
+            vec!["resource fetcher panicked: connection reset"]
+        );
+
+        // reading it again doesn't report the same failure twice
+        assert_eq!(resource.read(cx), None);
+        assert_eq!(errors.borrow().len(), 1);
+
+        // but a refetch that fails again does
+        resource.refetch();
+        assert_eq!(errors.borrow().len(), 2);
+        assert!(suspense.ready());
+    })
+    .dispose()
+}
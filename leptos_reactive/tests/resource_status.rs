@@ -0,0 +1,116 @@
+use leptos_reactive::{
+    create_resource, create_runtime, create_scope, suppress_resource_load,
+    Resource, ResourceStatus,
+};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+#[test]
+fn map_applies_to_values() {
+    let ready: ResourceStatus<i32, String> = ResourceStatus::Ready(1);
+    assert_eq!(ready.map(|n| n + 1), ResourceStatus::Ready(2));
+
+    let reloading: ResourceStatus<i32, String> = ResourceStatus::Reloading(1);
+    assert_eq!(reloading.map(|n| n * 10), ResourceStatus::Reloading(10));
+
+    let loading: ResourceStatus<i32, String> = ResourceStatus::Loading;
+    assert_eq!(loading.map(|n| n + 1), ResourceStatus::Loading);
+}
+
+#[test]
+fn and_then_can_fail() {
+    let ready: ResourceStatus<&str, String> = ResourceStatus::Ready("12");
+    assert_eq!(
+        ready.and_then(|s| s.parse::<i32>().map_err(|e| e.to_string())),
+        ResourceStatus::Ready(12)
+    );
+
+    let ready: ResourceStatus<&str, String> = ResourceStatus::Ready("twelve");
+    assert!(matches!(
+        ready.and_then(|s| s.parse::<i32>().map_err(|e| e.to_string())),
+        ResourceStatus::Errored(_)
+    ));
+}
+
+#[test]
+fn value_and_is_loading() {
+    let reloading: ResourceStatus<i32, ()> = ResourceStatus::Reloading(3);
+    assert!(reloading.is_loading());
+    assert_eq!(reloading.value(), Some(3));
+
+    let errored: ResourceStatus<i32, ()> = ResourceStatus::Errored(());
+    assert!(!errored.is_loading());
+    assert_eq!(errored.value(), None);
+}
+
+// fetchers return immediately, so each load resolves as soon as it starts;
+// the state while it is loading is recorded by the fetcher's future
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn state_follows_each_load() {
+    create_scope(create_runtime(), |cx| {
+        let results = Rc::new(RefCell::new(VecDeque::from([
+            Ok(1),
+            Ok(2),
+            Err("down".to_string()),
+            Ok(4),
+        ])));
+        let this = Rc::new(RefCell::new(None::<Resource<(), _>>));
+        let seen_while_loading = Rc::new(RefCell::new(Vec::new()));
+        let resource = create_resource(cx, || (), {
+            let this = Rc::clone(&this);
+            let seen_while_loading = Rc::clone(&seen_while_loading);
+            move |_| {
+                let this = *this.borrow();
+                let seen_while_loading = Rc::clone(&seen_while_loading);
+                let result = results.borrow_mut().pop_front().unwrap();
+                async move {
+                    if let Some(this) = this {
+                        seen_while_loading.borrow_mut().push(this.state(cx));
+                    }
+                    result
+                }
+            }
+        });
+        *this.borrow_mut() = Some(resource);
+        assert_eq!(resource.state(cx), ResourceStatus::Ready(1));
+
+        resource.refetch();
+        assert_eq!(resource.state(cx), ResourceStatus::Ready(2));
+
+        resource.refetch();
+        assert_eq!(
+            resource.state(cx),
+            ResourceStatus::Errored("down".to_string())
+        );
+
+        resource.refetch();
+        assert_eq!(resource.state(cx), ResourceStatus::Ready(4));
+
+        // an error isn't kept as the previous value while reloading
+        assert_eq!(
+            *seen_while_loading.borrow(),
+            vec![
+                ResourceStatus::Reloading(1),
+                ResourceStatus::Reloading(2),
+                ResourceStatus::Loading,
+            ]
+        );
+    })
+    .dispose()
+}
+
+#[cfg(not(feature = "ssr"))]
+#[test]
+fn state_is_loading_before_the_first_value() {
+    create_scope(create_runtime(), |cx| {
+        suppress_resource_load(true);
+        let resource =
+            create_resource(cx, || (), |_| async { Ok::<i32, String>(1) });
+        suppress_resource_load(false);
+
+        assert_eq!(resource.state(cx), ResourceStatus::Loading);
+        assert!(resource.state(cx).is_loading());
+    })
+    .dispose()
+}
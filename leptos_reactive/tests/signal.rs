@@ -28,3 +28,70 @@ fn derived_signals() {
     })
     .dispose()
 }
+
+#[cfg(not(any(feature = "stable", feature = "ssr")))]
+#[test]
+fn signal_from_stream_holds_latest_value() {
+    use leptos_reactive::{create_signal_from_stream, SignalGet};
+
+    create_scope(create_runtime(), |cx| {
+        let values =
+            create_signal_from_stream(cx, futures::stream::iter(vec![1, 2, 3]));
+        assert_eq!(values.get(), Some(3));
+    })
+    .dispose()
+}
+
+#[cfg(not(any(feature = "stable", feature = "ssr")))]
+#[test]
+fn signal_from_stream_stops_polling_when_disposed() {
+    use leptos_reactive::{create_signal_from_stream, raw_scope_and_disposer};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        task::Poll,
+    };
+
+    struct Dropped(Rc<Cell<bool>>);
+
+    impl Drop for Dropped {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let runtime = create_runtime();
+    let (cx, disposer) = raw_scope_and_disposer(runtime);
+    let disposer = Rc::new(RefCell::new(Some(disposer)));
+    let polls = Rc::new(Cell::new(0));
+    let dropped = Rc::new(Cell::new(false));
+
+    // yields one value, then disposes the scope while the task is still
+    // waiting for the next one
+    let stream = futures::stream::poll_fn({
+        let disposer = Rc::clone(&disposer);
+        let polls = Rc::clone(&polls);
+        let dropped = Dropped(Rc::clone(&dropped));
+        move |task| {
+            let _ = &dropped;
+            polls.set(polls.get() + 1);
+            match polls.get() {
+                1 => Poll::Ready(Some(1)),
+                2 => {
+                    if let Some(disposer) = disposer.borrow_mut().take() {
+                        disposer.dispose();
+                    }
+                    task.waker().wake_by_ref();
+                    Poll::Pending
+                }
+                _ => Poll::Ready(None),
+            }
+        }
+    });
+    _ = create_signal_from_stream(cx, stream);
+
+    assert!(disposer.borrow().is_none());
+    assert_eq!(polls.get(), 2);
+    assert!(dropped.get());
+    runtime.dispose();
+}
@@ -0,0 +1,74 @@
+use leptos_reactive::{
+    create_runtime, create_scope, GlobalSuspenseContext, SignalGet,
+    SuspenseContext,
+};
+
+#[test]
+fn ready_once_every_resource_resolves() {
+    create_scope(create_runtime(), |cx| {
+        let context = SuspenseContext::new(cx);
+        assert!(context.ready());
+
+        context.increment(true);
+        context.increment(false);
+        assert!(!context.ready());
+        assert_eq!(context.pending_resources.get(), 2);
+
+        context.decrement(true);
+        assert!(!context.ready());
+        context.decrement(false);
+        assert!(context.ready());
+    })
+    .dispose()
+}
+
+#[test]
+fn decrement_does_not_underflow() {
+    create_scope(create_runtime(), |cx| {
+        let context = SuspenseContext::new(cx);
+        context.decrement(true);
+        assert_eq!(context.pending_resources.get(), 0);
+
+        context.increment(true);
+        assert!(!context.ready());
+    })
+    .dispose()
+}
+
+#[test]
+fn local_only_until_a_serializable_resource_is_read() {
+    create_scope(create_runtime(), |cx| {
+        let context = SuspenseContext::new(cx);
+        context.increment(false);
+        assert!(context.has_local_only());
+
+        context.increment(true);
+        assert!(!context.has_local_only());
+    })
+    .dispose()
+}
+
+#[test]
+fn clear_resets_pending_resources() {
+    create_scope(create_runtime(), |cx| {
+        let context = SuspenseContext::new(cx);
+        context.increment(true);
+        context.increment(true);
+        context.clear();
+        assert!(context.ready());
+    })
+    .dispose()
+}
+
+#[test]
+fn global_context_can_be_reset() {
+    create_scope(create_runtime(), |cx| {
+        let global = GlobalSuspenseContext::new(cx);
+        global.with_inner(|context| context.increment(true));
+        assert!(!global.with_inner(SuspenseContext::ready));
+
+        global.reset(cx);
+        assert!(global.with_inner(SuspenseContext::ready));
+    })
+    .dispose()
+}
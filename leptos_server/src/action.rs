@@ -1,4 +1,4 @@
-use crate::{ServerFn, ServerFnError};
+use crate::{Progress, ServerFn, ServerFnError};
 use leptos_reactive::{
     create_rw_signal, signal_prelude::*, spawn_local, store_value, ReadSignal,
     RwSignal, Scope, StoredValue,
@@ -364,3 +364,66 @@ where
     let c = move |args: &S| S::call_fn_client(args.clone(), cx);
     create_action(cx, c).using_server_fn::<S>()
 }
+
+/// Creates an [Action] that can be used to call a server function, along
+/// with a signal containing the [`Progress`] of the most recent call’s
+/// request and response bodies.
+///
+/// The progress signal is reset to `None` each time the action is
+/// dispatched. This can be used to show a progress bar while uploading a
+/// large file, or while downloading a large result.
+///
+/// ```rust
+/// # use leptos::*;
+///
+/// #[server(UploadFile)]
+/// async fn upload_file(contents: Vec<u8>) -> Result<(), ServerFnError> {
+///     todo!()
+/// }
+///
+/// # run_scope(create_runtime(), |cx| {
+/// let (upload, progress) = create_server_action_with_progress::<UploadFile>(cx);
+/// let percent = move || {
+///     progress
+///         .get()
+///         .and_then(|progress| progress.percent())
+///         .unwrap_or_default()
+/// };
+/// # });
+/// ```
+///
+/// During server-side rendering, the server function is called directly,
+/// so no progress is reported.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all,)
+)]
+pub fn create_server_action_with_progress<S>(
+    cx: Scope,
+) -> (
+    Action<S, Result<S::Output, ServerFnError>>,
+    ReadSignal<Option<Progress>>,
+)
+where
+    S: Clone + ServerFn,
+    S::Output: serde::de::DeserializeOwned,
+{
+    let progress = create_rw_signal(cx, None);
+
+    #[cfg(feature = "ssr")]
+    let c = move |args: &S| {
+        progress.set(None);
+        S::call_fn(args.clone(), cx)
+    };
+    #[cfg(not(feature = "ssr"))]
+    let c = move |args: &S| {
+        progress.set(None);
+        S::call_fn_client_with_progress(
+            args.clone(),
+            Rc::new(move |p: Progress| progress.set(Some(p))),
+        )
+    };
+    let action = create_action(cx, c).using_server_fn::<S>();
+
+    (action, progress.read_only())
+}
@@ -80,7 +80,10 @@
 //!   or response or other server-only dependencies, but it does *not* have access to reactive state that exists in the client.
 
 use leptos_reactive::*;
-pub use server_fn::{Encoding, Payload, ServerFnError};
+pub use server_fn::{
+    Encoding, Payload, Progress, ProgressDirection, ServerFnError,
+    ServerFnGuard,
+};
 
 mod action;
 mod multi_action;
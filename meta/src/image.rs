@@ -0,0 +1,419 @@
+use crate::use_head;
+use leptos::*;
+use std::{borrow::Cow, fmt, rc::Rc};
+
+/// The widths, in pixels, that an [`ImageLoader`] generates versions of an
+/// [`Image`] for, unless its `widths` are set.
+pub const DEFAULT_IMAGE_WIDTHS: &[u32] =
+    &[320, 640, 750, 828, 1080, 1200, 1920, 2048];
+
+/// Generates the URL of a version of an image that is a given number of
+/// pixels wide, so that an [`Image`] can list them in its `srcset`.
+///
+/// This is usually a URL for an image CDN or, with
+/// [`ImageLoader::endpoint`], a server endpoint that resizes images. It is
+/// provided to every [`Image`] with [`provide_image_loader`].
+///
+/// ```
+/// use leptos_meta::ImageLoader;
+///
+/// let loader = ImageLoader::new(|src, width| {
+///     format!("https://cdn.example.com/{src}?w={width}")
+/// });
+/// assert_eq!(
+///     loader.url("cat.jpg", 640),
+///     "https://cdn.example.com/cat.jpg?w=640"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ImageLoader(Rc<dyn Fn(&str, u32) -> String>);
+
+impl ImageLoader {
+    /// Creates a loader from a function that takes the image’s `src` and a
+    /// width, and returns the URL of the image at that width.
+    pub fn new(loader: impl Fn(&str, u32) -> String + 'static) -> Self {
+        Self(Rc::new(loader))
+    }
+
+    /// Returns the URL of the image at `src`, resized to `width` pixels.
+    pub fn url(&self, src: &str, width: u32) -> String {
+        (self.0)(src, width)
+    }
+}
+
+impl ImageLoader {
+    /// Creates a loader for a server endpoint that resizes images, such as
+    /// the one added by `leptos_axum::handle_image_optimization`. The
+    /// URLs are the `path` with an [`ImageRequest`] as their query string.
+    ///
+    /// ```
+    /// use leptos_meta::ImageLoader;
+    ///
+    /// let loader = ImageLoader::endpoint("/_images");
+    /// assert_eq!(
+    ///     loader.url("/photos/cat 1.jpg", 640),
+    ///     "/_images?src=%2Fphotos%2Fcat%201.jpg&w=640"
+    /// );
+    /// ```
+    pub fn endpoint(path: &'static str) -> Self {
+        Self::new(move |src, width| {
+            let request = ImageRequest {
+                src: src.to_string(),
+                width,
+            };
+            format!("{path}?{}", request.to_query())
+        })
+    }
+}
+
+/// A request for a version of an image that is `width` pixels wide, as sent
+/// to an [`ImageLoader::endpoint`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImageRequest {
+    /// The `src` of the original image.
+    pub src: String,
+    /// The width to resize the image to, in pixels.
+    pub width: u32,
+}
+
+impl ImageRequest {
+    /// Encodes the request as a query string, e.g., `src=cat.jpg&w=640`.
+    pub fn to_query(&self) -> String {
+        format!("src={}&w={}", encode_component(&self.src), self.width)
+    }
+
+    /// Decodes a query string created with [`to_query`](Self::to_query),
+    /// returning `None` if it is missing the `src` or `w`.
+    ///
+    /// ```
+    /// use leptos_meta::ImageRequest;
+    ///
+    /// let request = ImageRequest::from_query("w=640&src=cat%201.jpg");
+    /// assert_eq!(
+    ///     request,
+    ///     Some(ImageRequest {
+    ///         src: "cat 1.jpg".to_string(),
+    ///         width: 640
+    ///     })
+    /// );
+    /// ```
+    pub fn from_query(query: &str) -> Option<Self> {
+        let mut src = None;
+        let mut width = None;
+        for pair in query.split('&') {
+            match pair.split_once('=') {
+                Some(("src", value)) => src = decode_component(value),
+                Some(("w", value)) => width = value.parse().ok(),
+                _ => {}
+            }
+        }
+        Some(Self {
+            src: src?,
+            width: width?,
+        })
+    }
+}
+
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn decode_component(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                let hex = std::str::from_utf8(&hex).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            }
+            b'+' => bytes.push(b' '),
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Escapes `value` to be put between double quotes in CSS.
+fn escape_css_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            // a newline can't be escaped with a backslash alone
+            '\n' => escaped.push_str("\\a "),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl fmt::Debug for ImageLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ImageLoader").finish()
+    }
+}
+
+/// Provides an [`ImageLoader`] to every [`Image`] in this [`Scope`] and its
+/// descendants, unless an image sets its own `loader`.
+pub fn provide_image_loader(cx: Scope, loader: ImageLoader) {
+    provide_context(cx, loader);
+}
+
+/// Renders an [HTMLImageElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement)
+/// that avoids layout shift and only loads when it is about to be seen.
+///
+/// - The `width` and `height` are always set, so the browser reserves space
+///   for the image before it loads.
+/// - The image is lazy-loaded, unless it is a `priority` image, such as the
+///   largest image above the fold. Those are loaded eagerly, with a high
+///   fetch priority, and are preloaded with a `<link rel="preload">` in the
+///   document head, so they are found while the server-rendered HTML is
+///   still being parsed.
+/// - If there is an [`ImageLoader`], from the `loader` prop or
+///   [`provide_image_loader`], a `srcset` with a version of the image for
+///   each of the `widths` is generated, so the browser can pick the smallest
+///   that fits the `sizes`.
+/// - If there is a `placeholder`, like a tiny, inlined version of the
+///   image, it is shown blurred until the image loads.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Hero(cx: Scope) -> impl IntoView {
+///     provide_meta_context(cx);
+///     provide_image_loader(
+///         cx,
+///         ImageLoader::new(|src, width| format!("/images/{src}?w={width}")),
+///     );
+///
+///     view! { cx,
+///       <Image
+///         src="mountains.jpg"
+///         alt="Mountains at sunrise"
+///         width=1200
+///         height=800
+///         sizes="100vw"
+///         priority=true
+///       />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Image(
+    cx: Scope,
+    /// The [`src`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/img#attr-src) of the image, which is passed to the [`ImageLoader`], if there is one.
+    #[prop(into)]
+    src: Cow<'static, str>,
+    /// The [`alt`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/img#attr-alt) text. Use `""` for decorative images.
+    #[prop(into)]
+    alt: Cow<'static, str>,
+    /// The intrinsic width of the image, in pixels.
+    width: u32,
+    /// The intrinsic height of the image, in pixels.
+    height: u32,
+    /// The [`sizes`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/img#attr-sizes) attribute, describing how wide the image is shown.
+    #[prop(optional, into)]
+    sizes: Option<Cow<'static, str>>,
+    /// The widths to list in the `srcset`. Defaults to [`DEFAULT_IMAGE_WIDTHS`].
+    #[prop(optional)]
+    widths: Option<Vec<u32>>,
+    /// Generates the URLs of the image at each width, instead of the [`ImageLoader`]
+    /// from [`provide_image_loader`].
+    #[prop(optional)]
+    loader: Option<ImageLoader>,
+    /// Whether the image is loaded eagerly and preloaded, rather than lazy-loaded.
+    #[prop(optional)]
+    priority: bool,
+    /// The URL of an image, often a tiny `data:` URL, that is shown blurred until this
+    /// image loads.
+    #[prop(optional, into)]
+    placeholder: Option<Cow<'static, str>>,
+    /// The [`class`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/class) attribute.
+    #[prop(optional, into)]
+    class: Option<Cow<'static, str>>,
+) -> impl IntoView {
+    let loader = loader.or_else(|| use_context::<ImageLoader>(cx));
+    let (src, srcset) = match &loader {
+        Some(loader) => {
+            let widths = widths.as_deref().unwrap_or(DEFAULT_IMAGE_WIDTHS);
+            let srcset = widths
+                .iter()
+                .map(|w| format!("{} {w}w", loader.url(&src, *w)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            (Cow::Owned(loader.url(&src, width)), Some(srcset))
+        }
+        None => (src, None),
+    };
+
+    if priority {
+        let meta = use_head(cx);
+        let next_id = meta.tags.get_next_id();
+        let id: Cow<'static, str> = format!("leptos-link-{}", next_id.0).into();
+        let builder_el = leptos::leptos_dom::html::as_meta_tag({
+            let id = id.clone();
+            let href = src.clone();
+            let srcset = srcset.clone();
+            let sizes = sizes.clone();
+            move || {
+                leptos::leptos_dom::html::link(cx)
+                    .attr("id", id)
+                    .attr("rel", "preload")
+                    .attr("as", "image")
+                    .attr("href", href)
+                    .attr("imagesrcset", srcset)
+                    .attr("imagesizes", sizes)
+                    .attr("fetchpriority", "high")
+            }
+        });
+        meta.tags.register(cx, id, builder_el.into_any());
+    }
+
+    let img = create_node_ref::<html::Img>(cx);
+    let loaded = create_rw_signal(cx, placeholder.is_none());
+    // the image may have loaded before the app hydrated
+    img.on_load(cx, move |img| {
+        if img.complete() {
+            loaded.set(true);
+        }
+    });
+    let style = move || {
+        placeholder
+            .as_ref()
+            .filter(|_| !loaded.get())
+            .map(|placeholder| {
+                format!(
+                    "background-image: url(\"{}\"); \
+                     background-size: cover; background-position: center; \
+                     filter: blur(20px)",
+                    escape_css_string(placeholder)
+                )
+            })
+    };
+
+    view! { cx,
+        <img
+            node_ref=img
+            src=src
+            srcset=srcset
+            sizes=sizes
+            alt=alt
+            width=width
+            height=height
+            loading=if priority { "eager" } else { "lazy" }
+            decoding="async"
+            fetchpriority=priority.then_some("high")
+            class=class
+            style=style
+            on:load=move |_| loaded.set(true)
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_requests_round_trip_through_a_query() {
+        let request = ImageRequest {
+            src: "/photos/über cat&dog.jpg".to_string(),
+            width: 640,
+        };
+        let query = request.to_query();
+        let (src, width) = query.split_once('&').unwrap();
+        assert!(!src.contains([' ', '/']));
+        assert_eq!(width, "w=640");
+        assert_eq!(ImageRequest::from_query(&query), Some(request));
+    }
+
+    #[test]
+    fn incomplete_or_malformed_queries_are_rejected() {
+        assert_eq!(ImageRequest::from_query("src=cat.jpg"), None);
+        assert_eq!(ImageRequest::from_query("w=640"), None);
+        assert_eq!(ImageRequest::from_query("src=cat.jpg&w=wide"), None);
+        assert_eq!(ImageRequest::from_query("src=cat%2&w=640"), None);
+        assert_eq!(ImageRequest::from_query("src=%FF&w=640"), None);
+    }
+
+    #[test]
+    fn placeholders_are_escaped_for_css() {
+        assert_eq!(
+            escape_css_string("data:image/png;base64,AA=="),
+            "data:image/png;base64,AA=="
+        );
+        assert_eq!(
+            escape_css_string(r#"a"); background: url("evil\"#),
+            r#"a\"); background: url(\"evil\\"#
+        );
+        assert_eq!(escape_css_string("a\nb"), "a\\a b");
+    }
+
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    #[test]
+    fn images_list_each_width_in_their_srcset() {
+        _ = create_scope(create_runtime(), |cx| {
+            let loader = ImageLoader::new(|src, w| format!("/{src}?w={w}"));
+            let html = view! { cx,
+                <Image
+                    src="cat.jpg"
+                    alt="A cat"
+                    width=640
+                    height=480
+                    widths=vec![320, 640]
+                    loader=loader
+                    placeholder="data:x\"y"
+                />
+            }
+            .into_view(cx)
+            .render_to_string(cx);
+
+            assert!(html.contains(r#"src="/cat.jpg?w=640""#));
+            assert!(html.contains(
+                r#"srcset="/cat.jpg?w=320 320w, /cat.jpg?w=640 640w""#
+            ));
+            assert!(html.contains(r#"loading="lazy""#));
+            assert!(!html.contains("fetchpriority"));
+            assert!(html.contains(r#"url(&quot;data:x\&quot;y&quot;)"#));
+        });
+    }
+
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    #[test]
+    fn priority_images_are_preloaded() {
+        _ = create_scope(create_runtime(), |cx| {
+            crate::provide_meta_context(cx);
+            let html = view! { cx,
+                <Image src="hero.jpg" alt="" width=1200 height=800 priority=true/>
+            }
+            .into_view(cx)
+            .render_to_string(cx);
+
+            assert!(html.contains(r#"loading="eager""#));
+            assert!(html.contains(r#"fetchpriority="high""#));
+            let head = use_head(cx).dehydrate();
+            assert!(head.contains(r#"rel="preload""#));
+            assert!(head.contains(r#"href="hero.jpg""#));
+        });
+    }
+}
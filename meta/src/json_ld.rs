@@ -0,0 +1,151 @@
+use crate::use_head;
+use leptos::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Injects [structured data](https://developers.google.com/search/docs/appearance/structured-data/intro-structured-data)
+/// into the document head as a `<script type="application/ld+json">`, serializing
+/// `data` with [`serde_json`].
+///
+/// The JSON is escaped so that it can't end the `<script>` early, whatever strings it
+/// contains. If the same data is added more than once, for example by every card in a
+/// list, only one `<script>` is rendered.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Article {
+///     #[serde(rename = "@context")]
+///     context: &'static str,
+///     #[serde(rename = "@type")]
+///     kind: &'static str,
+///     headline: String,
+/// }
+///
+/// #[component]
+/// fn BlogPost(cx: Scope, title: String) -> impl IntoView {
+///     provide_meta_context(cx);
+///
+///     view! { cx,
+///       <JsonLd data=Article {
+///           context: "https://schema.org",
+///           kind: "Article",
+///           headline: title.clone(),
+///       }/>
+///       <h1>{title}</h1>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn JsonLd<T>(
+    cx: Scope,
+    /// The structured data, usually a struct with `@context` and `@type` fields.
+    data: T,
+    /// The [`id`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/id) attribute.
+    /// Defaults to an ID derived from the data, so that identical data is only added once.
+    #[prop(optional, into)]
+    id: Option<Cow<'static, str>>,
+) -> impl IntoView
+where
+    T: Serialize,
+{
+    let json = match serde_json::to_string(&data) {
+        Ok(json) => escape_json(&json),
+        Err(e) => {
+            leptos::error!("<JsonLd/> could not serialize its data: {e}");
+            return;
+        }
+    };
+
+    let meta = use_head(cx);
+    let id: Cow<'static, str> = id.unwrap_or_else(|| {
+        format!("leptos-json-ld-{:016x}", fnv1a(json.as_bytes())).into()
+    });
+
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone();
+        move || {
+            leptos::leptos_dom::html::script(cx)
+                .attr("id", id)
+                .attr("type", "application/ld+json")
+                .child(json)
+        }
+    });
+
+    meta.tags.register(cx, id, builder_el.into_any());
+}
+
+/// Escapes the characters that could close the `<script>` or be misread by
+/// the HTML parser. They can only appear inside JSON strings, where the
+/// escapes mean the same thing.
+pub(crate) fn escape_json(json: &str) -> String {
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A hash that is the same on the server and in the browser, so that the
+/// generated IDs match during hydration.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_cannot_close_the_script() {
+        let json = serde_json::to_string("</script><script>alert(1)").unwrap();
+        let escaped = escape_json(&json);
+        assert!(!escaped.contains('<') && !escaped.contains('>'));
+        assert_eq!(
+            escaped,
+            r#""\u003c/script\u003e\u003cscript\u003ealert(1)""#
+        );
+        // the escapes still decode to the same string
+        let decoded: String = serde_json::from_str(&escaped).unwrap();
+        assert_eq!(decoded, "</script><script>alert(1)");
+    }
+
+    #[test]
+    fn json_cannot_open_a_comment() {
+        let escaped = escape_json(r#"{"a":"<!-- b -->","c":"d & e"}"#);
+        assert_eq!(escaped, r#"{"a":"\u003c!-- b --\u003e","c":"d \u0026 e"}"#);
+    }
+
+    #[test]
+    fn line_separators_are_escaped() {
+        assert_eq!(escape_json("\"\u{2028}\u{2029}\""), r#""\u2028\u2029""#);
+    }
+
+    #[test]
+    fn identical_data_shares_one_registration() {
+        _ = create_scope(create_runtime(), |cx| {
+            crate::provide_meta_context(cx);
+            let tags = use_head(cx).tags.clone();
+            let id: Cow<'static, str> = "leptos-json-ld-0".into();
+
+            tags.retain(&id);
+            tags.retain(&id);
+            assert!(!tags.release(&id), "another component still uses it");
+            assert!(tags.release(&id));
+            // a tag that was never counted can always be removed
+            assert!(tags.release("other"));
+        });
+    }
+}
@@ -61,6 +61,8 @@ use wasm_bindgen::{JsCast, UnwrapThrowExt};
 
 mod body;
 mod html;
+mod image;
+mod json_ld;
 mod link;
 mod meta_tags;
 mod script;
@@ -69,6 +71,8 @@ mod stylesheet;
 mod title;
 pub use body::*;
 pub use html::*;
+pub use image::*;
+pub use json_ld::*;
 pub use link::*;
 pub use meta_tags::*;
 pub use script::*;
@@ -90,6 +94,19 @@ pub struct MetaContext {
     pub body: BodyContext,
     /// Other metadata tags.
     pub tags: MetaTagsContext,
+    /// The metadata that has already been sent in the `<head>`.
+    #[cfg(feature = "ssr")]
+    sent: SentHead,
+}
+
+/// The metadata that has been sent by [MetaContext::dehydrate] and
+/// [MetaContext::dehydrate_late], so that only changes are sent afterward.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Default, Debug)]
+struct SentHead {
+    dehydrated: Rc<Cell<bool>>,
+    title: Rc<RefCell<Option<String>>>,
+    tags: Rc<RefCell<std::collections::HashMap<Cow<'static, str>, String>>>,
 }
 
 /// Manages all of the element created by components.
@@ -105,6 +122,10 @@ pub struct MetaTagsContext {
             >,
         >,
     >,
+    // how many components have registered each tag, as components like
+    // <JsonLd/> can share one
+    registrations:
+        Rc<RefCell<std::collections::HashMap<Cow<'static, str>, usize>>>,
 }
 
 impl std::fmt::Debug for MetaTagsContext {
@@ -117,11 +138,28 @@ impl MetaTagsContext {
     /// Converts metadata tags into an HTML string.
     #[cfg(any(feature = "ssr", docs))]
     pub fn as_string(&self) -> String {
-        self.els
+        self.render_each()
+            .into_iter()
+            .map(|(_, html)| html)
+            .collect()
+    }
+
+    /// Renders each tag, in the order they were first registered.
+    #[cfg(any(feature = "ssr", docs))]
+    fn render_each(&self) -> Vec<(Cow<'static, str>, String)> {
+        // clone the tags first, as rendering them may register more
+        let els = self
+            .els
             .borrow()
             .iter()
-            .map(|(_, (builder_el, cx, _))| {
-                builder_el.clone().into_view(*cx).render_to_string(*cx)
+            .map(|(id, (builder_el, cx, _))| {
+                (id.clone(), builder_el.clone(), *cx)
+            })
+            .collect::<Vec<_>>();
+        els.into_iter()
+            .map(|(id, builder_el, cx)| {
+                let html = builder_el.into_view(cx).render_to_string(cx);
+                (id, html.into_owned())
             })
             .collect()
     }
@@ -152,14 +190,17 @@ impl MetaTagsContext {
                     }
                 });
 
+                self.retain(&id);
                 on_cleanup(cx, {
                     let el = el.clone();
-                    let els = self.els.clone();
+                    let tags = self.clone();
                     let id = id.clone();
                     move || {
-                        let head = document().head().unwrap_throw();
-                        _ = head.remove_child(&el);
-                        els.borrow_mut().remove(&id);
+                        if tags.release(&id) {
+                            let head = document().head().unwrap_throw();
+                            _ = head.remove_child(&el);
+                            tags.els.borrow_mut().remove(&id);
+                        }
                     }
                 });
 
@@ -173,6 +214,39 @@ impl MetaTagsContext {
             }
         }
     }
+
+    /// Counts another registration of the tag with this `id`.
+    #[cfg_attr(
+        not(any(feature = "csr", feature = "hydrate")),
+        allow(dead_code)
+    )]
+    fn retain(&self, id: &Cow<'static, str>) {
+        *self
+            .registrations
+            .borrow_mut()
+            .entry(id.clone())
+            .or_default() += 1;
+    }
+
+    /// Removes a registration of the tag with this `id`, returning `true`
+    /// if it was the last one, so the tag should be removed.
+    #[cfg_attr(
+        not(any(feature = "csr", feature = "hydrate")),
+        allow(dead_code)
+    )]
+    fn release(&self, id: &str) -> bool {
+        let mut registrations = self.registrations.borrow_mut();
+        match registrations.get_mut(id) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                false
+            }
+            _ => {
+                registrations.remove(id);
+                true
+            }
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -269,16 +343,119 @@ impl MetaContext {
         let mut tags = String::new();
 
         // Title
-        if let Some(title) = self.title.as_string() {
+        let title = self.title.as_string();
+        if let Some(title) = &title {
             tags.push_str("<title>");
-            tags.push_str(&title);
+            tags.push_str(title);
             tags.push_str("</title>");
         }
-        tags.push_str(&self.tags.as_string());
+        let mut sent = self.sent.tags.borrow_mut();
+        for (id, html) in self.tags.render_each() {
+            tags.push_str(&html);
+            sent.insert(id, html);
+        }
+        *self.sent.title.borrow_mut() = title;
+        self.sent.dehydrated.set(true);
 
         HydrationCtx::continue_from(prev_key);
         tags
     }
+
+    #[cfg(feature = "ssr")]
+    /// Returns a `<script>` that updates the document head with any metadata
+    /// that has changed since it was sent by
+    /// [dehydrate](MetaContext::dehydrate), or an empty string if nothing has
+    /// changed.
+    ///
+    /// When streaming, the head is sent along with the app’s shell, before
+    /// any `<Suspense/>` has resolved, so components that render later, like
+    /// a `<Title/>` that shows data loaded by a resource, would otherwise be
+    /// missing from the head. The server integrations add this to each chunk
+    /// of the stream after the head.
+    ///
+    /// The head is patched by the same rules as the initial render:
+    /// - the most recent `<Title/>` wins
+    /// - a tag with the same `id` as one that has already been sent replaces
+    ///   it, and other tags are added to the end of the head, in the order in
+    ///   which they were first rendered
+    /// - tags are never removed, even if the component that rendered them
+    ///   was replaced while streaming
+    ///
+    /// Like other HTML inserted into the document, a patched `<Script/>` is
+    /// not run.
+    ///
+    /// ```
+    /// use leptos::*;
+    /// use leptos_meta::*;
+    ///
+    /// # #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+    /// run_scope(create_runtime(), |cx| {
+    ///   provide_meta_context(cx);
+    ///   _ = view! { cx, <Title text="Loading..."/> };
+    ///   let meta = use_head(cx);
+    ///   assert!(meta.dehydrate().contains("<title>Loading...</title>"));
+    ///   assert_eq!(meta.dehydrate_late(), "");
+    ///
+    ///   // e.g., once a <Suspense/> has resolved
+    ///   _ = view! { cx, <Title text="My Post"/> };
+    ///   assert!(meta.dehydrate_late().contains("My Post"));
+    ///   assert_eq!(meta.dehydrate_late(), "");
+    /// });
+    /// # }
+    /// ```
+    pub fn dehydrate_late(&self) -> String {
+        use leptos::leptos_dom::HydrationCtx;
+
+        if !self.sent.dehydrated.get() {
+            return String::new();
+        }
+
+        let prev_key = HydrationCtx::peek();
+
+        let title = self.title.as_string();
+        let title = if title != *self.sent.title.borrow() {
+            self.sent.title.replace(title.clone());
+            title
+        } else {
+            None
+        };
+
+        let mut tags = String::new();
+        let mut sent = self.sent.tags.borrow_mut();
+        for (id, html) in self.tags.render_each() {
+            if sent.get(&id) != Some(&html) {
+                tags.push_str(&html);
+                sent.insert(id, html);
+            }
+        }
+
+        HydrationCtx::continue_from(prev_key);
+
+        if title.is_none() && tags.is_empty() {
+            return String::new();
+        }
+        let to_js = |value: &str| {
+            json_ld::escape_json(
+                &serde_json::to_string(value).expect("a string to serialize"),
+            )
+        };
+        let title =
+            title.as_deref().map(to_js).unwrap_or_else(|| "null".into());
+        let tags = to_js(&tags);
+        format!(
+            "<script>(function() {{\
+               var title = {title};\
+               if (title !== null) {{ document.title = title; }}\
+               var tpl = document.createElement(\"template\");\
+               tpl.innerHTML = {tags};\
+               Array.from(tpl.content.children).forEach(function(el) {{\
+                 var prev = el.id && document.getElementById(el.id);\
+                 if (prev) {{ prev.replaceWith(el); }}\
+                 else {{ document.head.appendChild(el); }}\
+               }});\
+             }})();</script>"
+        )
+    }
 }
 
 /// Extracts the metadata that should be used to close the `<head>` tag
@@ -0,0 +1,205 @@
+use crate::{use_router, NavigateOptions, RouterContext};
+use leptos::*;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+type BlockerFn = Rc<dyn Fn() -> bool>;
+
+/// The set of blockers registered with a router, and the navigation (if any)
+/// that they are currently holding back.
+pub(crate) struct NavigationBlockers {
+    blockers: RefCell<Vec<(usize, BlockerFn)>>,
+    next_id: Cell<usize>,
+    bypass: Cell<bool>,
+    pending: RwSignal<Option<(String, NavigateOptions)>>,
+}
+
+impl NavigationBlockers {
+    pub(crate) fn new(cx: Scope) -> Self {
+        Self {
+            blockers: Default::default(),
+            next_id: Default::default(),
+            bypass: Default::default(),
+            pending: create_rw_signal(cx, None),
+        }
+    }
+
+    /// Whether any registered blocker currently wants to block navigation.
+    pub(crate) fn is_blocking(&self) -> bool {
+        if self.bypass.get() {
+            return false;
+        }
+        let blockers = self
+            .blockers
+            .borrow()
+            .iter()
+            .map(|(_, when)| Rc::clone(when))
+            .collect::<Vec<_>>();
+        blockers.iter().any(|when| when())
+    }
+
+    /// If navigation is blocked, holds on to the navigation so that it can
+    /// be resumed later, and returns `true`.
+    pub(crate) fn block(&self, to: &str, options: &NavigateOptions) -> bool {
+        let blocked = self.is_blocking();
+        if blocked {
+            self.pending.set(Some((to.to_string(), options.clone())));
+        }
+        blocked
+    }
+}
+
+impl std::fmt::Debug for NavigationBlockers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NavigationBlockers")
+            .field("blockers", &self.blockers.borrow().len())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// A handle to a navigation blocker created with [use_navigation_blocker].
+#[derive(Debug, Clone)]
+pub struct NavigationBlocker {
+    router: RouterContext,
+    message: Rc<str>,
+}
+
+impl NavigationBlocker {
+    /// `true` while a navigation has been blocked and is waiting for
+    /// [proceed](NavigationBlocker::proceed) or
+    /// [cancel](NavigationBlocker::cancel) to be called.
+    pub fn is_blocked(&self) -> Signal<bool> {
+        let pending = self.router.inner.blockers.pending;
+        Signal::derive(self.router.inner.cx, move || {
+            pending.with(Option::is_some)
+        })
+    }
+
+    /// The path the blocked navigation was going to, if any.
+    pub fn blocked_path(&self) -> Signal<Option<String>> {
+        let pending = self.router.inner.blockers.pending;
+        Signal::derive(self.router.inner.cx, move || {
+            pending.with(|pending| pending.as_ref().map(|(to, _)| to.clone()))
+        })
+    }
+
+    /// The message passed to [use_navigation_blocker].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Continues the blocked navigation, ignoring all blockers.
+    pub fn proceed(&self) {
+        let blockers = &self.router.inner.blockers;
+        if let Some((to, options)) = blockers.pending.get_untracked() {
+            blockers.pending.set(None);
+            blockers.bypass.set(true);
+            let result = Rc::clone(&self.router.inner)
+                .navigate_from_route(&to, &options);
+            blockers.bypass.set(false);
+            if let Err(e) = result {
+                leptos::error!("{e:#?}");
+            }
+        }
+    }
+
+    /// Discards the blocked navigation, staying on the current page.
+    pub fn cancel(&self) {
+        self.router.inner.blockers.pending.set(None);
+    }
+}
+
+/// Blocks navigations away from the current page while `when` returns `true`,
+/// for example while a form has unsaved changes.
+///
+/// Blocked navigations within the router (following links, calling
+/// [use_navigate](crate::use_navigate), and the back and forward buttons) are
+/// held until [NavigationBlocker::proceed] or [NavigationBlocker::cancel] is
+/// called, so that you can ask the user to confirm. Navigations that leave the
+/// app, like closing the tab or reloading, trigger the browser’s own
+/// confirmation dialog via the
+/// [`beforeunload`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeunload_event)
+/// event. Browsers generally show their own text in that dialog rather than
+/// `message`.
+///
+/// The blocker is removed when the scope it was created in is disposed.
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// pub fn EditProfile(cx: Scope) -> impl IntoView {
+///     let (dirty, set_dirty) = create_signal(cx, false);
+///     let blocker = use_navigation_blocker(
+///         cx,
+///         move || dirty.get(),
+///         "You have unsaved changes.",
+///     );
+///
+///     view! { cx,
+///       <input on:input=move |_| set_dirty.set(true)/>
+///       <Show when={let blocker = blocker.clone(); move || blocker.is_blocked().get()} fallback=|_| ()>
+///         <p>{blocker.message().to_string()}</p>
+///         <button on:click={let blocker = blocker.clone(); move |_| blocker.proceed()}>"Leave"</button>
+///         <button on:click={let blocker = blocker.clone(); move |_| blocker.cancel()}>"Stay"</button>
+///       </Show>
+///     }
+/// }
+/// ```
+pub fn use_navigation_blocker(
+    cx: Scope,
+    when: impl Fn() -> bool + 'static,
+    message: impl Into<String>,
+) -> NavigationBlocker {
+    let router = use_router(cx);
+    let message: Rc<str> = message.into().into();
+    let when: BlockerFn = Rc::new(move || cx.untrack(&when));
+
+    let blockers = &router.inner.blockers;
+    let id = blockers.next_id.get();
+    blockers.next_id.set(id + 1);
+    blockers.blockers.borrow_mut().push((id, Rc::clone(&when)));
+
+    on_cleanup(cx, {
+        let router = router.clone();
+        move || {
+            router
+                .inner
+                .blockers
+                .blockers
+                .borrow_mut()
+                .retain(|(blocker_id, _)| *blocker_id != id);
+        }
+    });
+
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        use wasm_bindgen::{prelude::Closure, JsCast};
+
+        let message = Rc::clone(&message);
+        let on_before_unload =
+            Closure::wrap(Box::new(move |ev: web_sys::BeforeUnloadEvent| {
+                if when() {
+                    ev.prevent_default();
+                    ev.set_return_value(&message);
+                }
+            })
+                as Box<dyn FnMut(web_sys::BeforeUnloadEvent)>)
+            .into_js_value();
+        let window = leptos::window();
+        _ = window.add_event_listener_with_callback(
+            "beforeunload",
+            on_before_unload.unchecked_ref(),
+        );
+        on_cleanup(cx, move || {
+            _ = window.remove_event_listener_with_callback(
+                "beforeunload",
+                on_before_unload.unchecked_ref(),
+            );
+        });
+    }
+
+    NavigationBlocker { router, message }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    matching::{get_route_matches, RouteMatch},
+    use_router, ParamsMap,
+};
+use leptos::*;
+use leptos_meta::JsonLd;
+use serde::Serialize;
+use std::rc::Rc;
+
+/// The label of a route in [use_breadcrumbs], set with the `breadcrumb` prop
+/// of a [Route](crate::Route): either a fixed string, or a function that
+/// derives one from the route params.
+#[derive(Clone)]
+pub struct BreadcrumbLabel(Rc<dyn Fn(&ParamsMap) -> String>);
+
+impl BreadcrumbLabel {
+    /// Resolves the label for the given route params.
+    pub fn resolve(&self, params: &ParamsMap) -> String {
+        (self.0)(params)
+    }
+}
+
+impl std::fmt::Debug for BreadcrumbLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BreadcrumbLabel").finish()
+    }
+}
+
+impl From<String> for BreadcrumbLabel {
+    fn from(label: String) -> Self {
+        Self(Rc::new(move |_| label.clone()))
+    }
+}
+
+impl From<&str> for BreadcrumbLabel {
+    fn from(label: &str) -> Self {
+        label.to_string().into()
+    }
+}
+
+impl<F> From<F> for BreadcrumbLabel
+where
+    F: Fn(&ParamsMap) -> String + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+/// One level of the matched route chain, as returned by [use_breadcrumbs].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Breadcrumb {
+    /// The full path pattern of the route, e.g., `/users/:id`.
+    pub pattern: String,
+    /// The part of the current path matched by the route, e.g., `/users/42`,
+    /// which can be used as the `href` of the breadcrumb.
+    pub path: String,
+    /// The params matched by the route and its parents.
+    pub params: ParamsMap,
+    /// The resolved `breadcrumb` label of the route, if it has one.
+    pub label: Option<String>,
+}
+
+/// Returns the chain of routes that match the current page, from the
+/// outermost to the innermost, so that breadcrumbs can be rendered from the
+/// route tree instead of duplicating it.
+///
+/// A nested route that matches the same path as its parent (like an index
+/// route with the path `""`) is merged into the parent’s breadcrumb, keeping
+/// the innermost label. While a [modal route](crate::ModalRoutes) is open, the
+/// chain is that of the page behind it.
+///
+/// The matches are looked up in the first [`<Routes/>`](crate::Routes) that
+/// renders, so the list is empty until it has. During server-side rendering,
+/// read it inside or after the `<Routes/>`.
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// fn Breadcrumbs(cx: Scope) -> impl IntoView {
+///     let crumbs = use_breadcrumbs(cx);
+///     view! { cx,
+///       <nav aria-label="Breadcrumb">
+///         <For
+///           each=move || crumbs.get()
+///           key=|crumb| crumb.path.clone()
+///           view=|cx, crumb: Breadcrumb| view! { cx,
+///             <A href=crumb.path>{crumb.label}</A>
+///           }
+///         />
+///       </nav>
+///     }
+/// }
+/// ```
+pub fn use_breadcrumbs(cx: Scope) -> Memo<Vec<Breadcrumb>> {
+    let router = use_router(cx);
+    let options = router.match_options();
+    let page_pathname = router.inner.page_pathname;
+    let routes_base = router.inner.routes_base;
+
+    create_memo(cx, move |_| {
+        let Some(base) = routes_base.get() else {
+            return Vec::new();
+        };
+        let matches = get_route_matches(&base, page_pathname.get(), options);
+        breadcrumbs(&matches)
+    })
+}
+
+/// Builds the breadcrumbs for a chain of route matches, merging each match
+/// that has the same path as its parent into the parent's breadcrumb.
+fn breadcrumbs(matches: &[RouteMatch]) -> Vec<Breadcrumb> {
+    let mut crumbs: Vec<Breadcrumb> = Vec::with_capacity(matches.len());
+    for route_match in matches {
+        let params = &route_match.path_match.params;
+        let label = route_match
+            .route
+            .key
+            .breadcrumb
+            .as_ref()
+            .map(|label| label.resolve(params));
+        let path = match route_match.path_match.path.as_str() {
+            "" => "/".to_string(),
+            path => path.to_string(),
+        };
+
+        match crumbs.last_mut() {
+            Some(parent) if parent.path == path => {
+                parent.pattern = route_match.route.pattern.clone();
+                parent.params = params.clone();
+                if label.is_some() {
+                    parent.label = label;
+                }
+            }
+            _ => crumbs.push(Breadcrumb {
+                pattern: route_match.route.pattern.clone(),
+                path,
+                params: params.clone(),
+                label,
+            }),
+        }
+    }
+    crumbs
+}
+
+/// Adds the labeled breadcrumbs from [use_breadcrumbs] to the `<head>` as a
+/// [schema.org `BreadcrumbList`](https://schema.org/BreadcrumbList), using
+/// [JsonLd](leptos_meta::JsonLd). The list is updated when the route changes.
+///
+/// Search engines expect absolute URLs, so each path is prefixed with
+/// `origin`, e.g., `"https://example.com"`.
+#[component(transparent)]
+pub fn BreadcrumbsJsonLd(
+    cx: Scope,
+    /// The scheme and host that the breadcrumb paths are relative to.
+    #[prop(into)]
+    origin: String,
+) -> impl IntoView {
+    let crumbs = use_breadcrumbs(cx);
+    let data = create_memo(cx, move |_| {
+        crumbs.with(|crumbs| breadcrumb_list(&origin, crumbs))
+    });
+
+    // like route metadata, the tag is recreated whenever the list changes
+    create_isomorphic_effect(cx, move |prev: Option<ScopeDisposer>| {
+        if let Some(prev) = prev {
+            prev.dispose();
+        }
+        let data = data.get();
+        let (_, disposer) = cx.run_child_scope(|cx| {
+            cx.untrack(|| {
+                _ = view! { cx,
+                    <JsonLd data=data id="leptos-breadcrumbs"/>
+                };
+            })
+        });
+        disposer
+    });
+}
+
+fn breadcrumb_list(origin: &str, crumbs: &[Breadcrumb]) -> serde_json::Value {
+    let origin = origin.trim_end_matches('/');
+    let items = crumbs
+        .iter()
+        .filter_map(|crumb| Some((crumb.label.as_ref()?, &crumb.path)))
+        .enumerate()
+        .map(|(index, (label, path))| {
+            serde_json::json!({
+                "@type": "ListItem",
+                "position": index + 1,
+                "name": label,
+                "item": format!("{origin}{path}"),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "BreadcrumbList",
+        "itemListElement": items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        matching::{Matcher, PathMatch, RouteDefinition},
+        RouteData,
+    };
+
+    /// A match of the route `pattern` at `path`, with a `breadcrumb` label.
+    fn route_match(
+        pattern: &str,
+        path: &str,
+        params: &[(&str, &str)],
+        label: Option<BreadcrumbLabel>,
+    ) -> RouteMatch {
+        let mut definition = RouteDefinition::new(pattern, |_| ());
+        definition.breadcrumb = label;
+        let mut params_map = ParamsMap::new();
+        for (key, value) in params {
+            params_map.insert(key.to_string(), value.to_string());
+        }
+        RouteMatch {
+            path_match: PathMatch {
+                path: path.to_string(),
+                params: params_map,
+            },
+            route: RouteData {
+                id: definition.id,
+                key: definition,
+                pattern: pattern.to_string(),
+                original_path: pattern.to_string(),
+                matcher: Matcher::new(pattern),
+            },
+        }
+    }
+
+    fn crumb(path: &str, label: Option<&str>) -> (String, Option<String>) {
+        (path.to_string(), label.map(str::to_string))
+    }
+
+    #[test]
+    fn each_level_of_the_route_chain_is_a_breadcrumb() {
+        let user = |params: &ParamsMap| {
+            format!("User {}", params.get("id").cloned().unwrap_or_default())
+        };
+        let crumbs = breadcrumbs(&[
+            route_match("", "", &[], Some("Home".into())),
+            route_match("/users", "/users", &[], Some("Users".into())),
+            route_match(
+                "/users/:id",
+                "/users/42",
+                &[("id", "42")],
+                Some(user.into()),
+            ),
+        ]);
+
+        let paths_and_labels = crumbs
+            .iter()
+            .map(|crumb| (crumb.path.clone(), crumb.label.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths_and_labels,
+            vec![
+                crumb("/", Some("Home")),
+                crumb("/users", Some("Users")),
+                crumb("/users/42", Some("User 42")),
+            ]
+        );
+        assert_eq!(crumbs[2].pattern, "/users/:id");
+        assert_eq!(crumbs[2].params.get("id").map(String::as_str), Some("42"));
+    }
+
+    #[test]
+    fn routes_with_the_same_path_are_merged() {
+        // an index route keeps the innermost label, and its pattern
+        let crumbs = breadcrumbs(&[
+            route_match("/users", "/users", &[], Some("Users".into())),
+            route_match("/users/", "/users", &[], Some("All users".into())),
+        ]);
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].label.as_deref(), Some("All users"));
+        assert_eq!(crumbs[0].pattern, "/users/");
+
+        // but a nested route without a label keeps the parent's
+        let crumbs = breadcrumbs(&[
+            route_match("/users", "/users", &[], Some("Users".into())),
+            route_match("/users/", "/users", &[], None),
+        ]);
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].label.as_deref(), Some("Users"));
+    }
+
+    #[test]
+    fn breadcrumb_list_skips_unlabeled_crumbs() {
+        let crumbs = breadcrumbs(&[
+            route_match("", "", &[], Some("Home".into())),
+            route_match("/users", "/users", &[], None),
+            route_match(
+                "/users/:id",
+                "/users/42",
+                &[("id", "42")],
+                Some("Ada".into()),
+            ),
+        ]);
+
+        let list = breadcrumb_list("https://example.com/", &crumbs);
+        assert_eq!(
+            list,
+            serde_json::json!({
+                "@context": "https://schema.org",
+                "@type": "BreadcrumbList",
+                "itemListElement": [
+                    {
+                        "@type": "ListItem",
+                        "position": 1,
+                        "name": "Home",
+                        "item": "https://example.com/",
+                    },
+                    {
+                        "@type": "ListItem",
+                        "position": 2,
+                        "name": "Ada",
+                        "item": "https://example.com/users/42",
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{use_navigate, use_resolved_path, ToHref, Url};
-use leptos::{html::form, *};
+use crate::{
+    use_navigate, use_resolved_path, FieldErrors, FormState, ToHref, Url,
+};
+use leptos::{html::form, server_fn::csrf, *};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{error::Error, rc::Rc};
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
@@ -53,6 +55,10 @@ pub fn Form<A>(
     /// Arbitrary attributes to add to the `<form>`
     #[prop(optional, into)]
     attributes: Option<MaybeSignal<AdditionalAttributes>>,
+    /// The form’s fields, which must all be valid for it to be submitted.
+    /// See [FormState].
+    #[prop(optional)]
+    validation: Option<FormState>,
     /// Component children; should include the HTML of the form elements.
     children: Children,
 ) -> impl IntoView
@@ -72,12 +78,20 @@ where
         children: Children,
         node_ref: Option<NodeRef<html::Form>>,
         attributes: Option<MaybeSignal<AdditionalAttributes>>,
+        validation: Option<FormState>,
     ) -> HtmlElement<html::Form> {
         let action_version = version;
         let on_submit = move |ev: web_sys::SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
+            if let Some(validation) = validation {
+                if !validation.validate_for_submit() {
+                    ev.prevent_default();
+                    ev.stop_propagation();
+                    return;
+                }
+            }
             let navigate = use_navigate(cx);
 
             let (form, method, action, enctype) = extract_form_attributes(&ev);
@@ -300,6 +314,7 @@ where
         children,
         node_ref,
         attributes,
+        validation,
     )
 }
 
@@ -312,6 +327,12 @@ where
 /// default `Url` encoding or the `GetJSON` encoding, not with `CBOR` or other
 /// encoding schemes. This is to ensure that `<ActionForm/>` works correctly
 /// both before and after WASM has loaded.
+///
+/// ## CSRF Protection
+/// The form includes a hidden input with the CSRF token, so that, if CSRF
+/// protection has been enabled, the server function accepts it even if it is
+/// submitted before WASM has loaded. See
+/// [`server_fn::csrf`](leptos::server_fn::csrf).
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "trace", skip_all,)
@@ -335,6 +356,11 @@ pub fn ActionForm<I, O>(
     /// Arbitrary attributes to add to the `<form>`
     #[prop(optional, into)]
     attributes: Option<MaybeSignal<AdditionalAttributes>>,
+    /// The form’s fields, which must all be valid for it to be submitted.
+    /// If the server function returns [FieldErrors], they’re shown on the
+    /// matching fields. See [FormState].
+    #[prop(optional)]
+    validation: Option<FormState>,
     /// Component children; should include the HTML of the form elements.
     children: Children,
 ) -> impl IntoView
@@ -355,6 +381,18 @@ where
     let value = action.value();
     let input = action.input();
 
+    if let Some(validation) = validation {
+        create_effect(cx, move |_| {
+            value.with(|value| {
+                if let Some(Err(e)) = value {
+                    if let Some(errors) = FieldErrors::from_server_fn_error(e) {
+                        validation.set_server_errors(errors);
+                    }
+                }
+            })
+        });
+    }
+
     let on_form_data = Rc::new(move |form_data: &web_sys::FormData| {
         let data = I::from_form_data(form_data);
         match data {
@@ -382,7 +420,10 @@ where
                         let json = json
                             .as_string()
                             .expect("couldn't get String from JsString");
-                        if (500..=599).contains(&status) {
+                        if status == 401
+                            || status == 403
+                            || (500..=599).contains(&status)
+                        {
                             match serde_json::from_str::<ServerFnError>(&json) {
                                 Ok(res) => {
                                     value.try_set(Some(Err(res)));
@@ -446,11 +487,17 @@ where
         .on_response(on_response)
         .method("post")
         .class(class)
-        .children(children)
+        .children(Box::new(move |cx| {
+            Fragment::new(vec![
+                csrf_input(cx).into_view(cx),
+                children(cx).into_view(cx),
+            ])
+        }))
         .build();
     props.error = error;
     props.node_ref = node_ref;
     props.attributes = attributes;
+    props.validation = validation;
     Form(cx, props)
 }
 
@@ -527,6 +574,7 @@ where
         .attr("action", action)
         .on(ev::submit, on_submit)
         .attr("class", class)
+        .child(csrf_input(cx))
         .child(children(cx));
     if let Some(node_ref) = node_ref {
         form = form.node_ref(node_ref)
@@ -541,6 +589,28 @@ where
     }
     form
 }
+
+/// A hidden input with the CSRF token, which the server function checks when
+/// the form is submitted without JavaScript.
+fn csrf_input(cx: Scope) -> HtmlElement<html::Input> {
+    let token = use_context::<csrf::CsrfToken>(cx)
+        .map(|token| token.0)
+        .unwrap_or_else(|| {
+            #[cfg(target_arch = "wasm32")]
+            {
+                csrf::client_csrf_token()
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                String::new()
+            }
+        });
+    html::input(cx)
+        .attr("type", "hidden")
+        .attr("name", csrf::CSRF_FIELD)
+        .attr("value", token)
+}
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "trace", skip_all,)
@@ -1,4 +1,4 @@
-use crate::{use_location, use_resolved_path, State};
+use crate::{split_locale, use_resolved_path, use_router, State};
 use leptos::{leptos_dom::IntoView, *};
 
 /// Describes a value that is either a static or a reactive URL, i.e.,
@@ -63,12 +63,21 @@ pub fn A<H>(
     /// will skip this page.)
     #[prop(optional)]
     replace: bool,
+    /// If `true`, the page is shown as a [modal route](crate::ModalRoutes)
+    /// above the current page, rather than replacing it.
+    #[prop(optional)]
+    modal: bool,
     /// Sets the `class` attribute on the underlying `<a>` tag, making it easier to style.
     #[prop(optional, into)]
     class: Option<AttributeValue>,
     /// Sets the `id` attribute on the underlying `<a>` tag, making it easier to target.
     #[prop(optional, into)]
     id: Option<String>,
+    /// In a [Router](crate::Router) with `locales`, switches to this locale
+    /// rather than keeping the active one. The link is only marked active if
+    /// this is the active locale.
+    #[prop(optional, into)]
+    locale: Option<String>,
     /// The nodes or elements to be shown inside the link.
     children: Children,
 ) -> impl IntoView
@@ -85,8 +94,10 @@ where
         exact: bool,
         state: Option<State>,
         replace: bool,
+        modal: bool,
         class: Option<AttributeValue>,
         id: Option<String>,
+        locale: Option<String>,
         children: Children,
     ) -> HtmlElement<leptos::html::A> {
         #[cfg(not(any(feature = "hydrate", feature = "csr")))]
@@ -97,19 +108,34 @@ where
         #[cfg(not(any(feature = "hydrate", feature = "csr")))]
         {
             _ = replace;
+            _ = modal;
         }
 
-        let location = use_location(cx);
+        let router = use_router(cx);
+        let pathname = router.pathname();
+        let locales = router.inner.locales;
+        let active_locale = router.inner.locale;
+        let link_locale = locale.clone();
         let is_active = create_memo(cx, move |_| match href.get() {
             None => false,
-
+            Some(_)
+                if link_locale.is_some()
+                    && active_locale.map(|active| active.get())
+                        != link_locale =>
+            {
+                false
+            }
             Some(to) => {
+                let to = match locales {
+                    Some(locales) => split_locale(&to, locales).1.into_owned(),
+                    None => to,
+                };
                 let path = to
                     .split(['?', '#'])
                     .next()
                     .unwrap_or_default()
                     .to_lowercase();
-                let loc = location.pathname.get().to_lowercase();
+                let loc = pathname.get().to_lowercase();
                 if exact {
                     loc == path
                 } else {
@@ -120,9 +146,17 @@ where
 
         view! { cx,
             <a
-                href=move || href.get().unwrap_or_default()
+                href=move || {
+                    href.get()
+                        .map(|href| match &locale {
+                            Some(locale) => router.href_in_locale(&href, locale),
+                            None => router.href(&href),
+                        })
+                        .unwrap_or_default()
+                }
                 prop:state={state.map(|s| s.to_js_value())}
                 prop:replace={replace}
+                prop:modal={modal}
                 aria-current=move || if is_active.get() { Some("page") } else { None }
                 class=class
                 id=id
@@ -133,5 +167,7 @@ where
     }
 
     let href = use_resolved_path(cx, move || href.to_href()());
-    inner(cx, href, exact, state, replace, class, id, children)
+    inner(
+        cx, href, exact, state, replace, modal, class, id, locale, children,
+    )
 }
@@ -1,17 +1,21 @@
+mod breadcrumbs;
 mod form;
 mod link;
 mod outlet;
 mod progress;
 mod redirect;
 mod route;
+mod route_meta;
 mod router;
 mod routes;
 
+pub use breadcrumbs::*;
 pub use form::*;
 pub use link::*;
 pub use outlet::*;
 pub use progress::*;
 pub use redirect::*;
 pub use route::*;
+pub use route_meta::*;
 pub use router::*;
 pub use routes::*;
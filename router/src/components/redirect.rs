@@ -66,7 +66,7 @@ where
 /// and [Redirect].
 #[derive(Clone)]
 pub struct ServerRedirectFunction {
-    f: Rc<dyn Fn(&str)>,
+    pub(crate) f: Rc<dyn Fn(&str)>,
 }
 
 impl std::fmt::Debug for ServerRedirectFunction {
@@ -90,3 +90,35 @@ pub fn provide_server_redirect(cx: Scope, handler: impl Fn(&str) + 'static) {
         },
     )
 }
+
+/// Wrapping type for a function provided as context to allow for setting
+/// the status code of the response on the server. See
+/// [provide_server_status] and [GuardedRoute](crate::GuardedRoute).
+#[derive(Clone)]
+pub struct ServerStatusFunction {
+    pub(crate) f: Rc<dyn Fn(u16)>,
+}
+
+impl std::fmt::Debug for ServerStatusFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerStatusFunction").finish()
+    }
+}
+
+/// Provides a function that can be used to set the status code of the
+/// response, on the server.
+///
+/// **Note**: This is provided by the server framework integrations
+/// (`leptos_actix`, `leptos_axum`, and `leptos_viz`).
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all,)
+)]
+pub fn provide_server_status(cx: Scope, handler: impl Fn(u16) + 'static) {
+    provide_context(
+        cx,
+        ServerStatusFunction {
+            f: Rc::new(handler),
+        },
+    )
+}
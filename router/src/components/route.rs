@@ -1,14 +1,27 @@
 use crate::{
     matching::{resolve_path, PathMatch, RouteDefinition, RouteMatch},
-    ParamsMap, RouterContext, SsrMode,
+    provide_route_meta, BreadcrumbLabel, ParamsMap, RouteMetadata,
+    RouterContext, SsrMode,
+};
+use leptos::{leptos_dom::Transparent, server_fn::ServerFnGuard, *};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
-use leptos::{leptos_dom::Transparent, *};
-use std::{cell::Cell, rc::Rc};
 
 thread_local! {
     static ROUTE_ID: Cell<usize> = Cell::new(0);
 }
 
+/// Returns a new unique [RouteDefinition::id].
+pub(crate) fn next_route_id() -> usize {
+    ROUTE_ID.with(|id| {
+        let next = id.get() + 1;
+        id.set(next);
+        next
+    })
+}
+
 /// Represents an HTTP method that can be handled by this route.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Method {
@@ -54,6 +67,14 @@ pub fn Route<E, F, P>(
     /// The HTTP methods that this route can handle (defaults to only `GET`).
     #[prop(default = &[Method::Get])]
     methods: &'static [Method],
+    /// The label of this route in [use_breadcrumbs](crate::use_breadcrumbs):
+    /// either a string, or a function that derives one from the route params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// The title and other `<head>` metadata of the page: either a
+    /// [RouteMeta](crate::RouteMeta), or a function that derives one.
+    #[prop(optional, into)]
+    meta: Option<RouteMetadata>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -67,9 +88,15 @@ where
         cx,
         children,
         path.to_string(),
-        Rc::new(move |cx| view(cx).into_view(cx)),
+        Rc::new(move |cx| {
+            if let Some(meta) = &meta {
+                provide_route_meta(cx, meta);
+            }
+            view(cx).into_view(cx)
+        }),
         ssr,
         methods,
+        breadcrumb,
     )
 }
 
@@ -99,6 +126,10 @@ pub fn ProtectedRoute<P, E, F, C>(
     /// The HTTP methods that this route can handle (defaults to only `GET`).
     #[prop(default = &[Method::Get])]
     methods: &'static [Method],
+    /// The label of this route in [use_breadcrumbs](crate::use_breadcrumbs):
+    /// either a string, or a function that derives one from the route params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -126,8 +157,249 @@ where
         }),
         ssr,
         methods,
+        breadcrumb,
     )
 }
+
+/// Describes a route that is guarded by a check that can fail with a
+/// [`ServerFnError`]. This works the same way as [`<Route/>`](Route), except
+/// that if `guard` returns an error, it shows `fallback` instead of its `view`.
+///
+/// The guard is a [`ServerFnGuard`], so the same `async` check can guard both
+/// a server function and the route that calls it. While it runs, the route
+/// shows nothing, inside a [Suspense]. Its result is serialized like that of
+/// any resource, so a page rendered on the server isn’t checked again when
+/// it hydrates.
+///
+/// When rendered on the server, a [`ServerFnError::Unauthorized`] or
+/// [`ServerFnError::Forbidden`] error also sets a `401` or `403` status on the
+/// response, using the function provided by [`provide_server_status`]. As the
+/// status can only be set before the response has started, use
+/// [`SsrMode::Async`] for a guard that has to wait on anything.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # async fn is_admin(_cx: Scope) -> bool { false }
+/// async fn require_admin(cx: Scope) -> Result<(), ServerFnError> {
+///     if is_admin(cx).await {
+///         Ok(())
+///     } else {
+///         Err(ServerFnError::Forbidden("admins only".to_string()))
+///     }
+/// }
+///
+/// #[component]
+/// pub fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///       <Router>
+///         <Routes>
+///           <GuardedRoute
+///             path="/admin"
+///             guard=require_admin
+///             view=|cx| view! { cx, <p>"Admin"</p> }
+///             fallback=|cx, e| view! { cx, <p>{e.to_string()}</p> }.into_view(cx)
+///           />
+///         </Routes>
+///       </Router>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all,)
+)]
+#[component(transparent)]
+pub fn GuardedRoute<P, G, F, E>(
+    cx: Scope,
+    /// The path fragment that this route should match. This can be static (`users`),
+    /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
+    /// wildcard (`user/*any`).
+    path: P,
+    /// Checks whether the route may be shown, each time it is.
+    guard: G,
+    /// View that will be shown if the guard passes.
+    view: F,
+    /// Shown, with the error, if the guard fails. Defaults to showing nothing.
+    #[prop(optional)]
+    fallback: Option<fn(Scope, ServerFnError) -> View>,
+    /// The mode that this route prefers during server-side rendering. Defaults to out-of-order streaming.
+    #[prop(optional)]
+    ssr: SsrMode,
+    /// The HTTP methods that this route can handle (defaults to only `GET`).
+    #[prop(default = &[Method::Get])]
+    methods: &'static [Method],
+    /// The label of this route in [use_breadcrumbs](crate::use_breadcrumbs):
+    /// either a string, or a function that derives one from the route params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// `children` may be empty or include nested routes.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView
+where
+    P: std::fmt::Display,
+    G: ServerFnGuard<Scope> + Clone + 'static,
+    G::Future: 'static,
+    F: Fn(Scope) -> E + 'static,
+    E: IntoView,
+{
+    let view = Rc::new(view);
+
+    define_route(
+        cx,
+        children,
+        path.to_string(),
+        Rc::new(move |cx| {
+            let check = create_resource(cx, || (), {
+                let guard = guard.clone();
+                move |_| guard.clone().check(cx)
+            });
+            let view = Rc::clone(&view);
+            let guarded = move || {
+                check.read(cx).map(|result| match result {
+                    Ok(()) => view(cx).into_view(cx),
+                    Err(e) => {
+                        let is_auth_error = matches!(
+                            e,
+                            ServerFnError::Unauthorized(_)
+                                | ServerFnError::Forbidden(_)
+                        );
+                        if let Some(set_status) =
+                            use_context::<crate::ServerStatusFunction>(cx)
+                                .filter(|_| is_auth_error)
+                        {
+                            (set_status.f)(e.status_code());
+                        }
+                        fallback
+                            .map(|fallback| fallback(cx, e))
+                            .unwrap_or_default()
+                    }
+                })
+            };
+
+            view! { cx, <Suspense fallback=|| ()>{guarded}</Suspense> }
+                .into_view(cx)
+        }),
+        ssr,
+        methods,
+        breadcrumb,
+    )
+}
+
+/// Describes a route whose view is loaded asynchronously the first time the
+/// route is matched. This works the same way as [`<Route/>`](Route), except that
+/// `view` is returned by the `loader` future.
+///
+/// While the loader runs, the route shows `pending` inside a [Suspense]. The
+/// loaded view is kept, so later visits to the route render it without
+/// calling the loader again. The router doesn’t split the app into chunks
+/// itself: what the loader does to produce the view, such as fetching a
+/// separately-compiled module, is up to you.
+///
+/// The loader only runs in the browser, so during server rendering the route
+/// shows its `pending` view.
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # fn load_settings_chunk() -> impl std::future::Future<Output = fn(Scope) -> View> {
+/// #     async { (|cx| view! { cx, <p>"Settings"</p> }.into_view(cx)) as fn(Scope) -> View }
+/// # }
+/// #[component]
+/// pub fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///       <Router>
+///         <Routes>
+///           <LazyRoute
+///             path="/settings"
+///             loader=load_settings_chunk
+///             pending=|cx| view! { cx, <p>"Loading..."</p> }.into_view(cx)
+///           />
+///         </Routes>
+///       </Router>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all,)
+)]
+#[component(transparent)]
+pub fn LazyRoute<P, L, Fut, F, E>(
+    cx: Scope,
+    /// The path fragment that this route should match. This can be static (`users`),
+    /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
+    /// wildcard (`user/*any`).
+    path: P,
+    /// Loads the view that should be shown when this route is matched.
+    loader: L,
+    /// Shown while the view is loading. Defaults to showing nothing.
+    #[prop(optional)]
+    pending: Option<fn(Scope) -> View>,
+    /// The mode that this route prefers during server-side rendering. Defaults to out-of-order streaming.
+    #[prop(optional)]
+    ssr: SsrMode,
+    /// The HTTP methods that this route can handle (defaults to only `GET`).
+    #[prop(default = &[Method::Get])]
+    methods: &'static [Method],
+    /// The label of this route in [use_breadcrumbs](crate::use_breadcrumbs):
+    /// either a string, or a function that derives one from the route params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// `children` may be empty or include nested routes.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView
+where
+    P: std::fmt::Display,
+    L: Fn() -> Fut + 'static,
+    Fut: std::future::Future<Output = F> + 'static,
+    F: Fn(Scope) -> E + Clone + 'static,
+    E: IntoView,
+{
+    type LoadedView = Rc<dyn Fn(Scope) -> View>;
+
+    let loader = Rc::new(loader);
+    let loaded: Rc<RefCell<Option<LoadedView>>> = Default::default();
+
+    define_route(
+        cx,
+        children,
+        path.to_string(),
+        Rc::new(move |cx| {
+            if let Some(view) = loaded.borrow().clone() {
+                return view(cx);
+            }
+
+            let view = create_local_resource(cx, || (), {
+                let loader = Rc::clone(&loader);
+                let loaded = Rc::clone(&loaded);
+                move |_| {
+                    let load = loader();
+                    let loaded = Rc::clone(&loaded);
+                    async move {
+                        let view = load.await;
+                        let view: LoadedView =
+                            Rc::new(move |cx| view(cx).into_view(cx));
+                        *loaded.borrow_mut() = Some(Rc::clone(&view));
+                        view
+                    }
+                }
+            });
+
+            view! { cx,
+                <Suspense fallback=move || pending.map(|pending| pending(cx))>
+                    {move || view.read(cx).map(|view| view(cx))}
+                </Suspense>
+            }
+            .into_view(cx)
+        }),
+        ssr,
+        methods,
+        breadcrumb,
+    )
+}
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all,)
@@ -139,6 +411,7 @@ pub(crate) fn define_route(
     view: Rc<dyn Fn(Scope) -> View>,
     ssr_mode: SsrMode,
     methods: &'static [Method],
+    breadcrumb: Option<BreadcrumbLabel>,
 ) -> RouteDefinition {
     let children = children
         .map(|children| {
@@ -155,19 +428,14 @@ pub(crate) fn define_route(
         })
         .unwrap_or_default();
 
-    let id = ROUTE_ID.with(|id| {
-        let next = id.get() + 1;
-        id.set(next);
-        next
-    });
-
     RouteDefinition {
-        id,
+        id: next_route_id(),
         path,
         children,
         view,
         ssr_mode,
         methods,
+        breadcrumb,
     }
 }
 
@@ -0,0 +1,173 @@
+use leptos::*;
+use leptos_meta::{Meta, Title};
+use std::rc::Rc;
+
+/// The title and other `<head>` metadata of a route, set with the `meta`
+/// prop of a [Route](crate::Route).
+///
+/// While the route is matched, the title is set with [leptos_meta::Title]
+/// and the other fields are added as [leptos_meta::Meta] tags, both during
+/// server-side rendering and when navigating in the browser. If nested routes
+/// both set a title, the innermost one is used.
+///
+/// ```
+/// # use leptos_router::RouteMeta;
+/// let meta = RouteMeta::new()
+///     .title("About")
+///     .description("Who we are.")
+///     .og("image", "https://example.com/about.png");
+/// assert_eq!(meta.og, vec![("image".to_string(), "https://example.com/about.png".to_string())]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteMeta {
+    /// The document title.
+    pub title: Option<String>,
+    /// The content of `<meta name="description">`.
+    pub description: Option<String>,
+    /// [Open Graph](https://ogp.me/) properties, without the `og:` prefix,
+    /// and their content.
+    pub og: Vec<(String, String)>,
+}
+
+impl RouteMeta {
+    /// Creates empty metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the document title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds an [Open Graph](https://ogp.me/) property, e.g., `"image"` for
+    /// `og:image`.
+    pub fn og(
+        mut self,
+        property: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        self.og.push((property.into(), content.into()));
+        self
+    }
+}
+
+/// Where a route’s [RouteMeta] comes from: either a fixed value, or a
+/// function that derives it, e.g., from the route’s params or from a
+/// resource that loads the page’s data. The function is reactive, so the
+/// `<head>` is updated when the data it reads changes.
+#[derive(Clone)]
+pub struct RouteMetadata(Rc<dyn Fn(Scope) -> RouteMeta>);
+
+impl std::fmt::Debug for RouteMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RouteMetadata").finish()
+    }
+}
+
+impl From<RouteMeta> for RouteMetadata {
+    fn from(meta: RouteMeta) -> Self {
+        Self(Rc::new(move |_| meta.clone()))
+    }
+}
+
+impl<F> From<F> for RouteMetadata
+where
+    F: Fn(Scope) -> RouteMeta + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+/// Sets the `<head>` metadata of a route, for as long as `cx` lives.
+pub(crate) fn provide_route_meta(cx: Scope, meta: &RouteMetadata) {
+    let meta = Rc::clone(&meta.0);
+    let meta = create_memo(cx, move |_| meta(cx));
+
+    // which tags are needed can change, e.g., once a resource has loaded, so
+    // they're all recreated whenever the metadata changes
+    create_isomorphic_effect(cx, move |prev: Option<ScopeDisposer>| {
+        if let Some(prev) = prev {
+            prev.dispose();
+        }
+        let meta = meta.get();
+        let (_, disposer) =
+            cx.run_child_scope(|cx| cx.untrack(|| head_tags(cx, meta)));
+        disposer
+    });
+}
+
+fn head_tags(cx: Scope, meta: RouteMeta) {
+    let RouteMeta {
+        title,
+        description,
+        og,
+    } = meta;
+    if let Some(title) = title {
+        _ = view! { cx, <Title text=title/> };
+    }
+    if let Some(description) = description {
+        _ = view! { cx, <Meta name="description" content=description/> };
+    }
+    for (property, content) in og {
+        let property = format!("og:{property}");
+        _ = view! { cx, <Meta property=property content=content/> };
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use leptos_meta::{provide_meta_context, use_head};
+
+    #[test]
+    fn static_meta_is_added_to_the_head() {
+        let runtime = create_runtime();
+        run_scope(runtime, |cx| {
+            provide_meta_context(cx);
+            let meta = RouteMeta::new()
+                .title("About")
+                .description("Who we are.")
+                .og("image", "/about.png");
+            provide_route_meta(cx, &meta.into());
+
+            let head = use_head(cx).dehydrate();
+            assert!(head.contains("<title>About</title>"));
+            assert!(head.contains("content=\"Who we are.\""));
+            assert!(head.contains("og:image"));
+            assert!(head.contains("content=\"/about.png\""));
+        });
+        runtime.dispose();
+    }
+
+    #[test]
+    fn derived_meta_follows_its_signals() {
+        let runtime = create_runtime();
+        run_scope(runtime, |cx| {
+            provide_meta_context(cx);
+            let (name, set_name) = create_signal(cx, "first");
+            let meta: RouteMetadata =
+                (move |_: Scope| RouteMeta::new().title(name.get())).into();
+            provide_route_meta(cx, &meta);
+            assert_eq!(
+                use_head(cx).title.as_string().as_deref(),
+                Some("first")
+            );
+
+            set_name.set("second");
+            assert_eq!(
+                use_head(cx).title.as_string().as_deref(),
+                Some("second")
+            );
+        });
+        runtime.dispose();
+    }
+}
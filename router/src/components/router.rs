@@ -1,6 +1,9 @@
 use crate::{
-    create_location, matching::resolve_path, Branch, History, Location,
-    LocationChange, RouteContext, RouterIntegrationContext, State,
+    create_location,
+    locale::{preferred_locale, with_locale},
+    matching::{resolve_path, MatchOptions},
+    split_locale, Branch, History, Locale, Location, LocationChange,
+    NavigationBlockers, RouteContext, RouterIntegrationContext, State,
 };
 #[cfg(not(feature = "ssr"))]
 use crate::{unescape, Url};
@@ -27,18 +30,59 @@ pub fn Router(
     /// A signal that will be set while the navigation process is underway.
     #[prop(optional, into)]
     set_is_routing: Option<SignalSetter<bool>>,
+    /// How paths with a trailing slash (`/about/`) are treated. Defaults to
+    /// [TrailingSlash::Ignore].
+    #[prop(optional)]
+    trailing_slash: TrailingSlash,
+    /// If `false`, the static parts of route paths are matched ignoring
+    /// ASCII case, so `/About` matches a route defined as `/about`.
+    /// Defaults to `true`.
+    #[prop(default = true)]
+    case_sensitive: bool,
+    /// The locales the app is available in, e.g., `&["en", "de"]`.
+    ///
+    /// If set, every path starts with a locale (`/en/about`, `/de/about`),
+    /// which is removed before matching routes, so routes are defined
+    /// without it. The active locale is provided as context (see
+    /// [use_locale](crate::use_locale)), links and navigation stay in it, and
+    /// a path without a locale redirects to the one that best matches the
+    /// user’s languages, falling back to the first. Can’t be combined with
+    /// `base`.
+    #[prop(optional)]
+    locales: Option<&'static [&'static str]>,
     /// The `<Router/>` should usually wrap your whole page. It can contain
     /// any elements, and should include a [Routes](crate::Routes) component somewhere
     /// to define and display [Route](crate::Route)s.
     children: Children,
 ) -> impl IntoView {
     // create a new RouterContext and provide it to every component beneath the router
-    let router = RouterContext::new(cx, base, fallback);
-    provide_context(cx, router);
+    let router = RouterContext::new(
+        cx,
+        base,
+        fallback,
+        trailing_slash,
+        case_sensitive,
+        locales,
+    );
+    provide_context(cx, router.clone());
     provide_context(cx, GlobalSuspenseContext::new(cx));
     if let Some(set_is_routing) = set_is_routing {
         provide_context(cx, SetIsRouting(set_is_routing));
     }
+    if trailing_slash == TrailingSlash::Redirect {
+        router.inner.redirect_trailing_slash();
+    }
+    if let Some(locale) = router.inner.locale {
+        provide_context(cx, Locale(locale));
+        // numbers and dates are formatted for the active locale
+        leptos_dom::intl::provide_intl_locale(cx, locale);
+        router.inner.redirect_to_locale();
+    }
+    // the server integrations list each route once for each locale
+    #[cfg(feature = "ssr")]
+    if let Some(context) = use_context::<crate::PossibleBranchContext>(cx) {
+        context.1.set(locales);
+    }
 
     children(cx)
 }
@@ -58,14 +102,24 @@ pub(crate) struct RouterContextInner {
     #[allow(unused)] // used in CSR/hydrate
     base_path: String,
     history: Box<dyn History>,
-    cx: Scope,
+    pub(crate) cx: Scope,
     reference: ReadSignal<String>,
     set_reference: WriteSignal<String>,
     referrers: Rc<RefCell<Vec<LocationChange>>>,
     state: ReadSignal<State>,
     set_state: WriteSignal<State>,
+    background: ReadSignal<Option<String>>,
+    set_background: WriteSignal<Option<String>>,
     pub(crate) is_back: RwSignal<bool>,
     pub(crate) path_stack: StoredValue<Vec<String>>,
+    pub(crate) blockers: NavigationBlockers,
+    pub(crate) trailing_slash: TrailingSlash,
+    pub(crate) case_sensitive: bool,
+    pub(crate) locales: Option<&'static [&'static str]>,
+    pub(crate) locale: Option<Memo<String>>,
+    pathname: Memo<String>,
+    pub(crate) page_pathname: Memo<String>,
+    pub(crate) routes_base: RwSignal<Option<String>>,
 }
 
 impl std::fmt::Debug for RouterContextInner {
@@ -79,7 +133,13 @@ impl std::fmt::Debug for RouterContextInner {
             .field("referrers", &self.referrers)
             .field("state", &self.state)
             .field("set_state", &self.set_state)
+            .field("background", &self.background)
             .field("path_stack", &self.path_stack)
+            .field("blockers", &self.blockers)
+            .field("trailing_slash", &self.trailing_slash)
+            .field("case_sensitive", &self.case_sensitive)
+            .field("locales", &self.locales)
+            .field("locale", &self.locale)
             .finish()
     }
 }
@@ -93,6 +153,9 @@ impl RouterContext {
         cx: Scope,
         base: Option<&'static str>,
         fallback: Option<fn(Scope) -> View>,
+        trailing_slash: TrailingSlash,
+        case_sensitive: bool,
+        locales: Option<&'static [&'static str]>,
     ) -> Self {
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
@@ -127,6 +190,7 @@ impl RouterContext {
                     replace: true,
                     scroll: false,
                     state: State(None),
+                    ..Default::default()
                 });
             }
         }
@@ -139,12 +203,58 @@ impl RouterContext {
         let (state, set_state) =
             create_signal(cx, source.with_untracked(|s| s.state.clone()));
 
+        // the page behind a modal route, if one is open
+        let (background, set_background) =
+            create_signal(cx, source.with_untracked(|s| s.background.clone()));
+
         // we'll use this transition to wait for async resources to load when navigating to a new route
         #[cfg(feature = "transition")]
         let transition = use_transition(cx);
 
         // Each field of `location` reactively represents a different part of the current location
         let location = create_location(cx, reference, state);
+
+        // with locales, the locale is the first segment of the path, and
+        // routes are matched against the rest of it
+        let locale = locales.map(|locales| {
+            let pathname = location.pathname;
+            create_memo(cx, move |_| {
+                pathname
+                    .with(|path| {
+                        split_locale(path, locales).0.map(str::to_string)
+                    })
+                    .unwrap_or_else(|| {
+                        preferred_locale(cx, locales).to_string()
+                    })
+            })
+        });
+        let pathname = match locales {
+            Some(locales) => {
+                let pathname = location.pathname;
+                create_memo(cx, move |_| {
+                    pathname
+                        .with(|path| split_locale(path, locales).1.into_owned())
+                })
+            }
+            None => location.pathname,
+        };
+        // the pathname of the page that <Routes/> should render, which is the
+        // background of a modal route if one is open
+        let page_pathname = create_memo(cx, move |_| {
+            background.with(|background| match background {
+                Some(background) => {
+                    let path =
+                        background.split(['?', '#']).next().unwrap_or_default();
+                    match locales {
+                        Some(locales) => {
+                            split_locale(path, locales).1.into_owned()
+                        }
+                        None => path.to_string(),
+                    }
+                }
+                None => pathname.get(),
+            })
+        });
         let referrers: Rc<RefCell<Vec<LocationChange>>> =
             Rc::new(RefCell::new(Vec::new()));
 
@@ -159,11 +269,21 @@ impl RouterContext {
         // this will trigger the new route match below
 
         create_render_effect(cx, move |_| {
-            let LocationChange { value, state, .. } = source.get();
+            let LocationChange {
+                value,
+                state,
+                background: next_background,
+                ..
+            } = source.get();
             cx.untrack(move || {
-                if value != reference.get() {
-                    set_reference.update(move |r| *r = value);
-                    set_state.update(move |s| *s = state);
+                if value != reference.get()
+                    || next_background != background.get()
+                {
+                    cx.batch(move || {
+                        set_background.set(next_background);
+                        set_reference.update(move |r| *r = value);
+                        set_state.update(move |s| *s = state);
+                    });
                 }
             });
         });
@@ -183,8 +303,18 @@ impl RouterContext {
             referrers,
             state,
             set_state,
+            background,
+            set_background,
             possible_routes: Default::default(),
             is_back: create_rw_signal(cx, false),
+            blockers: NavigationBlockers::new(cx),
+            trailing_slash,
+            case_sensitive,
+            locales,
+            locale,
+            pathname,
+            page_pathname,
+            routes_base: create_rw_signal(cx, None),
         });
 
         // handle all click events on anchor tags
@@ -198,9 +328,16 @@ impl RouterContext {
         Self { inner }
     }
 
-    /// The current [`pathname`](https://developer.mozilla.org/en-US/docs/Web/API/Location/pathname).
+    /// The current [`pathname`](https://developer.mozilla.org/en-US/docs/Web/API/Location/pathname),
+    /// without the locale if the router has `locales`.
     pub fn pathname(&self) -> Memo<String> {
-        self.inner.location.pathname
+        self.inner.pathname
+    }
+
+    /// The URL of the page behind the open [modal route](crate::ModalRoutes),
+    /// or `None` if no modal route is open.
+    pub fn background(&self) -> ReadSignal<Option<String>> {
+        self.inner.background
     }
 
     /// The [RouteContext] of the base route.
@@ -208,6 +345,43 @@ impl RouterContext {
         self.inner.base.clone()
     }
 
+    /// Converts a path within the app into the `href` that a link to it
+    /// should use, which is a hash link if the router’s [History] is
+    /// [hash-based](History::is_hash_based).
+    ///
+    /// If the router has `locales`, the path is put in the active locale,
+    /// unless it already starts with one.
+    pub fn href(&self, path: &str) -> String {
+        self.localized_href(path, None)
+    }
+
+    /// Like [href](Self::href), but puts the path in the given locale, for
+    /// a link that switches to another language.
+    pub fn href_in_locale(&self, path: &str, locale: &str) -> String {
+        self.localized_href(path, Some(locale))
+    }
+
+    fn localized_href(&self, path: &str, locale: Option<&str>) -> String {
+        let path = self.inner.localize(path, locale);
+        let path = match self.inner.trailing_slash {
+            TrailingSlash::Ignore => path,
+            _ => without_trailing_slash(&path),
+        };
+        if self.inner.history.is_hash_based() {
+            format!("#{path}")
+        } else {
+            path
+        }
+    }
+
+    pub(crate) fn match_options(&self) -> MatchOptions {
+        MatchOptions {
+            strict_trailing_slash: self.inner.trailing_slash
+                == TrailingSlash::Strict,
+            case_sensitive: self.inner.case_sensitive,
+        }
+    }
+
     /// A list of all possible routes this router can match.
     pub fn possible_branches(&self) -> Vec<Branch> {
         self.inner
@@ -219,6 +393,14 @@ impl RouterContext {
 }
 
 impl RouterContextInner {
+    /// Records the base of the first `<Routes/>` to be rendered, which is
+    /// where [use_breadcrumbs](crate::use_breadcrumbs) looks up matches.
+    pub(crate) fn set_routes_base(&self, base: &str) {
+        if self.routes_base.with_untracked(Option::is_none) {
+            self.routes_base.set(Some(base.to_string()));
+        }
+    }
+
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         tracing::instrument(level = "trace", skip_all,)
@@ -227,16 +409,44 @@ impl RouterContextInner {
         self: Rc<Self>,
         to: &str,
         options: &NavigateOptions,
+    ) -> Result<(), NavigationError> {
+        // a modal route opened from another keeps the original background
+        let background = options.modal.then(|| {
+            self.background
+                .get_untracked()
+                .unwrap_or_else(|| self.reference.get_untracked())
+        });
+        self.navigate_with_background(to, options, background)
+    }
+
+    /// Navigates to `to`, showing it as a modal route above `background`
+    /// if there is one.
+    pub(crate) fn navigate_with_background(
+        self: Rc<Self>,
+        to: &str,
+        options: &NavigateOptions,
+        background: Option<String>,
     ) -> Result<(), NavigationError> {
         let cx = self.cx;
         let this = Rc::clone(&self);
 
+        if self.blockers.block(to, options) {
+            return Ok(());
+        }
+
         cx.untrack(move || {
             let resolved_to = if options.resolve {
                 this.base.resolve_path(to)
             } else {
                 resolve_path("", to, None).map(String::from)
             };
+            let resolved_to = if options.preserve_query {
+                let search = this.location.search.get_untracked();
+                resolved_to.map(|to| merge_query(&to, &search))
+            } else {
+                resolved_to
+            };
+            let resolved_to = resolved_to.map(|to| this.localize(&to, None));
 
             // reset count of pending resources at global level
             expect_context::<GlobalSuspenseContext>(cx).reset(cx);
@@ -250,6 +460,7 @@ impl RouterContextInner {
 
                     if resolved_to != this.reference.get()
                         || options.state != (this.state).get()
+                        || background != this.background.get()
                     {
                         {
                             self.referrers.borrow_mut().push(LocationChange {
@@ -257,22 +468,29 @@ impl RouterContextInner {
                                 replace: options.replace,
                                 scroll: options.scroll,
                                 state: self.state.get(),
+                                background: self.background.get(),
                             });
                         }
                         let len = self.referrers.borrow().len();
 
                         let set_reference = self.set_reference;
                         let set_state = self.set_state;
+                        let set_background = self.set_background;
                         let referrers = self.referrers.clone();
                         let this = Rc::clone(&self);
 
                         let resolved = resolved_to.to_string();
                         let state = options.state.clone();
-                        set_reference.update(move |r| *r = resolved);
-
-                        set_state.update({
-                            let next_state = state.clone();
-                            move |state| *state = next_state
+                        // update the background and the URL together, so
+                        // that the page behind a modal isn't re-rendered
+                        cx.batch({
+                            let state = state.clone();
+                            let background = background.clone();
+                            move || {
+                                set_background.set(background);
+                                set_reference.update(move |r| *r = resolved);
+                                set_state.update(move |s| *s = state);
+                            }
                         });
 
                         let global_suspense =
@@ -300,6 +518,7 @@ impl RouterContextInner {
                                     replace: false,
                                     scroll: true,
                                     state,
+                                    background,
                                 });
                             }
                         });
@@ -311,10 +530,127 @@ impl RouterContextInner {
         })
     }
 
+    /// Puts `path` in `locale`, or in the active locale if it doesn't
+    /// already start with one, for a router with `locales`.
+    pub(crate) fn localize(&self, path: &str, locale: Option<&str>) -> String {
+        match (self.locales, self.locale) {
+            (Some(locales), Some(active)) => match locale {
+                Some(locale) => with_locale(path, locale, locales),
+                None if split_locale(path, locales).0.is_some() => {
+                    path.to_string()
+                }
+                None => {
+                    active.with(|active| with_locale(path, active, locales))
+                }
+            },
+            _ => path.to_string(),
+        }
+    }
+
+    /// Redirects any path with a trailing slash to the same path without one.
+    pub(crate) fn redirect_trailing_slash(self: &Rc<Self>) {
+        let location = self.location.clone();
+        self.redirect_to(move || {
+            let pathname = location.pathname.get();
+            if pathname.len() > 1 && pathname.ends_with('/') {
+                let search = location.search.get();
+                Some(
+                    without_trailing_slash(&pathname)
+                        + if search.is_empty() { "" } else { "?" }
+                        + &search
+                        + &location.hash.get(),
+                )
+            } else {
+                None
+            }
+        });
+    }
+
+    /// Redirects any path without a locale to the same path in the user's
+    /// preferred locale, for a router with `locales`.
+    pub(crate) fn redirect_to_locale(self: &Rc<Self>) {
+        let Some(locales) = self.locales else {
+            return;
+        };
+        let location = self.location.clone();
+        let cx = self.cx;
+        self.redirect_to(move || {
+            let pathname = location.pathname.get();
+            if split_locale(&pathname, locales).0.is_some() {
+                None
+            } else {
+                let locale = preferred_locale(cx, locales);
+                let search = location.search.get();
+                Some(
+                    with_locale(&pathname, locale, locales)
+                        + if search.is_empty() { "" } else { "?" }
+                        + &search
+                        + &location.hash.get(),
+                )
+            }
+        });
+    }
+
+    /// Redirects to the path returned by `canonical`, whenever it returns
+    /// one, on the server with the [ServerRedirectFunction](crate::ServerRedirectFunction)
+    /// and in the browser by replacing the current history entry.
+    fn redirect_to(
+        self: &Rc<Self>,
+        canonical: impl Fn() -> Option<String> + 'static,
+    ) {
+        #[cfg(feature = "ssr")]
+        if let Some(redirect) =
+            use_context::<crate::ServerRedirectFunction>(self.cx)
+        {
+            if let Some(path) = self.cx.untrack(canonical) {
+                (redirect.f)(&path);
+            }
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        create_effect(self.cx, {
+            let this = Rc::clone(self);
+            move |_| {
+                if let Some(path) = canonical() {
+                    let this = Rc::clone(&this);
+                    if let Err(e) = this.navigate_from_route(
+                        &path,
+                        &NavigateOptions {
+                            resolve: false,
+                            replace: true,
+                            scroll: false,
+                            ..Default::default()
+                        },
+                    ) {
+                        leptos::error!("{e:#?}");
+                    }
+                }
+            }
+        });
+    }
+
+    /// Puts the router's current location back into the browser history,
+    /// after the browser has moved to a different entry on its own.
+    ///
+    /// The entry the browser moved to is replaced, rather than a new one
+    /// being pushed, so that blocking a navigation doesn't grow the history.
+    pub(crate) fn restore_location(&self) {
+        self.history.navigate(&LocationChange {
+            value: self.reference.get_untracked(),
+            replace: true,
+            scroll: false,
+            state: self.state.get_untracked(),
+            background: self.background.get_untracked(),
+        });
+    }
+
     pub(crate) fn navigate_end(self: Rc<Self>, mut next: LocationChange) {
         let first = self.referrers.borrow().get(0).cloned();
         if let Some(first) = first {
-            if next.value != first.value || next.state != first.state {
+            if next.value != first.value
+                || next.state != first.state
+                || next.background != first.background
+            {
                 next.replace = first.replace;
                 next.scroll = first.scroll;
                 self.history.navigate(&next);
@@ -369,7 +705,27 @@ impl RouterContextInner {
                 return;
             }
 
-            let url = Url::try_from(href.as_str()).unwrap();
+            let mut url = Url::try_from(href.as_str()).unwrap();
+
+            // in hash-based routing, a link to a route is a hash link to the
+            // current page, and the route itself is in the hash
+            if self.history.is_hash_based() {
+                let page = leptos_dom::helpers::location();
+                if url.hash.starts_with("#/")
+                    && url.pathname == page.pathname().unwrap_or_default()
+                {
+                    match Url::try_from(&url.hash[1..]) {
+                        Ok(route) => {
+                            url = Url {
+                                origin: url.origin,
+                                ..route
+                            }
+                        }
+                        Err(_) => return,
+                    }
+                }
+            }
+
             let path_name = unescape(&url.pathname);
 
             // let browser handle this event if it leaves our domain
@@ -407,6 +763,11 @@ impl RouterContextInner {
                     .ok()
                     .and_then(|value| value.as_bool())
                     .unwrap_or(false);
+            let modal =
+                leptos_dom::helpers::get_property(a.unchecked_ref(), "modal")
+                    .ok()
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(false);
             if let Err(e) = self.navigate_from_route(
                 &to,
                 &NavigateOptions {
@@ -414,6 +775,8 @@ impl RouterContextInner {
                     replace,
                     scroll: !a.has_attribute("noscroll"),
                     state: State(state),
+                    modal,
+                    ..Default::default()
                 },
             ) {
                 leptos::error!("{e:#?}");
@@ -433,7 +796,46 @@ pub enum NavigationError {
     MaxRedirects,
 }
 
+/// How the [Router] treats paths that end in a slash, like `/about/`.
+///
+/// Route paths are always defined without a trailing slash.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TrailingSlash {
+    /// `/about/` matches the same route as `/about`.
+    #[default]
+    Ignore,
+    /// `/about/` does not match a route defined as `/about`, although it may
+    /// still match a wildcard route. Links are rendered without a trailing slash.
+    Strict,
+    /// `/about/` redirects to `/about`, on the server and in the browser.
+    /// Links are rendered without a trailing slash.
+    Redirect,
+}
+
+fn without_trailing_slash(path: &str) -> String {
+    let end = path.find(['?', '#']).unwrap_or(path.len());
+    let (pathname, rest) = path.split_at(end);
+    let trimmed = pathname.trim_end_matches('/');
+    if trimmed.is_empty() && pathname.starts_with('/') {
+        format!("/{rest}")
+    } else {
+        format!("{trimmed}{rest}")
+    }
+}
+
 /// Options that can be used to configure a navigation. Used with [use_navigate](crate::use_navigate).
+///
+/// New options can be added in later versions, so set the ones you need and
+/// fill in the rest with `..Default::default()`, rather than listing every
+/// field:
+///
+/// ```
+/// # use leptos_router::NavigateOptions;
+/// let options = NavigateOptions {
+///     replace: true,
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Clone, Debug)]
 pub struct NavigateOptions {
     /// Whether the URL being navigated to should be resolved relative to the current route.
@@ -447,6 +849,14 @@ pub struct NavigateOptions {
     /// [State](https://developer.mozilla.org/en-US/docs/Web/API/History/state) that should be pushed
     /// onto the history stack during navigation.
     pub state: State,
+    /// If `true`, the current query parameters will be kept, merged with any
+    /// query parameters in the new URL. Parameters in the new URL take
+    /// precedence. Defaults to `false`.
+    pub preserve_query: bool,
+    /// If `true`, the new location is shown by
+    /// [`ModalRoutes`](crate::ModalRoutes), above the current page, which
+    /// stays mounted. Going back closes it. Defaults to `false`.
+    pub modal: bool,
 }
 
 impl Default for NavigateOptions {
@@ -456,6 +866,41 @@ impl Default for NavigateOptions {
             replace: false,
             scroll: true,
             state: State(None),
+            preserve_query: false,
+            modal: false,
         }
     }
 }
+
+/// Adds the query parameters in `search` to the URL `to`, unless `to`
+/// already has a parameter with the same key.
+fn merge_query(to: &str, search: &str) -> String {
+    let (to, hash) = match to.split_once('#') {
+        Some((to, hash)) => (to, Some(hash)),
+        None => (to, None),
+    };
+    let (path, query) = to.split_once('?').unwrap_or((to, ""));
+
+    fn key(pair: &str) -> &str {
+        pair.split('=').next().unwrap_or_default()
+    }
+
+    let new_pairs = query.split('&').filter(|pair| !pair.is_empty());
+    let new_keys = new_pairs.clone().map(key).collect::<Vec<_>>();
+    let pairs = search
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !new_keys.contains(&key(pair)))
+        .chain(new_pairs)
+        .collect::<Vec<_>>();
+
+    let mut url = path.to_string();
+    if !pairs.is_empty() {
+        url.push('?');
+        url.push_str(&pairs.join("&"));
+    }
+    if let Some(hash) = hash {
+        url.push('#');
+        url.push_str(hash);
+    }
+    url
+}
@@ -14,6 +14,7 @@ use std::{
     ops::IndexMut,
     rc::Rc,
 };
+use wasm_bindgen::JsCast;
 
 /// Contains route definitions and manages the actual routing process.
 ///
@@ -39,6 +40,7 @@ pub fn Routes(
     let base = base.unwrap_or_default();
 
     Branches::initialize(&base, children(cx));
+    router.inner.set_routes_base(&base);
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>(cx) {
@@ -47,8 +49,7 @@ pub fn Routes(
         });
     }
 
-    let next_route = router.pathname();
-    let current_route = next_route;
+    let current_route = router.inner.page_pathname;
 
     let root_equal = Rc::new(Cell::new(true));
     let route_states =
@@ -115,6 +116,7 @@ pub fn AnimatedRoutes(
     let base = base.unwrap_or_default();
 
     Branches::initialize(&base, children(cx));
+    router.inner.set_routes_base(&base);
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>(cx) {
@@ -134,12 +136,13 @@ pub fn AnimatedRoutes(
     let is_back = use_is_back_navigation(cx);
     let (animation_state, set_animation_state) =
         create_signal(cx, AnimationState::Finally);
-    let next_route = router.pathname();
+    let next_route = router.inner.page_pathname;
 
     let is_complete = Rc::new(Cell::new(true));
     let animation_and_route = create_memo(cx, {
         let is_complete = Rc::clone(&is_complete);
         let base = base.clone();
+        let options = router.match_options();
 
         move |prev: Option<&(AnimationState, String)>| {
             let animation_state = animation_state.get();
@@ -147,8 +150,8 @@ pub fn AnimatedRoutes(
             let prev_matches = prev
                 .map(|(_, r)| r)
                 .cloned()
-                .map(|location| get_route_matches(&base, location));
-            let matches = get_route_matches(&base, next_route.clone());
+                .map(|location| get_route_matches(&base, location, options));
+            let matches = get_route_matches(&base, next_route.clone(), options);
             let same_route = prev_matches
                 .and_then(|p| p.get(0).as_ref().map(|r| r.route.key.clone()))
                 == matches.get(0).as_ref().map(|r| r.route.key.clone());
@@ -223,6 +226,177 @@ pub fn AnimatedRoutes(
         .into_view(cx)
 }
 
+/// Renders the route for the current location as a modal, above the page it
+/// was opened from, after navigating with
+/// [`NavigateOptions::modal`](crate::NavigateOptions::modal) or an
+/// [`A`](crate::A) with `modal=true`.
+///
+/// While a modal route is open, [`Routes`] keeps rendering the page behind it,
+/// so that page stays mounted, along with its state and scroll position.
+/// Going back, or navigating without `modal`, closes the modal. If the page is
+/// reloaded, or the URL is opened directly, the route is shown as a full page
+/// by [`Routes`] instead, as it is when rendered on the server.
+///
+/// The routes are the ones defined in the [`Routes`] with the same `base`,
+/// which must be rendered before this component. Nothing is rendered while no
+/// modal route is open; otherwise the route is wrapped in a
+/// `<div role="dialog" aria-modal="true">`.
+///
+/// Opening the modal moves focus into it, and <kbd>Tab</kbd> keeps focus
+/// inside it until it closes, when focus moves back to where it was.
+/// <kbd>Escape</kbd> closes it by going back.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_router::*;
+///
+/// #[component]
+/// fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///       <Router>
+///         <Routes>
+///           <Route path="/" view=|cx| view! { cx, <Feed/> }/>
+///           <Route path="/photos/:id" view=|cx| view! { cx, <Photo/> }/>
+///         </Routes>
+///         <ModalRoutes class="overlay"/>
+///       </Router>
+///     }
+/// }
+///
+/// #[component]
+/// fn Feed(cx: Scope) -> impl IntoView {
+///     // shows the photo above the feed, rather than instead of it
+///     view! { cx, <A href="/photos/1" modal=true>"Photo 1"</A> }
+/// }
+/// # #[component]
+/// # fn Photo(cx: Scope) -> impl IntoView {
+/// #     view! { cx, <img src="/photos/1.jpg"/> }
+/// # }
+/// ```
+#[component]
+pub fn ModalRoutes(
+    cx: Scope,
+    /// Base path relative at which the routes are mounted, which should be
+    /// the same as the `base` of the [`Routes`].
+    #[prop(optional)]
+    base: Option<String>,
+    /// Sets the `class` attribute on the `<div>` wrapping the modal route.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+) -> impl IntoView {
+    let router = use_context::<RouterContext>(cx).expect(
+        "<ModalRoutes/> component should be nested within a <Router/>.",
+    );
+    let base = base.unwrap_or_default();
+
+    let background = router.background();
+    let is_open = create_memo(cx, move |_| background.with(Option::is_some));
+    let prev_disposer = Rc::new(RefCell::new(None::<ScopeDisposer>));
+
+    (move || {
+        if let Some(disposer) = prev_disposer.take() {
+            disposer.dispose();
+        }
+        if !is_open.get() {
+            return None;
+        }
+
+        let (view, disposer) = cx.run_child_scope(|cx| {
+            let root_equal = Rc::new(Cell::new(true));
+            let route_states = route_states(
+                cx,
+                base.clone(),
+                &router,
+                router.pathname(),
+                &root_equal,
+            );
+            let root = root_route(cx, router.base(), route_states, root_equal);
+            let class = class.clone();
+
+            // focus moves into the dialog when it opens, and back to where it
+            // was when it closes
+            let dialog = create_node_ref::<html::Div>(cx);
+            dialog.on_load(cx, |dialog| _ = dialog.focus());
+            let opener = document()
+                .active_element()
+                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+            on_cleanup(cx, move || {
+                if let Some(opener) = opener {
+                    _ = opener.focus();
+                }
+            });
+
+            html::div(cx)
+                .node_ref(dialog)
+                .attr("role", "dialog")
+                .attr("aria-modal", "true")
+                .attr("tabindex", "-1")
+                .attr("class", move || class.as_ref().map(TextProp::get))
+                .on(ev::keydown, move |ev| match ev.key().as_str() {
+                    "Escape" => {
+                        ev.prevent_default();
+                        _ = window()
+                            .history()
+                            .and_then(|history| history.back());
+                    }
+                    "Tab" => {
+                        if let Some(dialog) = dialog.get_untracked() {
+                            trap_focus(&ev, &dialog);
+                        }
+                    }
+                    _ => {}
+                })
+                .child(move || root.get())
+                .into_view(cx)
+        });
+        *prev_disposer.borrow_mut() = Some(disposer);
+        Some(view)
+    })
+    .into_view(cx)
+}
+
+/// The elements that can be focused with <kbd>Tab</kbd>.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), \
+                         input:not([disabled]), select:not([disabled]), \
+                         textarea:not([disabled]), iframe, [contenteditable], \
+                         [tabindex]:not([tabindex=\"-1\"])";
+
+/// Keeps focus inside `dialog` when <kbd>Tab</kbd> or <kbd>Shift+Tab</kbd>
+/// would move it past the last or first element that can be focused.
+fn trap_focus(ev: &ev::KeyboardEvent, dialog: &web_sys::HtmlElement) {
+    let focusable = dialog
+        .query_selector_all(FOCUSABLE)
+        .map(|nodes| {
+            (0..nodes.length())
+                .filter_map(|i| nodes.get(i))
+                .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last())
+    else {
+        // there is nothing else to focus, so focus stays on the dialog
+        ev.prevent_default();
+        return;
+    };
+
+    let active = document().active_element();
+    let is_active = |el: &web_sys::HtmlElement| {
+        active
+            .as_ref()
+            .map_or(false, |active| active.is_same_node(Some(el.as_ref())))
+    };
+    let wraps_to = if ev.shift_key() {
+        (is_active(first) || is_active(dialog)).then_some(last)
+    } else {
+        is_active(last).then_some(first)
+    };
+    if let Some(next) = wraps_to {
+        ev.prevent_default();
+        _ = next.focus();
+    }
+}
+
 pub(crate) struct Branches;
 
 thread_local! {
@@ -286,8 +460,10 @@ fn route_states(
     root_equal: &Rc<Cell<bool>>,
 ) -> Memo<RouterState> {
     // whenever path changes, update matches
-    let matches =
-        create_memo(cx, move |_| get_route_matches(&base, current_route.get()));
+    let options = router.match_options();
+    let matches = create_memo(cx, move |_| {
+        get_route_matches(&base, current_route.get(), options)
+    });
 
     // iterate over the new matches, reusing old routes when they are the same
     // and replacing them with new routes when they differ
@@ -495,21 +671,7 @@ pub struct RouteData {
 
 impl RouteData {
     fn score(&self) -> i32 {
-        let (pattern, splat) = match self.pattern.split_once("/*") {
-            Some((p, s)) => (p, Some(s)),
-            None => (self.pattern.as_str(), None),
-        };
-        let segments = pattern
-            .split('/')
-            .filter(|n| !n.is_empty())
-            .collect::<Vec<_>>();
-        #[allow(clippy::bool_to_int_with_if)] // on the splat.is_none()
-        segments.iter().fold(
-            (segments.len() as i32) - if splat.is_none() { 0 } else { 1 },
-            |score, segment| {
-                score + if segment.starts_with(':') { 2 } else { 3 }
-            },
-        )
+        self.matcher.score()
     }
 }
 
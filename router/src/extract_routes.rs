@@ -2,11 +2,18 @@ use crate::{
     Branch, Method, RouterIntegrationContext, ServerIntegration, SsrMode,
 };
 use leptos::*;
-use std::{cell::RefCell, collections::HashSet, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    rc::Rc,
+};
 
-/// Context to contain all possible routes.
+/// Context to contain all possible routes, and the router's locales.
 #[derive(Clone, Default, Debug)]
-pub struct PossibleBranchContext(pub(crate) Rc<RefCell<Vec<Branch>>>);
+pub struct PossibleBranchContext(
+    pub(crate) Rc<RefCell<Vec<Branch>>>,
+    pub(crate) Rc<Cell<Option<&'static [&'static str]>>>,
+);
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 /// A route that this application can serve.
@@ -52,11 +59,99 @@ impl RouteListing {
 pub fn generate_route_list_inner<IV>(
     app_fn: impl FnOnce(Scope) -> IV + 'static,
 ) -> Vec<RouteListing>
+where
+    IV: IntoView + 'static,
+{
+    let (branches, locales) = possible_branches(app_fn);
+    branches
+        .iter()
+        .flat_map(|branch| {
+            let mode = branch
+                .routes
+                .iter()
+                .map(|route| route.key.ssr_mode)
+                .max()
+                .unwrap_or_default();
+            let methods = branch
+                .routes
+                .iter()
+                .flat_map(|route| route.key.methods)
+                .copied()
+                .collect::<HashSet<_>>();
+            let pattern =
+                branch.routes.last().map(|route| route.pattern.clone());
+            pattern.map(|path| RouteListing {
+                path: server_pattern(&path),
+                mode,
+                methods: methods.clone(),
+            })
+        })
+        // with locales, each route is also served at `/{locale}/...`,
+        // while the path without a locale redirects to one
+        .flat_map(|listing| {
+            let localized = locales.iter().map({
+                let listing = listing.clone();
+                move |locale| RouteListing {
+                    path: crate::locale::with_locale(
+                        &listing.path,
+                        locale,
+                        &[],
+                    ),
+                    ..listing.clone()
+                }
+            });
+            std::iter::once(listing).chain(localized)
+        })
+        .collect()
+}
+
+/// Returns the paths declared with [`typed_routes!`](crate::typed_routes)
+/// that no `<Route/>` in the application serves.
+///
+/// The typed routes are declared separately from the `<Route/>`s, so use
+/// their `PATH`s in the `<Route/>`s, and check in a test that the two
+/// haven't drifted apart:
+/// ```ignore
+/// #[test]
+/// fn every_link_has_a_route() {
+///     let unrouted = unrouted_paths(|cx| view! { cx, <App/> }, routes::PATHS);
+///     assert!(unrouted.is_empty(), "no route for {unrouted:?}");
+/// }
+/// ```
+pub fn unrouted_paths<IV>(
+    app_fn: impl FnOnce(Scope) -> IV + 'static,
+    paths: &[&'static str],
+) -> Vec<&'static str>
+where
+    IV: IntoView + 'static,
+{
+    let (branches, _) = possible_branches(app_fn);
+    let patterns = branches
+        .iter()
+        .filter_map(|branch| branch.routes.last())
+        .map(|route| route.pattern.as_str())
+        .collect::<HashSet<_>>();
+    paths
+        .iter()
+        .copied()
+        .filter(|path| {
+            !crate::expand_optionals(path).iter().all(|path| {
+                patterns.contains(crate::join_paths("", path).as_str())
+            })
+        })
+        .collect()
+}
+
+// renders the app as if on the server, collecting every branch of its
+// routes and the locales of its router
+fn possible_branches<IV>(
+    app_fn: impl FnOnce(Scope) -> IV + 'static,
+) -> (Vec<Branch>, &'static [&'static str])
 where
     IV: IntoView + 'static,
 {
     let runtime = create_runtime();
-    let routes = run_scope(runtime, move |cx| {
+    let branches = run_scope(runtime, move |cx| {
         let integration = ServerIntegration {
             path: "http://leptos.rs/".to_string(),
         };
@@ -69,32 +164,34 @@ where
         _ = app_fn(cx).into_view(cx);
         leptos::suppress_resource_load(false);
 
-        let branches = branches.0.borrow();
-        branches
-            .iter()
-            .flat_map(|branch| {
-                let mode = branch
-                    .routes
-                    .iter()
-                    .map(|route| route.key.ssr_mode)
-                    .max()
-                    .unwrap_or_default();
-                let methods = branch
-                    .routes
-                    .iter()
-                    .flat_map(|route| route.key.methods)
-                    .copied()
-                    .collect::<HashSet<_>>();
-                let pattern =
-                    branch.routes.last().map(|route| route.pattern.clone());
-                pattern.map(|path| RouteListing {
-                    path,
-                    mode,
-                    methods: methods.clone(),
-                })
-            })
-            .collect()
+        let locales = branches.1.get().unwrap_or_default();
+        let branches = branches.0.borrow().clone();
+        (branches, locales)
     });
     runtime.dispose();
-    routes
+    branches
+}
+
+/// Server routers only understand plain `:param`s and trailing wildcards, so
+/// strips parameter constraints (`:id(\d+)`, `:id<int>`) and any segments that
+/// follow a wildcard. The constraints are still applied when the route is
+/// matched by the Leptos router.
+fn server_pattern(pattern: &str) -> String {
+    let pattern = match pattern.split_once("/*") {
+        Some((prefix, splat)) => {
+            format!("{prefix}/*{}", splat.split('/').next().unwrap_or_default())
+        }
+        None => pattern.to_string(),
+    };
+    pattern
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(param) => {
+                let name = param.split(['(', '<']).next().unwrap_or_default();
+                format!(":{name}")
+            }
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
 }
@@ -51,6 +51,19 @@ pub struct Location {
 }
 
 /// A description of a navigation.
+///
+/// New fields can be added in later versions, so set the ones you need and
+/// fill in the rest with `..Default::default()`, rather than listing every
+/// field:
+///
+/// ```
+/// # use leptos_router::LocationChange;
+/// let change = LocationChange {
+///     value: "/about".to_string(),
+///     replace: false,
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct LocationChange {
     /// The new URL.
@@ -62,6 +75,9 @@ pub struct LocationChange {
     pub scroll: bool,
     /// The [`state`](https://developer.mozilla.org/en-US/docs/Web/API/History/state) that will be added during navigation.
     pub state: State,
+    /// If this is a navigation to a [modal route](crate::ModalRoutes), the
+    /// URL of the page that stays mounted behind it.
+    pub background: Option<String>,
 }
 
 impl Default for LocationChange {
@@ -71,6 +87,7 @@ impl Default for LocationChange {
             replace: true,
             scroll: true,
             state: Default::default(),
+            background: None,
         }
     }
 }
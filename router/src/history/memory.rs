@@ -0,0 +1,154 @@
+use super::{History, LocationChange, State};
+use leptos::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// An integration that keeps its history stack in memory, rather than
+/// reading from and writing to the browser’s URL.
+///
+/// This allows the router to be driven without a browser, for example in
+/// native unit tests: provide the integration above the
+/// [`Router`](crate::Router), navigate with [`MemoryIntegration::navigate_to`],
+/// [`back`](MemoryIntegration::back) and [`forward`](MemoryIntegration::forward),
+/// and check the result with [`MemoryIntegration::current_path`].
+///
+/// Cloning the integration returns a handle to the same history stack.
+/// ```
+/// # use leptos_router::*;
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let history = MemoryIntegration::new("/");
+/// provide_context(cx, RouterIntegrationContext::new(history.clone()));
+///
+/// // ...render a <Router/>, then
+/// history.navigate_to("/users/1");
+/// assert_eq!(history.current_path(), "/users/1");
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct MemoryIntegration {
+    inner: Rc<RefCell<MemoryHistory>>,
+}
+
+#[derive(Debug)]
+struct MemoryHistory {
+    entries: Vec<LocationChange>,
+    index: usize,
+    set_location: Option<WriteSignal<LocationChange>>,
+}
+
+impl MemoryIntegration {
+    /// Creates a history stack containing only the given path.
+    pub fn new(initial_path: impl Into<String>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(MemoryHistory {
+                entries: vec![LocationChange {
+                    value: initial_path.into(),
+                    replace: true,
+                    scroll: true,
+                    state: State(None),
+                    ..Default::default()
+                }],
+                index: 0,
+                set_location: None,
+            })),
+        }
+    }
+
+    /// The path of the current entry in the history stack.
+    pub fn current_path(&self) -> String {
+        let inner = self.inner.borrow();
+        inner.entries[inner.index].value.clone()
+    }
+
+    /// The paths of every entry in the history stack, oldest first.
+    pub fn entries(&self) -> Vec<String> {
+        self.inner
+            .borrow()
+            .entries
+            .iter()
+            .map(|entry| entry.value.clone())
+            .collect()
+    }
+
+    /// Pushes a new entry onto the history stack and routes to it, as if
+    /// the user had entered the path in the address bar. Any entries after
+    /// the current one are discarded.
+    pub fn navigate_to(&self, path: impl Into<String>) {
+        self.navigate(&LocationChange {
+            value: path.into(),
+            replace: false,
+            scroll: true,
+            state: State(None),
+            ..Default::default()
+        });
+        self.notify();
+    }
+
+    /// Moves back one entry in the history stack, if possible.
+    pub fn back(&self) {
+        self.go(-1);
+    }
+
+    /// Moves forward one entry in the history stack, if possible.
+    pub fn forward(&self) {
+        self.go(1);
+    }
+
+    /// Moves `delta` entries through the history stack, like
+    /// [`History.go()`](https://developer.mozilla.org/en-US/docs/Web/API/History/go).
+    /// Does nothing if that would move past either end of the stack.
+    pub fn go(&self, delta: isize) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            let index = inner.index as isize + delta;
+            if delta == 0 || index < 0 || index >= inner.entries.len() as isize
+            {
+                return;
+            }
+            inner.index = index as usize;
+        }
+        self.notify();
+    }
+
+    // tells the router that the current entry has changed
+    fn notify(&self) {
+        let (set_location, current) = {
+            let inner = self.inner.borrow();
+            (inner.set_location, inner.entries[inner.index].clone())
+        };
+        if let Some(set_location) = set_location {
+            set_location.set(current);
+        }
+    }
+}
+
+impl Default for MemoryIntegration {
+    fn default() -> Self {
+        Self::new("/")
+    }
+}
+
+impl History for MemoryIntegration {
+    fn location(&self, cx: Scope) -> ReadSignal<LocationChange> {
+        let current = {
+            let inner = self.inner.borrow();
+            inner.entries[inner.index].clone()
+        };
+        let (location, set_location) = create_signal(cx, current);
+        self.inner.borrow_mut().set_location = Some(set_location);
+        location
+    }
+
+    fn navigate(&self, loc: &LocationChange) {
+        let mut inner = self.inner.borrow_mut();
+        if loc.replace {
+            let index = inner.index;
+            inner.entries[index] = loc.clone();
+        } else {
+            let index = inner.index + 1;
+            inner.entries.truncate(index);
+            inner.entries.push(loc.clone());
+            inner.index = index;
+        }
+    }
+}
@@ -1,14 +1,16 @@
 use leptos::*;
 use std::rc::Rc;
-use wasm_bindgen::UnwrapThrowExt;
+use wasm_bindgen::{JsValue, UnwrapThrowExt};
 
 mod location;
+mod memory;
 mod params;
 mod state;
 mod url;
 
 pub use self::url::*;
 pub use location::*;
+pub use memory::*;
 pub use params::*;
 pub use state::*;
 
@@ -27,6 +29,15 @@ pub trait History {
 
     /// Called to navigate to a new location.
     fn navigate(&self, loc: &LocationChange);
+
+    /// Whether this integration stores the route in the hash fragment of the
+    /// URL (`/#/users/1`) rather than in its path (`/users/1`).
+    ///
+    /// If `true`, the [`A`](crate::A) component renders its `href` as a hash
+    /// link, and the router handles clicks on hash links as navigations.
+    fn is_hash_based(&self) -> bool {
+        false
+    }
 }
 
 /// The default integration when you are running in the browser, which uses
@@ -37,100 +48,251 @@ pub struct BrowserIntegration {}
 impl BrowserIntegration {
     fn current() -> LocationChange {
         let loc = leptos_dom::helpers::location();
+        let (state, background) = read_history_state();
         LocationChange {
             value: loc.pathname().unwrap_or_default()
                 + &loc.search().unwrap_or_default()
                 + &loc.hash().unwrap_or_default(),
             replace: true,
             scroll: true,
-            state: State(None),
+            state,
+            background,
         }
     }
 }
 
 impl History for BrowserIntegration {
     fn location(&self, cx: Scope) -> ReadSignal<LocationChange> {
-        use crate::{NavigateOptions, RouterContext};
+        listen_for_popstate(cx, Self::current)
+    }
+
+    fn navigate(&self, loc: &LocationChange) {
+        let history = leptos_dom::window().history().unwrap_throw();
+
+        let state = &to_history_state(loc);
+        if loc.replace {
+            history
+                .replace_state_with_url(state, "", Some(&loc.value))
+                .unwrap_throw();
+        } else {
+            // push the "forward direction" marker
+            history
+                .push_state_with_url(state, "", Some(&loc.value))
+                .unwrap_throw();
+        }
+        // scroll to el
+        let hash = leptos_dom::helpers::location().hash().unwrap_or_default();
+        if !hash.is_empty() {
+            let hash = js_sys::decode_uri(&hash[1..])
+                .ok()
+                .and_then(|decoded| decoded.as_string())
+                .unwrap_or(hash);
+            let el = leptos_dom::document().get_element_by_id(&hash);
+            if let Some(el) = el {
+                el.scroll_into_view()
+            } else if loc.scroll {
+                leptos_dom::window().scroll_to_with_x_and_y(0.0, 0.0);
+            }
+        } else if loc.scroll {
+            leptos_dom::window().scroll_to_with_x_and_y(0.0, 0.0);
+        }
+    }
+}
+
+// The key of `History.state` that holds the background location of a modal
+// route, next to the user's own state.
+const BACKGROUND_KEY: &str = "__leptos_background";
+
+/// Converts the state and background location of a navigation into the value
+/// stored in `History.state`.
+fn to_history_state(loc: &LocationChange) -> JsValue {
+    match &loc.background {
+        None => loc.state.to_js_value(),
+        Some(background) => {
+            let value = js_sys::Object::new();
+            _ = js_sys::Reflect::set(
+                &value,
+                &BACKGROUND_KEY.into(),
+                &background.into(),
+            );
+            _ = js_sys::Reflect::set(
+                &value,
+                &"state".into(),
+                &loc.state.to_js_value(),
+            );
+            value.into()
+        }
+    }
+}
+
+/// Reads the state and background location of the current history entry, as
+/// stored by [to_history_state].
+fn read_history_state() -> (State, Option<String>) {
+    let value = leptos_dom::window()
+        .history()
+        .and_then(|history| history.state())
+        .unwrap_or(JsValue::UNDEFINED);
+    let some = |value: JsValue| {
+        State((!value.is_undefined() && !value.is_null()).then_some(value))
+    };
+    let background = js_sys::Reflect::get(&value, &BACKGROUND_KEY.into())
+        .ok()
+        .and_then(|background| background.as_string());
+    match background {
+        Some(background) => {
+            let state = js_sys::Reflect::get(&value, &"state".into())
+                .unwrap_or(JsValue::UNDEFINED);
+            (some(state), Some(background))
+        }
+        None => (some(value), None),
+    }
+}
 
-        let (location, set_location) = create_signal(cx, Self::current());
+/// Creates a signal of the current location, which is updated by running
+/// the router's navigation logic whenever the browser fires `popstate`.
+fn listen_for_popstate(
+    cx: Scope,
+    current: fn() -> LocationChange,
+) -> ReadSignal<LocationChange> {
+    use crate::{NavigateOptions, RouterContext};
 
-        leptos::window_event_listener_untyped("popstate", move |_| {
-            let router = use_context::<RouterContext>(cx);
-            if let Some(router) = router {
-                let path_stack = router.inner.path_stack;
+    // a modal route that is reloaded is shown as a full page, as it is when
+    // rendered on the server
+    let (location, set_location) = create_signal(
+        cx,
+        LocationChange {
+            background: None,
+            ..current()
+        },
+    );
 
-                let is_back = router.inner.is_back;
-                let change = Self::current();
+    leptos::window_event_listener_untyped("popstate", move |_| {
+        let router = use_context::<RouterContext>(cx);
+        if let Some(router) = router {
+            let change = current();
 
-                let is_navigating_back = path_stack.with_value(|stack| {
-                    stack.len() == 1
-                        || stack.get(stack.len() - 2) == Some(&change.value)
-                });
-                if is_navigating_back {
-                    path_stack.update_value(|stack| {
-                        stack.pop();
-                    });
-                }
+            // the browser has already moved to the new entry, so if the
+            // navigation is blocked, put the current location back
+            if router.inner.blockers.block(
+                &change.value,
+                &NavigateOptions {
+                    resolve: false,
+                    ..Default::default()
+                },
+            ) {
+                router.inner.restore_location();
+                return;
+            }
+
+            let path_stack = router.inner.path_stack;
 
-                is_back.set(is_navigating_back);
+            let is_back = router.inner.is_back;
 
-                request_animation_frame(move || {
-                    is_back.set(false);
+            let is_navigating_back = path_stack.with_value(|stack| {
+                stack.len() == 1
+                    || stack.get(stack.len() - 2) == Some(&change.value)
+            });
+            if is_navigating_back {
+                path_stack.update_value(|stack| {
+                    stack.pop();
                 });
-                if let Err(e) = router.inner.navigate_from_route(
-                    &change.value,
-                    &NavigateOptions {
-                        resolve: false,
-                        replace: change.replace,
-                        scroll: change.scroll,
-                        state: change.state,
-                    },
-                ) {
-                    leptos::error!("{e:#?}");
-                }
-                set_location.set(Self::current());
-            } else {
-                leptos::warn!("RouterContext not found");
             }
-        });
 
-        location
+            is_back.set(is_navigating_back);
+
+            request_animation_frame(move || {
+                is_back.set(false);
+            });
+            if let Err(e) = router.inner.navigate_with_background(
+                &change.value,
+                &NavigateOptions {
+                    resolve: false,
+                    replace: change.replace,
+                    scroll: change.scroll,
+                    state: change.state,
+                    ..Default::default()
+                },
+                change.background,
+            ) {
+                leptos::error!("{e:#?}");
+            }
+            set_location.set(current());
+        } else {
+            leptos::warn!("RouterContext not found");
+        }
+    });
+
+    location
+}
+
+/// An integration that stores the current route in the
+/// [`hash`](https://developer.mozilla.org/en-US/docs/Web/API/Location/hash)
+/// of the URL instead of its path, so that `/users/1` is shown as `/#/users/1`.
+///
+/// This is useful for client-side rendered apps served by a static file host
+/// that can’t be configured to serve `index.html` for every path. Because the
+/// hash is never sent to the server, it can’t be used with server rendering.
+///
+/// Routes and links are defined exactly as they are with the default
+/// [`BrowserIntegration`]; provide this integration above the
+/// [`Router`](crate::Router) to use it.
+/// ```
+/// # use leptos_router::*;
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// provide_context(cx, RouterIntegrationContext::new(HashIntegration {}));
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HashIntegration {}
+
+impl HashIntegration {
+    fn current() -> LocationChange {
+        let hash = leptos_dom::helpers::location().hash().unwrap_or_default();
+        let path = hash.strip_prefix('#').unwrap_or(&hash);
+        let (state, background) = read_history_state();
+        LocationChange {
+            value: if path.is_empty() {
+                "/".to_string()
+            } else {
+                path.to_string()
+            },
+            replace: true,
+            scroll: true,
+            state,
+            background,
+        }
+    }
+}
+
+impl History for HashIntegration {
+    fn location(&self, cx: Scope) -> ReadSignal<LocationChange> {
+        listen_for_popstate(cx, Self::current)
     }
 
     fn navigate(&self, loc: &LocationChange) {
         let history = leptos_dom::window().history().unwrap_throw();
+        let url = format!("#{}", loc.value);
+        let state = &to_history_state(loc);
 
         if loc.replace {
             history
-                .replace_state_with_url(
-                    &loc.state.to_js_value(),
-                    "",
-                    Some(&loc.value),
-                )
+                .replace_state_with_url(state, "", Some(&url))
                 .unwrap_throw();
         } else {
-            // push the "forward direction" marker
-            let state = &loc.state.to_js_value();
             history
-                .push_state_with_url(state, "", Some(&loc.value))
+                .push_state_with_url(state, "", Some(&url))
                 .unwrap_throw();
         }
-        // scroll to el
-        if let Ok(hash) = leptos_dom::helpers::location().hash() {
-            if !hash.is_empty() {
-                let hash = js_sys::decode_uri(&hash[1..])
-                    .ok()
-                    .and_then(|decoded| decoded.as_string())
-                    .unwrap_or(hash);
-                let el = leptos_dom::document().get_element_by_id(&hash);
-                if let Some(el) = el {
-                    el.scroll_into_view()
-                } else if loc.scroll {
-                    leptos_dom::window().scroll_to_with_x_and_y(0.0, 0.0);
-                }
-            }
+        // the hash holds the route, so there's no fragment to scroll to
+        if loc.scroll {
+            leptos_dom::window().scroll_to_with_x_and_y(0.0, 0.0);
         }
     }
+
+    fn is_hash_based(&self) -> bool {
+        true
+    }
 }
 
 /// The wrapper type that the [Router](crate::Router) uses to interact with a [History].
@@ -165,6 +327,10 @@ impl History for RouterIntegrationContext {
     fn navigate(&self, loc: &LocationChange) {
         self.0.navigate(loc)
     }
+
+    fn is_hash_based(&self) -> bool {
+        self.0.is_hash_based()
+    }
 }
 
 /// A generic router integration for the server side.
@@ -199,6 +365,7 @@ impl History for ServerIntegration {
                 replace: false,
                 scroll: true,
                 state: State(None),
+                ..Default::default()
             },
         )
         .0
@@ -77,6 +77,30 @@ pub fn use_resolved_path(
 
 /// Returns a function that can be used to navigate to a new route.
 ///
+/// The [NavigateOptions] control how the navigation happens: whether it
+/// replaces the current entry in the history stack, the history state to
+/// attach, whether to scroll to the top of the page afterward, and whether
+/// to keep the current query parameters.
+/// ```rust
+/// # use leptos::{create_scope,create_runtime};
+/// # use leptos_router::NavigateOptions;
+/// # create_scope(create_runtime(), |cx| {
+/// # if false { // can't actually navigate, no <Router/>
+/// let navigate = leptos_router::use_navigate(cx);
+/// // go to the second page of results, keeping the other filters
+/// _ = navigate(
+///     "?page=2",
+///     NavigateOptions {
+///         replace: true,
+///         scroll: false,
+///         preserve_query: true,
+///         ..Default::default()
+///     },
+/// );
+/// # }
+/// # });
+/// ```
+///
 /// ## Panics
 /// `use_navigate` can sometimes panic due to a `BorrowMut` runtime error
 /// if it is called immediately during routing/rendering. In this case, you should
@@ -28,7 +28,7 @@
 //! ## Example
 //!
 //! ```rust
-//! 
+//!
 //! use leptos::*;
 //! use leptos_router::*;
 //!
@@ -188,19 +188,27 @@
 #![cfg_attr(not(feature = "stable"), feature(type_name_of_val))]
 
 mod animation;
+mod blocker;
 mod components;
 #[cfg(any(feature = "ssr", doc))]
 mod extract_routes;
 mod history;
 mod hooks;
+mod locale;
 #[doc(hidden)]
 pub mod matching;
 mod render_mode;
+mod typed_routes;
+mod validation;
+pub use blocker::*;
 pub use components::*;
 #[cfg(any(feature = "ssr", doc))]
 pub use extract_routes::*;
 pub use history::*;
 pub use hooks::*;
+pub use locale::*;
 pub use matching::{RouteDefinition, *};
 pub use render_mode::*;
+pub use typed_routes::*;
+pub use validation::*;
 extern crate tracing;
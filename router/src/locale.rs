@@ -0,0 +1,147 @@
+use cfg_if::cfg_if;
+use leptos::*;
+use std::borrow::Cow;
+
+/// The value of the `Accept-Language` header of the current request. The
+/// server integrations provide this as context, so that a [Router](crate::Router)
+/// with `locales` can pick a locale for a path that doesn’t have one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AcceptLanguage(pub String);
+
+/// The active locale of a [Router](crate::Router) with `locales`, provided
+/// as context beneath the router. See [use_locale].
+#[derive(Copy, Clone, Debug)]
+pub struct Locale(pub Memo<String>);
+
+/// Returns the active locale, i.e., the first segment of the current path,
+/// of a [Router](crate::Router) with `locales`, or `None` outside such a
+/// router.
+pub fn use_locale(cx: Scope) -> Option<Memo<String>> {
+    use_context::<Locale>(cx).map(|locale| locale.0)
+}
+
+/// Splits the locale off the front of a path, if its first segment is one
+/// of `locales`, returning the locale and the rest of the path.
+///
+/// ```
+/// # use leptos_router::split_locale;
+/// let locales = ["en", "de"];
+/// let (locale, path) = split_locale("/de/about?tab=1", &locales);
+/// assert_eq!((locale, path.as_ref()), (Some("de"), "/about?tab=1"));
+/// let (locale, path) = split_locale("/de?tab=1", &locales);
+/// assert_eq!((locale, path.as_ref()), (Some("de"), "/?tab=1"));
+/// let (locale, path) = split_locale("/fr/about", &locales);
+/// assert_eq!((locale, path.as_ref()), (None, "/fr/about"));
+/// ```
+pub fn split_locale<'a>(
+    path: &'a str,
+    locales: &[&str],
+) -> (Option<&'a str>, Cow<'a, str>) {
+    let Some(rest) = path.strip_prefix('/') else {
+        return (None, Cow::Borrowed(path));
+    };
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (segment, rest) = rest.split_at(end);
+    if !locales.contains(&segment) {
+        (None, Cow::Borrowed(path))
+    } else if rest.starts_with('/') {
+        (Some(segment), Cow::Borrowed(rest))
+    } else {
+        (Some(segment), Cow::Owned(format!("/{rest}")))
+    }
+}
+
+/// Puts `locale` at the front of `path`, replacing any of `locales` that is
+/// already there.
+pub(crate) fn with_locale(
+    path: &str,
+    locale: &str,
+    locales: &[&str],
+) -> String {
+    let (_, path) = split_locale(path, locales);
+    match path.strip_prefix('/') {
+        None if path.is_empty() => format!("/{locale}"),
+        None => path.into_owned(),
+        Some(rest) if rest.is_empty() || rest.starts_with(['?', '#']) => {
+            format!("/{locale}{rest}")
+        }
+        Some(_) => format!("/{locale}{path}"),
+    }
+}
+
+/// Picks the best of `locales` for an `Accept-Language` header, e.g.,
+/// `"de-AT,de;q=0.9,en;q=0.8"`.
+///
+/// Languages are tried in order of preference. A language matches a locale
+/// that is the same (ignoring case), or that is its primary language, so
+/// `de-AT` matches a `de` locale.
+///
+/// ```
+/// # use leptos_router::negotiate_locale;
+/// let locales = ["en", "de"];
+/// assert_eq!(negotiate_locale("fr;q=0.9, de-AT", &locales), Some("de"));
+/// assert_eq!(negotiate_locale("en;q=0.5, de;q=0.8", &locales), Some("de"));
+/// assert_eq!(negotiate_locale("fr", &locales), None);
+/// ```
+pub fn negotiate_locale<'a>(
+    accept_language: &str,
+    locales: &[&'a str],
+) -> Option<&'a str> {
+    let mut languages = accept_language
+        .split(',')
+        .enumerate()
+        .filter_map(|(index, language)| {
+            let mut parts = language.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && quality > 0.0).then_some((tag, quality, index))
+        })
+        .collect::<Vec<_>>();
+    // the sort is stable, but sorting by index as well keeps equal qualities
+    // in the order they were given
+    languages.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+
+    languages.into_iter().find_map(|(tag, _, _)| {
+        let primary = tag.split('-').next().unwrap_or(tag);
+        locales
+            .iter()
+            .find(|locale| locale.eq_ignore_ascii_case(tag))
+            .or_else(|| {
+                locales
+                    .iter()
+                    .find(|locale| locale.eq_ignore_ascii_case(primary))
+            })
+            .copied()
+    })
+}
+
+/// The locale to use for a path that doesn’t have one: negotiated from the
+/// [AcceptLanguage] on the server, or the browser’s languages in the browser,
+/// and otherwise the first of `locales`.
+pub(crate) fn preferred_locale(
+    cx: Scope,
+    locales: &'static [&'static str],
+) -> &'static str {
+    cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            let accept_language =
+                use_context::<AcceptLanguage>(cx).map(|header| header.0);
+        } else {
+            _ = cx;
+            let accept_language = leptos_dom::helpers::window()
+                .navigator()
+                .languages()
+                .iter()
+                .filter_map(|language| language.as_string())
+                .collect::<Vec<_>>();
+            let accept_language = Some(accept_language.join(","));
+        }
+    }
+    accept_language
+        .and_then(|header| negotiate_locale(&header, locales))
+        .or_else(|| locales.first().copied())
+        .unwrap_or_default()
+}
@@ -2,6 +2,7 @@
 // see https://github.com/solidjs/solid-router/blob/main/src/utils.ts
 
 use crate::ParamsMap;
+use std::{cell::RefCell, collections::HashMap};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(hidden)]
@@ -10,11 +11,267 @@ pub struct PathMatch {
     pub params: ParamsMap,
 }
 
+/// Options that control how a [Matcher] compares a location to its pattern.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchOptions {
+    /// If `true`, a location with a trailing slash (`/about/`) only matches
+    /// wildcard routes, and not a route like `/about`.
+    pub strict_trailing_slash: bool,
+    /// If `false`, static segments are compared ignoring ASCII case.
+    pub case_sensitive: bool,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self {
+            strict_trailing_slash: false,
+            case_sensitive: true,
+        }
+    }
+}
+
+/// Tests a path segment's value for a `:param<name>` segment.
+pub type ParamMatcher = fn(&str) -> bool;
+
+thread_local! {
+    static PARAM_MATCHERS: RefCell<HashMap<&'static str, ParamMatcher>> =
+        RefCell::new(HashMap::from([
+            ("int", is_int as ParamMatcher),
+            ("uint", is_uint as ParamMatcher),
+        ]));
+}
+
+fn is_int(value: &str) -> bool {
+    value.parse::<i64>().is_ok()
+}
+
+fn is_uint(value: &str) -> bool {
+    value.parse::<u64>().is_ok()
+}
+
+/// Registers a custom matcher that can be used to constrain route parameters,
+/// by adding its name in angle brackets to a parameter segment.
+///
+/// Matchers named `int` and `uint` are registered by default.
+/// ```
+/// # use leptos_router::*;
+/// register_param_matcher("hex", |value| {
+///     value.chars().all(|c| c.is_ascii_hexdigit())
+/// });
+///
+/// let matcher = Matcher::new("/colors/:color<hex>");
+/// assert!(matcher.test("/colors/ff0000").is_some());
+/// assert!(matcher.test("/colors/red").is_none());
+/// ```
+pub fn register_param_matcher(name: &'static str, matcher: ParamMatcher) {
+    PARAM_MATCHERS.with(|matchers| matchers.borrow_mut().insert(name, matcher));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Static(String),
+    Param {
+        name: String,
+        constraint: Option<Constraint>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Constraint {
+    /// `:id(\d+)`: the value must match the whole regular expression.
+    Regex(String),
+    /// `:id<int>`: the value must pass a [register_param_matcher] matcher.
+    Named(String),
+}
+
+impl Segment {
+    fn parse(segment: &str) -> Self {
+        let Some(param) = segment.strip_prefix(':') else {
+            return Segment::Static(segment.to_string());
+        };
+        let constrained = |open, close| {
+            param
+                .strip_suffix(close)
+                .and_then(|param| param.split_once(open))
+                .map(|(name, constraint)| {
+                    (name.to_string(), constraint.to_string())
+                })
+        };
+        if let Some((name, re)) = constrained('(', ')') {
+            Segment::Param {
+                name,
+                constraint: Some(Constraint::Regex(re)),
+            }
+        } else if let Some((name, matcher)) = constrained('<', '>') {
+            Segment::Param {
+                name,
+                constraint: Some(Constraint::Named(matcher)),
+            }
+        } else {
+            Segment::Param {
+                name: param.to_string(),
+                constraint: None,
+            }
+        }
+    }
+
+    /// Tests the segment against one segment of a location, adding
+    /// the value to `params` if the segment is a parameter.
+    fn test(
+        &self,
+        value: &str,
+        params: &mut ParamsMap,
+        options: MatchOptions,
+    ) -> bool {
+        match self {
+            Segment::Static(segment) if options.case_sensitive => {
+                segment == value
+            }
+            Segment::Static(segment) => segment.eq_ignore_ascii_case(value),
+            Segment::Param { name, constraint } => {
+                let matches = match constraint {
+                    None => true,
+                    Some(Constraint::Regex(re)) => regex_matches(re, value),
+                    Some(Constraint::Named(matcher)) => PARAM_MATCHERS
+                        .with(|matchers| {
+                            matchers.borrow().get(matcher.as_str()).copied()
+                        })
+                        .map(|matcher| matcher(value))
+                        .unwrap_or_else(|| {
+                            leptos::warn!(
+                                "[Leptos Router] No param matcher named \
+                                 {matcher:?} has been registered."
+                            );
+                            false
+                        }),
+                };
+                if matches {
+                    params.insert(name.clone(), value.to_string());
+                }
+                matches
+            }
+        }
+    }
+
+    fn score(&self) -> i32 {
+        match self {
+            Segment::Static(_) => 8,
+            Segment::Param {
+                constraint: Some(_),
+                ..
+            } => 7,
+            Segment::Param { .. } => 6,
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn regex_matches(re: &str, value: &str) -> bool {
+    use js_sys::{Array, Function, Reflect, RegExp};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    thread_local! {
+        static REGEX_CACHE: RefCell<HashMap<String, Option<RegExp>>> =
+            Default::default();
+    }
+
+    REGEX_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(re.to_string())
+            .or_insert_with(|| {
+                // `RegExp::new` throws on an invalid pattern, which would
+                // abort the app, so the constructor is called in a way that
+                // catches the exception
+                let constructor = Reflect::get(
+                    &js_sys::global(),
+                    &JsValue::from_str("RegExp"),
+                )
+                .ok()?
+                .dyn_into::<Function>()
+                .ok()?;
+                let args = Array::of2(
+                    &JsValue::from_str(&format!("^(?:{re})$")),
+                    &JsValue::from_str("u"),
+                );
+                Reflect::construct(&constructor, &args)
+                    .map_err(|e| {
+                        leptos::error!(
+                            "[Leptos Router] Invalid route parameter pattern \
+                             {re:?}\n\n{e:?}"
+                        )
+                    })
+                    .ok()
+                    .map(JsCast::unchecked_into::<RegExp>)
+            })
+            .as_ref()
+            .map(|re| re.test(value))
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(feature = "ssr")]
+fn regex_matches(re: &str, value: &str) -> bool {
+    use regex::Regex;
+
+    thread_local! {
+        static REGEX_CACHE: RefCell<HashMap<String, Option<Regex>>> =
+            Default::default();
+    }
+
+    REGEX_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(re.to_string())
+            .or_insert_with(|| {
+                Regex::new(&format!("^(?:{re})$"))
+                    .map_err(|e| {
+                        leptos::error!(
+                            "[Leptos Router] Invalid route parameter pattern \
+                             {re:?}\n\n{e}"
+                        )
+                    })
+                    .ok()
+            })
+            .as_ref()
+            .map(|re| re.is_match(value))
+            .unwrap_or(false)
+    })
+}
+
+/// Matches locations against a route pattern.
+///
+/// Patterns are made up of segments separated by `/`, each of which is one of
+/// - a static segment, like `users`, which matches only itself
+/// - a parameter, like `:id`, which matches any value and stores it in the
+///   params under the name `id`
+/// - a parameter constrained by a regular expression, like `:id(\d+)`, which
+///   must match the entire segment value
+/// - a parameter constrained by a named matcher, like `:id<int>` (see
+///   [register_param_matcher])
+/// - a wildcard, like `*rest`, which matches any number of segments and stores
+///   them in the params under the name `rest`. A wildcard can appear at the
+///   end of the pattern (`/files/*path`) or be followed by more segments
+///   (`/files/*path/edit`).
+///
+/// A segment that ends in `?`, like `:id?`, is optional; see
+/// [expand_optionals](crate::expand_optionals). Constraint patterns cannot
+/// contain `/` or `?`.
+///
+/// Constraint patterns are compiled with the [`regex`](https://docs.rs/regex)
+/// crate on the server, and as a JavaScript `RegExp` with the `u` flag in the
+/// browser. The two dialects differ: for example, lookaround and
+/// backreferences only work in the browser, and inline flags like `(?i)` only
+/// on the server. A pattern that uses either would match differently on each
+/// side and cause hydration mismatches, so patterns should stick to what both
+/// support. An invalid pattern is logged as an error, and matches nothing.
 #[doc(hidden)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Matcher {
     splat: Option<String>,
-    segments: Vec<String>,
+    segments: Vec<Segment>,
+    suffix: Vec<Segment>,
     len: usize,
     partial: bool,
 }
@@ -27,40 +284,81 @@ impl Matcher {
 
     #[doc(hidden)]
     pub fn new_with_partial(path: &str, partial: bool) -> Self {
-        let (pattern, splat) = match path.split_once("/*") {
-            Some((p, s)) => (p, Some(s.to_string())),
-            None => (path, None),
+        fn parse_segments(path: &str) -> Vec<Segment> {
+            path.split('/')
+                .filter(|n| !n.is_empty())
+                .map(Segment::parse)
+                .collect()
+        }
+
+        let (pattern, splat, suffix) = match path.split_once("/*") {
+            Some((p, s)) => {
+                let (splat, suffix) = s.split_once('/').unwrap_or((s, ""));
+                (p, Some(splat.to_string()), parse_segments(suffix))
+            }
+            None => (path, None, Vec::new()),
         };
-        let segments = pattern
-            .split('/')
-            .filter(|n| !n.is_empty())
-            .map(|n| n.to_string())
-            .collect::<Vec<_>>();
+        let segments = parse_segments(pattern);
 
         let len = segments.len();
 
         Self {
             splat,
             segments,
+            suffix,
             len,
             partial,
         }
     }
 
+    /// How specific the pattern is: when several routes match a location,
+    /// the one with the highest score wins. Static segments are more
+    /// specific than constrained parameters, which are more specific than
+    /// unconstrained parameters; wildcards make a pattern less specific.
+    #[doc(hidden)]
+    pub fn score(&self) -> i32 {
+        let segments = self.segments.iter().chain(self.suffix.iter());
+        let score = segments.map(Segment::score).sum::<i32>();
+        if self.splat.is_some() {
+            score - 2
+        } else {
+            score
+        }
+    }
+
     #[doc(hidden)]
     pub fn test(&self, location: &str) -> Option<PathMatch> {
+        self.test_with(location, MatchOptions::default())
+    }
+
+    #[doc(hidden)]
+    pub fn test_with(
+        &self,
+        location: &str,
+        options: MatchOptions,
+    ) -> Option<PathMatch> {
+        if options.strict_trailing_slash
+            && location.len() > 1
+            && location.ends_with('/')
+            && self.splat.is_none()
+            && !self.partial
+        {
+            return None;
+        }
+
         let loc_segments = location
             .split('/')
             .filter(|n| !n.is_empty())
             .collect::<Vec<_>>();
 
         let loc_len = loc_segments.len();
-        let len_diff: i32 = loc_len as i32 - self.len as i32;
+        let len_diff: i32 =
+            loc_len as i32 - (self.len + self.suffix.len()) as i32;
 
         // quick path: not a match if
         // 1) matcher has add'l segments not found in location
         // 2) location has add'l segments, there's no splat, and partial matches not allowed
-        if loc_len < self.len
+        if len_diff < 0
             || (len_diff > 0 && self.splat.is_none() && !self.partial)
         {
             None
@@ -73,10 +371,8 @@ impl Matcher {
             for (segment, loc_segment) in
                 self.segments.iter().zip(loc_segments.iter())
             {
-                if let Some(param_name) = segment.strip_prefix(':') {
-                    params.insert(param_name.into(), (*loc_segment).into());
-                } else if segment != loc_segment {
-                    // if any segment doesn't match and isn't a param, there's no path match
+                // if any segment doesn't match, there's no path match
+                if !segment.test(loc_segment, &mut params, options) {
                     return None;
                 }
 
@@ -85,14 +381,31 @@ impl Matcher {
             }
 
             if let Some(splat) = &self.splat {
+                let suffix_start = loc_len - self.suffix.len();
+                for (segment, loc_segment) in
+                    self.suffix.iter().zip(loc_segments[suffix_start..].iter())
+                {
+                    if !segment.test(loc_segment, &mut params, options) {
+                        return None;
+                    }
+                }
+
                 if !splat.is_empty() {
                     let value = if len_diff > 0 {
-                        loc_segments[self.len..].join("/")
+                        loc_segments[self.len..suffix_start].join("/")
                     } else {
                         "".into()
                     };
                     params.insert(splat.into(), value);
                 }
+
+                // a wildcard followed by segments consumes the whole location
+                if !self.suffix.is_empty() {
+                    for loc_segment in &loc_segments[self.len..] {
+                        path.push('/');
+                        path.push_str(loc_segment);
+                    }
+                }
             }
 
             Some(PathMatch { path, params })
@@ -1,10 +1,24 @@
-use crate::{Method, SsrMode};
+use crate::{BreadcrumbLabel, Method, SsrMode};
 use leptos::{leptos_dom::View, *};
 use std::rc::Rc;
 
 /// Defines a single route in a nested route tree. This is the return
 /// type of the [`<Route/>`](crate::Route) component, but can also be
 /// used to build your own configuration-based or filesystem-based routing.
+///
+/// New fields can be added in later versions, so build it with
+/// [`RouteDefinition::new`] and the builder methods, rather than a struct
+/// literal:
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// let users = RouteDefinition::new("/users", |cx| view! { cx, <Outlet/> })
+///     .breadcrumb("Users")
+///     .children(vec![RouteDefinition::new(":id", |cx| {
+///         view! { cx, <p>"A user"</p> }
+///     })]);
+/// ```
 #[derive(Clone)]
 pub struct RouteDefinition {
     /// A unique ID for each route.
@@ -19,6 +33,57 @@ pub struct RouteDefinition {
     pub ssr_mode: SsrMode,
     /// The HTTP request methods this route is able to handle.
     pub methods: &'static [Method],
+    /// The label of this route in [use_breadcrumbs](crate::use_breadcrumbs).
+    pub breadcrumb: Option<BreadcrumbLabel>,
+}
+
+impl RouteDefinition {
+    /// Creates a route that renders `view` at `path`, with a new unique
+    /// [`id`](RouteDefinition::id), no children, the default [SsrMode], and
+    /// only handling `GET` requests.
+    pub fn new<F, IV>(path: impl Into<String>, view: F) -> Self
+    where
+        F: Fn(Scope) -> IV + 'static,
+        IV: IntoView,
+    {
+        Self {
+            id: crate::components::next_route_id(),
+            path: path.into(),
+            children: Vec::new(),
+            view: Rc::new(move |cx| view(cx).into_view(cx)),
+            ssr_mode: SsrMode::default(),
+            methods: &[Method::Get],
+            breadcrumb: None,
+        }
+    }
+
+    /// Sets the routes nested within this one.
+    pub fn children(mut self, children: Vec<RouteDefinition>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Sets the mode this route prefers during server-side rendering.
+    pub fn ssr_mode(mut self, ssr_mode: SsrMode) -> Self {
+        self.ssr_mode = ssr_mode;
+        self
+    }
+
+    /// Sets the HTTP request methods this route is able to handle.
+    pub fn methods(mut self, methods: &'static [Method]) -> Self {
+        self.methods = methods;
+        self
+    }
+
+    /// Sets the label of this route in
+    /// [use_breadcrumbs](crate::use_breadcrumbs).
+    pub fn breadcrumb(
+        mut self,
+        breadcrumb: impl Into<BreadcrumbLabel>,
+    ) -> Self {
+        self.breadcrumb = Some(breadcrumb.into());
+        self
+    }
 }
 
 impl std::fmt::Debug for RouteDefinition {
@@ -27,6 +92,7 @@ impl std::fmt::Debug for RouteDefinition {
             .field("path", &self.path)
             .field("children", &self.children)
             .field("ssr_mode", &self.ssr_mode)
+            .field("breadcrumb", &self.breadcrumb)
             .finish()
     }
 }
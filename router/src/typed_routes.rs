@@ -0,0 +1,209 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::fmt;
+
+// characters that can appear in a path segment without being escaped
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Declares typed functions for building links to your routes, so that a
+/// link to a route that doesn't exist, or with the wrong parameters, is a
+/// compile error rather than a 404.
+///
+/// Each route generates a function that returns its href, and a module of
+/// the same name with the route's `PATH`, which you should pass to
+/// [`Route`](crate::Route) so that there is only one copy of each path. The
+/// parameters of each function must match the `:params` and `*wildcards` in
+/// its path, each used once; this is checked at compile time. Constraints on
+/// params, like `:id<int>` or `:id(\d+)`, are part of the path, but not of
+/// the param's name.
+///
+/// All the paths are also collected in a `PATHS` constant, which
+/// [`unrouted_paths`](crate::unrouted_paths) can use to check that every
+/// typed route is served by a `<Route/>`.
+///
+/// ```
+/// mod routes {
+///     # use leptos_router::typed_routes;
+///     typed_routes! {
+///         /// The home page.
+///         pub fn home() => "/";
+///         /// A user's profile.
+///         pub fn user_detail(id) => "/users/:id<uint>";
+///         /// A file in a user's folder.
+///         pub fn user_file(id, path) => "/users/:id/files/*path";
+///     }
+/// }
+///
+/// assert_eq!(routes::home(), "/");
+/// assert_eq!(routes::user_detail(42), "/users/42");
+/// assert_eq!(routes::user_detail::PATH, "/users/:id<uint>");
+/// assert_eq!(
+///     routes::user_file("ada lovelace", "notes/engine.txt"),
+///     "/users/ada%20lovelace/files/notes/engine.txt"
+/// );
+/// ```
+///
+/// The hrefs are plain `String`s, so they can be used anywhere a path is
+/// expected, e.g., `<A href=routes::user_detail(id)>` or
+/// `navigate(&routes::home(), Default::default())`.
+#[macro_export]
+macro_rules! typed_routes {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($param:ident),* $(,)?) => $path:literal;
+    )*) => {
+        /// The paths of all the typed routes declared here.
+        #[allow(dead_code)]
+        pub const PATHS: &[&str] = &[$($path),*];
+
+        $(
+            $(#[$meta])*
+            $vis fn $name($($param: impl ::std::fmt::Display),*) -> String {
+                $crate::build_href(
+                    $name::PATH,
+                    &[$((
+                        stringify!($param),
+                        &$param as &dyn ::std::fmt::Display,
+                    )),*],
+                )
+            }
+
+            #[doc = concat!(
+                "The route pattern for [`", stringify!($name), "`]."
+            )]
+            $vis mod $name {
+                /// The path to pass to `<Route/>`.
+                pub const PATH: &str = $path;
+
+                const _: () = assert!(
+                    $crate::path_params_match(PATH, &[$(stringify!($param)),*]),
+                    concat!(
+                        "the parameters of `", stringify!($name),
+                        "` don't match the route \"", $path, "\""
+                    )
+                );
+            }
+        )*
+    };
+}
+
+/// Builds an href from a route pattern, by filling in each `:param` and
+/// `*wildcard` with the value of the same name.
+///
+/// Param values are percent-encoded. Wildcard values are inserted as-is,
+/// as they may contain several path segments. An optional `:param?` whose
+/// value is empty is left out. Constraints are not checked.
+///
+/// ```
+/// # use leptos_router::build_href;
+/// assert_eq!(build_href("/posts/:id", &[("id", &7)]), "/posts/7");
+/// assert_eq!(build_href("/posts/:id<int>", &[("id", &7)]), "/posts/7");
+/// assert_eq!(build_href("/posts/:id?", &[("id", &"")]), "/posts");
+/// ```
+pub fn build_href(
+    pattern: &str,
+    params: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let value = |name: &str| {
+        params
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_default()
+    };
+
+    let mut segments = Vec::new();
+    for segment in pattern.split('/') {
+        if let Some(param) = segment.strip_prefix(':') {
+            let optional = param.ends_with('?');
+            let value = value(param_name(param));
+            if !(optional && value.is_empty()) {
+                segments.push(
+                    utf8_percent_encode(&value, PATH_SEGMENT).to_string(),
+                );
+            }
+        } else if let Some(name) = segment.strip_prefix('*') {
+            let value = value(name);
+            if !value.is_empty() {
+                segments.push(value);
+            }
+        } else {
+            segments.push(segment.to_string());
+        }
+    }
+
+    match segments.join("/") {
+        href if href.is_empty() => "/".to_string(),
+        href => href,
+    }
+}
+
+// the name of a `:param` segment, without its constraint or `?`
+fn param_name(param: &str) -> &str {
+    param
+        .split(|c| matches!(c, '(' | '<' | '?'))
+        .next()
+        .unwrap_or_default()
+}
+
+/// Whether the names of the params and wildcards in `path` are exactly
+/// `params`, each used once. Used by [`typed_routes!`] to check its routes
+/// at compile time.
+#[doc(hidden)]
+pub const fn path_params_match(path: &str, params: &[&str]) -> bool {
+    let mut p = 0;
+    while p < params.len() {
+        if count_param(path.as_bytes(), params[p].as_bytes()) != 1 {
+            return false;
+        }
+        p += 1;
+    }
+    count_param(path.as_bytes(), &[]) == params.len()
+}
+
+// counts the params and wildcards in `path` named `name`, or all of them if
+// `name` is empty
+const fn count_param(path: &[u8], name: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < path.len() {
+        let starts_segment = i == 0 || path[i - 1] == b'/';
+        if starts_segment && (path[i] == b':' || path[i] == b'*') {
+            let start = i + 1;
+            let mut end = start;
+            while end < path.len() && !ends_name(path[end]) {
+                end += 1;
+            }
+            // a bare `*` matches anything, but has no name
+            if end > start && (name.is_empty() || same(path, start, end, name))
+            {
+                count += 1;
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+const fn ends_name(byte: u8) -> bool {
+    matches!(byte, b'/' | b'?' | b'(' | b'<')
+}
+
+const fn same(path: &[u8], start: usize, end: usize, name: &[u8]) -> bool {
+    if name.len() != end - start {
+        return false;
+    }
+    let mut j = 0;
+    while j < name.len() {
+        if name[j] != path[start + j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
@@ -0,0 +1,533 @@
+//! Client-side form validation, with server-side errors mapped back onto
+//! the same fields.
+//!
+//! A [FormState] is created with [create_form], and each of its fields is
+//! registered with [FormState::field], which parses the field’s text into a
+//! typed value and tracks whether it has been touched or changed. Checks are
+//! added with [Field::validate] and [Field::validate_async].
+//!
+//! Passing the form to an [ActionForm](crate::ActionForm) as `validation`
+//! stops the form from being submitted while any field is invalid. If the
+//! server function rejects the input by returning [FieldErrors], they’re
+//! shown on the matching fields, whether the form was submitted with
+//! JavaScript or, before WASM has loaded, without it.
+//!
+//! ```rust
+//! use leptos::*;
+//! use leptos_router::*;
+//!
+//! #[server(SignUp, "/api")]
+//! pub async fn sign_up(email: String, age: u8) -> Result<(), ServerFnError> {
+//!     if email == "taken@example.com" {
+//!         return Err(FieldErrors::new().add("email", "is already taken").into());
+//!     }
+//!     Ok(())
+//! }
+//!
+//! #[component]
+//! pub fn SignUpForm(cx: Scope) -> impl IntoView {
+//!     let sign_up = create_server_action::<SignUp>(cx);
+//!     let form = create_form(cx);
+//!     let email = form
+//!         .field::<String>(cx, "email", "")
+//!         .validate(|email| {
+//!             email.contains('@').then_some(()).ok_or("must be an email address".into())
+//!         });
+//!     let age = form.field::<u8>(cx, "age", "18");
+//!
+//!     let error = move |field: Signal<Option<String>>, touched: RwSignal<bool>| {
+//!         move || touched.get().then(|| field.get()).flatten()
+//!     };
+//!     view! { cx,
+//!         <ActionForm action=sign_up validation=form>
+//!             <label>"Email" {email.input(cx)}</label>
+//!             <p class="error">{error(email.error(), email.touched())}</p>
+//!             <label>"Age" {age.input(cx).attr("type", "number")}</label>
+//!             <p class="error">{error(age.error(), age.touched())}</p>
+//!             <input type="submit" disabled=move || !form.is_valid()/>
+//!         </ActionForm>
+//!     }
+//! }
+//! ```
+
+use crate::RouterContext;
+use leptos::{server_fn::ERROR_QUERY_PARAM, *};
+use std::{
+    cell::Cell, collections::BTreeMap, fmt::Display, future::Future, rc::Rc,
+    str::FromStr,
+};
+
+/// Error messages for the fields of a form, keyed by each field’s `name`.
+///
+/// A server function can return these as its error, which is a
+/// [ServerFnError::InvalidFields], so that a form with
+/// [validation](FormState) shows each message next to its field.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::FieldErrors;
+/// let errors = FieldErrors::new().add("email", "is already taken");
+/// let error: ServerFnError = errors.clone().into();
+/// assert_eq!(FieldErrors::from_server_fn_error(&error), Some(errors));
+/// assert_eq!(FieldErrors::new().into_result(), Ok(()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldErrors(pub BTreeMap<String, String>);
+
+impl FieldErrors {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error message for the field with the given `name`.
+    pub fn add(
+        mut self,
+        name: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.0.insert(name.into(), message.into());
+        self
+    }
+
+    /// Returns the error message for the field with the given `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Whether there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `Ok(())` if there are no errors, and otherwise the errors as
+    /// a [ServerFnError], so that a server function can check its input with
+    /// `FieldErrors::new().add(...).into_result()?`.
+    pub fn into_result(self) -> Result<(), ServerFnError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.into())
+        }
+    }
+
+    /// Gets the field errors back out of an error returned by a server
+    /// function, if that’s what it carries.
+    pub fn from_server_fn_error(error: &ServerFnError) -> Option<Self> {
+        match error {
+            ServerFnError::InvalidFields(errors) => Some(Self(errors.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl From<FieldErrors> for ServerFnError {
+    fn from(errors: FieldErrors) -> Self {
+        ServerFnError::InvalidFields(errors.0)
+    }
+}
+
+/// The fields of a form, and whether they’re valid. See the
+/// [module docs](self) for an example.
+#[derive(Copy, Clone)]
+pub struct FormState {
+    fields: StoredValue<Vec<FieldEntry>>,
+    /// Errors from the last submission, kept for fields that register later.
+    server_errors: StoredValue<FieldErrors>,
+}
+
+/// What a [FormState] needs to know about a field, whatever its type.
+#[derive(Clone)]
+struct FieldEntry {
+    name: String,
+    error: Signal<Option<String>>,
+    validating: Signal<bool>,
+    dirty: Signal<bool>,
+    touched: RwSignal<bool>,
+    server_error: RwSignal<Option<String>>,
+    reset: Rc<dyn Fn()>,
+}
+
+/// Creates a [FormState], to which fields can be added with
+/// [FormState::field].
+///
+/// If the page was loaded after submitting a form without JavaScript, and
+/// the server function returned [FieldErrors], the fields start with those
+/// errors.
+pub fn create_form(cx: Scope) -> FormState {
+    let server_errors = use_context::<RouterContext>(cx)
+        .and_then(|router| {
+            router
+                .inner
+                .location
+                .query
+                .get_untracked()
+                .get(ERROR_QUERY_PARAM)
+                .cloned()
+        })
+        .and_then(|error| serde_json::from_str::<ServerFnError>(&error).ok())
+        .and_then(|error| FieldErrors::from_server_fn_error(&error))
+        .unwrap_or_default();
+
+    FormState {
+        fields: store_value(cx, Vec::new()),
+        server_errors: store_value(cx, server_errors),
+    }
+}
+
+impl FormState {
+    /// Adds a field named `name`, whose text starts as `initial`, and whose
+    /// value is parsed into a `T`.
+    pub fn field<T>(
+        &self,
+        cx: Scope,
+        name: impl Into<String>,
+        initial: impl Into<String>,
+    ) -> Field<T>
+    where
+        T: FromStr + Clone + PartialEq + 'static,
+        T::Err: Display,
+    {
+        let name = name.into();
+        let initial = initial.into();
+
+        let raw = create_rw_signal(cx, initial.clone());
+        let value = create_memo(cx, move |_| {
+            raw.with(|raw| raw.trim().parse::<T>().map_err(|e| e.to_string()))
+        });
+        let check = create_memo(cx, move |_| {
+            value.with(|value| value.as_ref().err().cloned())
+        });
+        let server_error = create_rw_signal(
+            cx,
+            self.server_errors
+                .with_value(|errors| errors.get(&name).map(str::to_string)),
+        );
+        // an error from the server is shown straight away, and then cleared
+        // once the field is edited
+        let touched =
+            create_rw_signal(cx, server_error.get_untracked().is_some());
+        create_effect(cx, move |prev: Option<()>| {
+            raw.with(|_| ());
+            if prev.is_some() {
+                server_error.set(None);
+            }
+        });
+
+        let index = self.fields.with_value(Vec::len);
+        let field = Field {
+            cx,
+            form: *self,
+            index,
+            name: store_value(cx, name),
+            initial: store_value(cx, initial),
+            raw,
+            value,
+            check,
+            async_error: Signal::derive(cx, || None),
+            validating: Signal::derive(cx, || false),
+            server_error,
+            touched,
+        };
+        self.fields
+            .update_value(|fields| fields.push(field.entry()));
+        field
+    }
+
+    /// Whether every field is valid, and no asynchronous checks are still
+    /// running.
+    pub fn is_valid(&self) -> bool {
+        self.fields.with_value(|fields| {
+            fields.iter().all(|field| {
+                field.error.with(Option::is_none) && !field.validating.get()
+            })
+        })
+    }
+
+    /// Whether any asynchronous checks are running.
+    pub fn is_validating(&self) -> bool {
+        self.fields.with_value(|fields| {
+            fields.iter().any(|field| field.validating.get())
+        })
+    }
+
+    /// Whether any field has been changed from its initial text.
+    pub fn is_dirty(&self) -> bool {
+        self.fields
+            .with_value(|fields| fields.iter().any(|field| field.dirty.get()))
+    }
+
+    /// Marks every field as touched, e.g., so that all of their errors are
+    /// shown.
+    pub fn touch_all(&self) {
+        self.fields.with_value(|fields| {
+            for field in fields {
+                field.touched.set(true);
+            }
+        });
+    }
+
+    /// Marks every field as touched, and returns whether the form can be
+    /// submitted. This is called by a [Form](crate::Form) or
+    /// [ActionForm](crate::ActionForm) with `validation` before submitting.
+    pub fn validate_for_submit(&self) -> bool {
+        self.touch_all();
+        self.fields.with_value(|fields| {
+            fields.iter().all(|field| {
+                field.error.with_untracked(Option::is_none)
+                    && !field.validating.get_untracked()
+            })
+        })
+    }
+
+    /// Shows errors from the server on the matching fields, replacing any
+    /// that were shown before.
+    pub fn set_server_errors(&self, errors: FieldErrors) {
+        self.fields.with_value(|fields| {
+            for field in fields {
+                let error = errors.get(&field.name).map(str::to_string);
+                if error.is_some() {
+                    field.touched.set(true);
+                }
+                field.server_error.set(error);
+            }
+        });
+        self.server_errors.set_value(errors);
+    }
+
+    /// Puts every field back to its initial text, untouched and without
+    /// errors from the server.
+    pub fn reset(&self) {
+        self.server_errors.set_value(FieldErrors::new());
+        self.fields.with_value(|fields| {
+            for field in fields {
+                (field.reset)();
+            }
+        });
+    }
+}
+
+impl std::fmt::Debug for FormState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormState").finish_non_exhaustive()
+    }
+}
+
+/// A field of a [FormState], whose text is parsed into a `T`.
+pub struct Field<T: 'static> {
+    cx: Scope,
+    form: FormState,
+    index: usize,
+    name: StoredValue<String>,
+    initial: StoredValue<String>,
+    raw: RwSignal<String>,
+    value: Memo<Result<T, String>>,
+    /// The parse error, or the first error from a synchronous check.
+    check: Memo<Option<String>>,
+    async_error: Signal<Option<String>>,
+    validating: Signal<bool>,
+    server_error: RwSignal<Option<String>>,
+    touched: RwSignal<bool>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Field<T> {}
+
+impl<T> std::fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name.get_value())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Field<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// The field’s `name`.
+    pub fn name(&self) -> String {
+        self.name.get_value()
+    }
+
+    /// The field’s text.
+    pub fn raw(&self) -> RwSignal<String> {
+        self.raw
+    }
+
+    /// The field’s text, without surrounding whitespace, parsed into a `T`,
+    /// or the parse error.
+    pub fn value(&self) -> Memo<Result<T, String>> {
+        self.value
+    }
+
+    /// The field’s error, if any: an error from the server, if it hasn’t
+    /// been edited since, or the parse error, or the first failed check.
+    pub fn error(&self) -> Signal<Option<String>> {
+        let Self {
+            check,
+            async_error,
+            server_error,
+            ..
+        } = *self;
+        Signal::derive(self.cx, move || {
+            server_error
+                .get()
+                .or_else(|| check.get())
+                .or_else(|| async_error.get())
+        })
+    }
+
+    /// Whether the field has lost focus since it was created or reset. Errors
+    /// are usually only shown once a field has been touched.
+    pub fn touched(&self) -> RwSignal<bool> {
+        self.touched
+    }
+
+    /// Whether the field’s text has been changed from its initial text.
+    pub fn dirty(&self) -> Signal<bool> {
+        let Self { raw, initial, .. } = *self;
+        Signal::derive(self.cx, move || {
+            raw.with(|raw| initial.with_value(|initial| raw != initial))
+        })
+    }
+
+    /// Whether an asynchronous check is running.
+    pub fn validating(&self) -> Signal<bool> {
+        self.validating
+    }
+
+    /// Adds a check that the parsed value is valid, returning an error
+    /// message if it isn’t. Checks run in the order they’re added, and only
+    /// the first error is shown.
+    pub fn validate(
+        mut self,
+        check: impl Fn(&T) -> Result<(), String> + 'static,
+    ) -> Self {
+        let Self {
+            value, check: prev, ..
+        } = self;
+        self.check = create_memo(self.cx, move |_| {
+            prev.get().or_else(|| {
+                value.with(|value| {
+                    value.as_ref().ok().and_then(|value| check(value).err())
+                })
+            })
+        });
+        self.register();
+        self
+    }
+
+    /// Adds a check that runs asynchronously, e.g., by calling a server
+    /// function, whenever the value changes and passes the synchronous
+    /// checks. The form isn’t valid until it has finished. Only the result
+    /// for the latest value is used.
+    pub fn validate_async<Fu>(
+        mut self,
+        check: impl Fn(T) -> Fu + 'static,
+    ) -> Self
+    where
+        Fu: Future<Output = Result<(), String>> + 'static,
+    {
+        let Self {
+            cx,
+            value,
+            check: sync_check,
+            ..
+        } = self;
+        let error = create_rw_signal(cx, None::<String>);
+        let pending = create_rw_signal(cx, false);
+        let version = Rc::new(Cell::new(0_usize));
+
+        create_effect(cx, move |_| {
+            let run = version.get().wrapping_add(1);
+            version.set(run);
+            error.set(None);
+
+            let value = match (sync_check.get(), value.get()) {
+                (None, Ok(value)) => value,
+                _ => {
+                    pending.set(false);
+                    return;
+                }
+            };
+            pending.set(true);
+            let result = check(value);
+            let version = Rc::clone(&version);
+            spawn_local(async move {
+                let result = result.await;
+                // a newer value has been sent off since
+                if version.get() == run {
+                    error.try_set(result.err());
+                    pending.try_set(false);
+                }
+            });
+        });
+
+        let (prev_error, prev_validating) = (self.async_error, self.validating);
+        self.async_error = Signal::derive(cx, move || {
+            prev_error.get().or_else(|| error.get())
+        });
+        self.validating =
+            Signal::derive(cx, move || prev_validating.get() || pending.get());
+        self.register();
+        self
+    }
+
+    /// Creates an `<input>` bound to the field, which sets its text on
+    /// every `input` event and marks it as touched when it loses focus.
+    ///
+    /// Its `aria-invalid` attribute is set while the field is touched and
+    /// has an error. More attributes, like `type`, can be added to it.
+    pub fn input(&self, cx: Scope) -> HtmlElement<html::Input> {
+        let Self { raw, touched, .. } = *self;
+        let error = self.error();
+        html::input(cx)
+            .attr("name", self.name())
+            // the attribute is rendered on the server, and the property
+            // keeps the input in sync
+            .attr("value", raw.get_untracked())
+            .prop("value", move || raw.get())
+            .attr("aria-invalid", move || {
+                (touched.get() && error.with(Option::is_some)).then_some("true")
+            })
+            .on(ev::input, move |ev| raw.set(event_target_value(&ev)))
+            .on(ev::blur, move |_| touched.set(true))
+    }
+
+    fn entry(&self) -> FieldEntry {
+        let Self {
+            raw,
+            initial,
+            touched,
+            server_error,
+            ..
+        } = *self;
+        FieldEntry {
+            name: self.name(),
+            error: self.error(),
+            validating: self.validating,
+            dirty: self.dirty(),
+            touched,
+            server_error,
+            reset: Rc::new(move || {
+                raw.set(initial.get_value());
+                touched.set(false);
+                server_error.set(None);
+            }),
+        }
+    }
+
+    /// Updates the form’s copy of the field after a check is added.
+    fn register(&self) {
+        let entry = self.entry();
+        self.form
+            .fields
+            .update_value(|fields| fields[self.index] = entry);
+    }
+}
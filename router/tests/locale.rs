@@ -0,0 +1,17 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::use_locale;
+
+        #[test]
+        fn use_locale_is_none_without_locales() {
+            let runtime = create_runtime();
+            run_scope(runtime, |cx| {
+                assert!(use_locale(cx).is_none());
+            });
+            runtime.dispose();
+        }
+    }
+}
@@ -5,7 +5,10 @@ use cfg_if::cfg_if;
 
 cfg_if! {
     if #[cfg(feature = "ssr")] {
-        use leptos_router::{params_map, Matcher, PathMatch};
+        use leptos_router::{
+            params_map, register_param_matcher, MatchOptions, Matcher,
+            PathMatch,
+        };
 
         #[test]
         fn create_matcher_should_return_no_params_when_location_matches_exactly() {
@@ -92,5 +95,90 @@ cfg_if! {
                 })
             );
         }
+
+        #[test]
+        fn create_matcher_should_apply_regex_constraints() {
+            let matcher = Matcher::new(r"/users/:id(\d+)");
+            assert_eq!(
+                matcher.test("/users/123"),
+                Some(PathMatch {
+                    path: "/users/123".into(),
+                    params: params_map!(
+                        "id" => "123"
+                    )
+                })
+            );
+            assert_eq!(matcher.test("/users/abc"), None);
+            assert_eq!(matcher.test("/users/123abc"), None);
+        }
+
+        #[test]
+        fn create_matcher_should_apply_named_constraints() {
+            register_param_matcher("even", |value| {
+                value.parse::<u32>().map(|n| n % 2 == 0).unwrap_or(false)
+            });
+            let matcher = Matcher::new("/page/:n<even>");
+            assert!(matcher.test("/page/4").is_some());
+            assert_eq!(matcher.test("/page/5"), None);
+
+            let matcher = Matcher::new("/page/:n<int>");
+            assert!(matcher.test("/page/-5").is_some());
+            assert_eq!(matcher.test("/page/five"), None);
+        }
+
+        #[test]
+        fn create_matcher_should_match_wildcard_followed_by_segments() {
+            let matcher = Matcher::new("/files/*path/edit");
+            assert_eq!(
+                matcher.test("/files/a/b/c/edit"),
+                Some(PathMatch {
+                    path: "/files/a/b/c/edit".into(),
+                    params: params_map!(
+                        "path" => "a/b/c"
+                    )
+                })
+            );
+            assert_eq!(
+                matcher.test("/files/edit"),
+                Some(PathMatch {
+                    path: "/files/edit".into(),
+                    params: params_map!(
+                        "path" => ""
+                    )
+                })
+            );
+            assert_eq!(matcher.test("/files/a/b/c"), None);
+        }
+
+        #[test]
+        fn create_matcher_should_score_by_specificity() {
+            let static_segment = Matcher::new("/users/me").score();
+            let constrained = Matcher::new(r"/users/:id(\d+)").score();
+            let param = Matcher::new("/users/:id").score();
+            let wildcard = Matcher::new("/users/*any").score();
+            assert!(static_segment > constrained);
+            assert!(constrained > param);
+            assert!(param > wildcard);
+        }
+
+        #[test]
+        fn create_matcher_should_apply_match_options() {
+            let matcher = Matcher::new("/about");
+            let strict = MatchOptions {
+                strict_trailing_slash: true,
+                ..Default::default()
+            };
+            assert!(matcher.test("/about/").is_some());
+            assert!(matcher.test_with("/about", strict).is_some());
+            assert_eq!(matcher.test_with("/about/", strict), None);
+            assert!(Matcher::new("/*any").test_with("/about/", strict).is_some());
+
+            let insensitive = MatchOptions {
+                case_sensitive: false,
+                ..Default::default()
+            };
+            assert_eq!(matcher.test("/About"), None);
+            assert!(matcher.test_with("/About", insensitive).is_some());
+        }
     }
 }
@@ -0,0 +1,67 @@
+use leptos::*;
+use leptos_router::{History, MemoryIntegration};
+
+#[test]
+fn memory_history_pushes_and_moves_through_entries() {
+    run_scope(create_runtime(), |cx| {
+        let history = MemoryIntegration::new("/");
+        let location = history.location(cx);
+
+        history.navigate_to("/a");
+        history.navigate_to("/b");
+        assert_eq!(history.entries(), vec!["/", "/a", "/b"]);
+        assert_eq!(location.get_untracked().value, "/b");
+
+        history.back();
+        assert_eq!(history.current_path(), "/a");
+        assert_eq!(location.get_untracked().value, "/a");
+
+        history.forward();
+        assert_eq!(history.current_path(), "/b");
+
+        // can't move past the end of the stack
+        history.go(5);
+        assert_eq!(history.current_path(), "/b");
+    });
+}
+
+#[test]
+fn memory_history_discards_forward_entries_on_navigation() {
+    run_scope(create_runtime(), |cx| {
+        let history = MemoryIntegration::new("/");
+        _ = history.location(cx);
+
+        history.navigate_to("/a");
+        history.navigate_to("/b");
+        history.go(-2);
+        history.navigate_to("/c");
+        assert_eq!(history.entries(), vec!["/", "/c"]);
+    });
+}
+
+#[test]
+fn memory_history_keeps_the_background_of_modal_routes() {
+    use leptos_router::LocationChange;
+
+    run_scope(create_runtime(), |cx| {
+        let history = MemoryIntegration::new("/feed");
+        let location = history.location(cx);
+
+        history.navigate(&LocationChange {
+            value: "/photos/1".to_string(),
+            replace: false,
+            background: Some("/feed".to_string()),
+            ..Default::default()
+        });
+        history.navigate_to("/about");
+        assert_eq!(location.get_untracked().background, None);
+
+        history.back();
+        let current = location.get_untracked();
+        assert_eq!(current.value, "/photos/1");
+        assert_eq!(current.background.as_deref(), Some("/feed"));
+
+        history.back();
+        assert_eq!(location.get_untracked().background, None);
+    });
+}
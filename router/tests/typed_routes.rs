@@ -0,0 +1,102 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::{
+            build_href, path_params_match, unrouted_paths, Route, Router,
+            Routes,
+        };
+
+        mod routes {
+            leptos_router::typed_routes! {
+                pub fn home() => "/";
+                pub fn post(id) => "/posts/:id";
+                pub fn comment(post, comment) => "/posts/:post/comments/:comment";
+                pub fn archive(year) => "/archive/:year?";
+                pub fn docs(path) => "/docs/*path";
+                pub fn user(id) => "/users/:id<int>";
+                pub fn color(hex) => "/colors/:hex([0-9a-f]+)";
+                pub fn page(n) => "/pages/:n<uint>?";
+            }
+        }
+
+        #[test]
+        fn typed_routes_should_fill_in_params() {
+            assert_eq!(routes::home(), "/");
+            assert_eq!(routes::post(1), "/posts/1");
+            assert_eq!(routes::comment("first", 2), "/posts/first/comments/2");
+            assert_eq!(routes::docs("guide/routing"), "/docs/guide/routing");
+            assert_eq!(routes::comment::PATH, "/posts/:post/comments/:comment");
+        }
+
+        #[test]
+        fn build_href_should_escape_params() {
+            assert_eq!(
+                build_href("/posts/:id", &[("id", &"a/b c")]),
+                "/posts/a%2Fb%20c"
+            );
+        }
+
+        #[test]
+        fn build_href_should_skip_empty_optional_params() {
+            assert_eq!(routes::archive(""), "/archive");
+            assert_eq!(routes::archive(2023), "/archive/2023");
+        }
+
+        #[test]
+        fn path_params_match_should_compare_names() {
+            assert!(path_params_match("/posts/:id", &["id"]));
+            assert!(path_params_match("/posts/*", &[]));
+            assert!(!path_params_match("/posts/:id", &[]));
+            assert!(!path_params_match("/posts/:id", &["slug"]));
+            assert!(!path_params_match("/posts", &["id"]));
+        }
+
+        #[test]
+        fn typed_routes_should_ignore_constraints() {
+            assert_eq!(routes::user(3), "/users/3");
+            assert_eq!(routes::color("ff0000"), "/colors/ff0000");
+            assert_eq!(routes::page(""), "/pages");
+            assert_eq!(routes::page(2), "/pages/2");
+            assert_eq!(routes::user::PATH, "/users/:id<int>");
+            assert!(path_params_match("/users/:id<int>", &["id"]));
+            assert!(path_params_match("/users/:id(\\d+)/edit", &["id"]));
+            assert!(path_params_match("/pages/:n<uint>?", &["n"]));
+            assert!(!path_params_match("/users/:id<int>", &["id<int>"]));
+        }
+
+        #[test]
+        fn path_params_match_should_reject_duplicates() {
+            assert!(!path_params_match("/a/:id/b/:id", &["id", "other"]));
+            assert!(!path_params_match("/a/:id/b/:id", &["id"]));
+            assert!(!path_params_match("/a/:id", &["id", "id"]));
+        }
+
+        #[test]
+        fn unrouted_paths_should_list_paths_without_a_route() {
+            let app = |cx| {
+                view! { cx,
+                    <Router>
+                        <Routes>
+                            <Route path=routes::home::PATH view=|_| ()/>
+                            <Route path=routes::post::PATH view=|_| ()/>
+                            <Route path=routes::archive::PATH view=|_| ()/>
+                            <Route path="/users/:id" view=|_| ()/>
+                        </Routes>
+                    </Router>
+                }
+            };
+            assert_eq!(
+                unrouted_paths(app, routes::PATHS),
+                [
+                    routes::comment::PATH,
+                    routes::docs::PATH,
+                    routes::user::PATH,
+                    routes::color::PATH,
+                    routes::page::PATH,
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::{create_form, FieldErrors};
+
+        #[test]
+        fn field_errors_round_trip_through_server_fn_error() {
+            let errors = FieldErrors::new()
+                .add("email", "is already taken")
+                .add("age", "must be at least 18");
+            let error: ServerFnError = errors.clone().into();
+            assert!(matches!(error, ServerFnError::InvalidFields(_)));
+
+            // as sent to the client, or in the query after a no-JS submit
+            let json = serde_json::to_string(&error).unwrap();
+            let error: ServerFnError = serde_json::from_str(&json).unwrap();
+            assert_eq!(FieldErrors::from_server_fn_error(&error), Some(errors));
+        }
+
+        #[test]
+        fn other_errors_carry_no_field_errors() {
+            let error = ServerFnError::ServerError(
+                "invalid fields: {\"email\":\"taken\"}".into(),
+            );
+            assert_eq!(FieldErrors::from_server_fn_error(&error), None);
+            assert!(FieldErrors::new().into_result().is_ok());
+        }
+
+        #[test]
+        fn fields_are_parsed_and_checked() {
+            let runtime = create_runtime();
+            run_scope(runtime, |cx| {
+                let form = create_form(cx);
+                let age = form.field::<u8>(cx, "age", "17").validate(|age| {
+                    (*age >= 18)
+                        .then_some(())
+                        .ok_or_else(|| "must be at least 18".to_string())
+                });
+                assert_eq!(age.value().get(), Ok(17));
+                assert_eq!(
+                    age.error().get().as_deref(),
+                    Some("must be at least 18")
+                );
+                assert!(!form.is_valid());
+
+                age.raw().set(" 20 ".to_string());
+                assert_eq!(age.error().get(), None);
+                assert!(form.is_valid());
+                assert!(form.is_dirty());
+
+                age.raw().set("old".to_string());
+                assert!(age.error().get().is_some());
+                assert!(!form.validate_for_submit());
+                assert!(age.touched().get());
+            });
+            runtime.dispose();
+        }
+
+        #[test]
+        fn server_errors_are_shown_until_reset() {
+            let runtime = create_runtime();
+            run_scope(runtime, |cx| {
+                let form = create_form(cx);
+                let email = form.field::<String>(cx, "email", "a@b.c");
+                assert!(form.is_valid());
+
+                form.set_server_errors(
+                    FieldErrors::new().add("email", "is already taken"),
+                );
+                assert_eq!(
+                    email.error().get().as_deref(),
+                    Some("is already taken")
+                );
+                assert!(email.touched().get());
+                assert!(!form.is_valid());
+
+                form.reset();
+                assert_eq!(email.error().get(), None);
+                assert!(!email.touched().get());
+                assert!(form.is_valid());
+            });
+            runtime.dispose();
+        }
+    }
+}
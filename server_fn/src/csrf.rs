@@ -0,0 +1,193 @@
+//! Protection against cross-site request forgery (CSRF).
+//!
+//! Server functions use the “double-submit cookie” pattern. When the server
+//! renders a page, it issues a random token in the [`CSRF_COOKIE`] cookie.
+//! Every mutating (`POST`) call to a server function must send the same token
+//! back, either in the [`CSRF_HEADER`] header, which the client adds
+//! automatically, or in a [`CSRF_FIELD`] form field, which `<ActionForm/>`
+//! adds. Another site can make a user’s browser send the cookie, but it can’t
+//! read it, so it can’t send the matching token.
+//!
+//! The check is off by default, as clients other than the browser, like
+//! other servers or scripts, don’t know about the token. Turn it on with
+//! [`enable_csrf_protection`] when the server starts.
+
+#[cfg(any(feature = "ssr", doc))]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The name of the cookie that holds the CSRF token.
+pub const CSRF_COOKIE: &str = "leptos_csrf";
+
+/// The name of the header in which the client sends the CSRF token.
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+/// The name of the form field in which a `<form>` sends the CSRF token.
+pub const CSRF_FIELD: &str = "_csrf";
+
+/// The CSRF token for the current request. The server integrations provide
+/// this as context while rendering a page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrfToken(pub String);
+
+/// Generates a new random CSRF token.
+pub fn generate_csrf_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+/// Finds the CSRF token in the value of a `Cookie` header.
+///
+/// ```
+/// # use server_fn::csrf::*;
+/// let cookies = "theme=dark; leptos_csrf=abc123";
+/// assert_eq!(csrf_token_from_cookies(cookies), Some("abc123"));
+/// assert!(verify_csrf_token(csrf_token_from_cookies(cookies), Some("abc123")));
+/// assert!(!verify_csrf_token(csrf_token_from_cookies(cookies), Some("abc124")));
+/// ```
+pub fn csrf_token_from_cookies(cookies: &str) -> Option<&str> {
+    cookies.split(';').find_map(|cookie| {
+        let (name, value) = cookie.trim().split_once('=')?;
+        (name == CSRF_COOKIE && !value.is_empty()).then_some(value)
+    })
+}
+
+/// Finds the CSRF token in a URL-encoded form body.
+pub fn csrf_token_from_form(body: &[u8]) -> Option<&str> {
+    body.split(|byte| *byte == b'&').find_map(|pair| {
+        let value = pair
+            .strip_prefix(CSRF_FIELD.as_bytes())?
+            .strip_prefix(b"=")?;
+        std::str::from_utf8(value).ok()
+    })
+}
+
+/// Whether the token submitted with a request matches the one in its cookie.
+pub fn verify_csrf_token(
+    cookie: Option<&str>,
+    submitted: Option<&str>,
+) -> bool {
+    match (cookie, submitted) {
+        (Some(cookie), Some(submitted)) if !cookie.is_empty() => {
+            // compare in constant time, so the token can't be guessed
+            // byte by byte
+            cookie.len() == submitted.len()
+                && cookie
+                    .bytes()
+                    .zip(submitted.bytes())
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+                    == 0
+        }
+        _ => false,
+    }
+}
+
+#[cfg(any(feature = "ssr", doc))]
+static CSRF_PROTECTION: AtomicBool = AtomicBool::new(false);
+
+/// Turns on the CSRF check for all server functions, and the issuing of
+/// tokens to the pages that call them.
+///
+/// Every client that calls a server function with `POST` must then send the
+/// token, so only call this if they are all browsers running this app, or
+/// know to send it.
+#[cfg(any(feature = "ssr", doc))]
+pub fn enable_csrf_protection() {
+    CSRF_PROTECTION.store(true, Ordering::Relaxed);
+}
+
+/// Whether [`enable_csrf_protection`] has been called.
+#[cfg(any(feature = "ssr", doc))]
+pub fn csrf_protection_enabled() -> bool {
+    CSRF_PROTECTION.load(Ordering::Relaxed)
+}
+
+/// Checks the CSRF token of a mutating call to a server function, given the
+/// values of its `Cookie` and [`CSRF_HEADER`] headers and its body.
+///
+/// Always passes unless [`enable_csrf_protection`] has been called.
+#[cfg(any(feature = "ssr", doc))]
+pub fn check_csrf(
+    cookies: Option<&str>,
+    header: Option<&str>,
+    body: &[u8],
+) -> bool {
+    if !csrf_protection_enabled() {
+        return true;
+    }
+    let cookie = cookies.and_then(csrf_token_from_cookies);
+    let submitted = header.or_else(|| csrf_token_from_form(body));
+    verify_csrf_token(cookie, submitted)
+}
+
+/// Gets the CSRF token for a page request from the value of its `Cookie`
+/// header, or issues a new one.
+///
+/// Returns the token, and the value of a `Set-Cookie` header to send if a new
+/// one was issued, or `None` unless [`enable_csrf_protection`] has been
+/// called.
+#[cfg(any(feature = "ssr", doc))]
+pub fn issue_csrf_token(
+    cookies: Option<&str>,
+) -> Option<(CsrfToken, Option<String>)> {
+    if !csrf_protection_enabled() {
+        return None;
+    }
+    Some(match cookies.and_then(csrf_token_from_cookies) {
+        Some(token) => (CsrfToken(token.to_string()), None),
+        None => {
+            let token = generate_csrf_token();
+            (CsrfToken(token.clone()), Some(csrf_cookie(&token)))
+        }
+    })
+}
+
+/// The value of a `Set-Cookie` header, or of `document.cookie`, that sets
+/// the CSRF cookie to `token`.
+///
+/// The cookie is `Secure`, so it is never sent over plain HTTP, except to
+/// `localhost` during development.
+pub fn csrf_cookie(token: &str) -> String {
+    format!("{CSRF_COOKIE}={token}; Path=/; SameSite=Lax; Secure")
+}
+
+/// The CSRF token to send with a call to a server function, read from the
+/// [`CSRF_COOKIE`] cookie.
+///
+/// If there is no cookie yet, e.g., in an app that is only rendered on the
+/// client, this sets one.
+#[cfg(target_arch = "wasm32")]
+pub fn client_csrf_token() -> String {
+    use wasm_bindgen::JsValue;
+
+    let document =
+        js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("document"))
+            .ok();
+    let cookies = document
+        .as_ref()
+        .and_then(|document| {
+            js_sys::Reflect::get(document, &JsValue::from_str("cookie")).ok()
+        })
+        .and_then(|cookies| cookies.as_string())
+        .unwrap_or_default();
+    if let Some(token) = csrf_token_from_cookies(&cookies) {
+        return token.to_string();
+    }
+
+    let token = generate_csrf_token();
+    if let Some(document) = document {
+        _ = js_sys::Reflect::set(
+            &document,
+            &JsValue::from_str("cookie"),
+            &JsValue::from_str(&csrf_cookie(&token)),
+        );
+    }
+    token
+}
+
+/// The CSRF token to send with a call to a server function. Outside the
+/// browser, the client sends this token as both the cookie and the header.
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "ssr")))]
+pub fn client_csrf_token() -> String {
+    static TOKEN: once_cell::sync::Lazy<String> =
+        once_cell::sync::Lazy::new(generate_csrf_token);
+    TOKEN.clone()
+}
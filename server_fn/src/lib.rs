@@ -81,11 +81,14 @@
 // used by the macro
 #[doc(hidden)]
 pub use const_format;
+pub mod csrf;
 use proc_macro2::TokenStream;
 use quote::TokenStreamExt;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use server_fn_macro_default::server;
-use std::{future::Future, pin::Pin, str::FromStr, sync::Arc};
+use std::{
+    collections::BTreeMap, future::Future, pin::Pin, str::FromStr, sync::Arc,
+};
 use syn::parse_quote;
 use thiserror::Error;
 // used by the macro
@@ -297,6 +300,29 @@ where
         cx: T,
     ) -> Pin<Box<dyn Future<Output = Result<Self::Output, ServerFnError>>>>;
 
+    /// Runs the function on the client by sending an HTTP request to the
+    /// server, reporting the [`Progress`] of the request and response bodies
+    /// to `on_progress` as it goes.
+    #[cfg(not(feature = "ssr"))]
+    fn call_fn_client_with_progress(
+        self,
+        on_progress: std::rc::Rc<dyn Fn(Progress)>,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Output, ServerFnError>>>>
+    where
+        Self::Output: DeserializeOwned,
+    {
+        Box::pin(async move {
+            let url = Self::prefix().to_string() + "/" + Self::url();
+            call_server_fn_with_progress(
+                &url,
+                self,
+                Self::encoding(),
+                on_progress,
+            )
+            .await
+        })
+    }
+
     /// Registers the server function, allowing the server to query it by URL.
     #[cfg(any(feature = "ssr", doc,))]
     fn register_in<R: ServerFunctionRegistry<T>>() -> Result<(), ServerFnError>
@@ -361,7 +387,10 @@ where
 }
 
 /// Type for errors that can occur when using server functions.
+///
+/// More kinds of error may be added, so a `match` on this needs a `_` arm.
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ServerFnError {
     /// Error while trying to register the server function (only occurs in case of poisoned RwLock).
     #[error("error while trying to register the server function: {0}")]
@@ -384,6 +413,204 @@ pub enum ServerFnError {
     /// Occurs on the server if there's a missing argument.
     #[error("missing argument {0}")]
     MissingArg(String),
+    /// Occurs on the server if the caller is not signed in, e.g., when a
+    /// [guard](ServerFnGuard) rejects the call. Sent with a `401` status.
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+    /// Occurs on the server if the caller is not allowed to do this, e.g.,
+    /// when a [guard](ServerFnGuard) rejects the call. Sent with a `403`
+    /// status.
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    /// Occurs on the server if some of the arguments are invalid, with an
+    /// error message for each of them, keyed by the name of its form field.
+    #[error("invalid fields: {0:?}")]
+    InvalidFields(BTreeMap<String, String>),
+}
+
+impl ServerFnError {
+    /// The HTTP status code that the server should respond with when a
+    /// server function returns this error.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::Unauthorized(_) => 401,
+            Self::Forbidden(_) => 403,
+            _ => 500,
+        }
+    }
+
+    /// Where to send a `<form>` that was submitted without JavaScript when
+    /// its server function returns this error: back to the `referer`, with
+    /// the error serialized into the [`ERROR_QUERY_PARAM`] query parameter,
+    /// so that the page can show it. An error left in the query by an
+    /// earlier submission is replaced.
+    ///
+    /// ```
+    /// # use server_fn::ServerFnError;
+    /// let error = ServerFnError::ServerError("try again".into());
+    /// let location = error.redirect_location("/signup?plan=pro#form");
+    /// assert!(location.starts_with("/signup?plan=pro&server_fn_error="));
+    /// assert!(location.ends_with("#form"));
+    /// ```
+    pub fn redirect_location(&self, referer: &str) -> String {
+        #[derive(Serialize)]
+        struct ErrorParam {
+            server_fn_error: String,
+        }
+
+        let (url, hash) = match referer.find('#') {
+            Some(index) => referer.split_at(index),
+            None => (referer, ""),
+        };
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let param = serde_qs::to_string(&ErrorParam {
+            server_fn_error: serde_json::to_string(self)
+                .unwrap_or_else(|_| self.to_string()),
+        })
+        .unwrap_or_default();
+        let query = query
+            .split('&')
+            .filter(|pair| {
+                !pair.is_empty()
+                    && pair.split('=').next() != Some(ERROR_QUERY_PARAM)
+            })
+            .chain(std::iter::once(param.as_str()))
+            .collect::<Vec<_>>()
+            .join("&");
+        format!("{path}?{query}{hash}")
+    }
+}
+
+/// The query parameter in which [`ServerFnError::redirect_location`] sends a
+/// server function’s error back to a page that submitted a `<form>` to it
+/// without JavaScript.
+pub const ERROR_QUERY_PARAM: &str = "server_fn_error";
+
+/// Whether a call to a server function is a `<form>` that the browser
+/// submitted without JavaScript, given the call’s `Content-Type` and
+/// `Accept` headers: its body is a URL-encoded or multipart form, and the
+/// browser will show the response as a page.
+///
+/// The server integrations only redirect these calls back to the page when
+/// the server function fails, using
+/// [`redirect_location`](ServerFnError::redirect_location). Every other
+/// client gets the error itself.
+///
+/// ```
+/// # use server_fn::is_form_submission;
+/// let form = Some("application/x-www-form-urlencoded");
+/// assert!(is_form_submission(form, Some("text/html,*/*;q=0.8")));
+/// assert!(!is_form_submission(form, Some("*/*")));
+/// assert!(!is_form_submission(Some("application/json"), Some("text/html")));
+/// ```
+pub fn is_form_submission(
+    content_type: Option<&str>,
+    accept: Option<&str>,
+) -> bool {
+    // the media type of a header value, without its parameters
+    fn media_type(value: &str) -> &str {
+        value.split(';').next().unwrap_or_default().trim()
+    }
+
+    let is_form = content_type.map(media_type).map_or(false, |ty| {
+        ty.eq_ignore_ascii_case("application/x-www-form-urlencoded")
+            || ty.eq_ignore_ascii_case("multipart/form-data")
+    });
+    let wants_page = accept.map_or(false, |accept| {
+        accept
+            .split(',')
+            .any(|ty| media_type(ty).eq_ignore_ascii_case("text/html"))
+    });
+    is_form && wants_page
+}
+
+/// A check that runs on the server before the body of a server function,
+/// declared with `#[server(MyFn, "/api", guard = my_guard)]`.
+///
+/// Any `async` function or closure that takes the server function’s context
+/// and returns `Result<(), ServerFnError>` is a guard. If it returns an error,
+/// the body is not run and the error is returned instead. Return
+/// [`ServerFnError::Unauthorized`] or [`ServerFnError::Forbidden`] to send a
+/// `401` or `403` status.
+///
+/// ```rust,ignore
+/// fn require_role(role: &'static str) -> impl ServerFnGuard<Scope> {
+///     move |cx: Scope| async move {
+///         match current_user(cx).await {
+///             None => Err(ServerFnError::Unauthorized("sign in".into())),
+///             Some(user) if !user.has_role(role) => {
+///                 Err(ServerFnError::Forbidden(format!("must be {role}")))
+///             }
+///             Some(_) => Ok(()),
+///         }
+///     }
+/// }
+///
+/// #[server(DeletePost, "/api", guard = require_role("admin"))]
+/// pub async fn delete_post(cx: Scope, id: u32) -> Result<(), ServerFnError> {
+///     // only admins get here
+///     todo!()
+/// }
+/// ```
+pub trait ServerFnGuard<T> {
+    /// The future returned by [`check`](ServerFnGuard::check).
+    type Future: Future<Output = Result<(), ServerFnError>>;
+
+    /// Checks whether the server function may run.
+    fn check(self, cx: T) -> Self::Future;
+}
+
+impl<T, F, Fut> ServerFnGuard<T> for F
+where
+    F: FnOnce(T) -> Fut,
+    Fut: Future<Output = Result<(), ServerFnError>>,
+{
+    type Future = Fut;
+
+    fn check(self, cx: T) -> Self::Future {
+        self(cx)
+    }
+}
+
+/// Which body of a server function call a [`Progress`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgressDirection {
+    /// The request body, containing the arguments sent to the server.
+    Upload,
+    /// The response body, containing the result sent back by the server.
+    Download,
+}
+
+/// The progress of sending or receiving the body of a server function call,
+/// as reported by [`call_server_fn_with_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Whether this is the progress of the request or the response.
+    pub direction: ProgressDirection,
+    /// The number of bytes transferred so far.
+    pub loaded: u64,
+    /// The total size of the body, if known. It is `None` when the server
+    /// sends no `Content-Length`, in which case progress should be shown as
+    /// indeterminate.
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    /// The percentage of the body transferred so far, from `0.0` to `100.0`,
+    /// or `None` if the total size is not known.
+    ///
+    /// The total comes from the `Content-Length` header, which can be
+    /// smaller than the number of bytes received, so this never goes above
+    /// `100.0`.
+    pub fn percent(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(100.0),
+            Some(total) => {
+                Some((self.loaded as f64 / total as f64 * 100.0).min(100.0))
+            }
+            None => None,
+        }
+    }
 }
 
 /// Executes the HTTP call to call a server function from the client, given its URL and argument type.
@@ -393,6 +620,42 @@ pub async fn call_server_fn<T, C: 'static>(
     args: impl ServerFn<C>,
     enc: Encoding,
 ) -> Result<T, ServerFnError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Sized,
+{
+    call_server_fn_inner(url, args, enc, None).await
+}
+
+/// Executes the HTTP call to call a server function from the client, like
+/// [`call_server_fn`], calling `on_progress` as the request and response
+/// bodies are transferred.
+///
+/// In the browser, the request is sent with `XMLHttpRequest` rather than
+/// `fetch`, because `fetch` can't report the progress of sending a body, so
+/// both directions report real progress. Outside the browser, the response
+/// body is read as a stream, so download progress is reported for every
+/// chunk received, but upload progress is only reported once when the
+/// request starts and once when the server begins to respond.
+#[cfg(not(feature = "ssr"))]
+pub async fn call_server_fn_with_progress<T, C: 'static>(
+    url: &str,
+    args: impl ServerFn<C>,
+    enc: Encoding,
+    on_progress: std::rc::Rc<dyn Fn(Progress)>,
+) -> Result<T, ServerFnError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Sized,
+{
+    call_server_fn_inner(url, args, enc, Some(on_progress)).await
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn call_server_fn_inner<T, C: 'static>(
+    url: &str,
+    args: impl ServerFn<C>,
+    enc: Encoding,
+    on_progress: Option<std::rc::Rc<dyn Fn(Progress)>>,
+) -> Result<T, ServerFnError>
 where
     T: serde::Serialize + serde::de::DeserializeOwned + Sized,
 {
@@ -433,6 +696,64 @@ where
         Encoding::Cbor | Encoding::GetCBOR => "application/cbor",
     };
 
+    let upload_size = match (&enc, &args_encoded) {
+        (Encoding::GetJSON | Encoding::GetCBOR, _) => 0,
+        (_, Payload::Binary(b)) => b.len() as u64,
+        (_, Payload::Url(s)) => s.len() as u64,
+    };
+    if let Some(on_progress) = &on_progress {
+        on_progress(Progress {
+            direction: ProgressDirection::Upload,
+            loaded: 0,
+            total: Some(upload_size),
+        });
+    }
+
+    // `fetch` can't report the progress of sending a body, so calls whose
+    // progress is watched are sent with `XMLHttpRequest` instead
+    #[cfg(target_arch = "wasm32")]
+    if let Some(on_progress) = on_progress {
+        let (method, url, body) = match (&enc, args_encoded) {
+            (Encoding::Url | Encoding::Cbor, Payload::Binary(b)) => {
+                ("POST", url.to_string(), Some(b))
+            }
+            (Encoding::Url | Encoding::Cbor, Payload::Url(s)) => {
+                ("POST", url.to_string(), Some(s.into_bytes()))
+            }
+            (Encoding::GetCBOR | Encoding::GetJSON, Payload::Binary(_)) => {
+                panic!(
+                    "Binary data cannot be transferred via GET request in a \
+                     query string. Please try using the CBOR encoding."
+                )
+            }
+            (Encoding::GetCBOR | Encoding::GetJSON, Payload::Url(s)) => {
+                ("GET", format!("{url}?{s}"), None)
+            }
+        };
+        let csrf_token = csrf::client_csrf_token();
+        let mut headers = vec![
+            ("Content-Type", content_type_header),
+            ("Accept", accept_header),
+        ];
+        if method == "POST" {
+            headers.push((csrf::CSRF_HEADER, csrf_token.as_str()));
+        }
+
+        let (status, status_text, body) = send_with_progress(
+            method,
+            &url,
+            &headers,
+            body.as_deref(),
+            on_progress,
+        )
+        .await?;
+        if status == 401 || status == 403 || (500..=599).contains(&status) {
+            return Err(serde_json::from_slice(&body)
+                .unwrap_or(ServerFnError::ServerError(status_text)));
+        }
+        return decode_body(&enc, &body);
+    }
+
     #[cfg(target_arch = "wasm32")]
     let resp = match &enc {
         Encoding::Url | Encoding::Cbor => match args_encoded {
@@ -442,6 +763,7 @@ where
                 gloo_net::http::Request::post(url)
                     .header("Content-Type", content_type_header)
                     .header("Accept", accept_header)
+                    .header(csrf::CSRF_HEADER, &csrf::client_csrf_token())
                     .body(js_array)
                     .send()
                     .await
@@ -450,6 +772,7 @@ where
             Payload::Url(s) => gloo_net::http::Request::post(url)
                 .header("Content-Type", content_type_header)
                 .header("Accept", accept_header)
+                .header(csrf::CSRF_HEADER, &csrf::client_csrf_token())
                 .body(s)
                 .send()
                 .await
@@ -472,12 +795,18 @@ where
         },
     };
     #[cfg(not(target_arch = "wasm32"))]
+    let csrf_token = csrf::client_csrf_token();
+    #[cfg(not(target_arch = "wasm32"))]
+    let csrf_cookie = format!("{}={csrf_token}", csrf::CSRF_COOKIE);
+    #[cfg(not(target_arch = "wasm32"))]
     let resp = match &enc {
         Encoding::Url | Encoding::Cbor => match args_encoded {
             Payload::Binary(b) => CLIENT
                 .post(url)
                 .header("Content-Type", content_type_header)
                 .header("Accept", accept_header)
+                .header("Cookie", &csrf_cookie)
+                .header(csrf::CSRF_HEADER, &csrf_token)
                 .body(b)
                 .send()
                 .await
@@ -486,6 +815,8 @@ where
                 .post(url)
                 .header("Content-Type", content_type_header)
                 .header("Accept", accept_header)
+                .header("Cookie", &csrf_cookie)
+                .header(csrf::CSRF_HEADER, &csrf_token)
                 .body(s)
                 .send()
                 .await
@@ -510,11 +841,21 @@ where
         },
     };
 
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(on_progress) = &on_progress {
+        on_progress(Progress {
+            direction: ProgressDirection::Upload,
+            loaded: upload_size,
+            total: Some(upload_size),
+        });
+    }
+
     // check for error status
     let status = resp.status();
     #[cfg(not(target_arch = "wasm32"))]
     let status = status.as_u16();
-    if (500..=599).contains(&status) {
+    // 401 and 403 are sent if a guard or the CSRF check fails
+    if status == 401 || status == 403 || (500..=599).contains(&status) {
         let text = resp.text().await.unwrap_or_default();
         #[cfg(target_arch = "wasm32")]
         let status_text = resp.status_text();
@@ -524,6 +865,13 @@ where
             .unwrap_or(ServerFnError::ServerError(status_text)));
     }
 
+    // Read the body in chunks if anyone is watching its progress
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(on_progress) = on_progress {
+        let body = read_body_with_progress(resp, &*on_progress).await?;
+        return decode_body(&enc, &body);
+    }
+
     // Decoding the body of the request
     if (enc == Encoding::Cbor) || (enc == Encoding::GetCBOR) {
         #[cfg(target_arch = "wasm32")]
@@ -555,6 +903,111 @@ where
     }
 }
 
+#[cfg(not(feature = "ssr"))]
+fn decode_body<T>(enc: &Encoding, body: &[u8]) -> Result<T, ServerFnError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if (*enc == Encoding::Cbor) || (*enc == Encoding::GetCBOR) {
+        ciborium::de::from_reader(body)
+            .map_err(|e| ServerFnError::Deserialization(e.to_string()))
+    } else {
+        serde_json::from_slice(body)
+            .map_err(|e| ServerFnError::Deserialization(e.to_string()))
+    }
+}
+
+/// Sends a request with `XMLHttpRequest`, reporting the progress of both
+/// bodies, and resolves to the status, status text and response body.
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+async fn send_with_progress(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&[u8]>,
+    on_progress: std::rc::Rc<dyn Fn(Progress)>,
+) -> Result<(u16, String, Vec<u8>), ServerFnError> {
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::{ProgressEvent, XmlHttpRequest, XmlHttpRequestResponseType};
+
+    let request_err = |e: JsValue| ServerFnError::Request(format!("{e:?}"));
+    let xhr = XmlHttpRequest::new().map_err(request_err)?;
+    xhr.open(method, url).map_err(request_err)?;
+    xhr.set_response_type(XmlHttpRequestResponseType::Arraybuffer);
+    for (name, value) in headers {
+        xhr.set_request_header(name, value).map_err(request_err)?;
+    }
+
+    let listener = |direction: ProgressDirection| {
+        let on_progress = std::rc::Rc::clone(&on_progress);
+        Closure::wrap(Box::new(move |ev: ProgressEvent| {
+            on_progress(Progress {
+                direction,
+                loaded: ev.loaded() as u64,
+                total: ev.length_computable().then(|| ev.total() as u64),
+            })
+        }) as Box<dyn FnMut(ProgressEvent)>)
+        .into_js_value()
+    };
+    let on_upload = listener(ProgressDirection::Upload);
+    xhr.upload()
+        .map_err(request_err)?
+        .set_onprogress(Some(on_upload.unchecked_ref()));
+    let on_download = listener(ProgressDirection::Download);
+    xhr.set_onprogress(Some(on_download.unchecked_ref()));
+
+    let done = js_sys::Promise::new(&mut |resolve, reject| {
+        xhr.set_onload(Some(&resolve));
+        xhr.set_onerror(Some(&reject));
+        xhr.set_onabort(Some(&reject));
+    });
+    let body = body.map(js_sys::Uint8Array::from);
+    xhr.send_with_opt_buffer_source(body.as_deref())
+        .map_err(request_err)?;
+    wasm_bindgen_futures::JsFuture::from(done)
+        .await
+        .map_err(|_| ServerFnError::Request("the request failed".into()))?;
+
+    let status = xhr.status().map_err(request_err)?;
+    let status_text = xhr.status_text().map_err(request_err)?;
+    let body = xhr.response().map_err(request_err)?;
+    let body = if body.is_null() {
+        Vec::new()
+    } else {
+        js_sys::Uint8Array::new(&body).to_vec()
+    };
+    Ok((status, status_text, body))
+}
+
+#[cfg(all(not(feature = "ssr"), not(target_arch = "wasm32")))]
+async fn read_body_with_progress(
+    mut resp: reqwest::Response,
+    on_progress: &dyn Fn(Progress),
+) -> Result<Vec<u8>, ServerFnError> {
+    let total = resp.content_length();
+    let mut body = Vec::new();
+    on_progress(Progress {
+        direction: ProgressDirection::Download,
+        loaded: 0,
+        total,
+    });
+
+    while let Some(chunk) = resp
+        .chunk()
+        .await
+        .map_err(|e| ServerFnError::Deserialization(e.to_string()))?
+    {
+        body.extend_from_slice(&chunk);
+        on_progress(Progress {
+            direction: ProgressDirection::Download,
+            loaded: body.len() as u64,
+            total,
+        });
+    }
+
+    Ok(body)
+}
+
 // Lazily initialize the client to be reused for all server function calls.
 #[cfg(any(all(not(feature = "ssr"), not(target_arch = "wasm32")), doc))]
 static CLIENT: once_cell::sync::Lazy<reqwest::Client> =
@@ -0,0 +1,57 @@
+use server_fn::csrf::*;
+
+#[test]
+fn token_is_found_among_other_cookies() {
+    assert_eq!(
+        csrf_token_from_cookies("a=1; leptos_csrf=abc; b=2"),
+        Some("abc")
+    );
+    assert_eq!(csrf_token_from_cookies("leptos_csrf="), None);
+    assert_eq!(csrf_token_from_cookies("not_leptos_csrf=abc"), None);
+}
+
+#[test]
+fn token_is_found_in_form_body() {
+    assert_eq!(csrf_token_from_form(b"name=x&_csrf=abc&y=2"), Some("abc"));
+    assert_eq!(csrf_token_from_form(b"name=x&x_csrf=abc"), None);
+}
+
+#[test]
+fn tokens_must_match() {
+    assert!(verify_csrf_token(Some("abc"), Some("abc")));
+    assert!(!verify_csrf_token(Some("abc"), Some("abd")));
+    assert!(!verify_csrf_token(Some("abc"), Some("abcd")));
+    assert!(!verify_csrf_token(Some("abc"), None));
+    assert!(!verify_csrf_token(None, Some("abc")));
+    assert!(!verify_csrf_token(Some(""), Some("")));
+}
+
+#[test]
+fn cookie_is_secure() {
+    let cookie = csrf_cookie("abc");
+    assert!(cookie.starts_with("leptos_csrf=abc;"));
+    assert!(cookie.contains("; Secure"));
+    assert!(cookie.contains("; SameSite=Lax"));
+}
+
+// the protection is a global switch, so it is only turned on in this test
+#[cfg(feature = "ssr")]
+#[test]
+fn protection_is_opt_in() {
+    assert!(!csrf_protection_enabled());
+    assert!(check_csrf(None, None, b""));
+    assert_eq!(issue_csrf_token(None), None);
+
+    enable_csrf_protection();
+    assert!(!check_csrf(None, None, b""));
+    assert!(!check_csrf(Some("leptos_csrf=abc"), Some("abd"), b""));
+    assert!(check_csrf(Some("leptos_csrf=abc"), Some("abc"), b""));
+    assert!(check_csrf(Some("leptos_csrf=abc"), None, b"_csrf=abc"));
+
+    let (token, set_cookie) = issue_csrf_token(None).unwrap();
+    assert_eq!(set_cookie, Some(csrf_cookie(&token.0)));
+    assert_eq!(
+        issue_csrf_token(Some("leptos_csrf=abc")),
+        Some((CsrfToken("abc".to_string()), None))
+    );
+}
@@ -0,0 +1,61 @@
+use server_fn::{is_form_submission, ServerFnError, ERROR_QUERY_PARAM};
+
+const URLENCODED: Option<&str> = Some("application/x-www-form-urlencoded");
+const BROWSER_ACCEPT: Option<&str> =
+    Some("text/html,application/xhtml+xml,*/*;q=0.8");
+
+#[test]
+fn browser_forms_are_form_submissions() {
+    assert!(is_form_submission(URLENCODED, BROWSER_ACCEPT));
+    assert!(is_form_submission(
+        Some("multipart/form-data; boundary=----abc"),
+        BROWSER_ACCEPT
+    ));
+    assert!(is_form_submission(
+        Some("Application/X-WWW-Form-Urlencoded; charset=UTF-8"),
+        Some("TEXT/HTML")
+    ));
+}
+
+#[test]
+fn api_clients_are_not_form_submissions() {
+    // the server_fn client, and ActionForm with JavaScript
+    assert!(!is_form_submission(URLENCODED, Some("application/json")));
+    assert!(!is_form_submission(
+        URLENCODED,
+        Some("application/x-www-form-urlencoded")
+    ));
+    // clients that accept anything, or don't say
+    assert!(!is_form_submission(URLENCODED, Some("*/*")));
+    assert!(!is_form_submission(URLENCODED, None));
+    assert!(!is_form_submission(
+        Some("application/cbor"),
+        BROWSER_ACCEPT
+    ));
+    assert!(!is_form_submission(None, BROWSER_ACCEPT));
+}
+
+#[test]
+fn redirect_replaces_an_earlier_error() {
+    let error = ServerFnError::ServerError("try again".into());
+    let first = error.redirect_location("/signup?plan=pro");
+    let second = error.redirect_location(&first);
+    assert_eq!(first, second);
+    assert_eq!(second.matches(ERROR_QUERY_PARAM).count(), 1);
+    assert!(second.starts_with("/signup?plan=pro&"));
+}
+
+#[test]
+fn redirect_carries_the_error() {
+    let error = ServerFnError::Forbidden("admins only".into());
+    let location = error.redirect_location("/");
+    let (_, query) = location.split_once('?').unwrap();
+    let params: std::collections::HashMap<String, String> =
+        serde_qs::from_str(query).unwrap();
+    let sent: ServerFnError =
+        serde_json::from_str(&params[ERROR_QUERY_PARAM]).unwrap();
+    match sent {
+        ServerFnError::Forbidden(message) => assert_eq!(message, "admins only"),
+        other => panic!("unexpected error {other:?}"),
+    }
+}
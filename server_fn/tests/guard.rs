@@ -0,0 +1,50 @@
+use futures::executor::block_on;
+use server_fn::{ServerFnError, ServerFnGuard};
+
+fn require_role(
+    role: &'static str,
+) -> impl ServerFnGuard<Option<&'static str>> + Clone {
+    move |user: Option<&'static str>| async move {
+        match user {
+            None => Err(ServerFnError::Unauthorized("sign in".into())),
+            Some(user) if user != role => {
+                Err(ServerFnError::Forbidden(format!("must be {role}")))
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+async fn always_fails(_: ()) -> Result<(), ServerFnError> {
+    Err(ServerFnError::ServerError("down".into()))
+}
+
+#[test]
+fn async_closures_are_guards() {
+    let guard = require_role("admin");
+    assert!(block_on(guard.clone().check(Some("admin"))).is_ok());
+    assert!(matches!(
+        block_on(guard.clone().check(Some("editor"))),
+        Err(ServerFnError::Forbidden(_))
+    ));
+    assert!(matches!(
+        block_on(guard.check(None)),
+        Err(ServerFnError::Unauthorized(_))
+    ));
+}
+
+#[test]
+fn async_fns_are_guards() {
+    assert!(block_on(always_fails.check(())).is_err());
+}
+
+#[test]
+fn auth_errors_have_their_own_status() {
+    assert_eq!(
+        ServerFnError::Unauthorized(String::new()).status_code(),
+        401
+    );
+    assert_eq!(ServerFnError::Forbidden(String::new()).status_code(), 403);
+    assert_eq!(ServerFnError::ServerError(String::new()).status_code(), 500);
+    assert_eq!(ServerFnError::Request(String::new()).status_code(), 500);
+}
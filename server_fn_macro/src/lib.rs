@@ -77,6 +77,7 @@ pub fn server_macro_impl(
         prefix,
         encoding,
         fn_path,
+        guard,
         ..
     } = syn::parse2::<ServerFnName>(args)?;
     let prefix = prefix.unwrap_or_else(|| Literal::string(""));
@@ -197,6 +198,27 @@ pub fn server_macro_impl(
         .map(|path| quote!(#path))
         .unwrap_or_else(|| quote! { server_fn });
 
+    // the guard is given the context the function is called with, so it can
+    // only be used if the function takes one
+    let guard_check = guard.map(|guard| {
+        let cx = match (&server_context, cx_arg) {
+            (None, _) => quote! { () },
+            (Some(_), Some(FnArg::Typed(t))) => {
+                let pat = &t.pat;
+                quote! { #pat }
+            }
+            (Some(ctx), _) => abort!(
+                guard,
+                "a server function with a guard must take a `{}` as its \
+                 first argument",
+                ctx.ty
+            ),
+        };
+        quote! {
+            #server_fn_path::ServerFnGuard::check(#guard, #cx).await?;
+        }
+    });
+
     let key_env_var = match option_env!("SERVER_FN_OVERRIDE_KEY") {
         Some(_) => "SERVER_FN_OVERRIDE_KEY",
         None => "CARGO_MANIFEST_DIR",
@@ -259,6 +281,7 @@ pub fn server_macro_impl(
         #docs
         #[cfg(feature = "ssr")]
         #vis async fn #fn_name(#(#fn_args),*) #output_arrow #return_ty {
+            #guard_check
             #block
         }
 
@@ -287,6 +310,12 @@ struct ServerFnName {
     encoding: Path,
     _comma3: Option<Token![,]>,
     fn_path: Option<Literal>,
+    _comma4: Option<Token![,]>,
+    guard: Option<Expr>,
+}
+
+mod kw {
+    syn::custom_keyword!(guard);
 }
 
 impl Parse for ServerFnName {
@@ -309,6 +338,14 @@ impl Parse for ServerFnName {
             .unwrap_or_else(|_| syn::parse_quote!(Encoding::Url));
         let _comma3 = input.parse()?;
         let fn_path = input.parse()?;
+        let _comma4 = input.parse()?;
+        let guard = if input.peek(kw::guard) {
+            input.parse::<kw::guard>()?;
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
         Ok(Self {
             struct_name,
@@ -318,6 +355,8 @@ impl Parse for ServerFnName {
             encoding,
             _comma3,
             fn_path,
+            _comma4,
+            guard,
         })
     }
 }